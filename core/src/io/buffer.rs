@@ -1,26 +1,72 @@
-use godot::{classes::ProjectSettings, prelude::*};
-use std::{ops::Range, sync::Arc};
-use crate::io::bytesource::{ByteSource, DiskFileSource, MemoryByteSource};
+use godot::{classes::ProjectSettings, global::Error, prelude::*};
+use std::{iter::Peekable, str::Chars, sync::Arc};
+use half::f16;
+use crate::io::{
+    diff::{diff_sources, DiffKind, DiffOptions},
+    bytesource::{open_file_source, ByteSource, CacheStats, MemoryByteSource, OpenMode, OverlaySource, SubrangeSource},
+    hash::{hash_source, HashAlgorithm},
+    hexdump::{hexdump, HexdumpOptions},
+    relocation::{RelocationBase, Relocations},
+    schema::NebulaSchema,
+    search::{self, Pattern},
+    text::TextEncoding,
+};
 
 type BoxedByteSource = Arc<dyn ByteSource + Send + Sync + 'static>;
 
+/// The first failure recorded by a [NebulaBuffer] since its error state was last cleared.
+struct BufferError {
+    code: Error,
+    offset: u64,
+    size: u64,
+    message: String,
+}
+
+/// An offset stored in the buffer that [NebulaBuffer::splice_impl] keeps pointing at the same data.
+struct OffsetField {
+    /// Position of the field itself.
+    pos: u64,
+    /// Size of the field in bytes.
+    width: u8,
+    /// Position the stored value is relative to.
+    base: u64,
+    big_endian: bool,
+}
+
 #[derive(GodotClass)]
 #[class(base = RefCounted)]
 /// A buffer for reading binary data from files or memory sources.
 /// 
-/// Provides methods for reading integers, floats, and strings of various encodings,
+/// Provides methods for reading integers, floats, and strings of various encodings (including Shift-JIS),
 /// supports both little-endian and big-endian formats, and allows peeking or seeking
 /// without advancing the cursor. Also supports Python-style format unpacking with [method unpack].
+/// Bit fields and bit streams are handled by [method read_bits] and [method write_bits].
 pub struct NebulaBuffer {
     #[var] 
     /// Determines the endianness used for multi-byte reads.
     /// `false` = little-endian (Default), `true` = big-endian.
-    big_endian: bool,
+    pub(crate) big_endian: bool,
+
+    #[var]
+    /// When `true`, the first failed read or write logs an error and emits [signal error_occurred].
+    /// The error is recorded with or without strict mode, see [method get_error].
+    strict: bool,
+
+    #[var]
+    /// Determines the bit order used by [method read_bits] and [method write_bits].
+    /// `false` = most significant bit first (Default), `true` = least significant bit first.
+    lsb_first: bool,
 
-    offset: usize,
+    offset: u64,
+    /// Partially consumed byte left by the bit cursor, as `(offset right after it, bits used)`.
+    /// Only meaningful while [NebulaBuffer::offset] still equals the first value.
+    bit_state: Option<(u64, u8)>,
 
     pub source: Option<BoxedByteSource>,
-    range: Range<u64>,
+    overlay: Option<Arc<OverlaySource>>,
+    error: Option<BufferError>,
+    offset_fields: Vec<OffsetField>,
+    relocations: Relocations,
 
     base: Base<RefCounted>,
 }
@@ -32,9 +78,15 @@ impl IRefCounted for NebulaBuffer {
 
         Self {
             big_endian: false,
+            strict: false,
+            lsb_first: false,
             offset: 0,
-            source: Some(memory_source.clone()),
-            range: 0..memory_source.len(),
+            bit_state: None,
+            source: Some(memory_source),
+            overlay: None,
+            error: None,
+            offset_fields: Vec::new(),
+            relocations: Relocations::default(),
             base,
         }
     }
@@ -48,21 +100,109 @@ impl NebulaBuffer {
             .expect("NebulaBuffer used without a source")
     }
 
+    /// Returns the source of `other`, which may be this very buffer and then must not be bound again.
+    fn source_of(&self, other: &Gd<NebulaBuffer>) -> BoxedByteSource {
+        if other.instance_id() == self.base().instance_id() {
+            return self.source().clone();
+        }
+        other.bind().source().clone()
+    }
+
     #[inline(always)]
     fn abs_pos(&self) -> u64 {
-        self.range.start + self.offset as u64
+        self.offset
     }
 
     #[inline(always)]
-    fn read_u8_impl(&mut self, advance: bool) -> u8 {
-        let pos = self.abs_pos();
+    fn len(&self) -> u64 {
+        self.source.as_ref().map_or(0, |src| src.len())
+    }
+
+    /// Records a failure unless one is already pending. In strict mode the first failure
+    /// is also logged and reported through [signal error_occurred].
+    fn set_error(&mut self, code: Error, offset: u64, size: u64, message: String) {
+        if self.error.is_some() {
+            return;
+        }
 
-        let mut out = 0u8;
-        if let Ok(bytes) = self.source().read_range(pos, 1) {
-            if !bytes.is_empty() {
-                out = bytes[0];
+        if self.strict {
+            godot_error!(
+                "NebulaBuffer: {} (offset 0x{:X}, size {})",
+                message,
+                offset,
+                size
+            );
+        }
+
+        self.error = Some(BufferError { code, offset, size, message });
+
+        if self.strict {
+            self.signals()
+                .error_occurred()
+                .emit(code, offset as i64, size as i64);
+        }
+    }
+
+    /// Fills `buf` from the absolute offset `pos` without moving the cursor, recording an error
+    /// on I/O failure or if fewer bytes are available. Returns the number of bytes read.
+    pub(crate) fn read_into(&mut self, pos: u64, buf: &mut [u8]) -> usize {
+        match self.source().read_into(pos, buf) {
+            Ok(n) => {
+                if n < buf.len() {
+                    self.set_error(
+                        Error::ERR_FILE_EOF,
+                        pos,
+                        buf.len() as u64,
+                        format!("read past the end of the buffer ({} bytes available)", n),
+                    );
+                }
+                n
+            }
+            Err(err) => {
+                self.set_error(Error::ERR_FILE_CANT_READ, pos, buf.len() as u64, err.to_string());
+                0
             }
         }
+    }
+
+    /// Reads `size` bytes at `pos` into a new vector, see [NebulaBuffer::read_into].
+    fn read_checked(&mut self, pos: u64, size: usize) -> Vec<u8> {
+        let available = self.len().saturating_sub(pos).min(size as u64) as usize;
+        let mut buf = vec![0; available];
+        let n = self.read_into(pos, &mut buf);
+        buf.truncate(n);
+
+        if available < size {
+            self.set_error(
+                Error::ERR_FILE_EOF,
+                pos,
+                size as u64,
+                format!("read past the end of the buffer ({} bytes available)", n),
+            );
+        }
+        buf
+    }
+
+    fn write_checked(&mut self, pos: u64, bytes: &[u8]) {
+        let Some(src) = &self.source else {
+            return;
+        };
+
+        if let Err(err) = src.write_range(pos, bytes) {
+            let code = match err.kind() {
+                std::io::ErrorKind::ReadOnlyFilesystem | std::io::ErrorKind::PermissionDenied => Error::ERR_FILE_NO_PERMISSION,
+                _ => Error::ERR_FILE_CANT_WRITE,
+            };
+            self.set_error(code, pos, bytes.len() as u64, err.to_string());
+        }
+    }
+
+    #[inline(always)]
+    fn read_u8_impl(&mut self, advance: bool) -> u8 {
+        let pos = self.abs_pos();
+        let mut out = [0u8; 1];
+        self.read_into(pos, &mut out);
+        let out = out[0];
 
         if advance {
             self.offset += 1;
@@ -74,33 +214,25 @@ impl NebulaBuffer {
     #[inline(always)]
     fn write_u8_impl(&mut self, value: u8) {
         let pos = self.abs_pos();
-        if let Some(src) = &self.source {
-            let _ = src.write_range(pos, &[value]);
-        }
+        self.write_checked(pos, &[value]);
         self.offset += 1;
     }
 
     #[inline(always)]
     fn write_bytes_impl(&mut self, bytes: &[u8]) {
         let pos = self.abs_pos();
-        if let Some(src) = &self.source {
-            let _ = src.write_range(pos, bytes);
-        }
-        self.offset += bytes.len();
+        self.write_checked(pos, bytes);
+        self.offset += bytes.len() as u64;
     }
 
     #[inline(always)]
     fn read_bytes_impl<const N: usize>(&mut self, advance: bool) -> [u8; N] {
         let pos = self.abs_pos();
         let mut buf = [0u8; N];
-
-        if let Ok(bytes) = self.source().read_range(pos, N) {
-            let len = bytes.len().min(N);
-            buf[..len].copy_from_slice(&bytes[..len]);
-        }
+        self.read_into(pos, &mut buf);
 
         if advance {
-            self.offset += N;
+            self.offset += N as u64;
         }
 
         buf
@@ -109,6 +241,15 @@ impl NebulaBuffer {
 
 #[godot_api]
 impl NebulaBuffer {
+    /// Emitted in [member strict] mode on the first failed read or write.
+    ///
+    /// Parameters:
+    /// - `error`: [constant @GlobalScope.ERR_FILE_EOF] for out-of-bounds reads, [constant @GlobalScope.ERR_FILE_CANT_READ],
+    ///   [constant @GlobalScope.ERR_FILE_CANT_WRITE] or [constant @GlobalScope.ERR_FILE_NO_PERMISSION] for I/O failures.
+    /// - `offset`: Offset of the failed access.
+    /// - `size`: Number of bytes that were requested.
+    #[signal] fn error_occurred(error: Error, offset: i64, size: i64);
+
     #[func]
     pub fn from_bytes(bytes: PackedByteArray) -> Gd<NebulaBuffer> {
         let vec = bytes.to_vec();
@@ -119,16 +260,32 @@ impl NebulaBuffer {
         buf
     }
 
+    /// Open mode: the file must exist and is opened read-only.
+    /// Large files opened this way are memory-mapped.
+    #[constant] pub const OPEN_READ: i32 = 0;
+
+    /// Open mode: the file must exist and is opened for reading and writing.
+    #[constant] pub const OPEN_READ_WRITE: i32 = 1;
+
+    /// Open mode: the file is created, or truncated if it exists, and opened for reading and writing.
+    #[constant] pub const OPEN_CREATE: i32 = 2;
+
     #[func]
     /// Creates a [NebulaBuffer] from a file path.
+    /// `mode` is one of [constant OPEN_READ], [constant OPEN_READ_WRITE] or [constant OPEN_CREATE].
     /// Returns a new buffer instance. Logs an error if the file cannot be opened.
-    pub fn from_file(path: GString) -> Gd<NebulaBuffer> {
+    pub fn from_file(path: GString, #[opt(default = 1)] mode: i32) -> Gd<NebulaBuffer> {
         let path_str = ProjectSettings::singleton()
             .globalize_path(&path)
             .to_string();
 
-        let source = match DiskFileSource::new(&path_str) {
-            Ok(src) => Some(Arc::new(src) as BoxedByteSource),
+        let Some(open_mode) = Self::open_mode(mode) else {
+            godot_error!("NebulaBuffer::from_file: invalid open mode {}", mode);
+            return NebulaBuffer::new_gd();
+        };
+
+        let source = match open_file_source(&path_str, open_mode) {
+            Ok(src) => Some(src),
             Err(err) => {
                 godot_error!(
                     "NebulaBuffer::from_file: failed to open {}: {:?}",
@@ -147,38 +304,338 @@ impl NebulaBuffer {
         buf
     }
 
+    #[func]
+    /// Returns a new buffer over `size` bytes of this buffer starting at `offset`, or over the rest of
+    /// it if `size` is negative. No data is copied: reads and writes go through to this buffer's source,
+    /// so nested formats can be parsed in place.
+    ///
+    /// The slice has its own cursor, starting at 0, and its own [member big_endian], initially copied
+    /// from this buffer. It cannot grow, and writes past its end fail.
+    /// Logs an error and returns an empty buffer if the range lies outside this buffer.
+    pub fn slice(&self, offset: i64, #[opt(default = -1)] size: i64) -> Gd<NebulaBuffer> {
+        let mut buf = NebulaBuffer::new_gd();
+        buf.bind_mut().big_endian = self.big_endian;
+
+        let Some(src) = &self.source else {
+            return buf;
+        };
+
+        let len = src.len();
+        let size = if size < 0 { len.saturating_sub(offset.max(0) as u64) } else { size as u64 };
+        if offset < 0 || offset as u64 + size > len {
+            godot_error!(
+                "NebulaBuffer::slice: range 0x{:X}+{} outside buffer of size {}",
+                offset,
+                size,
+                len
+            );
+            return buf;
+        }
+
+        let slice = Arc::new(SubrangeSource::new(src.clone(), offset as u64, size));
+        buf.bind_mut().set_source(slice);
+        buf
+    }
+
+    #[func]
+    /// Returns `false` if writes to this buffer are rejected, e.g. because its file was
+    /// opened with [constant OPEN_READ].
+    pub fn is_writable(&self) -> bool {
+        self.source.as_ref().is_some_and(|src| src.is_writable())
+    }
+
+    #[func]
+    /// Returns a new buffer over the same data in which every write is kept in a
+    /// copy-on-write layer instead of reaching this buffer's source.
+    ///
+    /// The changes can then be written out with [method commit_overlay], stored with
+    /// [method save_overlay] or dropped with [method discard_overlay].
+    pub fn create_overlay(&self) -> Gd<NebulaBuffer> {
+        let mut buf = NebulaBuffer::new_gd();
+        if let Some(src) = &self.source {
+            buf.bind_mut().set_overlay(Arc::new(OverlaySource::new(src.clone())));
+        }
+        buf.bind_mut().big_endian = self.big_endian;
+        buf
+    }
+
+    #[func]
+    /// Returns `true` if this buffer has a copy-on-write layer, see [method create_overlay].
+    pub fn has_overlay(&self) -> bool {
+        self.overlay.is_some()
+    }
+
+    #[func]
+    /// Returns `true` if any write has been recorded in the copy-on-write layer.
+    pub fn has_overlay_changes(&self) -> bool {
+        self.overlay.as_ref().is_some_and(|overlay| overlay.is_dirty())
+    }
+
+    #[func]
+    /// Writes the original data with all recorded changes applied to a new file at `path`.
+    /// Returns `false` if the buffer has no overlay or the file cannot be written.
+    pub fn commit_overlay(&self, path: GString) -> bool {
+        self.with_overlay("commit_overlay", &path, |overlay, path| overlay.commit_to(path))
+    }
+
+    #[func]
+    /// Saves only the recorded changes to `path` so they can be restored later with [method load_overlay].
+    pub fn save_overlay(&self, path: GString) -> bool {
+        self.with_overlay("save_overlay", &path, |overlay, path| overlay.save_delta(path))
+    }
+
+    #[func]
+    /// Replaces the recorded changes with the ones saved at `path` by [method save_overlay].
+    pub fn load_overlay(&self, path: GString) -> bool {
+        self.with_overlay("load_overlay", &path, |overlay, path| overlay.load_delta(path))
+    }
+
+    #[func]
+    /// Throws away every change recorded in the copy-on-write layer.
+    pub fn discard_overlay(&mut self) {
+        if let Some(overlay) = &self.overlay {
+            overlay.discard();
+        }
+    }
+
+    #[func]
+    /// Returns `true` if a read or write has failed since the buffer was created
+    /// or [method clear_error] was last called.
+    pub fn has_error(&self) -> bool {
+        self.error.is_some()
+    }
+
+    #[func]
+    /// Returns the first error recorded since the last [method clear_error], or [constant @GlobalScope.OK].
+    ///
+    /// Reads that run past the end report [constant @GlobalScope.ERR_FILE_EOF] and return zero-filled data.
+    /// Later failures do not overwrite the recorded one.
+    pub fn get_error(&self) -> Error {
+        self.error.as_ref().map_or(Error::OK, |err| err.code)
+    }
+
+    #[func]
+    /// Returns the offset of the access that caused the recorded error, or `-1` if there is none.
+    pub fn get_error_offset(&self) -> i64 {
+        self.error.as_ref().map_or(-1, |err| err.offset as i64)
+    }
+
+    #[func]
+    /// Returns the number of bytes requested by the access that caused the recorded error.
+    pub fn get_error_size(&self) -> i64 {
+        self.error.as_ref().map_or(0, |err| err.size as i64)
+    }
+
+    #[func]
+    /// Returns a human-readable description of the recorded error.
+    pub fn get_error_message(&self) -> GString {
+        self.error.as_ref().map_or(GString::new(), |err| err.message.to_godot())
+    }
+
+    #[func]
+    /// Clears the recorded error so the next failure is reported again.
+    pub fn clear_error(&mut self) {
+        self.error = None;
+    }
+
+    #[func]
+    /// Returns the read cache statistics of the source behind this buffer.
+    ///
+    /// The dictionary contains `hits`, `misses`, `read_ahead`, `evictions`, `cached_pages`
+    /// and `page_size`. It is empty if the buffer is not backed by a cached source,
+    /// e.g. when it was created with [method from_bytes].
+    pub fn get_cache_stats(&self) -> VarDictionary {
+        Self::cache_stats_to_dict(self.source.as_ref().and_then(|src| src.cache_stats()))
+    }
+
     #[func]
     /// Returns the current buffer offset.
-    pub fn get_offset(&self) -> i32 {
-        self.offset as i32
+    pub fn get_offset(&self) -> i64 {
+        self.offset as i64
     }
 
     #[func]
     /// Returns `true` if the cursor has reached the end of the buffer.
     pub fn at_end(&self) -> bool {
-        self.abs_pos() >= self.range.end
+        self.abs_pos() >= self.len()
     }
 
     #[func]
     /// Moves the buffer cursor forward or backward by the given amount.
-    pub fn seek(&mut self, amount: i32) {
-        if amount >= 0 {
-            self.offset = self.offset.saturating_add(amount as usize);
-        } else {
-            self.offset = self.offset.saturating_sub((-amount) as usize);
-        }
+    pub fn seek(&mut self, amount: i64) {
+        self.offset = self.offset.saturating_add_signed(amount);
+        self.bit_state = None;
     }
 
     #[func]
     /// Sets the buffer cursor to the given absolute position.
-    pub fn goto(&mut self, position: i32) {
-        self.offset = position.max(0) as usize;
+    pub fn goto(&mut self, position: i64) {
+        self.offset = position.max(0) as u64;
+        self.bit_state = None;
+    }
+
+    #[func]
+    /// Returns the position of the bit cursor in bits from the start of the buffer.
+    /// After a partial [method read_bits] or [method write_bits] this points inside the byte
+    /// before [method get_offset]; otherwise it is `get_offset() * 8`.
+    pub fn get_bit_position(&self) -> i64 {
+        match self.partial_byte() {
+            Some((pos, used)) => (pos * 8 + used as u64) as i64,
+            None => (self.offset * 8) as i64,
+        }
+    }
+
+    #[func]
+    /// Moves the bit cursor to the given absolute bit position.
+    pub fn goto_bit(&mut self, position: i64) {
+        let position = position.max(0) as u64;
+        self.set_bit_cursor(position / 8, (position % 8) as u32);
+    }
+
+    #[func]
+    /// Reads `count` bits (1 to 64) at the bit cursor and advances it.
+    /// The bits are assembled in the order set by [member lsb_first].
+    ///
+    /// The bit cursor shares its position with the byte cursor: a partially read byte counts as
+    /// consumed for [method get_offset], so byte reads continue at the next whole byte.
+    /// Moving the cursor with [method seek] or [method goto] drops any partial byte.
+    pub fn read_bits(&mut self, count: i32) -> i64 {
+        self.read_bits_impl(count, true) as i64
+    }
+
+    #[func]
+    /// Reads `count` bits like [method read_bits] without advancing the cursor.
+    pub fn peek_bits(&mut self, count: i32) -> i64 {
+        self.read_bits_impl(count, false) as i64
+    }
+
+    #[func]
+    /// Writes the lowest `count` bits (1 to 64) of `value` at the bit cursor and advances it.
+    /// Other bits of the bytes touched are preserved.
+    pub fn write_bits(&mut self, count: i32, value: i64) {
+        let count = count.clamp(0, 64) as u32;
+        if count == 0 {
+            return;
+        }
+
+        let (pos, used) = self.bit_cursor();
+        let end = used + count;
+        let size = end.div_ceil(8) as usize;
+
+        let mut bytes = [0u8; 9];
+        let _ = self.source().read_into(pos, &mut bytes[..size]);
+
+        for i in 0..count {
+            let bit = if self.lsb_first {
+                (value as u64 >> i) & 1
+            } else {
+                (value as u64 >> (count - 1 - i)) & 1
+            };
+            let index = used + i;
+            let shift = if self.lsb_first { index % 8 } else { 7 - index % 8 };
+            let byte = &mut bytes[(index / 8) as usize];
+            *byte = (*byte & !(1 << shift)) | ((bit as u8) << shift);
+        }
+
+        self.write_checked(pos, &bytes[..size]);
+        self.set_bit_cursor(pos, end);
+    }
+
+    #[func]
+    /// Drops the rest of a partially read or written byte so the bit cursor lines up with
+    /// [method get_offset] again.
+    pub fn align_to_byte(&mut self) {
+        self.bit_state = None;
     }
 
     #[func]
     /// Returns the size of the buffer in bytes.
+    /// The size follows the source, so it grows when data is stored past the end.
     pub fn size(&self) -> i64 {
-        (self.range.end - self.range.start) as i64
+        self.len() as i64
+    }
+
+    #[func]
+    /// Grows or shrinks the buffer to `size` bytes. New bytes are zero-filled.
+    /// The cursor is left untouched. Returns `false` if the source cannot be resized,
+    /// e.g. a read-only file or a file inside an archive.
+    pub fn resize(&mut self, size: i64) -> bool {
+        let Some(src) = &self.source else {
+            return false;
+        };
+
+        match src.set_len(size.max(0) as u64) {
+            Ok(()) => true,
+            Err(err) => {
+                godot_error!("NebulaBuffer::resize: {}", err);
+                false
+            }
+        }
+    }
+
+    #[func]
+    /// Inserts `data` at `offset`, moving everything after it forward. See [method splice].
+    pub fn insert_bytes(&mut self, offset: i64, data: PackedByteArray) -> bool {
+        self.splice_impl("insert_bytes", offset, 0, data.as_slice())
+    }
+
+    #[func]
+    /// Removes `size` bytes at `offset`, moving everything after them back. See [method splice].
+    pub fn remove_bytes(&mut self, offset: i64, size: i64) -> bool {
+        self.splice_impl("remove_bytes", offset, size, &[])
+    }
+
+    #[func]
+    /// Replaces `remove` bytes at `offset` with `data`, moving everything after them so the buffer
+    /// grows or shrinks as needed. Only buffers created in memory, e.g. with [method from_bytes], support this.
+    ///
+    /// Fields registered with [method register_offset_field] are updated afterwards, and labels, placeholders and
+    /// the string table of [method finish] move with the data; placeholders in the removed range are dropped.
+    /// The cursor is left untouched.
+    /// Returns `false` and logs an error if the buffer is not memory-backed or the range is out of bounds.
+    pub fn splice(&mut self, offset: i64, remove: i64, data: PackedByteArray) -> bool {
+        self.splice_impl("splice", offset, remove, data.as_slice())
+    }
+
+    #[func]
+    /// Registers the `width`-byte unsigned field at `offset` (1, 2, 4 or 8, stored with the current
+    /// [member big_endian]) as an offset relative to `base` that [method insert_bytes], [method remove_bytes]
+    /// and [method splice] keep up to date.
+    ///
+    /// When data moves, the field itself moves with it, and its value is rewritten so it still points at the
+    /// same data. Data inserted exactly at the target is placed before it, while data inserted exactly at
+    /// `base` belongs after the base. A target that is removed ends up at the start of the removed range,
+    /// and a field that is removed is unregistered. Fields holding `0` are treated as null and left alone.
+    pub fn register_offset_field(
+        &mut self,
+        offset: i64,
+        #[opt(default = 4)] width: i32,
+        #[opt(default = 0)] base: i64,
+    ) {
+        if ![1, 2, 4, 8].contains(&width) || offset < 0 || base < 0 {
+            godot_error!("NebulaBuffer::register_offset_field: invalid field at {} (width {}, base {})", offset, width, base);
+            return;
+        }
+
+        self.offset_fields.retain(|field| field.pos != offset as u64);
+        self.offset_fields.push(OffsetField {
+            pos: offset as u64,
+            width: width as u8,
+            base: base as u64,
+            big_endian: self.big_endian,
+        });
+    }
+
+    #[func]
+    /// Stops updating the offset field registered at `offset`.
+    pub fn unregister_offset_field(&mut self, offset: i64) {
+        self.offset_fields.retain(|field| field.pos != offset as u64);
+    }
+
+    #[func]
+    /// Unregisters all offset fields.
+    pub fn clear_offset_fields(&mut self) {
+        self.offset_fields.clear();
     }
 
     #[func]
@@ -340,30 +797,15 @@ impl NebulaBuffer {
     }
 
     #[func]
-    /// Reads an ASCII string up to a null terminator or the specified length and advances the cursor.
-    pub fn read_string_ascii(&mut self, #[opt(default = -1)] amount: i32) -> GString {
-        let mut bytes = Vec::new();
-
-        if amount < 0 {
-            loop {
-                let b = self.read_u8();
-                if b == 0 { break; }
-                bytes.push(b);
-            }
-        } else {
-            for _ in 0..amount {
-                let b = self.read_u8();
-                if b == 0 { break; }
-                bytes.push(b);
-            }
-        }
-
-        GString::from(String::from_utf8_lossy(&bytes).as_ref())
+    /// Reads an ASCII string up to a null terminator or `amount` bytes if not negative.
+    /// The cursor moves past the terminator, or by `amount` bytes if there is none.
+    pub fn read_string_ascii(&mut self, #[opt(default = -1)] amount: i64) -> GString {
+        GString::from(&self.read_terminated_text(TextEncoding::Ascii, u64::try_from(amount).ok()))
     }
 
     #[func]
     /// Reads an ASCII string without advancing the cursor.
-    pub fn peek_string_ascii(&mut self, #[opt(default = -1)] amount: i32) -> GString {
+    pub fn peek_string_ascii(&mut self, #[opt(default = -1)] amount: i64) -> GString {
         let saved = self.offset;
         let s = self.read_string_ascii(amount);
         self.offset = saved;
@@ -371,30 +813,15 @@ impl NebulaBuffer {
     }
 
     #[func]
-    /// Reads a UTF-8 string up to a null terminator or the specified length and advances the cursor.
-    pub fn read_string_utf8(&mut self, #[opt(default = -1)] amount: i32) -> GString {
-        let mut bytes = Vec::new();
-
-        if amount < 0 {
-            loop {
-                let b = self.read_u8();
-                if b == 0 { break; }
-                bytes.push(b);
-            }
-        } else {
-            for _ in 0..amount {
-                let b = self.read_u8();
-                if b == 0 { break; }
-                bytes.push(b);
-            }
-        }
-
-        GString::from(String::from_utf8_lossy(&bytes).as_ref())
+    /// Reads a UTF-8 string up to a null terminator or `amount` bytes if not negative.
+    /// The cursor moves past the terminator, or by `amount` bytes if there is none.
+    pub fn read_string_utf8(&mut self, #[opt(default = -1)] amount: i64) -> GString {
+        GString::from(&self.read_terminated_text(TextEncoding::Utf8, u64::try_from(amount).ok()))
     }
 
     #[func]
     /// Reads a UTF-8 string without advancing the cursor.
-    pub fn peek_string_utf8(&mut self, #[opt(default = -1)] amount: i32) -> GString {
+    pub fn peek_string_utf8(&mut self, #[opt(default = -1)] amount: i64) -> GString {
         let saved = self.offset;
         let s = self.read_string_utf8(amount);
         self.offset = saved;
@@ -402,93 +829,347 @@ impl NebulaBuffer {
     }
 
     #[func]
-    /// Reads a UTF-16 string up to a null terminator or the specified number of units and advances the cursor.
-    pub fn read_string_utf16(&mut self, #[opt(default = -1)] amount: i32) -> GString {
-        let mut units = Vec::new();
-
-        if amount < 0 {
-            loop {
-                let u = self.read_u16();
-                if u == 0 { break; }
-                units.push(u);
-            }
-        } else {
-            for _ in 0..amount {
-                let u = self.read_u16();
-                if u == 0 { break; }
-                units.push(u);
-            }
-        }
-
-        GString::from(&String::from_utf16_lossy(&units))
+    /// Reads a UTF-16 string up to a null terminator or `amount` units if not negative.
+    /// The cursor moves past the terminator, or by `amount` units if there is none.
+    pub fn read_string_utf16(&mut self, #[opt(default = -1)] amount: i64) -> GString {
+        GString::from(&self.read_terminated_text(TextEncoding::Utf16, u64::try_from(amount).ok()))
     }
 
     #[func]
     /// Reads a UTF-16 string without advancing the cursor.
-    pub fn peek_string_utf16(&mut self, #[opt(default = -1)] amount: i32) -> GString {
+    pub fn peek_string_utf16(&mut self, #[opt(default = -1)] amount: i64) -> GString {
         let saved = self.offset;
         let s = self.read_string_utf16(amount);
         self.offset = saved;
         s
     }
 
+    #[func]
+    /// Reads a Shift-JIS string up to a null terminator or `amount` bytes if not negative.
+    /// The cursor moves past the terminator, or by `amount` bytes if there is none.
+    /// Invalid sequences are replaced with U+FFFD.
+    pub fn read_string_sjis(&mut self, #[opt(default = -1)] amount: i64) -> GString {
+        GString::from(&self.read_terminated_text(TextEncoding::ShiftJis, u64::try_from(amount).ok()))
+    }
+
+    #[func]
+    /// Reads a Shift-JIS string without advancing the cursor.
+    pub fn peek_string_sjis(&mut self, #[opt(default = -1)] amount: i64) -> GString {
+        let saved = self.offset;
+        let s = self.read_string_sjis(amount);
+        self.offset = saved;
+        s
+    }
+
+    #[func]
+    /// Reads a Windows-1252 string up to a null terminator or `amount` bytes if not negative.
+    /// The cursor moves past the terminator, or by `amount` bytes if there is none.
+    pub fn read_string_cp1252(&mut self, #[opt(default = -1)] amount: i64) -> GString {
+        GString::from(&self.read_terminated_text(TextEncoding::Windows1252, u64::try_from(amount).ok()))
+    }
+
+    #[func]
+    /// Reads a Windows-1252 string without advancing the cursor.
+    pub fn peek_string_cp1252(&mut self, #[opt(default = -1)] amount: i64) -> GString {
+        let saved = self.offset;
+        let s = self.read_string_cp1252(amount);
+        self.offset = saved;
+        s
+    }
+
     #[func]
     /// Reads a sequence of bytes from a given offset and length, returning a [PackedByteArray].
-    pub fn read_bytes(&self, offset: i32, size: i32) -> PackedByteArray {
-        if let Some(src) = &self.source {
-            src.read_range(offset as u64, size as usize)
-                .unwrap_or_default()
-                .into()
-        } else {
-            PackedByteArray::new()
+    pub fn read_bytes(&mut self, offset: i64, size: i64) -> PackedByteArray {
+        if self.source.is_none() {
+            return PackedByteArray::new();
         }
+
+        self.read_checked(offset.max(0) as u64, size.max(0) as usize).into()
     }
 
     #[func]
-    /// Unpacks multiple values from the buffer according to a format string.
+    /// Returns the offset of the first match of `pattern` between `start` and `end` (the end of the buffer
+    /// if negative), or `-1` if there is none. The cursor is left untouched.
     ///
-    /// The format string can contain:
-    /// - **Endianness prefix (optional)**:
-    ///   - `<` -> little endian
-    ///   - `>` -> big endian
-    ///   - If no prefix is provided, default endianness as set by [param big_endian] is used.
-    /// [br]
-    /// - **Type codes**:
-    ///   - `b` -> signed 8-bit integer (`i8`)
-    ///   - `B` -> unsigned 8-bit integer (`u8`)
-    ///   - `h` -> signed 16-bit integer (`i16`)
-    ///   - `H` -> unsigned 16-bit integer (`u16`)
-    ///   - `i` -> signed 32-bit integer (`i32`)
-    ///   - `I` -> unsigned 32-bit integer (`u32`)
-    ///   - `q` -> signed 64-bit integer (`i64`)
-    ///   - `Q` -> unsigned 64-bit integer (`u64`)
-    ///   - `f` -> 32-bit floating point (`f32`)
-    ///   - `d` -> 64-bit floating point (`f64`)
-    ///   - `x` -> pad byte (skip, does not produce output)
-    /// [br]
-    /// - **Repeat counts**:
-    ///   - A number before a type repeats that type multiple times, e.g.:
-    ///     - `5B` -> 5 unsigned bytes
-    ///     - `2b` -> 2 signed bytes
-    ///     - `3f` -> 3 floats
-    ///   - Padding can also be repeated: `4x` skips 4 bytes.
-    /// [br][br]
-    /// **Example**:
-    /// ```gdscript
-    /// # Binary layout (little-endian):
-    /// # offset 0: u32   magic
-    /// # offset 4: u16   version
-    /// # offset 6: u8    flags
-    /// # offset 7: pad   (1 byte)
-    /// # offset 8: f32   position_x
-    /// # offset 12: f32  position_y
-    /// 
-    /// var buf = NebulaBuffer.from_file("res://data.bin")
-    /// 
-    /// var values = buf.unpack("<IHBx2f")
-    /// 
-    /// var magic = values[0] # u32
-    /// var version = values[1] # u16
+    /// `pattern` is either a [PackedByteArray] matched exactly, or a String of hex bytes in which `??`
+    /// matches any byte, e.g. `"55 AA ?? 2D"`. Only matches starting at a multiple of `alignment` are reported.
+    pub fn find(
+        &mut self,
+        pattern: Variant,
+        #[opt(default = 0)] start: i64,
+        #[opt(default = -1)] end: i64,
+        #[opt(default = 1)] alignment: i64,
+    ) -> i64 {
+        let Some((pattern, start, end)) = self.search_args("find", &pattern, start, end) else {
+            return -1;
+        };
+
+        let result = search::find(self.source().as_ref(), &pattern, start, end, alignment.max(1) as u64);
+        self.search_result(result, start, end).map_or(-1, |at| at as i64)
+    }
+
+    #[func]
+    /// Returns the offsets of all matches of `pattern` between `start` and `end`, including overlapping ones.
+    /// Arguments are the same as in [method find].
+    pub fn find_all(
+        &mut self,
+        pattern: Variant,
+        #[opt(default = 0)] start: i64,
+        #[opt(default = -1)] end: i64,
+        #[opt(default = 1)] alignment: i64,
+    ) -> PackedInt64Array {
+        let mut found = PackedInt64Array::new();
+        let Some((pattern, start, end)) = self.search_args("find_all", &pattern, start, end) else {
+            return found;
+        };
+
+        let result = search::find_each(self.source().as_ref(), &pattern, start, end, alignment.max(1) as u64, |at| {
+            found.push(at as i64);
+            true
+        });
+        self.search_result(result.map(|_| None), start, end);
+        found
+    }
+
+    #[func]
+    /// Returns the offset of the last match of `pattern` between `start` and `end`, or `-1` if there is none.
+    /// Arguments are the same as in [method find].
+    pub fn rfind(
+        &mut self,
+        pattern: Variant,
+        #[opt(default = 0)] start: i64,
+        #[opt(default = -1)] end: i64,
+        #[opt(default = 1)] alignment: i64,
+    ) -> i64 {
+        let Some((pattern, start, end)) = self.search_args("rfind", &pattern, start, end) else {
+            return -1;
+        };
+
+        let result = search::rfind(self.source().as_ref(), &pattern, start, end, alignment.max(1) as u64);
+        self.search_result(result, start, end).map_or(-1, |at| at as i64)
+    }
+
+    /// Diff range type: bytes that differ in place.
+    #[constant] pub const DIFF_CHANGED: i32 = 0;
+
+    /// Diff range type: bytes that only exist in the other buffer.
+    #[constant] pub const DIFF_INSERTED: i32 = 1;
+
+    /// Diff range type: bytes that only exist in this buffer.
+    #[constant] pub const DIFF_REMOVED: i32 = 2;
+
+    #[func]
+    /// Compares this buffer (the old data) with `other` (the new data) and returns a Dictionary with:
+    /// - `ranges`: an Array of Dictionaries with `type` ([constant DIFF_CHANGED], [constant DIFF_INSERTED] or
+    ///   [constant DIFF_REMOVED]), `old_offset`, `old_size`, `new_offset` and `new_size`, in order.
+    /// - `stats`: a Dictionary with `equal_bytes`, `changed_bytes`, `inserted_bytes`, `removed_bytes`,
+    ///   `old_size` and `new_size`.
+    ///
+    /// Where the buffers stop matching, an insertion or removal of up to `window` bytes after which they match
+    /// again is preferred over a run of changed bytes. Only sizes that are a multiple of `alignment` are tried,
+    /// which helps with formats that pad their blocks. The data is streamed, so both buffers may be large files.
+    pub fn diff(
+        &mut self,
+        other: Gd<NebulaBuffer>,
+        #[opt(default = 4096)] window: i64,
+        #[opt(default = 1)] alignment: i64,
+    ) -> VarDictionary {
+        let mut result = VarDictionary::new();
+        let options = DiffOptions {
+            window: window.max(0) as u64,
+            alignment: alignment.max(1) as u64,
+            ..DiffOptions::default()
+        };
+
+        let new = self.source_of(&other);
+        let (ranges, stats) = match diff_sources(self.source().as_ref(), new.as_ref(), options) {
+            Ok(diff) => diff,
+            Err(err) => {
+                godot_error!("NebulaBuffer::diff: {}", err);
+                return result;
+            }
+        };
+
+        let mut array = VarArray::new();
+        for range in ranges {
+            let kind = match range.kind {
+                DiffKind::Changed => Self::DIFF_CHANGED,
+                DiffKind::Inserted => Self::DIFF_INSERTED,
+                DiffKind::Removed => Self::DIFF_REMOVED,
+            };
+
+            let mut dict = VarDictionary::new();
+            dict.set("type", kind);
+            dict.set("old_offset", range.old_offset as i64);
+            dict.set("old_size", range.old_size as i64);
+            dict.set("new_offset", range.new_offset as i64);
+            dict.set("new_size", range.new_size as i64);
+            array.push(&dict.to_variant());
+        }
+
+        let mut summary = VarDictionary::new();
+        summary.set("equal_bytes", stats.equal_bytes as i64);
+        summary.set("changed_bytes", stats.changed_bytes as i64);
+        summary.set("inserted_bytes", stats.inserted_bytes as i64);
+        summary.set("removed_bytes", stats.removed_bytes as i64);
+        summary.set("old_size", self.len() as i64);
+        summary.set("new_size", new.len() as i64);
+
+        result.set("ranges", array);
+        result.set("stats", summary);
+        result
+    }
+
+    #[func]
+    /// Formats `size` bytes at `offset` (up to the end if negative) as a hex dump for a [RichTextLabel]
+    /// with BBCode enabled and a monospace font. Each line shows the offset, `width` bytes in hex split into
+    /// groups of `group` bytes and, if `ascii` is `true`, their printable characters.
+    pub fn hexdump(
+        &mut self,
+        #[opt(default = 0)] offset: i64,
+        #[opt(default = -1)] size: i64,
+        #[opt(default = 16)] width: i64,
+        #[opt(default = 1)] group: i64,
+        #[opt(default = true)] ascii: bool,
+    ) -> GString {
+        self.hexdump_impl(offset, size, width, group, ascii, &[])
+    }
+
+    #[func]
+    /// Like [method hexdump], but treats `compare` as the old version of this buffer as in [method diff]
+    /// and highlights the bytes that were changed or inserted in this buffer.
+    pub fn hexdump_diff(
+        &mut self,
+        compare: Gd<NebulaBuffer>,
+        #[opt(default = 0)] offset: i64,
+        #[opt(default = -1)] size: i64,
+        #[opt(default = 16)] width: i64,
+        #[opt(default = 1)] group: i64,
+        #[opt(default = true)] ascii: bool,
+    ) -> GString {
+        let old = self.source_of(&compare);
+        let highlight: Vec<(u64, u64)> = match diff_sources(old.as_ref(), self.source().as_ref(), DiffOptions::default()) {
+            Ok((ranges, _)) => ranges.iter()
+                .filter(|range| range.new_size > 0)
+                .map(|range| (range.new_offset, range.new_size))
+                .collect(),
+            Err(err) => {
+                godot_error!("NebulaBuffer::hexdump_diff: {}", err);
+                Vec::new()
+            }
+        };
+
+        self.hexdump_impl(offset, size, width, group, ascii, &highlight)
+    }
+
+    /// Hash algorithm: CRC-32 (IEEE), returned as 4 big-endian bytes.
+    #[constant] pub const HASH_CRC32: i32 = 0;
+
+    /// Hash algorithm: Adler-32, returned as 4 big-endian bytes.
+    #[constant] pub const HASH_ADLER32: i32 = 1;
+
+    /// Hash algorithm: MD5 (16 bytes).
+    #[constant] pub const HASH_MD5: i32 = 2;
+
+    /// Hash algorithm: SHA-1 (20 bytes).
+    #[constant] pub const HASH_SHA1: i32 = 3;
+
+    /// Hash algorithm: SHA-256 (32 bytes).
+    #[constant] pub const HASH_SHA256: i32 = 4;
+
+    #[func]
+    /// Hashes `size` bytes starting at `offset` with one of the `HASH_*` algorithms
+    /// and returns the digest. A negative `size` hashes up to the end of the buffer.
+    /// The data is streamed from the source, so large files are never fully loaded.
+    /// Does not move the cursor. Returns an empty array and records an error on failure.
+    pub fn hash_range(
+        &mut self,
+        algorithm: i32,
+        #[opt(default = 0)] offset: i64,
+        #[opt(default = -1)] size: i64,
+    ) -> PackedByteArray {
+        let Some(algorithm) = HashAlgorithm::from_i32(algorithm) else {
+            godot_error!("NebulaBuffer::hash_range: invalid algorithm {}", algorithm);
+            return PackedByteArray::new();
+        };
+
+        let offset = offset.max(0) as u64;
+        let size = if size < 0 { self.len().saturating_sub(offset) } else { size as u64 };
+
+        match hash_source(self.source().as_ref(), offset, size, algorithm) {
+            Ok(digest) => digest.as_slice().into(),
+            Err(err) => {
+                let code = match err.kind() {
+                    std::io::ErrorKind::UnexpectedEof => Error::ERR_FILE_EOF,
+                    _ => Error::ERR_FILE_CANT_READ,
+                };
+                self.set_error(code, offset, size, err.to_string());
+                PackedByteArray::new()
+            }
+        }
+    }
+
+    #[func]
+    /// Unpacks multiple values from the buffer according to a format string.
+    ///
+    /// The format string can contain:
+    /// - **Endianness prefix (optional)**:
+    ///   - `<` -> little endian
+    ///   - `>` -> big endian
+    ///   - If no prefix is provided, default endianness as set by [param big_endian] is used.
+    /// [br]
+    /// - **Type codes**:
+    ///   - `b` -> signed 8-bit integer (`i8`)
+    ///   - `B` -> unsigned 8-bit integer (`u8`)
+    ///   - `h` -> signed 16-bit integer (`i16`)
+    ///   - `H` -> unsigned 16-bit integer (`u16`)
+    ///   - `i` -> signed 32-bit integer (`i32`)
+    ///   - `I` -> unsigned 32-bit integer (`u32`)
+    ///   - `q` -> signed 64-bit integer (`i64`)
+    ///   - `Q` -> unsigned 64-bit integer (`u64`)
+    ///   - `t` -> signed 24-bit integer
+    ///   - `T` -> unsigned 24-bit integer
+    ///   - `e` -> 16-bit floating point (half precision)
+    ///   - `f` -> 32-bit floating point (`f32`)
+    ///   - `d` -> 64-bit floating point (`f64`)
+    ///   - `y` -> signed 16-bit fixed point, 8 fraction bits unless given as `y[n]`, e.g. `y[12]`
+    ///   - `Y` -> signed 32-bit fixed point, 16 fraction bits unless given as `Y[n]`
+    ///   - `x` -> pad byte (skip, does not produce output)
+    /// [br]
+    /// - **String codes**:
+    ///   - `s`, `u`, `w`, `j` -> fixed-length ASCII, UTF-8, UTF-16 or Shift-JIS string.
+    ///     The number before the code is the length in code units (bytes, or 16-bit units for UTF-16),
+    ///     e.g. `16s`. The string ends at the first null unit; packing pads it with zeros or truncates it.
+    ///   - `S`, `U`, `W`, `J` -> null-terminated ASCII, UTF-8, UTF-16 or Shift-JIS string.
+    /// [br]
+    /// - **Alignment**:
+    ///   - `@N` -> skips to the next offset that is a multiple of `N`, e.g. `@32`. Offsets are counted
+    ///     from the start of the buffer. Produces no output.
+    /// [br]
+    /// - **Repeat counts**:
+    ///   - A number before a type repeats that type multiple times, e.g.:
+    ///     - `5B` -> 5 unsigned bytes
+    ///     - `2b` -> 2 signed bytes
+    ///     - `3f` -> 3 floats
+    ///     - `2S` -> 2 null-terminated strings
+    ///   - Padding can also be repeated: `4x` skips 4 bytes.
+    /// [br][br]
+    /// **Example**:
+    /// ```gdscript
+    /// # Binary layout (little-endian):
+    /// # offset 0: u32   magic
+    /// # offset 4: u16   version
+    /// # offset 6: u8    flags
+    /// # offset 7: pad   (1 byte)
+    /// # offset 8: f32   position_x
+    /// # offset 12: f32  position_y
+    /// 
+    /// var buf = NebulaBuffer.from_file("res://data.bin")
+    /// 
+    /// var values = buf.unpack("<IHBx2f")
+    /// 
+    /// var magic = values[0] # u32
+    /// var version = values[1] # u16
     /// var flags = values[2] # u8
     /// var position_x = values[3] # f32
     /// var position_y = values[4] # f32
@@ -514,19 +1195,54 @@ impl NebulaBuffer {
         }
 
         let mut out = VarArray::new();
-        let mut repeat: usize = 0;
+        let mut repeat: u64 = 0;
 
         while let Some(c) = chars.next() {
             if c.is_ascii_digit() {
-                repeat = repeat * 10 + (c as u8 - b'0') as usize;
+                let Some(next) = push_digit(repeat, c) else {
+                    godot_error!("NebulaBuffer::unpack: repeat count is too large");
+                    break;
+                };
+                repeat = next;
                 continue;
             }
 
             let count = if repeat == 0 { 1 } else { repeat };
             repeat = 0;
 
+            if let Some((encoding, fixed)) = format_string(c) {
+                if fixed {
+                    out.push(&GString::from(&self.read_text(encoding, Some(count))).to_variant());
+                } else {
+                    for _ in 0..count { out.push(&GString::from(&self.read_text(encoding, None)).to_variant()); }
+                }
+                continue;
+            }
+
             match c {
-                'x' => self.offset += count,
+                'x' => self.offset = self.offset.saturating_add(count),
+                '@' => match format_number(&mut chars) {
+                    Some(align) if align > 0 => self.offset = self.offset.next_multiple_of(align),
+                    _ => {
+                        godot_error!("NebulaBuffer::unpack: '@' must be followed by a positive alignment");
+                        break;
+                    }
+                },
+
+                'y' | 'Y' => {
+                    let bits = match format_fraction_bits(&mut chars, c) {
+                        Ok(bits) => bits,
+                        Err(err) => {
+                            godot_error!("NebulaBuffer::unpack: {}", err);
+                            break;
+                        }
+                    };
+                    let scale = (1u64 << bits) as f64;
+                    for _ in 0..count {
+                        let raw = if c == 'y' { self.read_i16() as f64 } else { self.read_i32() as f64 };
+                        out.push(&(raw / scale).to_variant());
+                    }
+                }
 
                 'b' => for _ in 0..count { out.push(&self.read_i8().to_variant()); },
                 'B' => for _ in 0..count { out.push(&self.read_u8().to_variant()); },
@@ -536,6 +1252,9 @@ impl NebulaBuffer {
                 'I' => for _ in 0..count { out.push(&self.read_u32().to_variant()); },
                 'q' => for _ in 0..count { out.push(&self.read_i64().to_variant()); },
                 'Q' => for _ in 0..count { out.push(&self.read_u64().to_variant()); },
+                't' => for _ in 0..count { out.push(&(((self.read_u24() << 8) as i32) >> 8).to_variant()); },
+                'T' => for _ in 0..count { out.push(&self.read_u24().to_variant()); },
+                'e' => for _ in 0..count { out.push(&f16::from_bits(self.read_u16()).to_f32().to_variant()); },
                 'f' => for _ in 0..count { out.push(&self.read_f32().to_variant()); },
                 'd' => for _ in 0..count { out.push(&self.read_f64().to_variant()); },
 
@@ -550,6 +1269,50 @@ impl NebulaBuffer {
         out
     }
 
+    #[func]
+    /// Reads a structure described by `schema` at the cursor and returns it as a nested Dictionary.
+    /// `schema` is a [NebulaSchema], or a Dictionary or text definition as described there.
+    /// The cursor ends up after the structure; data reached through pointers does not move it.
+    /// Logs an error and returns an empty Dictionary if the schema is invalid or does not match the data.
+    pub fn read_struct(&mut self, schema: Variant) -> VarDictionary {
+        let schema = match NebulaSchema::resolve(&schema) {
+            Ok(schema) => schema,
+            Err(err) => {
+                godot_error!("NebulaBuffer::read_struct: invalid schema: {}", err);
+                return VarDictionary::new();
+            }
+        };
+
+        match schema.read(self) {
+            Ok(dict) => dict,
+            Err(err) => {
+                godot_error!("NebulaBuffer::read_struct: {}", err);
+                VarDictionary::new()
+            }
+        }
+    }
+
+    #[func]
+    /// Writes `value` at the cursor using the layout described by `schema`, the reverse of [method read_struct].
+    ///
+    /// Count and length fields stored in the same struct as the array or string they describe are
+    /// updated from the data. Missing fields are written as zero. Pointer targets are written after
+    /// the structure, aligned to 4 bytes, and the pointers are filled in to match; the cursor ends
+    /// up after the last target.
+    pub fn write_struct(&mut self, schema: Variant, value: VarDictionary) {
+        let schema = match NebulaSchema::resolve(&schema) {
+            Ok(schema) => schema,
+            Err(err) => {
+                godot_error!("NebulaBuffer::write_struct: invalid schema: {}", err);
+                return;
+            }
+        };
+
+        if let Err(err) = schema.write(self, &value) {
+            godot_error!("NebulaBuffer::write_struct: {}", err);
+        }
+    }
+
     #[func] 
     /// Writes an unsigned byte at the current cursor position
     /// and advances the cursor.
@@ -677,12 +1440,153 @@ impl NebulaBuffer {
 
 
 
+    #[func]
+    /// Writes a Shift-JIS encoded string to the buffer and advances the cursor.
+    ///
+    /// Characters that Shift-JIS cannot represent are written as `?`.
+    /// If `escape` is `true`, a null terminator (`\0`) is written after the string.
+    pub fn store_string_sjis(
+        &mut self,
+        s: GString,
+        #[opt(default = false)] escape: bool,
+    ) {
+        let bytes = TextEncoding::ShiftJis.encode(&s.to_string(), self.big_endian);
+        self.write_bytes_impl(&bytes);
+        if escape {
+            self.write_u8_impl(0);
+        }
+    }
+
+    #[func]
+    /// Writes a Windows-1252 encoded string to the buffer and advances the cursor.
+    ///
+    /// Characters that Windows-1252 cannot represent are written as `?`.
+    /// If `escape` is `true`, a null terminator (`\0`) is written after the string.
+    pub fn store_string_cp1252(
+        &mut self,
+        s: GString,
+        #[opt(default = false)] escape: bool,
+    ) {
+        let bytes = TextEncoding::Windows1252.encode(&s.to_string(), self.big_endian);
+        self.write_bytes_impl(&bytes);
+        if escape {
+            self.write_u8_impl(0);
+        }
+    }
+
     #[func]
     /// Stores a sequence of bytes at the current offset
     pub fn store_bytes(&mut self, bytes: PackedByteArray) {
         self.write_bytes_impl(&bytes.to_vec());
     }
 
+    #[func]
+    /// Defines the label `name` at the cursor, for use by [method store_label_offset].
+    /// Defining a label again moves it.
+    pub fn set_label(&mut self, name: GString) {
+        self.relocations.set_label(&name.to_string(), self.offset);
+    }
+
+    #[func]
+    /// Returns the offset of the label `name`, or `-1` if it has not been defined.
+    pub fn get_label(&self, name: GString) -> i64 {
+        self.relocations.label(&name.to_string()).map_or(-1, |pos| pos as i64)
+    }
+
+    #[func]
+    /// Writes a `width`-byte placeholder (1, 2, 3, 4 or 8) and advances the cursor. [method finish] fills it
+    /// with the offset of `label`, which may be defined later, using the current [member big_endian].
+    ///
+    /// The offset is relative to `base`: the start of the buffer if empty, the placeholder itself if `"."`,
+    /// or otherwise the label of that name.
+    pub fn store_label_offset(
+        &mut self,
+        label: GString,
+        #[opt(default = 4)] width: i32,
+        #[opt(default = "")] base: GString,
+    ) {
+        if let Some((pos, width)) = self.store_placeholder("store_label_offset", width) {
+            let base = RelocationBase::from_name(&base.to_string());
+            self.relocations.add_label_fixup(pos, width, self.big_endian, &label.to_string(), base);
+        }
+    }
+
+    #[func]
+    /// Adds `text` to the string table unless an identical string is already in it, and writes a placeholder
+    /// that [method finish] fills with its offset, like [method store_label_offset].
+    /// Use the label passed to [method store_string_table] as `base` for offsets relative to the table.
+    pub fn store_string_offset(
+        &mut self,
+        text: GString,
+        #[opt(default = 4)] width: i32,
+        #[opt(default = "")] base: GString,
+    ) {
+        if let Some((pos, width)) = self.store_placeholder("store_string_offset", width) {
+            let base = RelocationBase::from_name(&base.to_string());
+            self.relocations.add_string_fixup(pos, width, self.big_endian, &text.to_string(), base);
+        }
+    }
+
+    #[func]
+    /// Writes the strings added by [method store_string_offset] at the cursor as null-terminated UTF-8,
+    /// defines `label` at its start if not empty, and advances the cursor. Strings can no longer be added
+    /// afterwards. If this is never called, [method finish] writes the table at the cursor.
+    pub fn store_string_table(&mut self, #[opt(default = "")] label: GString) {
+        if self.relocations.has_table() {
+            godot_error!("NebulaBuffer::store_string_table: string table already written");
+            return;
+        }
+
+        if !label.is_empty() {
+            self.set_label(label);
+        }
+        let table = self.relocations.take_table(self.offset);
+        self.write_bytes_impl(&table);
+    }
+
+    #[func]
+    /// Writes `fill` bytes until the cursor is a multiple of `alignment`.
+    pub fn pad_to_alignment(&mut self, alignment: i64, #[opt(default = 0)] fill: u8) {
+        if alignment <= 0 {
+            godot_error!("NebulaBuffer::pad_to_alignment: invalid alignment {}", alignment);
+            return;
+        }
+
+        let padding = self.offset.next_multiple_of(alignment as u64) - self.offset;
+        self.write_bytes_impl(&vec![fill; padding as usize]);
+    }
+
+    #[func]
+    /// Fills in every placeholder written by [method store_label_offset] and [method store_string_offset],
+    /// writing the string table first if needed, then forgets all labels, placeholders and strings.
+    /// The cursor is left after the string table, if one was written here.
+    ///
+    /// Returns `false` and logs an error for each placeholder that refers to an undefined label or whose
+    /// offset does not fit; those placeholders are left as zero.
+    pub fn finish(&mut self) -> bool {
+        if !self.relocations.has_table() {
+            let table = self.relocations.take_table(self.offset);
+            self.write_bytes_impl(&table);
+        }
+
+        let (patches, errors) = std::mem::take(&mut self.relocations).resolve();
+        for (pos, bytes) in patches {
+            self.write_checked(pos, &bytes);
+        }
+        for err in &errors {
+            godot_error!("NebulaBuffer::finish: {}", err);
+        }
+
+        errors.is_empty()
+    }
+
+    #[func]
+    /// Forgets all labels, placeholders and strings without filling in the placeholders, e.g. to start over
+    /// after a failed write. [method finish] does this itself.
+    pub fn clear_relocations(&mut self) {
+        self.relocations = Relocations::default();
+    }
+
     #[func]
     /// Packs multiple values into the buffer according to a format string.
     /// Format codes are the same as [method unpack], but values are taken from the input [Array].
@@ -699,26 +1603,73 @@ impl NebulaBuffer {
             }
         }
 
-        let mut repeat: usize = 0;
+        let mut repeat: u64 = 0;
         let mut value_index = 0;
 
-        while let Some(c) = chars.next() {
+        'format: while let Some(c) = chars.next() {
             if c.is_ascii_digit() {
-                repeat = repeat * 10 + (c as u8 - b'0') as usize;
+                let Some(next) = push_digit(repeat, c) else {
+                    godot_error!("NebulaBuffer::pack: repeat count is too large");
+                    break;
+                };
+                repeat = next;
                 continue;
             }
 
             let count = if repeat == 0 { 1 } else { repeat };
             repeat = 0;
 
+            match c {
+                'x' => {
+                    self.offset = self.offset.saturating_add(count); // skip
+                    continue;
+                }
+                '@' => {
+                    match format_number(&mut chars) {
+                        Some(align) if align > 0 => self.offset = self.offset.next_multiple_of(align),
+                        _ => {
+                            godot_error!("NebulaBuffer::pack: '@' must be followed by a positive alignment");
+                            break;
+                        }
+                    }
+                    continue;
+                }
+                _ => {}
+            }
+
+            let string = format_string(c);
+            let bits = match c {
+                'y' | 'Y' => match format_fraction_bits(&mut chars, c) {
+                    Ok(bits) => bits,
+                    Err(err) => {
+                        godot_error!("NebulaBuffer::pack: {}", err);
+                        break;
+                    }
+                },
+                _ => 0,
+            };
+            let scale = (1u64 << bits) as f64;
+
+            // A fixed-length string takes a single value; the count is its length.
+            let (count, length) = match string {
+                Some((_, true)) => (1, Some(count)),
+                _ => (count, None),
+            };
+
             for _ in 0..count {
-                if c != 'x' && value_index >= values.len() {
+                if value_index >= values.len() {
                     godot_error!("NebulaBuffer::pack: not enough values for format");
-                    return;
+                    break 'format;
+                }
+
+                if let Some((encoding, _)) = string {
+                    let text = values.get(value_index).map_or(GString::new(), |v| v.try_to::<GString>().unwrap_or_default());
+                    self.store_text(encoding, &text.to_string(), length);
+                    value_index += 1;
+                    continue;
                 }
 
                 match c {
-                    'x' => self.offset += 1, // skip
 
                     'b' => self.store_i8(values.get(value_index).map_or(0, |v| v.try_to::<i8>().unwrap_or(0))),
                     'B' => self.store_u8(values.get(value_index).map_or(0, |v| v.try_to::<u8>().unwrap_or(0))),
@@ -728,19 +1679,28 @@ impl NebulaBuffer {
                     'I' => self.store_u32(values.get(value_index).map_or(0, |v| v.try_to::<u32>().unwrap_or(0))),
                     'q' => self.store_i64(values.get(value_index).map_or(0, |v| v.try_to::<i64>().unwrap_or(0))),
                     'Q' => self.store_u64(values.get(value_index).map_or(0, |v| v.try_to::<u64>().unwrap_or(0))),
+                    't' => self.store_u24(values.get(value_index).map_or(0, |v| v.try_to::<i32>().unwrap_or(0)) as u32 & 0xFF_FFFF),
+                    'T' => self.store_u24(values.get(value_index).map_or(0, |v| v.try_to::<u32>().unwrap_or(0)) & 0xFF_FFFF),
+                    'e' => self.store_u16(f16::from_f64(values.get(value_index).map_or(0.0, |v| v.try_to::<f64>().unwrap_or(0.0))).to_bits()),
                     'f' => self.store_f32(values.get(value_index).map_or(0.0, |v| v.try_to::<f32>().unwrap_or(0.0))),
                     'd' => self.store_f64(values.get(value_index).map_or(0.0, |v| v.try_to::<f64>().unwrap_or(0.0))),
+                    'y' => {
+                        let value = values.get(value_index).map_or(0.0, |v| v.try_to::<f64>().unwrap_or(0.0));
+                        self.store_i16((value * scale).round().clamp(i16::MIN as f64, i16::MAX as f64) as i16);
+                    }
+                    'Y' => {
+                        let value = values.get(value_index).map_or(0.0, |v| v.try_to::<f64>().unwrap_or(0.0));
+                        self.store_i32((value * scale).round().clamp(i32::MIN as f64, i32::MAX as f64) as i32);
+                    }
 
                     _ => {
                         godot_error!("NebulaBuffer::pack: unknown format char '{}'", c);
-                        return;
+                        break 'format;
                     }
                 }
 
 
-                if c != 'x' {
-                    value_index += 1;
-                }
+                value_index += 1;
             }
         }
 
@@ -748,9 +1708,479 @@ impl NebulaBuffer {
     }
 
 
+    /// Maps one of the `OPEN_*` constants to an [OpenMode].
+    pub(crate) fn open_mode(mode: i32) -> Option<OpenMode> {
+        match mode {
+            Self::OPEN_READ => Some(OpenMode::Read),
+            Self::OPEN_READ_WRITE => Some(OpenMode::ReadWrite),
+            Self::OPEN_CREATE => Some(OpenMode::Create),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn cache_stats_to_dict(stats: Option<CacheStats>) -> VarDictionary {
+        let mut dict = VarDictionary::new();
+        if let Some(stats) = stats {
+            dict.set("hits", stats.hits as i64);
+            dict.set("misses", stats.misses as i64);
+            dict.set("read_ahead", stats.read_ahead as i64);
+            dict.set("evictions", stats.evictions as i64);
+            dict.set("cached_pages", stats.cached_pages as i64);
+            dict.set("page_size", stats.page_size as i64);
+        }
+        dict
+    }
+
     pub fn set_source(&mut self, source: BoxedByteSource) {
-        self.range = 0..source.len();
         self.source = Some(source);
+        self.overlay = None;
         self.offset = 0;
+        self.bit_state = None;
+        self.offset_fields.clear();
+    }
+
+    /// Returns the partially consumed byte before the cursor, as `(position, bits used)`.
+    fn partial_byte(&self) -> Option<(u64, u32)> {
+        match self.bit_state {
+            Some((end, used)) if end == self.offset => Some((end - 1, used as u32)),
+            _ => None,
+        }
+    }
+
+    /// Returns the bit cursor as `(byte position, bits used in that byte)`.
+    fn bit_cursor(&self) -> (u64, u32) {
+        self.partial_byte().unwrap_or((self.offset, 0))
+    }
+
+    /// Moves the cursor `bits` bits past the start of the byte at `pos`.
+    fn set_bit_cursor(&mut self, pos: u64, bits: u32) {
+        self.offset = pos + bits.div_ceil(8) as u64;
+        self.bit_state = match bits % 8 {
+            0 => None,
+            used => Some((self.offset, used as u8)),
+        };
+    }
+
+    fn read_bits_impl(&mut self, count: i32, advance: bool) -> u64 {
+        let count = count.clamp(0, 64) as u32;
+        if count == 0 {
+            return 0;
+        }
+
+        let (pos, used) = self.bit_cursor();
+        let end = used + count;
+
+        let mut bytes = [0u8; 9];
+        self.read_into(pos, &mut bytes[..end.div_ceil(8) as usize]);
+
+        let mut value = 0u64;
+        for i in 0..count {
+            let index = used + i;
+            let byte = bytes[(index / 8) as usize];
+            if self.lsb_first {
+                value |= (((byte >> (index % 8)) & 1) as u64) << i;
+            } else {
+                value = (value << 1) | ((byte >> (7 - index % 8)) & 1) as u64;
+            }
+        }
+
+        if advance {
+            self.set_bit_cursor(pos, end);
+        }
+
+        value
+    }
+
+    /// Number of bytes between the cursor and the end of the buffer.
+    pub(crate) fn remaining(&self) -> u64 {
+        self.len().saturating_sub(self.offset)
+    }
+
+    /// Runs `f` with the error state cleared, so that [method has_error] inside `f` only reports
+    /// failures caused by it. An error recorded before `f` ran is kept as the first one.
+    pub(crate) fn with_own_errors<T>(&mut self, f: impl FnOnce(&mut Self) -> T) -> T {
+        let previous = self.error.take();
+        let result = f(self);
+        if previous.is_some() {
+            self.error = previous;
+        }
+        result
+    }
+
+    /// Reads a fixed-size field of `length` code units at the cursor, stopping early at a null unit,
+    /// or a null-terminated string when `length` is `None`. The cursor moves past the whole field,
+    /// or past the terminator.
+    pub(crate) fn read_text(&mut self, encoding: TextEncoding, length: Option<u64>) -> String {
+        let Some(length) = length else {
+            return self.read_terminated_text(encoding, None);
+        };
+
+        let unit = encoding.unit_size();
+        let pos = self.abs_pos();
+        let mut bytes = self.read_checked(pos, length as usize * unit);
+        self.offset += length * unit as u64;
+        if let Some(end) = bytes.chunks_exact(unit).position(|u| u.iter().all(|&b| b == 0)) {
+            bytes.truncate(end * unit);
+        }
+        encoding.decode(&bytes, self.big_endian)
+    }
+
+    /// Reads a string at the cursor up to a null terminator or `limit` code units, and moves the
+    /// cursor past the terminator, or past the units read if there is none.
+    fn read_terminated_text(&mut self, encoding: TextEncoding, limit: Option<u64>) -> String {
+        let unit = encoding.unit_size();
+        let pos = self.abs_pos();
+        let (bytes, consumed) = match read_terminated(self.source().as_ref(), pos, unit, limit) {
+            Ok(read) => read,
+            Err(err) => {
+                self.set_error(Error::ERR_FILE_CANT_READ, pos, unit as u64, err.to_string());
+                (Vec::new(), 0)
+            }
+        };
+
+        self.offset += consumed;
+        let complete = consumed > bytes.len() as u64 || limit.is_some_and(|limit| consumed == limit * unit as u64);
+        if !complete {
+            self.set_error(
+                Error::ERR_FILE_EOF,
+                pos + consumed,
+                unit as u64,
+                "string runs past the end of the buffer".to_string(),
+            );
+        }
+        encoding.decode(&bytes, self.big_endian)
+    }
+
+    /// Writes `text` at the cursor, truncated or zero-padded to `length` code units,
+    /// or followed by a null terminator when `length` is `None`.
+    pub(crate) fn store_text(&mut self, encoding: TextEncoding, text: &str, length: Option<u64>) {
+        let unit = encoding.unit_size();
+        let bytes = match length {
+            Some(length) => {
+                let size = length as usize * unit;
+                let mut bytes = encoding.encode_truncated(text, size, self.big_endian);
+                bytes.resize(size, 0);
+                bytes
+            }
+            None => {
+                let mut bytes = encoding.encode(text, self.big_endian);
+                bytes.extend(std::iter::repeat_n(0, unit));
+                bytes
+            }
+        };
+
+        self.write_bytes_impl(&bytes);
+    }
+
+    fn read_u24(&mut self) -> u32 {
+        let b: [u8; 3] = self.read_bytes_impl(true);
+        if self.big_endian {
+            u32::from_be_bytes([0, b[0], b[1], b[2]])
+        } else {
+            u32::from_le_bytes([b[0], b[1], b[2], 0])
+        }
+    }
+
+    fn store_u24(&mut self, value: u32) {
+        let bytes = if self.big_endian {
+            value.to_be_bytes()[1..].to_vec()
+        } else {
+            value.to_le_bytes()[..3].to_vec()
+        };
+        self.write_bytes_impl(&bytes);
+    }
+
+    fn splice_impl(&mut self, name: &str, offset: i64, remove: i64, data: &[u8]) -> bool {
+        let Some(src) = self.source.clone() else {
+            return false;
+        };
+
+        if offset < 0 || remove < 0 {
+            godot_error!("NebulaBuffer::{}: invalid range {}+{}", name, offset, remove);
+            return false;
+        }
+
+        let (pos, removed, inserted) = (offset as u64, remove as u64, data.len() as u64);
+        let targets: Vec<u64> = self.offset_fields.iter()
+            .map(|field| read_offset_field(src.as_ref(), field))
+            .collect();
+
+        if let Err(err) = src.splice(pos, removed, data) {
+            godot_error!("NebulaBuffer::{}: {}", name, err);
+            return false;
+        }
+        self.bit_state = None;
+        self.relocations.shift(pos, removed, inserted);
+
+        // Maps a position from before the edit to after it. `pushed` positions move when data is
+        // inserted exactly at them; positions inside the removed range are `None`.
+        let relocate = |p: u64, pushed: bool| -> Option<u64> {
+            if p < pos || (p == pos && !pushed) {
+                Some(p)
+            } else if p < pos + removed {
+                None
+            } else {
+                Some(p - removed + inserted)
+            }
+        };
+
+        let fields = std::mem::take(&mut self.offset_fields);
+        for (mut field, value) in fields.into_iter().zip(targets) {
+            let Some(field_pos) = relocate(field.pos, true) else {
+                continue;
+            };
+
+            let base = field.base;
+            field.pos = field_pos;
+            field.base = relocate(base, false).unwrap_or(pos);
+
+            if value != 0 {
+                // Fields holding garbage may point anywhere; those are reported and left as they are.
+                let target = base.checked_add(value)
+                    .filter(|target| target.checked_add(inserted).is_some())
+                    .map(|target| relocate(target, true).unwrap_or(pos));
+                let Some(value) = target.and_then(|target| target.checked_sub(field.base)) else {
+                    godot_error!(
+                        "NebulaBuffer::{}: offset field at 0x{:X} points outside the buffer, not updated",
+                        name,
+                        field.pos
+                    );
+                    self.offset_fields.push(field);
+                    continue;
+                };
+                if field.width < 8 && value >> (field.width * 8) != 0 {
+                    godot_error!(
+                        "NebulaBuffer::{}: offset field at 0x{:X} cannot hold 0x{:X}",
+                        name,
+                        field.pos,
+                        value
+                    );
+                }
+
+                let bytes = if field.big_endian {
+                    value.to_be_bytes()[8 - field.width as usize..].to_vec()
+                } else {
+                    value.to_le_bytes()[..field.width as usize].to_vec()
+                };
+                self.write_checked(field.pos, &bytes);
+            }
+
+            self.offset_fields.push(field);
+        }
+
+        true
+    }
+
+    fn hexdump_impl(&mut self, offset: i64, size: i64, width: i64, group: i64, ascii: bool, highlight: &[(u64, u64)]) -> GString {
+        let offset = offset.max(0) as u64;
+        let size = if size < 0 { self.len().saturating_sub(offset) } else { size as u64 };
+        let options = HexdumpOptions {
+            width: width.max(1) as usize,
+            group: group.max(1) as usize,
+            ascii,
+        };
+
+        match hexdump(self.source().as_ref(), offset, size, options, highlight) {
+            Ok(text) => GString::from(&text),
+            Err(err) => {
+                self.set_error(Error::ERR_FILE_CANT_READ, offset, size, err.to_string());
+                GString::new()
+            }
+        }
+    }
+
+    /// Writes a zeroed placeholder of `width` bytes at the cursor and returns its position and width.
+    fn store_placeholder(&mut self, name: &str, width: i32) -> Option<(u64, u8)> {
+        if ![1, 2, 3, 4, 8].contains(&width) {
+            godot_error!("NebulaBuffer::{}: invalid width {}", name, width);
+            return None;
+        }
+
+        let pos = self.offset;
+        self.write_bytes_impl(&[0; 8][..width as usize]);
+        Some((pos, width as u8))
+    }
+
+    /// Converts the arguments shared by [method find], [method find_all] and [method rfind].
+    fn search_args(&self, name: &str, pattern: &Variant, start: i64, end: i64) -> Option<(Pattern, u64, u64)> {
+        self.source.as_ref()?;
+
+        let pattern = if let Ok(bytes) = pattern.try_to::<PackedByteArray>() {
+            Ok(Pattern::from_bytes(bytes.as_slice()))
+        } else if let Ok(text) = pattern.try_to::<GString>() {
+            Pattern::parse_hex(&text.to_string())
+        } else {
+            Err(format!("pattern must be a PackedByteArray or String, got {:?}", pattern.get_type()))
+        };
+
+        match pattern {
+            Ok(pattern) => {
+                let len = self.len();
+                let end = if end < 0 { len } else { (end as u64).min(len) };
+                Some((pattern, start.max(0) as u64, end))
+            }
+            Err(err) => {
+                godot_error!("NebulaBuffer::{}: {}", name, err);
+                None
+            }
+        }
+    }
+
+    fn search_result(&mut self, result: std::io::Result<Option<u64>>, start: u64, end: u64) -> Option<u64> {
+        result.unwrap_or_else(|err| {
+            self.set_error(Error::ERR_FILE_CANT_READ, start, end.saturating_sub(start), err.to_string());
+            None
+        })
+    }
+
+    pub(crate) fn set_overlay(&mut self, overlay: Arc<OverlaySource>) {
+        self.set_source(overlay.clone());
+        self.overlay = Some(overlay);
+    }
+
+    fn with_overlay(
+        &self,
+        name: &str,
+        path: &GString,
+        action: impl FnOnce(&OverlaySource, &str) -> std::io::Result<()>,
+    ) -> bool {
+        let Some(overlay) = &self.overlay else {
+            godot_error!("NebulaBuffer::{}: buffer has no overlay", name);
+            return false;
+        };
+
+        let path_str = ProjectSettings::singleton()
+            .globalize_path(path)
+            .to_string();
+
+        match action(overlay, &path_str) {
+            Ok(()) => true,
+            Err(err) => {
+                godot_error!("NebulaBuffer::{}: failed on {}: {}", name, path_str, err);
+                false
+            }
+        }
+    }
+}
+
+/// Maps a string format code to its encoding and whether it is fixed-length.
+fn format_string(c: char) -> Option<(TextEncoding, bool)> {
+    Some(match c {
+        's' => (TextEncoding::Ascii, true),
+        'u' => (TextEncoding::Utf8, true),
+        'w' => (TextEncoding::Utf16, true),
+        'j' => (TextEncoding::ShiftJis, true),
+        'S' => (TextEncoding::Ascii, false),
+        'U' => (TextEncoding::Utf8, false),
+        'W' => (TextEncoding::Utf16, false),
+        'J' => (TextEncoding::ShiftJis, false),
+        _ => return None,
+    })
+}
+
+/// Reads the digits following a format code, as in `@32`.
+fn format_number(chars: &mut Peekable<Chars>) -> Option<u64> {
+    let mut number = None;
+    while let Some(&digit) = chars.peek().filter(|c| c.is_ascii_digit()) {
+        number = Some(push_digit(number.unwrap_or(0), digit)?);
+        chars.next();
+    }
+    number
+}
+
+/// Appends the decimal `digit` to `number`, or returns `None` if the result does not fit.
+fn push_digit(number: u64, digit: char) -> Option<u64> {
+    number.checked_mul(10)?.checked_add(digit.to_digit(10)? as u64)
+}
+
+/// Reads the optional `[n]` fraction bit count after the fixed-point code `code`.
+fn format_fraction_bits(chars: &mut Peekable<Chars>, code: char) -> Result<u32, String> {
+    let (default, max) = if code == 'y' { (8, 16) } else { (16, 32) };
+    if chars.peek() != Some(&'[') {
+        return Ok(default);
+    }
+
+    chars.next();
+    let bits = format_number(chars);
+    match (bits, chars.next()) {
+        (Some(bits), Some(']')) if bits <= max => Ok(bits as u32),
+        _ => Err(format!("'{}' expects '[n]' with 0 to {} fraction bits", code, max)),
+    }
+}
+
+/// Reads the value stored in an offset field, or `0` if it cannot be read.
+fn read_offset_field(source: &dyn ByteSource, field: &OffsetField) -> u64 {
+    let mut bytes = [0u8; 8];
+    let width = field.width as usize;
+    if !matches!(source.read_into(field.pos, &mut bytes[..width]), Ok(n) if n == width) {
+        return 0;
+    }
+
+    if field.big_endian {
+        bytes[..width].reverse();
+    }
+    u64::from_le_bytes(bytes)
+}
+
+/// Reads `unit`-byte code units from `source` at `pos` up to a zero unit or `limit` units.
+/// Returns the bytes before the terminator and the number of bytes consumed, which includes the
+/// terminator if one was found.
+fn read_terminated(source: &dyn ByteSource, pos: u64, unit: usize, limit: Option<u64>) -> std::io::Result<(Vec<u8>, u64)> {
+    const CHUNK: usize = 256;
+    let max = limit.map_or(u64::MAX, |limit| limit.saturating_mul(unit as u64));
+    let mut bytes = Vec::new();
+    let mut chunk = [0u8; CHUNK];
+
+    while (bytes.len() as u64) < max {
+        let want = (max - bytes.len() as u64).min(CHUNK as u64) as usize;
+        let read = source.read_into(pos + bytes.len() as u64, &mut chunk[..want])?;
+        let units = &chunk[..read - read % unit];
+        if let Some(end) = units.chunks_exact(unit).position(|u| u.iter().all(|&b| b == 0)) {
+            bytes.extend_from_slice(&units[..end * unit]);
+            let consumed = bytes.len() as u64 + unit as u64;
+            return Ok((bytes, consumed));
+        }
+
+        bytes.extend_from_slice(units);
+        if read < want {
+            break;
+        }
+    }
+
+    let consumed = bytes.len() as u64;
+    Ok((bytes, consumed))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_numbers_reject_overflow() {
+        let mut chars = "123B".chars().peekable();
+        assert_eq!(format_number(&mut chars), Some(123));
+        assert_eq!(chars.next(), Some('B'));
+
+        assert_eq!(format_number(&mut "18446744073709551615".chars().peekable()), Some(u64::MAX));
+        assert_eq!(format_number(&mut "99999999999999999999B".chars().peekable()), None);
+        assert_eq!(push_digit(u64::MAX / 10, '6'), None);
+        assert_eq!(push_digit(7, 'x'), None);
+    }
+
+    #[test]
+    fn terminated_strings_stop_past_the_terminator() {
+        let source = MemoryByteSource::from_vec(b"ab\0cdefgh".to_vec());
+        assert_eq!(read_terminated(&source, 0, 1, None).unwrap(), (b"ab".to_vec(), 3));
+        assert_eq!(read_terminated(&source, 0, 1, Some(8)).unwrap(), (b"ab".to_vec(), 3));
+        assert_eq!(read_terminated(&source, 3, 1, Some(4)).unwrap(), (b"cdef".to_vec(), 4));
+        assert_eq!(read_terminated(&source, 3, 1, None).unwrap(), (b"cdefgh".to_vec(), 6));
+        assert_eq!(read_terminated(&source, 0, 1, Some(0)).unwrap(), (Vec::new(), 0));
+
+        let utf16 = MemoryByteSource::from_vec(vec![b'a', 0, 0, 1, 0, 0, b'b', 0]);
+        assert_eq!(read_terminated(&utf16, 0, 2, Some(4)).unwrap(), (vec![b'a', 0, 0, 1], 6));
+
+        let long: Vec<u8> = std::iter::repeat_n(b'x', 600).chain([0, b'y']).collect();
+        let long = MemoryByteSource::from_vec(long);
+        assert_eq!(read_terminated(&long, 0, 1, None).unwrap().1, 601);
     }
 }
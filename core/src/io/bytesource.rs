@@ -1,13 +1,96 @@
-use std::{fs::{File, OpenOptions}, io::{Read, Seek, SeekFrom, Write}, sync::{Arc, Mutex, RwLock}};
+use std::{collections::{BTreeMap, HashMap}, fs::{File, OpenOptions}, io::{Read, Seek, SeekFrom, Write}, sync::{Arc, Mutex, RwLock, atomic::{AtomicU64, Ordering}}};
+use memmap2::Mmap;
 
 pub trait ByteSource: Send + Sync {
     fn len(&self) -> u64;
     fn read_range(&self, offset: u64, size: usize) -> std::io::Result<Vec<u8>>;
     fn write_range(&self, offset: u64, data: &[u8]) -> std::io::Result<()>;
+
+    /// Reads up to `buf.len()` bytes at `offset` straight into `buf` and returns how many were read.
+    /// Fewer bytes than requested are only returned at the end of the source.
+    fn read_into(&self, offset: u64, buf: &mut [u8]) -> std::io::Result<usize> {
+        let bytes = self.read_range(offset, buf.len())?;
+        buf[..bytes.len()].copy_from_slice(&bytes);
+        Ok(bytes.len())
+    }
+
+    /// Grows or shrinks the source to exactly `len` bytes. Bytes added at the end read as zero.
+    fn set_len(&self, _len: u64) -> std::io::Result<()> {
+        Err(std::io::Error::new(std::io::ErrorKind::Unsupported, "source cannot be resized"))
+    }
+
+    /// Replaces `remove` bytes at `offset` with `data`, moving everything after them.
+    /// Only sources that keep their data in memory support this.
+    fn splice(&self, _offset: u64, _remove: u64, _data: &[u8]) -> std::io::Result<()> {
+        Err(std::io::Error::new(std::io::ErrorKind::Unsupported, "source cannot insert or remove bytes"))
+    }
+
+    /// Shrinks the source to `len` bytes. Does nothing if the source is already shorter.
+    fn truncate(&self, len: u64) -> std::io::Result<()> {
+        if len < self.len() {
+            self.set_len(len)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Returns `false` if [ByteSource::write_range] always fails with a [read_only_error].
+    fn is_writable(&self) -> bool {
+        true
+    }
+
+    /// Returns the page cache statistics if this source (or the source it wraps) is cached.
+    fn cache_stats(&self) -> Option<CacheStats> {
+        None
+    }
+}
+
+/// How [DiskFileSource::open] and [open_file_source] open a file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OpenMode {
+    /// Open an existing file for reading only.
+    Read,
+    /// Open an existing file for reading and writing.
+    ReadWrite,
+    /// Create the file, or truncate it if it already exists, and open it for reading and writing.
+    Create,
+}
+
+/// The error returned by [ByteSource::write_range] on sources that cannot be written to.
+pub fn read_only_error() -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::ReadOnlyFilesystem, "source is read-only")
+}
+
+/// Implements [ByteSource::read_range] on top of [ByteSource::read_into].
+fn read_range_into(source: &dyn ByteSource, offset: u64, size: usize) -> std::io::Result<Vec<u8>> {
+    let available = source.len().saturating_sub(offset).min(size as u64) as usize;
+    let mut buf = vec![0; available];
+    let n = source.read_into(offset, &mut buf)?;
+    buf.truncate(n);
+    Ok(buf)
+}
+
+/// Files at least this large are memory-mapped when they are opened read-only.
+pub const MMAP_THRESHOLD: u64 = 64 * 1024 * 1024;
+
+/// Opens a file on disk as a [ByteSource].
+///
+/// Files opened with [OpenMode::Read] that are at least [MMAP_THRESHOLD] bytes are served by a
+/// [MappedFileSource], everything else goes through a [CachedSource] over a [DiskFileSource].
+pub fn open_file_source(path: &str, mode: OpenMode) -> std::io::Result<Arc<dyn ByteSource>> {
+    if mode == OpenMode::Read && std::fs::metadata(path)?.len() >= MMAP_THRESHOLD {
+        return Ok(Arc::new(MappedFileSource::new(path)?));
+    }
+
+    let disk = DiskFileSource::open(path, mode)?;
+    Ok(Arc::new(CachedSource::new(Arc::new(disk))))
 }
 
 
 
+/// Largest gap that a write past the end of a [`MemoryByteSource`] may leave.
+pub const MAX_WRITE_GAP: u64 = 16 * 1024 * 1024;
+
 pub struct MemoryByteSource {
     data: RwLock<Vec<u8>>,
 }
@@ -41,13 +124,36 @@ impl ByteSource for MemoryByteSource {
         
         Ok(data[start..end].to_vec())
     }
+
+    fn read_into(&self, offset: u64, buf: &mut [u8]) -> std::io::Result<usize> {
+        let data = self.data.read().unwrap();
+        if offset >= data.len() as u64 {
+            return Ok(0);
+        }
+
+        let start = offset as usize;
+        let n = buf.len().min(data.len() - start);
+        buf[..n].copy_from_slice(&data[start..start + n]);
+        Ok(n)
+    }
     
+    /// Writes past the end grow the data and zero-fill the gap, which may be at most
+    /// [`MAX_WRITE_GAP`] bytes so that a bad offset fails instead of exhausting memory.
     fn write_range(&self, offset: u64, bytes: &[u8]) -> std::io::Result<()> {
         let mut data = self.data.write().unwrap();
+        let len = data.len() as u64;
+        if offset > len.saturating_add(MAX_WRITE_GAP) {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("write at 0x{:X} is too far past the end of the source (0x{:X} bytes)", offset, len),
+            ));
+        }
+
         let start = offset as usize;
         let end = start + bytes.len();
-        
         if end > data.len() {
+            data.try_reserve_exact(end - len as usize)
+                .map_err(|err| std::io::Error::new(std::io::ErrorKind::OutOfMemory, err.to_string()))?;
             data.resize(end, 0);
         }
         
@@ -59,18 +165,38 @@ impl ByteSource for MemoryByteSource {
         let data = self.data.read().unwrap();
         data.len() as u64
     }
+
+    fn set_len(&self, len: u64) -> std::io::Result<()> {
+        self.data.write().unwrap().resize(len as usize, 0);
+        Ok(())
+    }
+
+    fn splice(&self, offset: u64, remove: u64, bytes: &[u8]) -> std::io::Result<()> {
+        let mut data = self.data.write().unwrap();
+        if offset + remove > data.len() as u64 {
+            return Err(std::io::Error::new(std::io::ErrorKind::InvalidInput, "splice range past end of source"));
+        }
+
+        let start = offset as usize;
+        data.splice(start..start + remove as usize, bytes.iter().copied());
+        Ok(())
+    }
 }
 
 
 pub struct DiskFileSource {
     file: Mutex<File>,
-    size: u64,
+    size: AtomicU64,
+    writable: bool,
 }
 impl DiskFileSource {
-    pub fn new(path: &str) -> std::io::Result<Self> {
+    pub fn open(path: &str, mode: OpenMode) -> std::io::Result<Self> {
+        let writable = mode != OpenMode::Read;
         let mut file = OpenOptions::new()
             .read(true)
-            .write(true)
+            .write(writable)
+            .create(mode == OpenMode::Create)
+            .truncate(mode == OpenMode::Create)
             .open(path)?;
         
         let size = file.seek(SeekFrom::End(0))?;
@@ -78,29 +204,114 @@ impl DiskFileSource {
         
         Ok(DiskFileSource {
             file: Mutex::new(file),
-            size,
+            size: AtomicU64::new(size),
+            writable,
         })
     }
 }
 impl ByteSource for DiskFileSource {
-    fn len(&self) -> u64 { self.size }
+    fn len(&self) -> u64 { self.size.load(Ordering::Acquire) }
     
     fn read_range(&self, offset: u64, size: usize) -> std::io::Result<Vec<u8>> {
-        let mut buf = vec![0; size];
+        read_range_into(self, offset, size)
+    }
+
+    fn read_into(&self, offset: u64, buf: &mut [u8]) -> std::io::Result<usize> {
         let mut file = self.file.lock().unwrap();
         file.seek(SeekFrom::Start(offset))?;
-        let n = file.read(&mut buf)?;
-        buf.truncate(n);
-        Ok(buf)
+
+        let mut filled = 0;
+        while filled < buf.len() {
+            match file.read(&mut buf[filled..]) {
+                Ok(0) => break,
+                Ok(n) => filled += n,
+                Err(err) if err.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(filled)
     }
     
     fn write_range(&self, offset: u64, data: &[u8]) -> std::io::Result<()> {
+        if !self.writable {
+            return Err(read_only_error());
+        }
+
         let mut file = self.file.lock().unwrap();
         file.seek(SeekFrom::Start(offset))?;
         file.write_all(data)?;
         file.flush()?;
+        self.size.fetch_max(offset + data.len() as u64, Ordering::AcqRel);
+        Ok(())
+    }
+
+    fn set_len(&self, len: u64) -> std::io::Result<()> {
+        if !self.writable {
+            return Err(read_only_error());
+        }
+
+        let file = self.file.lock().unwrap();
+        file.set_len(len)?;
+        self.size.store(len, Ordering::Release);
         Ok(())
     }
+
+    fn is_writable(&self) -> bool {
+        self.writable
+    }
+}
+
+
+/// Read-only [ByteSource] backed by a memory map of a file.
+///
+/// Reads are plain copies out of the mapping, so concurrent readers never wait on each other.
+/// The file must not be truncated by another process while it is mapped.
+pub struct MappedFileSource {
+    map: Mmap,
+}
+impl MappedFileSource {
+    pub fn new(path: &str) -> std::io::Result<Self> {
+        let file = File::open(path)?;
+        // SAFETY: the mapping is read-only and disc images are not modified while they are open.
+        let map = unsafe { Mmap::map(&file)? };
+        Ok(Self { map })
+    }
+}
+impl ByteSource for MappedFileSource {
+    fn len(&self) -> u64 {
+        self.map.len() as u64
+    }
+
+    fn read_range(&self, offset: u64, size: usize) -> std::io::Result<Vec<u8>> {
+        let len = self.map.len();
+        let start = (offset.min(len as u64)) as usize;
+        let end = start.saturating_add(size).min(len);
+        Ok(self.map[start..end].to_vec())
+    }
+
+    fn read_into(&self, offset: u64, buf: &mut [u8]) -> std::io::Result<usize> {
+        let len = self.map.len();
+        if offset >= len as u64 {
+            return Ok(0);
+        }
+
+        let start = offset as usize;
+        let n = buf.len().min(len - start);
+        buf[..n].copy_from_slice(&self.map[start..start + n]);
+        Ok(n)
+    }
+
+    fn write_range(&self, _offset: u64, _data: &[u8]) -> std::io::Result<()> {
+        Err(read_only_error())
+    }
+
+    fn set_len(&self, _len: u64) -> std::io::Result<()> {
+        Err(read_only_error())
+    }
+
+    fn is_writable(&self) -> bool {
+        false
+    }
 }
 
 
@@ -130,17 +341,770 @@ impl ByteSource for SubrangeSource {
         self.parent.read_range(self.offset + offset, clamped)
     }
 
-    fn write_range(&self, offset: u64, data: &[u8]) -> std::io::Result<()> {
+    fn read_into(&self, offset: u64, buf: &mut [u8]) -> std::io::Result<usize> {
         if offset >= self.size {
-            return Ok(());
+            return Ok(0);
         }
 
         let max_size = (self.size - offset) as usize;
-        let clamped = data.len().min(max_size);
+        let clamped = buf.len().min(max_size);
+
+        self.parent.read_into(self.offset + offset, &mut buf[..clamped])
+    }
+
+    fn write_range(&self, offset: u64, data: &[u8]) -> std::io::Result<()> {
+        if offset + data.len() as u64 > self.size {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "write past the end of the subrange",
+            ));
+        }
+
+        self.parent.write_range(self.offset + offset, data)
+    }
+
+    fn is_writable(&self) -> bool {
+        self.parent.is_writable()
+    }
+
+    fn cache_stats(&self) -> Option<CacheStats> {
+        self.parent.cache_stats()
+    }
+}
+
+
+/// Presents an ordered list of sources as one contiguous address space, e.g. the
+/// `.wbfs`, `.wbf1`, `.wbf2`… parts of a split dump.
+///
+/// The layout is fixed when the source is created: writes cannot grow it.
+pub struct ConcatSource {
+    parts: Vec<Arc<dyn ByteSource>>,
+    starts: Vec<u64>,
+    len: u64,
+}
+impl ConcatSource {
+    pub fn new(parts: Vec<Arc<dyn ByteSource>>) -> Self {
+        let mut starts = Vec::with_capacity(parts.len());
+        let mut len = 0;
+        for part in &parts {
+            starts.push(len);
+            len += part.len();
+        }
+        Self { parts, starts, len }
+    }
+
+    /// Index of the part containing `offset`, which must be below the total length.
+    fn part_at(&self, offset: u64) -> usize {
+        self.starts.partition_point(|start| *start <= offset) - 1
+    }
+}
+impl ByteSource for ConcatSource {
+    fn len(&self) -> u64 {
+        self.len
+    }
+
+    fn read_range(&self, offset: u64, size: usize) -> std::io::Result<Vec<u8>> {
+        read_range_into(self, offset, size)
+    }
+
+    fn read_into(&self, offset: u64, buf: &mut [u8]) -> std::io::Result<usize> {
+        if offset >= self.len {
+            return Ok(0);
+        }
+
+        let end = (offset + buf.len() as u64).min(self.len);
+        let mut pos = offset;
+        let mut index = self.part_at(offset);
+
+        while pos < end && index < self.parts.len() {
+            let part_start = self.starts[index];
+            let part_end = part_start + self.parts[index].len();
+            let take = (end.min(part_end) - pos) as usize;
+            let at = (pos - offset) as usize;
+
+            let n = self.parts[index].read_into(pos - part_start, &mut buf[at..at + take])?;
+            pos += n as u64;
+            if n < take {
+                break;
+            }
+            index += 1;
+        }
+
+        Ok((pos - offset) as usize)
+    }
+
+    fn write_range(&self, offset: u64, data: &[u8]) -> std::io::Result<()> {
+        let end = offset + data.len() as u64;
+        if end > self.len {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::UnexpectedEof,
+                "write past the end of a split source",
+            ));
+        }
+        if data.is_empty() {
+            return Ok(());
+        }
+
+        let mut pos = offset;
+        let mut index = self.part_at(offset);
+        while pos < end {
+            let part_start = self.starts[index];
+            let part_end = part_start + self.parts[index].len();
+            let take = (end.min(part_end) - pos) as usize;
+            let from = (pos - offset) as usize;
+
+            self.parts[index].write_range(pos - part_start, &data[from..from + take])?;
+            pos += take as u64;
+            index += 1;
+        }
+
+        Ok(())
+    }
+
+    fn is_writable(&self) -> bool {
+        self.parts.iter().all(|part| part.is_writable())
+    }
+
+    fn cache_stats(&self) -> Option<CacheStats> {
+        self.parts
+            .iter()
+            .filter_map(|part| part.cache_stats())
+            .reduce(|total, stats| CacheStats {
+                hits: total.hits + stats.hits,
+                misses: total.misses + stats.misses,
+                read_ahead: total.read_ahead + stats.read_ahead,
+                evictions: total.evictions + stats.evictions,
+                cached_pages: total.cached_pages + stats.cached_pages,
+                page_size: total.page_size,
+            })
+    }
+}
+
+
+/// Magic at the start of a serialised [OverlaySource] delta.
+const DELTA_MAGIC: [u8; 4] = *b"NBDL";
+const DELTA_VERSION: u32 = 2;
+
+#[derive(Default)]
+struct OverlayState {
+    extents: BTreeMap<u64, Vec<u8>>,
+    len: u64,
+    /// Parent bytes at or past this offset have been cut off by a shrink and read as zero.
+    visible: u64,
+}
+
+/// Copy-on-write layer over a parent [ByteSource].
+///
+/// Writes are recorded in a sparse set of extents and never reach the parent. Reads return
+/// the parent data with the extents laid on top. The delta can be saved to and loaded from
+/// disk, committed to a new file, or discarded.
+pub struct OverlaySource {
+    parent: Arc<dyn ByteSource>,
+    state: RwLock<OverlayState>,
+}
+impl OverlaySource {
+    pub fn new(parent: Arc<dyn ByteSource>) -> Self {
+        let len = parent.len();
+        Self {
+            parent,
+            state: RwLock::new(OverlayState { extents: BTreeMap::new(), len, visible: len }),
+        }
+    }
+
+    /// Returns `true` if any write has been recorded since creation or the last [OverlaySource::discard].
+    pub fn is_dirty(&self) -> bool {
+        let state = self.state.read().unwrap();
+        let parent_len = self.parent.len();
+        !state.extents.is_empty() || state.len != parent_len || state.visible != parent_len
+    }
+
+    /// Total number of bytes held by the delta layer.
+    pub fn delta_size(&self) -> u64 {
+        let state = self.state.read().unwrap();
+        state.extents.values().map(|data| data.len() as u64).sum()
+    }
+
+    /// Throws away every recorded write.
+    pub fn discard(&self) {
+        let mut state = self.state.write().unwrap();
+        state.extents.clear();
+        state.len = self.parent.len();
+        state.visible = state.len;
+    }
+
+    /// Writes the merged contents to a new file at `path`, which must not be the parent file.
+    pub fn commit_to(&self, path: &str) -> std::io::Result<()> {
+        const CHUNK_SIZE: usize = 0x100000;
+
+        let mut file = File::create(path)?;
+        let len = self.len();
+        let mut pos = 0;
+        while pos < len {
+            let chunk = self.read_range(pos, CHUNK_SIZE.min((len - pos) as usize))?;
+            if chunk.is_empty() {
+                break;
+            }
+            file.write_all(&chunk)?;
+            pos += chunk.len() as u64;
+        }
+        file.flush()
+    }
+
+    /// Serialises the delta layer to `path`.
+    pub fn save_delta(&self, path: &str) -> std::io::Result<()> {
+        let state = self.state.read().unwrap();
+        let mut out = Vec::new();
+        out.extend_from_slice(&DELTA_MAGIC);
+        out.extend_from_slice(&DELTA_VERSION.to_le_bytes());
+        out.extend_from_slice(&self.parent.len().to_le_bytes());
+        out.extend_from_slice(&state.len.to_le_bytes());
+        out.extend_from_slice(&state.visible.to_le_bytes());
+        out.extend_from_slice(&(state.extents.len() as u64).to_le_bytes());
+        for (offset, data) in &state.extents {
+            out.extend_from_slice(&offset.to_le_bytes());
+            out.extend_from_slice(&(data.len() as u64).to_le_bytes());
+            out.extend_from_slice(data);
+        }
+        std::fs::write(path, out)
+    }
+
+    /// Replaces the delta layer with one previously written by [OverlaySource::save_delta].
+    /// Fails if the delta was recorded against a parent of a different size.
+    pub fn load_delta(&self, path: &str) -> std::io::Result<()> {
+        let invalid = |msg: &str| std::io::Error::new(std::io::ErrorKind::InvalidData, msg.to_string());
 
-        self.parent.write_range(
-            self.offset + offset,
-            &data[..clamped],
+        let raw = std::fs::read(path)?;
+        let mut reader = raw.as_slice();
+        let read_u64 = |reader: &mut &[u8]| -> std::io::Result<u64> {
+            let mut bytes = [0u8; 8];
+            reader.read_exact(&mut bytes)?;
+            Ok(u64::from_le_bytes(bytes))
+        };
+
+        let mut header = [0u8; 8];
+        reader.read_exact(&mut header)?;
+        if header[0..4] != DELTA_MAGIC {
+            return Err(invalid("not a delta file"));
+        }
+        if u32::from_le_bytes([header[4], header[5], header[6], header[7]]) != DELTA_VERSION {
+            return Err(invalid("unsupported delta version"));
+        }
+        if read_u64(&mut reader)? != self.parent.len() {
+            return Err(invalid("delta was recorded against a different source"));
+        }
+
+        let len = read_u64(&mut reader)?;
+        let visible = read_u64(&mut reader)?;
+        if visible > len.min(self.parent.len()) {
+            return Err(invalid("delta shows more of the source than it has"));
+        }
+
+        let count = read_u64(&mut reader)?;
+        let mut extents = BTreeMap::new();
+        let mut end = 0;
+        for _ in 0..count {
+            let offset = read_u64(&mut reader)?;
+            let size = read_u64(&mut reader)?;
+            if size > reader.len() as u64 {
+                return Err(invalid("delta file is truncated"));
+            }
+            // Extents are saved in order and never overlap or run past the end.
+            if offset < end || offset.checked_add(size).is_none_or(|extent_end| extent_end > len) {
+                return Err(invalid("delta extent out of bounds"));
+            }
+            let (data, rest) = reader.split_at(size as usize);
+            extents.insert(offset, data.to_vec());
+            end = offset + size;
+            reader = rest;
+        }
+
+        *self.state.write().unwrap() = OverlayState { extents, len, visible };
+        Ok(())
+    }
+}
+impl ByteSource for OverlaySource {
+    fn len(&self) -> u64 {
+        self.state.read().unwrap().len
+    }
+
+    fn read_range(&self, offset: u64, size: usize) -> std::io::Result<Vec<u8>> {
+        read_range_into(self, offset, size)
+    }
+
+    fn read_into(&self, offset: u64, buf: &mut [u8]) -> std::io::Result<usize> {
+        let state = self.state.read().unwrap();
+        if offset >= state.len {
+            return Ok(0);
+        }
+
+        let end = (offset + buf.len() as u64).min(state.len);
+        let out = &mut buf[..(end - offset) as usize];
+        let from_parent = if offset < state.visible {
+            let want = (end.min(state.visible) - offset) as usize;
+            self.parent.read_into(offset, &mut out[..want])?
+        } else {
+            0
+        };
+        out[from_parent..].fill(0);
+
+        for (&start, data) in state.extents.range(..end).rev() {
+            let ext_end = start + data.len() as u64;
+            if ext_end <= offset {
+                break;
+            }
+
+            let from = start.max(offset);
+            let to = ext_end.min(end);
+            out[(from - offset) as usize..(to - offset) as usize]
+                .copy_from_slice(&data[(from - start) as usize..(to - start) as usize]);
+        }
+
+        Ok(out.len())
+    }
+
+    fn write_range(&self, offset: u64, data: &[u8]) -> std::io::Result<()> {
+        if data.is_empty() {
+            return Ok(());
+        }
+
+        let mut state = self.state.write().unwrap();
+        let end = offset + data.len() as u64;
+
+        // Every extent that touches the written range is folded into a single new extent.
+        let touching: Vec<u64> = state.extents
+            .range(..=end)
+            .rev()
+            .take_while(|(start, ext)| *start + ext.len() as u64 >= offset)
+            .map(|(start, _)| *start)
+            .collect();
+
+        let mut merged_start = offset;
+        let mut merged_end = end;
+        for start in &touching {
+            merged_start = merged_start.min(*start);
+            merged_end = merged_end.max(start + state.extents[start].len() as u64);
+        }
+
+        let mut merged = vec![0u8; (merged_end - merged_start) as usize];
+        for start in touching {
+            let ext = state.extents.remove(&start).unwrap();
+            let at = (start - merged_start) as usize;
+            merged[at..at + ext.len()].copy_from_slice(&ext);
+        }
+        let at = (offset - merged_start) as usize;
+        merged[at..at + data.len()].copy_from_slice(data);
+
+        state.extents.insert(merged_start, merged);
+        state.len = state.len.max(end);
+        Ok(())
+    }
+
+    fn set_len(&self, len: u64) -> std::io::Result<()> {
+        let mut state = self.state.write().unwrap();
+        if len < state.len {
+            state.extents.retain(|start, _| *start < len);
+            if let Some((start, data)) = state.extents.iter_mut().next_back() {
+                data.truncate((len - start) as usize);
+            }
+            state.visible = state.visible.min(len);
+        }
+        state.len = len;
+        Ok(())
+    }
+
+    fn cache_stats(&self) -> Option<CacheStats> {
+        self.parent.cache_stats()
+    }
+}
+
+
+/// Hit/miss counters reported by a [CachedSource].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    pub read_ahead: u64,
+    pub evictions: u64,
+    pub cached_pages: u64,
+    pub page_size: u64,
+}
+
+struct CachedPage {
+    data: Arc<[u8]>,
+    tick: u64,
+}
+
+#[derive(Default)]
+struct CacheState {
+    pages: HashMap<u64, CachedPage>,
+    lru: BTreeMap<u64, u64>,
+    tick: u64,
+    last_page: Option<u64>,
+    /// Bumped on every invalidation so that pages read concurrently with a write are not cached.
+    generation: u64,
+    stats: CacheStats,
+}
+
+impl CacheState {
+    fn touch(&mut self, index: u64) -> Option<Arc<[u8]>> {
+        self.tick += 1;
+        let tick = self.tick;
+        let page = self.pages.get_mut(&index)?;
+        self.lru.remove(&page.tick);
+        self.lru.insert(tick, index);
+        page.tick = tick;
+        Some(page.data.clone())
+    }
+
+    fn insert(&mut self, index: u64, data: Arc<[u8]>, max_pages: usize) {
+        self.tick += 1;
+        let tick = self.tick;
+        if let Some(old) = self.pages.insert(index, CachedPage { data, tick }) {
+            self.lru.remove(&old.tick);
+        }
+        self.lru.insert(tick, index);
+
+        while self.pages.len() > max_pages {
+            let Some((_, victim)) = self.lru.pop_first() else { break };
+            self.pages.remove(&victim);
+            self.stats.evictions += 1;
+        }
+    }
+
+    fn invalidate(&mut self, first: u64, last: u64) {
+        self.generation += 1;
+        let stale: Vec<u64> = self.pages
+            .keys()
+            .copied()
+            .filter(|index| (first..=last).contains(index))
+            .collect();
+
+        for index in stale {
+            if let Some(page) = self.pages.remove(&index) {
+                self.lru.remove(&page.tick);
+            }
+        }
+    }
+}
+
+/// Read-through page cache in front of another [ByteSource].
+///
+/// Data is fetched from the inner source in fixed-size pages which are kept in an
+/// LRU shared by every clone of the owning `Arc`. When pages are requested in
+/// ascending order the following pages are fetched in the same inner read.
+/// Writes go straight to the inner source and drop any cached page they touch.
+pub struct CachedSource {
+    inner: Arc<dyn ByteSource>,
+    page_size: usize,
+    max_pages: usize,
+    read_ahead: usize,
+    state: Mutex<CacheState>,
+}
+
+impl CachedSource {
+    pub const DEFAULT_PAGE_SIZE: usize = 0x10000;
+    pub const DEFAULT_MAX_PAGES: usize = 256;
+    pub const DEFAULT_READ_AHEAD: usize = 4;
+
+    pub fn new(inner: Arc<dyn ByteSource>) -> Self {
+        Self::with_config(
+            inner,
+            Self::DEFAULT_PAGE_SIZE,
+            Self::DEFAULT_MAX_PAGES,
+            Self::DEFAULT_READ_AHEAD,
         )
     }
+
+    /// `page_size` is in bytes, `max_pages` bounds the LRU and `read_ahead` is the number
+    /// of extra pages fetched on a sequential miss.
+    pub fn with_config(inner: Arc<dyn ByteSource>, page_size: usize, max_pages: usize, read_ahead: usize) -> Self {
+        Self {
+            inner,
+            page_size: page_size.max(1),
+            max_pages: max_pages.max(1),
+            read_ahead,
+            state: Mutex::new(CacheState::default()),
+        }
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        let state = self.state.lock().unwrap();
+        CacheStats {
+            cached_pages: state.pages.len() as u64,
+            page_size: self.page_size as u64,
+            ..state.stats
+        }
+    }
+
+    /// Drops every cached page. Statistics are kept.
+    pub fn clear(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.pages.clear();
+        state.lru.clear();
+        state.last_page = None;
+        state.generation += 1;
+    }
+
+    fn page(&self, index: u64) -> std::io::Result<Arc<[u8]>> {
+        let (sequential, generation) = {
+            let mut state = self.state.lock().unwrap();
+            let sequential = state.last_page.is_some_and(|last| last + 1 == index);
+            state.last_page = Some(index);
+
+            if let Some(data) = state.touch(index) {
+                state.stats.hits += 1;
+                return Ok(data);
+            }
+
+            state.stats.misses += 1;
+            (sequential, state.generation)
+        };
+
+        // The lock is released while the inner source is read so other threads can keep hitting the cache.
+        let page_size = self.page_size as u64;
+        let total_pages = self.inner.len().div_ceil(page_size);
+        let extra = if sequential {
+            (self.read_ahead as u64).min(total_pages.saturating_sub(index + 1))
+        } else {
+            0
+        };
+
+        let bytes = self.inner.read_range(index * page_size, self.page_size * (extra as usize + 1))?;
+        let mut chunks = bytes.chunks(self.page_size);
+        let first: Arc<[u8]> = Arc::from(chunks.next().unwrap_or_default());
+
+        let mut state = self.state.lock().unwrap();
+        if state.generation != generation {
+            // A write invalidated the cache while the inner source was read; the pages may be stale.
+            return Ok(first);
+        }
+
+        state.insert(index, first.clone(), self.max_pages);
+        for (i, chunk) in chunks.enumerate() {
+            state.insert(index + 1 + i as u64, Arc::from(chunk), self.max_pages);
+            state.stats.read_ahead += 1;
+        }
+
+        Ok(first)
+    }
+}
+
+impl ByteSource for CachedSource {
+    fn len(&self) -> u64 {
+        self.inner.len()
+    }
+
+    fn read_range(&self, offset: u64, size: usize) -> std::io::Result<Vec<u8>> {
+        read_range_into(self, offset, size)
+    }
+
+    fn read_into(&self, offset: u64, buf: &mut [u8]) -> std::io::Result<usize> {
+        let len = self.inner.len();
+        if offset >= len || buf.is_empty() {
+            return Ok(0);
+        }
+
+        let end = (offset + buf.len() as u64).min(len);
+        let page_size = self.page_size as u64;
+        let mut pos = offset;
+
+        while pos < end {
+            let page = self.page(pos / page_size)?;
+            let start = (pos % page_size) as usize;
+            if start >= page.len() {
+                break;
+            }
+
+            let take = (page.len() - start).min((end - pos) as usize);
+            let at = (pos - offset) as usize;
+            buf[at..at + take].copy_from_slice(&page[start..start + take]);
+            pos += take as u64;
+        }
+
+        Ok((pos - offset) as usize)
+    }
+
+    fn write_range(&self, offset: u64, data: &[u8]) -> std::io::Result<()> {
+        let old_len = self.inner.len();
+        let result = self.inner.write_range(offset, data);
+
+        // Pages between the old end and the write may have been short, so they are dropped as well.
+        let page_size = self.page_size as u64;
+        let first = offset.min(old_len) / page_size;
+        let last = (offset + data.len() as u64) / page_size;
+        self.state.lock().unwrap().invalidate(first, last);
+
+        result
+    }
+
+    fn set_len(&self, len: u64) -> std::io::Result<()> {
+        let old_len = self.inner.len();
+        let result = self.inner.set_len(len);
+
+        let page_size = self.page_size as u64;
+        self.state.lock().unwrap().invalidate(old_len.min(len) / page_size, old_len.max(len) / page_size);
+
+        result
+    }
+
+    fn is_writable(&self) -> bool {
+        self.inner.is_writable()
+    }
+
+    fn cache_stats(&self) -> Option<CacheStats> {
+        Some(self.stats())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Source whose next read runs a hook after fetching its data, to interleave a write.
+    struct RacingSource {
+        data: MemoryByteSource,
+        hook: Mutex<Option<Box<dyn FnOnce() + Send>>>,
+    }
+
+    impl ByteSource for RacingSource {
+        fn len(&self) -> u64 {
+            self.data.len()
+        }
+
+        fn read_range(&self, offset: u64, size: usize) -> std::io::Result<Vec<u8>> {
+            let bytes = self.data.read_range(offset, size)?;
+            if let Some(hook) = self.hook.lock().unwrap().take() {
+                hook();
+            }
+            Ok(bytes)
+        }
+
+        fn write_range(&self, offset: u64, data: &[u8]) -> std::io::Result<()> {
+            self.data.write_range(offset, data)
+        }
+    }
+
+    #[test]
+    fn cached_source_reads_across_pages() {
+        let data: Vec<u8> = (0..1000u32).map(|i| i as u8).collect();
+        let cache = CachedSource::with_config(Arc::new(MemoryByteSource::from_vec(data.clone())), 64, 4, 2);
+
+        assert_eq!(cache.read_range(0, 1000).unwrap(), data);
+        assert_eq!(cache.read_range(60, 10).unwrap(), &data[60..70]);
+        assert_eq!(cache.read_range(990, 100).unwrap(), &data[990..]);
+        assert!(cache.stats().cached_pages <= 4);
+    }
+
+    #[test]
+    fn cached_source_drops_pages_on_write() {
+        let cache = CachedSource::with_config(Arc::new(MemoryByteSource::from_vec(vec![0; 256])), 64, 16, 0);
+        assert_eq!(cache.read_range(70, 2).unwrap(), [0, 0]);
+
+        cache.write_range(70, &[1, 2]).unwrap();
+        assert_eq!(cache.read_range(70, 2).unwrap(), [1, 2]);
+
+        cache.set_len(300).unwrap();
+        assert_eq!(cache.read_range(250, 50).unwrap(), vec![0; 50]);
+    }
+
+    #[test]
+    fn cached_source_skips_pages_read_during_a_write() {
+        let inner = Arc::new(RacingSource { data: MemoryByteSource::from_vec(vec![0; 128]), hook: Mutex::new(None) });
+        let cache = Arc::new(CachedSource::with_config(inner.clone(), 64, 16, 0));
+
+        let writer = Arc::downgrade(&cache);
+        *inner.hook.lock().unwrap() = Some(Box::new(move || {
+            writer.upgrade().unwrap().write_range(0, &[7]).unwrap();
+        }));
+
+        // The first read returns the data from before the write, but must not cache it.
+        assert_eq!(cache.read_range(0, 1).unwrap(), [0]);
+        assert_eq!(cache.read_range(0, 1).unwrap(), [7]);
+    }
+
+    #[test]
+    fn overlay_keeps_writes_off_the_parent() {
+        let parent = Arc::new(MemoryByteSource::from_vec(vec![1; 16]));
+        let overlay = OverlaySource::new(parent.clone());
+
+        overlay.write_range(4, &[9, 9]).unwrap();
+        overlay.write_range(5, &[8, 8]).unwrap();
+        overlay.write_range(20, &[5]).unwrap();
+
+        assert_eq!(overlay.len(), 21);
+        assert_eq!(overlay.read_range(3, 5).unwrap(), [1, 9, 8, 8, 1]);
+        assert_eq!(overlay.read_range(16, 5).unwrap(), [0, 0, 0, 0, 5]);
+        assert_eq!(parent.read_range(0, 16).unwrap(), vec![1; 16]);
+        assert_eq!(overlay.delta_size(), 4);
+        assert!(overlay.is_dirty());
+
+        overlay.discard();
+        assert!(!overlay.is_dirty());
+        assert_eq!(overlay.read_range(4, 2).unwrap(), [1, 1]);
+    }
+
+    #[test]
+    fn overlay_shrink_hides_parent_bytes() {
+        let overlay = OverlaySource::new(Arc::new(MemoryByteSource::from_vec(vec![1; 8])));
+        overlay.write_range(2, &[7, 7, 7]).unwrap();
+
+        overlay.set_len(3).unwrap();
+        overlay.set_len(6).unwrap();
+        assert_eq!(overlay.read_range(0, 6).unwrap(), [1, 1, 7, 0, 0, 0]);
+    }
+
+    #[test]
+    fn overlay_delta_round_trip() {
+        let parent: Arc<dyn ByteSource> = Arc::new(MemoryByteSource::from_vec(vec![0; 32]));
+        let overlay = OverlaySource::new(parent.clone());
+        overlay.write_range(10, b"delta").unwrap();
+
+        let path = std::env::temp_dir().join(format!("nebula_delta_{}.bin", std::process::id()));
+        let path = path.to_string_lossy().to_string();
+        overlay.save_delta(&path).unwrap();
+
+        let restored = OverlaySource::new(parent);
+        restored.load_delta(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(restored.read_range(0, 32).unwrap(), overlay.read_range(0, 32).unwrap());
+    }
+
+    #[test]
+    fn memory_writes_past_the_end_are_bounded() {
+        let source = MemoryByteSource::from_vec(vec![1, 2]);
+        source.write_range(4, &[5]).unwrap();
+        assert_eq!(source.read_range(0, 8).unwrap(), [1, 2, 0, 0, 5]);
+
+        assert!(source.write_range(5 + MAX_WRITE_GAP + 1, &[1]).is_err());
+        assert!(source.write_range(u64::MAX, &[1]).is_err());
+        assert_eq!(source.len(), 5);
+    }
+
+    #[test]
+    fn overlay_rejects_out_of_bounds_deltas() {
+        let parent: Arc<dyn ByteSource> = Arc::new(MemoryByteSource::from_vec(vec![0; 32]));
+        let path = std::env::temp_dir().join(format!("nebula_bad_delta_{}.bin", std::process::id()));
+        let path = path.to_string_lossy().to_string();
+        let delta = |len: u64, visible: u64, extents: &[(u64, &[u8])]| {
+            let mut out = DELTA_MAGIC.to_vec();
+            out.extend_from_slice(&DELTA_VERSION.to_le_bytes());
+            for value in [32, len, visible, extents.len() as u64] {
+                out.extend_from_slice(&value.to_le_bytes());
+            }
+            for (offset, data) in extents {
+                out.extend_from_slice(&offset.to_le_bytes());
+                out.extend_from_slice(&(data.len() as u64).to_le_bytes());
+                out.extend_from_slice(data);
+            }
+            std::fs::write(&path, out).unwrap();
+            OverlaySource::new(parent.clone()).load_delta(&path)
+        };
+
+        assert!(delta(40, 32, &[(0, b"ab"), (38, b"cd")]).is_ok());
+        assert!(delta(40, 32, &[(39, b"cd")]).is_err());
+        assert!(delta(40, 32, &[(u64::MAX, b"cd")]).is_err());
+        assert!(delta(40, 32, &[(4, b"abcd"), (6, b"cd")]).is_err());
+        assert!(delta(16, 32, &[]).is_err());
+        let _ = std::fs::remove_file(&path);
+    }
 }
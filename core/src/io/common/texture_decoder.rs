@@ -30,8 +30,8 @@ impl TextureDecoder {
         let lut = if use_alpha { &luts.0 } else { &luts.1 };
         let lut_size = lut.len();
         
-        let mut tex_buffer = tex.bind_mut();
-        let tex_size = tex_buffer.size();
+        let mut tex_data = vec![0u8; tex.bind().size() as usize];
+        let tex_size = tex.bind_mut().read_into(0, &mut tex_data);
         
         let width = 1024;
         let height = 256;
@@ -57,9 +57,8 @@ impl TextureDecoder {
                         
                         for x in 0..4 {
                             if tex_index + 1 < tex_size {
-                                tex_buffer.goto(tex_index as i32);
-                                let byte1 = tex_buffer.read_u8();
-                                let byte2 = tex_buffer.read_u8();
+                                let byte1 = tex_data[tex_index];
+                                let byte2 = tex_data[tex_index + 1];
                                 let pixel_value = ((byte1 as usize) << 8) | (byte2 as usize);
                                 tex_index += 2;
                                 
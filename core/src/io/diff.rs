@@ -0,0 +1,282 @@
+use std::io;
+use crate::io::bytesource::ByteSource;
+
+/// Size of the chunks compared at once while the two sources are equal.
+const DIFF_CHUNK: usize = 1 << 16;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DiffKind {
+    /// Bytes that differ in place; the old and new sizes are equal.
+    Changed,
+    /// Bytes that only exist in the new source.
+    Inserted,
+    /// Bytes that only exist in the old source.
+    Removed,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DiffRange {
+    pub kind: DiffKind,
+    pub old_offset: u64,
+    pub old_size: u64,
+    pub new_offset: u64,
+    pub new_size: u64,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DiffStats {
+    pub equal_bytes: u64,
+    pub changed_bytes: u64,
+    pub inserted_bytes: u64,
+    pub removed_bytes: u64,
+}
+
+/// How [`diff_sources`] realigns the sources after a mismatch.
+#[derive(Clone, Copy, Debug)]
+pub struct DiffOptions {
+    /// Largest change, insertion or removal that is looked for.
+    pub window: u64,
+    /// Only insertions and removals whose size is a multiple of this are looked for.
+    pub alignment: u64,
+    /// Number of bytes that must match after an insertion or removal to accept it.
+    pub sync: u64,
+}
+
+impl Default for DiffOptions {
+    fn default() -> Self {
+        Self { window: 4096, alignment: 1, sync: 16 }
+    }
+}
+
+/// Caches a region of a source so nearby reads do not go back to it.
+struct Window<'a> {
+    source: &'a dyn ByteSource,
+    start: u64,
+    data: Vec<u8>,
+}
+
+impl<'a> Window<'a> {
+    fn new(source: &'a dyn ByteSource) -> Self {
+        Self { source, start: 0, data: Vec::new() }
+    }
+
+    /// Returns up to `size` bytes at `pos`, fewer only at the end of the source.
+    fn get(&mut self, pos: u64, size: usize) -> io::Result<&[u8]> {
+        let len = self.source.len();
+        let size = size.min(len.saturating_sub(pos) as usize);
+
+        if pos < self.start || pos + size as u64 > self.start + self.data.len() as u64 {
+            let load = size.max(DIFF_CHUNK).min(len.saturating_sub(pos) as usize);
+            self.data.resize(load, 0);
+            let read = self.source.read_into(pos, &mut self.data)?;
+            if read < load {
+                return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "source shorter than its length"));
+            }
+            self.start = pos;
+        }
+
+        let at = (pos - self.start) as usize;
+        Ok(&self.data[at..at + size])
+    }
+}
+
+/// Compares `old` with `new` and returns the ranges that differ, in order, with summary statistics.
+///
+/// Each mismatch is explained as the smallest run of changed bytes, insertion or removal of up to
+/// [`DiffOptions::window`] bytes after which at least [`DiffOptions::sync`] bytes match again. If there
+/// is none, the bytes up to the next equal byte are reported as changed. Data left over at the end of
+/// either source is reported as inserted or removed.
+pub fn diff_sources(old: &dyn ByteSource, new: &dyn ByteSource, options: DiffOptions) -> io::Result<(Vec<DiffRange>, DiffStats)> {
+    let (old_len, new_len) = (old.len(), new.len());
+    let mut old_window = Window::new(old);
+    let mut new_window = Window::new(new);
+    let mut ranges: Vec<DiffRange> = Vec::new();
+    let mut stats = DiffStats::default();
+    let (mut i, mut j) = (0u64, 0u64);
+
+    while i < old_len && j < new_len {
+        let a = old_window.get(i, DIFF_CHUNK)?;
+        let b = new_window.get(j, DIFF_CHUNK)?;
+        let common = a.len().min(b.len());
+        let equal = a.iter().zip(b).position(|(x, y)| x != y).unwrap_or(common) as u64;
+
+        i += equal;
+        j += equal;
+        stats.equal_bytes += equal;
+        if equal as usize == common {
+            continue;
+        }
+
+        let range = match resync(&mut old_window, &mut new_window, i, j, options)? {
+            Some(range) => range,
+            None => {
+                // No realignment within the window: treat everything up to the next equal byte as changed.
+                let a = old_window.get(i, DIFF_CHUNK)?;
+                let b = new_window.get(j, DIFF_CHUNK)?;
+                let common = a.len().min(b.len());
+                let changed = a.iter().zip(b).position(|(x, y)| x == y).unwrap_or(common) as u64;
+                DiffRange { kind: DiffKind::Changed, old_offset: i, old_size: changed, new_offset: j, new_size: changed }
+            }
+        };
+
+        i += range.old_size;
+        j += range.new_size;
+        match range.kind {
+            DiffKind::Changed => stats.changed_bytes += range.old_size,
+            DiffKind::Inserted => stats.inserted_bytes += range.new_size,
+            DiffKind::Removed => stats.removed_bytes += range.old_size,
+        }
+
+        match ranges.last_mut() {
+            Some(last) if last.kind == range.kind
+                && last.old_offset + last.old_size == range.old_offset
+                && last.new_offset + last.new_size == range.new_offset => {
+                last.old_size += range.old_size;
+                last.new_size += range.new_size;
+            }
+            _ => ranges.push(range),
+        }
+    }
+
+    if i < old_len {
+        ranges.push(DiffRange { kind: DiffKind::Removed, old_offset: i, old_size: old_len - i, new_offset: j, new_size: 0 });
+        stats.removed_bytes += old_len - i;
+    }
+    if j < new_len {
+        ranges.push(DiffRange { kind: DiffKind::Inserted, old_offset: i, old_size: 0, new_offset: j, new_size: new_len - j });
+        stats.inserted_bytes += new_len - j;
+    }
+
+    Ok((ranges, stats))
+}
+
+/// Looks for the smallest run of changed bytes, insertion or removal at `i`/`j` after which the
+/// sources match for [`DiffOptions::sync`] bytes. At equal sizes a changed run is preferred.
+fn resync(old: &mut Window, new: &mut Window, i: u64, j: u64, options: DiffOptions) -> io::Result<Option<DiffRange>> {
+    let sync = options.sync.max(1) as usize;
+    let align = options.alignment.max(1) as usize;
+    let reach = (options.window as usize).saturating_add(sync);
+    let a = old.get(i, reach)?;
+    let b = new.get(j, reach)?;
+    let matches = |x: &[u8], from_x: usize, y: &[u8], from_y: usize| {
+        x.len() >= from_x + sync && y.len() >= from_y + sync && x[from_x..from_x + sync] == y[from_y..from_y + sync]
+    };
+
+    for shift in 1..=options.window as usize {
+        if shift + sync > a.len() && shift + sync > b.len() {
+            break;
+        }
+
+        let size = shift as u64;
+        if matches(a, shift, b, shift) {
+            return Ok(Some(DiffRange { kind: DiffKind::Changed, old_offset: i, old_size: size, new_offset: j, new_size: size }));
+        }
+        if shift % align != 0 {
+            continue;
+        }
+        if matches(a, 0, b, shift) {
+            return Ok(Some(DiffRange { kind: DiffKind::Inserted, old_offset: i, old_size: 0, new_offset: j, new_size: size }));
+        }
+        if matches(a, shift, b, 0) {
+            return Ok(Some(DiffRange { kind: DiffKind::Removed, old_offset: i, old_size: size, new_offset: j, new_size: 0 }));
+        }
+    }
+
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::bytesource::MemoryByteSource;
+
+    /// Bytes that never repeat within the diff window, so realignment is unambiguous.
+    fn noise(len: usize, seed: u32) -> Vec<u8> {
+        let mut state = seed;
+        (0..len).map(|_| {
+            state = state.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            (state >> 16) as u8
+        }).collect()
+    }
+
+    fn diff(old: &[u8], new: &[u8], options: DiffOptions) -> (Vec<DiffRange>, DiffStats) {
+        let old = MemoryByteSource::from_vec(old.to_vec());
+        let new = MemoryByteSource::from_vec(new.to_vec());
+        diff_sources(&old, &new, options).unwrap()
+    }
+
+    fn range(kind: DiffKind, old_offset: u64, old_size: u64, new_offset: u64, new_size: u64) -> DiffRange {
+        DiffRange { kind, old_offset, old_size, new_offset, new_size }
+    }
+
+    #[test]
+    fn equal_sources_have_no_ranges() {
+        let data = noise(3 * DIFF_CHUNK + 5, 1);
+        let (ranges, stats) = diff(&data, &data, DiffOptions::default());
+        assert!(ranges.is_empty());
+        assert_eq!(stats.equal_bytes, data.len() as u64);
+    }
+
+    #[test]
+    fn resyncs_after_insertion_and_removal() {
+        let old = noise(1000, 2);
+        let mut new = old.clone();
+        new.splice(100..100, [0xAA; 7]);
+        new.drain(507..512);
+
+        let (ranges, stats) = diff(&old, &new, DiffOptions::default());
+        assert_eq!(ranges, [
+            range(DiffKind::Inserted, 100, 0, 100, 7),
+            range(DiffKind::Removed, 500, 5, 507, 0),
+        ]);
+        assert_eq!(stats, DiffStats { equal_bytes: 995, changed_bytes: 0, inserted_bytes: 7, removed_bytes: 5 });
+    }
+
+    #[test]
+    fn prefers_changes_over_shifts() {
+        let old = noise(400, 3);
+        let mut new = old.clone();
+        new[200..203].copy_from_slice(&[!old[200], !old[201], !old[202]]);
+
+        let (ranges, stats) = diff(&old, &new, DiffOptions::default());
+        assert_eq!(ranges, [range(DiffKind::Changed, 200, 3, 200, 3)]);
+        assert_eq!(stats.changed_bytes, 3);
+    }
+
+    #[test]
+    fn only_shifts_by_the_alignment() {
+        let old = noise(400, 4);
+        let options = DiffOptions { alignment: 4, ..DiffOptions::default() };
+
+        let mut aligned = old.clone();
+        aligned.splice(64..64, noise(8, 5));
+        assert_eq!(diff(&old, &aligned, options).0, [range(DiffKind::Inserted, 64, 0, 64, 8)]);
+
+        let mut unaligned = old.clone();
+        unaligned.splice(64..64, noise(6, 5));
+        assert_eq!(diff(&old, &unaligned, DiffOptions::default()).0, [range(DiffKind::Inserted, 64, 0, 64, 6)]);
+        let (ranges, _) = diff(&old, &unaligned, options);
+        assert_eq!(ranges.first().map(|r| r.kind), Some(DiffKind::Changed));
+        assert!(!ranges.iter().any(|r| r.kind == DiffKind::Inserted && r.new_offset < old.len() as u64));
+    }
+
+    #[test]
+    fn reports_leftover_data_at_the_end() {
+        let old = noise(300, 6);
+        let (ranges, _) = diff(&old, &old[..250], DiffOptions::default());
+        assert_eq!(ranges, [range(DiffKind::Removed, 250, 50, 250, 0)]);
+        let (ranges, _) = diff(&old[..250], &old, DiffOptions::default());
+        assert_eq!(ranges, [range(DiffKind::Inserted, 250, 0, 250, 50)]);
+    }
+
+    #[test]
+    fn resyncs_across_chunk_boundaries() {
+        let old = noise(2 * DIFF_CHUNK, 7);
+        let mut new = old.clone();
+        new.splice(DIFF_CHUNK - 2..DIFF_CHUNK - 2, [1, 2, 3]);
+
+        let (ranges, stats) = diff(&old, &new, DiffOptions::default());
+        assert_eq!(ranges, [range(DiffKind::Inserted, DIFF_CHUNK as u64 - 2, 0, DIFF_CHUNK as u64 - 2, 3)]);
+        assert_eq!(stats.equal_bytes, old.len() as u64);
+    }
+}
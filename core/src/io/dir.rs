@@ -1,13 +1,28 @@
-use godot::prelude::*;
-use std::{path::Path, sync::Arc};
-use crate::io::{file::NebulaFile, fs::NebulaFs};
+use godot::{classes::ProjectSettings, prelude::*};
+use std::{
+    io::Write,
+    path::{Component, Path, PathBuf},
+    sync::Arc,
+};
+use crate::io::{
+    bytesource::ByteSource,
+    file::NebulaFile,
+    fs::NebulaFs,
+    hash::{hash_source, HashAlgorithm},
+    mount::MountFs,
+    native::NativeFs,
+    overlay::OverlayFs,
+    task::{NebulaTask, Progress},
+};
+
+/// Size of the chunks copied by [`extract_files`].
+const EXTRACT_CHUNK: usize = 1 << 20;
 
 #[derive(GodotClass)]
 #[class(base=RefCounted)]
 pub struct NebulaDir {
     fs: Option<Arc<dyn NebulaFs>>,
     path: String,
-    native_path: Option<String>,
     #[base] base: Base<RefCounted>,
 }
 
@@ -17,7 +32,6 @@ impl IRefCounted for NebulaDir {
         Self {
             fs: None,
             path: String::new(),
-            native_path: None,
             base
         }
     }
@@ -27,9 +41,9 @@ impl IRefCounted for NebulaDir {
 impl NebulaDir {
     #[func]
     /// Opens a directory from the regular filesystem at the given path.
-    /// Returns a new NebulaDir instance that operates on the native filesystem.
+    /// Paths below the returned [NebulaDir] cannot leave this directory.
     pub fn open(path: GString) -> Gd<Self> {
-        let path_str = path.to_string();
+        let path_str = ProjectSettings::singleton().globalize_path(&path).to_string();
         
         let path_obj = Path::new(&path_str);
         if !path_obj.exists() {
@@ -42,50 +56,22 @@ impl NebulaDir {
             return NebulaDir::new_gd();
         }
         
-        Gd::from_init_fn(|base| Self {
-            fs: None,
-            path: String::new(),
-            native_path: Some(path_str),
-            base,
-        })
+        Self::root(Arc::new(NativeFs::new(path_str)))
     }
 
     #[func]
     /// Returns a [PackedStringArray] of entries; directories are denoted by a "`/`" at the end.
     pub fn get_entries(&self) -> PackedStringArray {
-        // If using native filesystem
-        if let Some(native_path) = &self.native_path {
-            return self.get_native_entries(native_path);
-        }
-        
-        // Otherwise use virtual filesystem
-        match &self.fs {
+        match self.fs() {
             Some(fs) => fs.get_entries(&self.path),
-            None => {
-                godot_warn!("NebulaDir used before initialization");
-                PackedStringArray::new()
-            }
+            None => PackedStringArray::new(),
         }
     }
 
     #[func]
     pub fn get_file(&self, rel: String) -> Gd<NebulaFile> {
-        // If using native filesystem
-        if let Some(native_path) = &self.native_path {
-            let full_path = Path::new(native_path).join(&rel);
-            return NebulaFile::open(full_path.to_string_lossy().to_string().to_godot());
-        }
-        
-        // Otherwise use virtual filesystem
-        match &self.fs {
-            Some(fs) => {
-                let full = if self.path.is_empty() {
-                    rel
-                } else {
-                    format!("{}/{}", self.path, rel)
-                };
-                fs.get_file(&full)
-            }
+        match self.fs() {
+            Some(fs) => fs.get_file(&self.full_path(&rel)),
             None => NebulaFile::new_gd(),
         }
     }
@@ -104,47 +90,13 @@ impl NebulaDir {
 
     #[func]
     pub fn file_exists(&self, rel: String) -> bool {
-        // If using native filesystem
-        if let Some(native_path) = &self.native_path {
-            let full_path = Path::new(native_path).join(&rel);
-            return full_path.exists() && full_path.is_file();
-        }
-        
-        // Otherwise use virtual filesystem
-        match &self.fs {
-            Some(fs) => {
-                let full = if self.path.is_empty() {
-                    rel
-                } else {
-                    format!("{}/{}", self.path, rel)
-                };
-                fs.file_exists(&full)
-            }
-            None => false,
-        }
+        self.fs().is_some_and(|fs| fs.file_exists(&self.full_path(&rel)))
     }
 
     #[func]
     pub fn get_dir(&self, rel: String) -> Gd<NebulaDir> {
-        // If using native filesystem
-        if let Some(native_path) = &self.native_path {
-            let full_path = Path::new(native_path).join(&rel);
-            return NebulaDir::open(full_path.to_string_lossy().to_string().to_godot());
-        }
-        
-        // Otherwise use virtual filesystem
-        match &self.fs {
-            Some(fs) => {
-                let mut full = if self.path.is_empty() {
-                    rel
-                } else {
-                    format!("{}/{}", self.path, rel)
-                };
-                if full.ends_with('/') {
-                    full.pop();
-                }
-                fs.get_dir(&full)
-            }
+        match self.fs() {
+            Some(fs) => fs.get_dir(&self.full_path(&rel)),
             None => NebulaDir::new_gd(),
         }
     }
@@ -163,13 +115,12 @@ impl NebulaDir {
 
     #[func]
     /// Returns the path of this directory.
-    /// For virtual filesystems, this is the virtual path.
-    /// For native filesystems, this is the native filesystem path.
+    /// For directories opened with [method open], this is the native filesystem path.
+    /// Otherwise it is the path inside the virtual filesystem.
     pub fn get_path(&self) -> GString {
-        if let Some(native_path) = &self.native_path {
-            native_path.to_godot()
-        } else {
-            self.path.to_godot()
+        match self.fs.as_ref().and_then(|fs| fs.native_path(&self.path)) {
+            Some(native) => GString::from(native.to_string_lossy().as_ref()),
+            None => self.path.to_godot(),
         }
     }
 
@@ -177,61 +128,16 @@ impl NebulaDir {
     /// Creates a new directory at the given relative path.
     /// Returns true if successful, false otherwise.
     pub fn create_dir(&self, rel: String) -> bool {
-        // If using native filesystem
-        if let Some(native_path) = &self.native_path {
-            let full_path = Path::new(native_path).join(&rel);
-            return std::fs::create_dir_all(&full_path).is_ok();
-        }
-        
-        // Otherwise use virtual filesystem
-        match &self.fs {
-            Some(fs) => {
-                let full = if self.path.is_empty() {
-                    rel
-                } else {
-                    format!("{}/{}", self.path, rel)
-                };
-                fs.create_dir(&full)
-            }
-            None => {
-                godot_warn!("NebulaDir used before initialization");
-                false
-            }
-        }
+        self.fs().is_some_and(|fs| fs.create_dir(&self.full_path(&rel)))
     }
 
     #[func]
     /// Creates a new file at the given relative path.
     /// Returns a NebulaFile instance if successful, or an invalid instance otherwise.
     pub fn create_file(&self, rel: String) -> Gd<NebulaFile> {
-        // If using native filesystem
-        if let Some(native_path) = &self.native_path {
-            let full_path = Path::new(native_path).join(&rel);
-            let full_path_str = full_path.to_string_lossy().to_string();
-            
-            // Try to create an empty file
-            if std::fs::File::create(&full_path).is_ok() {
-                return NebulaFile::open(full_path_str.to_godot());
-            } else {
-                godot_error!("Failed to create file at '{}'", full_path_str);
-                return NebulaFile::new_gd();
-            }
-        }
-        
-        // Otherwise use virtual filesystem
-        match &self.fs {
-            Some(fs) => {
-                let full = if self.path.is_empty() {
-                    rel
-                } else {
-                    format!("{}/{}", self.path, rel)
-                };
-                fs.create_file(&full)
-            }
-            None => {
-                godot_warn!("NebulaDir used before initialization");
-                NebulaFile::new_gd()
-            }
+        match self.fs() {
+            Some(fs) => fs.create_file(&self.full_path(&rel)),
+            None => NebulaFile::new_gd(),
         }
     }
 
@@ -239,54 +145,14 @@ impl NebulaDir {
     /// Removes a file at the given relative path.
     /// Returns true if successful, false otherwise.
     pub fn remove_file(&self, rel: String) -> bool {
-        // If using native filesystem
-        if let Some(native_path) = &self.native_path {
-            let full_path = Path::new(native_path).join(&rel);
-            return std::fs::remove_file(&full_path).is_ok();
-        }
-        
-        // Otherwise use virtual filesystem
-        match &self.fs {
-            Some(fs) => {
-                let full = if self.path.is_empty() {
-                    rel
-                } else {
-                    format!("{}/{}", self.path, rel)
-                };
-                fs.remove_file(&full)
-            }
-            None => {
-                godot_warn!("NebulaDir used before initialization");
-                false
-            }
-        }
+        self.fs().is_some_and(|fs| fs.remove_file(&self.full_path(&rel)))
     }
 
     #[func]
     /// Removes a directory at the given relative path.
     /// Returns true if successful, false otherwise.
     pub fn remove_dir(&self, rel: String) -> bool {
-        // If using native filesystem
-        if let Some(native_path) = &self.native_path {
-            let full_path = Path::new(native_path).join(&rel);
-            return std::fs::remove_dir(&full_path).is_ok();
-        }
-        
-        // Otherwise use virtual filesystem
-        match &self.fs {
-            Some(fs) => {
-                let full = if self.path.is_empty() {
-                    rel
-                } else {
-                    format!("{}/{}", self.path, rel)
-                };
-                fs.remove_dir(&full)
-            }
-            None => {
-                godot_warn!("NebulaDir used before initialization");
-                false
-            }
-        }
+        self.fs().is_some_and(|fs| fs.remove_dir(&self.full_path(&rel)))
     }
 
     #[func]
@@ -294,60 +160,12 @@ impl NebulaDir {
     /// Both paths should be relative to this directory.
     /// Returns true if successful, false otherwise.
     pub fn rename_path(&self, from: String, to: String) -> bool {
-        // If using native filesystem
-        if let Some(native_path) = &self.native_path {
-            let from_path = Path::new(native_path).join(&from);
-            let to_path = Path::new(native_path).join(&to);
-            return std::fs::rename(&from_path, &to_path).is_ok();
-        }
-        
-        // Otherwise use virtual filesystem
-        match &self.fs {
-            Some(fs) => {
-                let from_full = if self.path.is_empty() {
-                    from
-                } else {
-                    format!("{}/{}", self.path, from)
-                };
-                let to_full = if self.path.is_empty() {
-                    to
-                } else {
-                    format!("{}/{}", self.path, to)
-                };
-                fs.rename_path(&from_full, &to_full)
-            }
-            None => {
-                godot_warn!("NebulaDir used before initialization");
-                false
-            }
-        }
+        self.fs().is_some_and(|fs| fs.rename_path(&self.full_path(&from), &self.full_path(&to)))
     }
 
     #[func]
     pub fn dir_exists(&self, rel: String) -> bool {
-        // If using native filesystem
-        if let Some(native_path) = &self.native_path {
-            let full_path = Path::new(native_path).join(&rel);
-            return full_path.exists() && full_path.is_dir();
-        }
-        
-        // Otherwise use virtual filesystem
-        match &self.fs {
-            Some(fs) => {
-                let mut full = if self.path.is_empty() {
-                    rel
-                } else {
-                    format!("{}/{}", self.path, rel)
-                };
-                
-                if full.ends_with('/') {
-                    full.pop();
-                }
-
-                fs.dir_exists(&full)
-            }
-            None => false,
-        }
+        self.fs().is_some_and(|fs| fs.dir_exists(&self.full_path(&rel)))
     }
 
     #[func]
@@ -360,12 +178,107 @@ impl NebulaDir {
         #[opt(default = false)] filesize: bool,
         #[opt(default = 0)] indent: i32,
     ) {
-        if let Some(native_path) = &self.native_path {
-            self.print_native_files(native_path, filesize, indent, true);
-        } else if let Some(fs) = &self.fs {
-            self.print_files_recursive(fs.as_ref(), &self.path, filesize, indent, true);
-        } else {
+        let Some(fs) = self.fs() else {
+            return;
+        };
+
+        let total_size = Self::print_files_recursive(fs.as_ref(), &self.path, filesize, indent);
+        if filesize {
+            let total_str = GString::humanize_size(total_size as i64);
+            godot_print!("TOTAL SIZE: {}", total_str);
+        }
+    }
+
+    #[func]
+    /// Returns this directory with archives listed as folders, or as files if `show` is `false`.
+    /// Paths into archives, like `Stage/01-01.arc/course/course1.bin`, work either way.
+    pub fn show_archives(&self, #[opt(default = true)] show: bool) -> Gd<NebulaDir> {
+        let Some(fs) = self.fs() else {
+            return NebulaDir::new_gd();
+        };
+
+        let fs = fs.show_archives(show).unwrap_or_else(|| Arc::new(MountFs::new(fs.clone(), show)));
+        Self::new(fs, self.path.clone())
+    }
+
+    #[func]
+    /// Returns a [NebulaDir] that shows this directory layered on top of `lower`, e.g. a folder of
+    /// replacement files over a disc. Files here take precedence, folders are merged and a
+    /// `.wh.<name>` file hides `<name>` in `lower`. Changes only modify this directory;
+    /// removing a file of `lower` adds such a `.wh.` file instead.
+    pub fn overlay(&self, lower: Gd<NebulaDir>) -> Gd<NebulaDir> {
+        let lower = lower.bind();
+        let (Some(fs), Some(lower_fs)) = (self.fs(), lower.fs()) else {
+            return NebulaDir::new_gd();
+        };
+
+        // Both layers already mount their archives, so the overlay is not wrapped again.
+        let layers = vec![(fs.clone(), self.path.clone()), (lower_fs.clone(), lower.path.clone())];
+        Self::new(Arc::new(OverlayFs::new(layers)), String::new())
+    }
+
+    #[func]
+    /// Copies every file below this directory to the native directory `target`,
+    /// creating it and any subdirectories as needed.
+    /// Returns `true` if all files were written.
+    pub fn extract(&self, target: GString) -> bool {
+        let Some(root) = self.detach() else {
+            godot_warn!("NebulaDir used before initialization");
+            return false;
+        };
+
+        let target = ProjectSettings::singleton().globalize_path(&target).to_string();
+        match extract_files(&root, Path::new(&target), &|_, _| true) {
+            Ok(_) => true,
+            Err(err) => {
+                godot_error!("NebulaDir.extract: {}", err);
+                false
+            }
+        }
+    }
+
+    #[func]
+    /// Like [method extract], but copies the files on a worker thread.
+    /// The returned [NebulaTask] reports the number of bytes written and completes with the
+    /// number of extracted files, or `null` on failure.
+    pub fn extract_async(&self, target: GString) -> Gd<NebulaTask> {
+        let root = self.detach();
+        let target = ProjectSettings::singleton().globalize_path(&target).to_string();
+
+        NebulaTask::spawn(
+            move |progress| {
+                let root = root.ok_or_else(|| "NebulaDir used before initialization".to_string())?;
+                extract_files(&root, Path::new(&target), progress)
+                    .map_err(|err| format!("NebulaDir.extract_async: {}", err))
+            },
+            |count| count.to_variant(),
+        )
+    }
+
+    #[func]
+    /// Hashes the file at the given relative path with one of the `NebulaBuffer.HASH_*`
+    /// algorithms, see [method NebulaBuffer.hash_range].
+    /// The file is streamed, not loaded into memory. Returns an empty array on failure.
+    pub fn hash_file(&self, rel: String, algorithm: i32) -> PackedByteArray {
+        let Some(algorithm) = HashAlgorithm::from_i32(algorithm) else {
+            godot_error!("NebulaDir.hash_file: invalid algorithm {}", algorithm);
+            return PackedByteArray::new();
+        };
+
+        let Some(dir) = self.detach() else {
             godot_warn!("NebulaDir used before initialization");
+            return PackedByteArray::new();
+        };
+
+        let digest = dir.open(&rel)
+            .and_then(|source| hash_source(source.as_ref(), 0, source.len(), algorithm));
+
+        match digest {
+            Ok(digest) => digest.as_slice().into(),
+            Err(err) => {
+                godot_error!("NebulaDir.hash_file: failed to hash '{}': {}", rel, err);
+                PackedByteArray::new()
+            }
         }
     }
 
@@ -379,10 +292,6 @@ impl NebulaDir {
         size += size_of_val(self) as u64;
         size += self.path.capacity() as u64;
 
-        if let Some(native_path) = &self.native_path {
-            size += native_path.capacity() as u64;
-        }
-
         if let Some(fs) = &self.fs {
             size += size_of_val(fs) as u64;
         }
@@ -392,141 +301,219 @@ impl NebulaDir {
 }
 
 impl NebulaDir {
+    pub(crate) fn detach(&self) -> Option<DetachedDir> {
+        self.fs.as_ref().map(|fs| DetachedDir::new(fs.clone(), self.path.clone()))
+    }
+
+    /// Like [`NebulaDir::detach`], but lists archives as files even if this directory shows them
+    /// as folders, so that they are copied as they are.
+    pub(crate) fn detach_archives_as_files(&self) -> Option<DetachedDir> {
+        self.fs.as_ref().map(|fs| {
+            let fs = fs.show_archives(false).unwrap_or_else(|| fs.clone());
+            DetachedDir::new(fs, self.path.clone())
+        })
+    }
+
     pub(crate) fn new(fs: Arc<dyn NebulaFs>, path: String) -> Gd<Self> {
         Gd::from_init_fn(|base| Self {
             fs: Some(fs),
             path,
-            native_path: None,
             base,
         })
     }
 
-    /// Helper to get entries from native filesystem
-    fn get_native_entries(&self, path: &str) -> PackedStringArray {
-        let mut entries = PackedStringArray::new();
-        
-        let read_dir = match std::fs::read_dir(path) {
-            Ok(rd) => rd,
-            Err(e) => {
-                godot_error!("Failed to read directory '{}': {}", path, e);
-                return entries;
-            }
-        };
-        
-        for entry in read_dir {
-            if let Ok(entry) = entry {
-                if let Ok(file_name) = entry.file_name().into_string() {
-                    if let Ok(file_type) = entry.file_type() {
-                        if file_type.is_dir() {
-                            entries.push(&format!("{}/", file_name));
-                        } else {
-                            entries.push(&file_name);
-                        }
-                    }
-                }
-            }
+    /// Creates a [`NebulaDir`] at the root of `fs` in which the archives inside `fs` are mounted
+    /// on demand and listed as files, see [`MountFs`].
+    pub(crate) fn root(fs: Arc<dyn NebulaFs>) -> Gd<Self> {
+        Self::new(Arc::new(MountFs::new(fs, false)), String::new())
+    }
+
+    /// Returns the filesystem, warning if this directory was never opened.
+    fn fs(&self) -> Option<&Arc<dyn NebulaFs>> {
+        if self.fs.is_none() {
+            godot_warn!("NebulaDir used before initialization");
         }
-        
-        entries
+        self.fs.as_ref()
     }
 
-    /// Helper to get file size from native filesystem
-    fn get_native_file_size(&self, path: &str) -> u64 {
-        std::fs::metadata(path)
-            .map(|m| m.len())
-            .unwrap_or(0)
+    /// Returns the filesystem path of `rel`, without a trailing `/`.
+    fn full_path(&self, rel: &str) -> String {
+        join_path(&self.path, rel.trim_end_matches('/'))
     }
 
-    /// Print files for native filesystem
-    fn print_native_files(
-        &self,
+    /// Prints the entries below `current_path` and returns the total size of their files.
+    fn print_files_recursive(
+        fs: &dyn NebulaFs,
         current_path: &str,
         filesize: bool,
         indent: i32,
-        is_root: bool,
     ) -> u64 {
         let prefix = "\t".repeat(indent as usize);
-        let entries = self.get_native_entries(current_path);
         let mut total_size: u64 = 0;
 
-        for i in 0..entries.len() {
-            let name = entries.get(i).unwrap().to_string();
-            let is_dir = name.ends_with("/");
-            let clean_name = name.trim_matches('/').to_string();
-            
-            let full_path = Path::new(current_path).join(&clean_name);
-            let full_path_str = full_path.to_string_lossy().to_string();
+        for entry in fs.entries(current_path) {
+            let (name, is_dir) = match entry.strip_suffix('/') {
+                Some(name) => (name, true),
+                None => (entry.as_str(), false),
+            };
+            let full_path = join_path(current_path, name);
 
             if is_dir {
-                godot_print!("{}[F] {}", prefix, clean_name);
-                total_size += self.print_native_files(&full_path_str, filesize, indent + 1, false);
+                godot_print!("{}[F] {}", prefix, name);
+                total_size += Self::print_files_recursive(fs, &full_path, filesize, indent + 1);
             } else {
-                let size = self.get_native_file_size(&full_path_str);
+                let size = fs.get_file_size(&full_path);
                 total_size += size;
 
                 if filesize {
                     let size_str = GString::humanize_size(size as i64);
-                    godot_print!("{}[f] {} <{}>", prefix, clean_name, size_str);
+                    godot_print!("{}[f] {} <{}>", prefix, name, size_str);
                 } else {
-                    godot_print!("{}[f] {}", prefix, clean_name);
+                    godot_print!("{}[f] {}", prefix, name);
                 }
             }
         }
 
-        if is_root && filesize {
-            let total_str = GString::humanize_size(total_size as i64);
-            godot_print!("TOTAL SIZE: {}", total_str);
-        }
-
         total_size
     }
+}
 
-    /// Print files for virtual filesystem
-    fn print_files_recursive(
-        &self,
-        fs: &dyn NebulaFs,
-        current_path: &str,
-        filesize: bool,
-        indent: i32,
-        is_root: bool,
-    ) -> u64 {
-        let prefix = "\t".repeat(indent as usize);
-        let entries = fs.get_entries(current_path);
-        let mut total_size: u64 = 0;
-
-        for i in 0..entries.len() {
-            let name = entries.get(i).unwrap().to_string().trim_matches('/').to_string();
-            
-            let full_path = if current_path.is_empty() {
-                name.clone()
-            } else {
-                format!("{}/{}", current_path, name)
-            };
+/// The files of a [`NebulaDir`], detached from the Godot object so they can be
+/// moved to a worker thread.
+pub(crate) struct DetachedDir {
+    fs: Arc<dyn NebulaFs>,
+    path: String,
+}
 
-            let dir = fs.get_dir(&full_path);
-            let is_dir = dir.bind().fs.is_some();
+impl DetachedDir {
+    pub(crate) fn new(fs: Arc<dyn NebulaFs>, path: String) -> Self {
+        Self { fs, path }
+    }
 
-            if is_dir {
-                godot_print!("{}[F] {}", prefix, name);
-                total_size += self.print_files_recursive(fs, &full_path, filesize, indent + 1, false);
-            } else {
-                let size = fs.get_file_size(&full_path);
-                total_size += size;
+    /// Lists the entries directly inside `rel`, with a trailing `/` on directories,
+    /// in the order of the filesystem.
+    pub(crate) fn list(&self, rel: &str) -> std::io::Result<Vec<String>> {
+        let full = join_path(&self.path, rel);
+        if !self.fs.dir_exists(&full) {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                format!("folder '{}' not found", full),
+            ));
+        }
+        Ok(self.fs.entries(&full))
+    }
 
-                if filesize {
-                    let size_str = GString::humanize_size(total_size as i64);
-                    godot_print!("{}[f] {} <{}>", prefix, name, size_str);
-                } else {
-                    godot_print!("{}[f] {}", prefix, name);
+    /// Collects every file below `rel` as `(relative path, size)`.
+    fn collect(&self, rel: &str, out: &mut Vec<(String, u64)>) -> std::io::Result<()> {
+        for name in self.list(rel)? {
+            match name.strip_suffix('/') {
+                Some(dir) => self.collect(&join_path(rel, dir), out)?,
+                None => {
+                    let path = join_path(rel, &name);
+                    out.push((path.clone(), self.fs.get_file_size(&join_path(&self.path, &path))));
                 }
             }
         }
 
-        if is_root && filesize {
-            let total_str = GString::humanize_size(total_size as i64);
-            godot_print!("VIRTUAL SIZE: {}", total_str);
+        Ok(())
+    }
+
+    pub(crate) fn open(&self, rel: &str) -> std::io::Result<Arc<dyn ByteSource>> {
+        let full = join_path(&self.path, rel);
+        self.fs.open_source(&full).ok_or_else(|| std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            format!("'{}' not found", full),
+        ))
+    }
+}
+
+/// Joins two `/`-separated virtual paths, either of which may be empty.
+pub(crate) fn join_path(base: &str, rel: &str) -> String {
+    match (base.is_empty(), rel.is_empty()) {
+        (true, _) => rel.to_string(),
+        (_, true) => base.to_string(),
+        _ => format!("{}/{}", base, rel),
+    }
+}
+
+/// Returns where the file `rel` of an archive or disc is extracted to below `target`.
+/// Names come from the file data, so paths with `..`, a root or a drive that would
+/// leave `target` are rejected.
+fn extract_path(target: &Path, rel: &str) -> Result<PathBuf, String> {
+    let mut out = target.to_path_buf();
+    for part in rel.split(['/', '\\']).filter(|part| *part != ".") {
+        let mut components = Path::new(part).components();
+        match (components.next(), components.next()) {
+            (Some(Component::Normal(name)), None) if !part.contains(':') => out.push(name),
+            _ => return Err(format!("refusing to extract '{}' outside of the target folder", rel)),
         }
+    }
 
-        total_size
+    if out == target || !out.starts_with(target) {
+        return Err(format!("refusing to extract '{}' outside of the target folder", rel));
+    }
+    Ok(out)
+}
+
+/// Copies every file of `root` below `target`, reporting the number of bytes written.
+/// Returns the number of files extracted.
+fn extract_files(root: &DetachedDir, target: &Path, progress: Progress) -> Result<i64, String> {
+    let mut files = Vec::new();
+    root.collect("", &mut files)
+        .map_err(|err| format!("failed to list files: {}", err))?;
+
+    let total: u64 = files.iter().map(|(_, size)| size).sum();
+    let mut done = 0;
+    let mut chunk = vec![0u8; EXTRACT_CHUNK];
+
+    if !progress(done, total) {
+        return Err("Cancelled".to_string());
+    }
+
+    for (rel, _) in &files {
+        let out_path = extract_path(target, rel)?;
+        if let Some(parent) = out_path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|err| format!("failed to create '{}': {}", parent.display(), err))?;
+        }
+
+        let source = root.open(rel)
+            .map_err(|err| format!("failed to open '{}': {}", rel, err))?;
+        let mut out = std::fs::File::create(&out_path)
+            .map_err(|err| format!("failed to create '{}': {}", out_path.display(), err))?;
+
+        let mut offset = 0;
+        while offset < source.len() {
+            let read = source.read_into(offset, &mut chunk)
+                .map_err(|err| format!("failed to read '{}': {}", rel, err))?;
+            if read == 0 {
+                break;
+            }
+
+            out.write_all(&chunk[..read])
+                .map_err(|err| format!("failed to write '{}': {}", out_path.display(), err))?;
+            offset += read as u64;
+            done += read as u64;
+
+            if !progress(done, total) {
+                return Err("Cancelled".to_string());
+            }
+        }
+    }
+
+    Ok(files.len() as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extract_path_stays_inside_target() {
+        let target = Path::new("out");
+        assert_eq!(extract_path(target, "Stage/./a.bin").unwrap(), target.join("Stage").join("a.bin"));
+        for rel in ["../a.bin", "Stage/../../a.bin", "/etc/passwd", "\\server\\share", "C:/a.bin", "a//b", "", "."] {
+            assert!(extract_path(target, rel).is_err(), "{}", rel);
+        }
     }
-}
\ No newline at end of file
+}
@@ -65,9 +65,9 @@ impl NebulaFile {
 
     #[func]
     /// Reads a byte range, similar to [NebulaBuffer].
-    pub fn read_range(&self, offset: i32, size: i32) -> PackedByteArray {
+    pub fn read_range(&mut self, offset: i64, size: i64) -> PackedByteArray {
         self.buffer
-            .bind()
+            .bind_mut()
             .read_bytes(offset, size)
     }
 }
@@ -1,18 +1,55 @@
+use std::{path::PathBuf, sync::Arc};
+
 use godot::global::godot_warn;
 use godot::obj::{Gd, NewGd};
-use godot::builtin::PackedStringArray;
+use godot::builtin::{GString, PackedStringArray};
 
+use crate::io::buffer::NebulaBuffer;
+use crate::io::bytesource::ByteSource;
 use crate::io::file::NebulaFile;
 use crate::io::dir::NebulaDir;
 
 pub trait NebulaFs: Send + Sync {
-    fn get_entries(&self, path: &str) -> PackedStringArray;
+    /// Lists the entries directly under `path`; directories end with a `/`.
+    /// Unlike [`NebulaFs::get_entries`] this can be called off the main thread.
+    fn entries(&self, path: &str) -> Vec<String>;
     fn file_exists(&self, path: &str) -> bool;
     fn dir_exists(&self, path: &str) -> bool;
-    fn get_file(&self, path: &str) -> Gd<NebulaFile>;
+    /// Returns the contents of the file at `path`, or `None` if there is no such file.
+    /// Can be called off the main thread.
+    fn open_source(&self, path: &str) -> Option<Arc<dyn ByteSource>>;
     fn get_dir(&self, path: &str) -> Gd<NebulaDir>;
     fn get_file_size(&self, path: &str) -> u64;
 
+    /// Returns up to `size` bytes from the start of the file at `path`, without loading the rest
+    /// of it, or `None` if there is no such file. Can be called off the main thread.
+    fn read_head(&self, path: &str, size: usize) -> Option<Vec<u8>> {
+        self.open_source(path)?.read_range(0, size).ok()
+    }
+
+    /// Returns where `path` lives on disk, for filesystems backed by a native folder.
+    fn native_path(&self, _path: &str) -> Option<PathBuf> {
+        None
+    }
+
+    /// Returns a view of this filesystem that lists archives as folders if `show` is `true`,
+    /// for filesystems that mount archives.
+    fn show_archives(&self, _show: bool) -> Option<Arc<dyn NebulaFs>> {
+        None
+    }
+
+    fn get_entries(&self, path: &str) -> PackedStringArray {
+        self.entries(path).iter().map(|entry| GString::from(entry.as_str())).collect()
+    }
+
+    fn get_file(&self, path: &str) -> Gd<NebulaFile> {
+        let mut buffer = NebulaBuffer::new_gd();
+        if let Some(source) = self.open_source(path) {
+            buffer.bind_mut().set_source(source);
+        }
+        NebulaFile::from_buffer(buffer)
+    }
+
     fn create_dir(&self, _path: &str) -> bool {
         godot_warn!("This filesystem is read-only!");
         false
@@ -23,6 +60,13 @@ pub trait NebulaFs: Send + Sync {
         NebulaFile::new_gd()
     }
 
+    /// Creates an empty file at `path`, replacing an existing one. Unlike [`NebulaFs::create_file`]
+    /// this can be called off the main thread.
+    fn create_empty_file(&self, _path: &str) -> bool {
+        godot_warn!("This filesystem is read-only!");
+        false
+    }
+
     fn remove_file(&self, _path: &str) -> bool {
         godot_warn!("This filesystem is read-only!");
         false
@@ -0,0 +1,96 @@
+use std::io;
+use md5::Md5;
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+use crate::io::bytesource::ByteSource;
+
+/// Size of the chunks fed to the hasher by [`hash_source`].
+const HASH_CHUNK: usize = 1 << 20;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HashAlgorithm {
+    Crc32,
+    Adler32,
+    Md5,
+    Sha1,
+    Sha256,
+}
+
+impl HashAlgorithm {
+    /// Maps a `NebulaBuffer.HASH_*` constant to an algorithm.
+    pub fn from_i32(value: i32) -> Option<Self> {
+        match value {
+            0 => Some(Self::Crc32),
+            1 => Some(Self::Adler32),
+            2 => Some(Self::Md5),
+            3 => Some(Self::Sha1),
+            4 => Some(Self::Sha256),
+            _ => None,
+        }
+    }
+}
+
+/// Incremental hasher over any of the supported algorithms.
+pub enum Hasher {
+    Crc32(crc32fast::Hasher),
+    Adler32(adler32::RollingAdler32),
+    Md5(Md5),
+    Sha1(Sha1),
+    Sha256(Sha256),
+}
+
+impl Hasher {
+    pub fn new(algorithm: HashAlgorithm) -> Self {
+        match algorithm {
+            HashAlgorithm::Crc32 => Self::Crc32(crc32fast::Hasher::new()),
+            HashAlgorithm::Adler32 => Self::Adler32(adler32::RollingAdler32::new()),
+            HashAlgorithm::Md5 => Self::Md5(Md5::new()),
+            HashAlgorithm::Sha1 => Self::Sha1(Sha1::new()),
+            HashAlgorithm::Sha256 => Self::Sha256(Sha256::new()),
+        }
+    }
+
+    pub fn update(&mut self, data: &[u8]) {
+        match self {
+            Self::Crc32(h) => h.update(data),
+            Self::Adler32(h) => h.update_buffer(data),
+            Self::Md5(h) => h.update(data),
+            Self::Sha1(h) => h.update(data),
+            Self::Sha256(h) => h.update(data),
+        }
+    }
+
+    /// Returns the digest. 32-bit checksums are returned as 4 big-endian bytes,
+    /// so their hex form matches the usual notation.
+    pub fn finish(self) -> Vec<u8> {
+        match self {
+            Self::Crc32(h) => h.finalize().to_be_bytes().to_vec(),
+            Self::Adler32(h) => h.hash().to_be_bytes().to_vec(),
+            Self::Md5(h) => h.finalize().to_vec(),
+            Self::Sha1(h) => h.finalize().to_vec(),
+            Self::Sha256(h) => h.finalize().to_vec(),
+        }
+    }
+}
+
+/// Hashes `size` bytes of `source` starting at `offset` without loading them all into memory.
+/// Fails with [`io::ErrorKind::UnexpectedEof`] if the range runs past the end of the source.
+pub fn hash_source(source: &dyn ByteSource, offset: u64, size: u64, algorithm: HashAlgorithm) -> io::Result<Vec<u8>> {
+    let mut hasher = Hasher::new(algorithm);
+    let mut chunk = vec![0u8; HASH_CHUNK.min(size as usize)];
+    let mut pos = offset;
+    let end = offset + size;
+
+    while pos < end {
+        let want = chunk.len().min((end - pos) as usize);
+        let read = source.read_into(pos, &mut chunk[..want])?;
+        if read == 0 {
+            return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "hash range past end of source"));
+        }
+
+        hasher.update(&chunk[..read]);
+        pos += read as u64;
+    }
+
+    Ok(hasher.finish())
+}
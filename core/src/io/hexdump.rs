@@ -0,0 +1,92 @@
+use std::{fmt::Write, io};
+use crate::io::bytesource::ByteSource;
+
+/// Color used for highlighted bytes.
+const HIGHLIGHT_COLOR: &str = "#ff6b6b";
+/// Color used for the offset column.
+const OFFSET_COLOR: &str = "#808080";
+
+/// Layout of the text produced by [`hexdump`].
+#[derive(Clone, Copy, Debug)]
+pub struct HexdumpOptions {
+    /// Bytes per line.
+    pub width: usize,
+    /// Bytes per group; groups are separated by an extra space.
+    pub group: usize,
+    /// Whether to add a column with the printable ASCII characters.
+    pub ascii: bool,
+}
+
+/// Formats `size` bytes of `source` at `offset` as BBCode for a `RichTextLabel`, one line per
+/// [`HexdumpOptions::width`] bytes. Bytes inside any of the `highlight` ranges, given as
+/// `(offset, size)`, are colored in both columns. The columns only line up with a monospace font.
+pub fn hexdump(
+    source: &dyn ByteSource,
+    offset: u64,
+    size: u64,
+    options: HexdumpOptions,
+    highlight: &[(u64, u64)],
+) -> io::Result<String> {
+    let width = options.width.max(1);
+    let group = options.group.clamp(1, width);
+    let end = offset.saturating_add(size).min(source.len());
+    let highlighted = |pos: u64| highlight.iter().any(|&(start, len)| pos >= start && pos - start < len);
+
+    let mut out = String::new();
+    let mut line = vec![0u8; width];
+    let mut pos = offset;
+
+    while pos < end {
+        let count = width.min((end - pos) as usize);
+        let read = source.read_into(pos, &mut line[..count])?;
+        let bytes = &line[..read];
+        if bytes.is_empty() {
+            break;
+        }
+
+        let _ = write!(out, "[color={}]{:08X}[/color]  ", OFFSET_COLOR, pos);
+
+        let mut hex = String::new();
+        let mut text = String::new();
+        let mut open = false;
+        for column in 0..width {
+            let marked = column < bytes.len() && highlighted(pos + column as u64);
+            if marked != open {
+                let tag = if marked { format!("[color={}]", HIGHLIGHT_COLOR) } else { "[/color]".to_string() };
+                hex.push_str(&tag);
+                text.push_str(&tag);
+                open = marked;
+            }
+
+            if column > 0 {
+                hex.push_str(if column % group == 0 { "  " } else { " " });
+            }
+
+            let Some(&byte) = bytes.get(column) else {
+                hex.push_str("  ");
+                text.push(' ');
+                continue;
+            };
+
+            let _ = write!(hex, "{:02X}", byte);
+            match byte {
+                b'[' => text.push_str("[lb]"),
+                0x20..=0x7E => text.push(byte as char),
+                _ => text.push('.'),
+            }
+        }
+        if open {
+            hex.push_str("[/color]");
+            text.push_str("[/color]");
+        }
+
+        out.push_str(&hex);
+        if options.ascii {
+            let _ = write!(out, "  |{}|", text);
+        }
+        out.push('\n');
+        pos += read as u64;
+    }
+
+    Ok(out)
+}
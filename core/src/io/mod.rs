@@ -1,8 +1,19 @@
 pub mod dir;
+pub mod diff;
 pub mod file;
 pub mod buffer;
 pub mod bytesource;
 pub mod fs;
+pub mod hash;
+pub mod hexdump;
+pub mod mount;
+pub mod native;
+pub mod overlay;
+pub mod relocation;
+pub mod schema;
+pub mod search;
+pub mod task;
+pub mod text;
 
 pub mod common;
 pub mod wii;
\ No newline at end of file
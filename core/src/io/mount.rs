@@ -0,0 +1,366 @@
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+use godot::prelude::*;
+use crate::io::{
+    bytesource::{ByteSource, MemoryByteSource, OverlaySource},
+    dir::{join_path, NebulaDir},
+    file::NebulaFile,
+    fs::NebulaFs,
+    wii::{arc::{ArcFs, U8_HEADER}, lzss},
+};
+
+/// Extensions of the files that are mounted as folders, lowercase.
+const ARCHIVE_EXTENSIONS: [&str; 2] = [".arc", ".arc.lz"];
+/// Extension of archives compressed with LZ11, lowercase.
+const LZ_EXTENSION: &str = ".lz";
+
+/// Mounts the archives inside another filesystem on demand, so that paths like
+/// `Stage/01-01.arc/course/course1.bin` reach into them.
+///
+/// U8 archives are recognised by their extension: `.arc`, or `.arc.LZ` for LZ11-compressed ones.
+/// Listing a folder with archives shown only reads their first bytes; they are mounted when entered.
+/// Uncompressed archives read their files straight from the archive file, compressed ones are
+/// decompressed into memory once. Files inside an archive can be written, but the changes only
+/// affect the mounted copy, never the archive file.
+///
+/// Mounted archives are cached, and the cache is shared with the views from [`NebulaFs::show_archives`].
+/// An archive is mounted again after its file is created, removed or renamed through this filesystem.
+/// Writing to the archive file itself, or replacing it from elsewhere, e.g. with `ARC.save` on
+/// an archive it lies in, does not affect a mounted copy: it keeps its tree and changes until the
+/// folder is opened again.
+#[derive(Clone)]
+pub struct MountFs {
+    inner: Arc<dyn NebulaFs>,
+    /// Mounted archives by path, `None` for files that turned out not to be valid archives.
+    /// Shared by the views returned from [`NebulaFs::show_archives`].
+    mounts: Arc<Mutex<HashMap<String, Option<Arc<MountFs>>>>>,
+    /// Whether [`NebulaFs::entries`] lists archives as folders rather than files.
+    show_archives: bool,
+}
+
+impl MountFs {
+    pub fn new(inner: Arc<dyn NebulaFs>, show_archives: bool) -> Self {
+        Self { inner, mounts: Arc::new(Mutex::new(HashMap::new())), show_archives }
+    }
+
+    /// Returns a view of this filesystem that shares its mounts, with `show_archives` set.
+    fn view(&self, show_archives: bool) -> MountFs {
+        Self { inner: self.inner.clone(), mounts: self.mounts.clone(), show_archives }
+    }
+
+    /// Returns the archive at `path` if it has already been mounted, as seen from this view.
+    fn mounted(&self, path: &str) -> Option<Option<Arc<MountFs>>> {
+        let mount = self.mounts.lock().unwrap().get(path).cloned()?;
+        Some(mount.map(|mount| self.nested(mount)))
+    }
+
+    /// Returns a mounted archive with the same `show_archives` as this view.
+    fn nested(&self, mount: Arc<MountFs>) -> Arc<MountFs> {
+        if mount.show_archives == self.show_archives {
+            mount
+        } else {
+            Arc::new(mount.view(self.show_archives))
+        }
+    }
+
+    /// Returns the archive mounted at `path`, mounting it first if needed,
+    /// or `None` if `path` is not an archive.
+    fn mount(&self, path: &str) -> Option<Arc<MountFs>> {
+        if !is_archive_name(path) {
+            return None;
+        }
+        if let Some(mount) = self.mounted(path) {
+            return mount;
+        }
+
+        // Archives are read without holding the lock, so other paths stay usable meanwhile.
+        let mount = self.inner.open_source(path)
+            .and_then(|source| open_archive(path, source).ok())
+            .map(|fs| Arc::new(MountFs::new(Arc::new(fs), self.show_archives)));
+        let mount = self.mounts.lock().unwrap().entry(path.to_string()).or_insert(mount).clone();
+        mount.map(|mount| self.nested(mount))
+    }
+
+    /// Returns `true` if `path` looks like an archive that can be mounted, without mounting it.
+    fn is_archive(&self, path: &str) -> bool {
+        if !is_archive_name(path) {
+            return false;
+        }
+        if let Some(mount) = self.mounted(path) {
+            return mount.is_some();
+        }
+
+        let magic: &[u8] = if is_compressed_name(path) { &[lzss::LZ11_TYPE] } else { &U8_HEADER };
+        self.inner.read_head(path, magic.len()).is_some_and(|head| head == magic)
+    }
+
+    /// Finds the outermost archive that `path` lies in and returns it with the path inside it.
+    /// With `inclusive`, `path` itself may be the archive, which then stands for its root folder.
+    fn split(&self, path: &str, inclusive: bool) -> Option<(Arc<MountFs>, String)> {
+        let ends = path.match_indices('/').map(|(slash, _)| slash);
+        let ends: Vec<usize> = if inclusive { ends.chain([path.len()]).collect() } else { ends.collect() };
+
+        ends.into_iter().find_map(|end| {
+            let prefix = &path[..end];
+            if !is_archive_name(prefix) || !self.inner.file_exists(prefix) {
+                return None;
+            }
+            let rest = path.get(end + 1..).unwrap_or_default();
+            self.mount(prefix).map(|mount| (mount, rest.to_string()))
+        })
+    }
+
+    /// Forgets the mounts of archives at or below `path` after it has been changed.
+    fn unmount(&self, path: &str) {
+        let prefix = format!("{}/", path);
+        self.mounts.lock().unwrap().retain(|mounted, _| mounted != path && !mounted.starts_with(&prefix));
+    }
+}
+
+impl NebulaFs for MountFs {
+    fn entries(&self, path: &str) -> Vec<String> {
+        if let Some((mount, rest)) = self.split(path, true) {
+            return mount.entries(&rest);
+        }
+
+        let mut entries = self.inner.entries(path);
+        if self.show_archives {
+            // Only files that start like an archive are listed as folders.
+            for entry in &mut entries {
+                if self.is_archive(&join_path(path, entry)) {
+                    entry.push('/');
+                }
+            }
+        }
+        entries
+    }
+
+    fn file_exists(&self, path: &str) -> bool {
+        match self.split(path, false) {
+            Some((mount, rest)) => mount.file_exists(&rest),
+            None => self.inner.file_exists(path),
+        }
+    }
+
+    fn dir_exists(&self, path: &str) -> bool {
+        match self.split(path, true) {
+            Some((mount, rest)) => mount.dir_exists(&rest),
+            None => self.inner.dir_exists(path),
+        }
+    }
+
+    fn open_source(&self, path: &str) -> Option<Arc<dyn ByteSource>> {
+        match self.split(path, false) {
+            Some((mount, rest)) => mount.open_source(&rest),
+            None => self.inner.open_source(path),
+        }
+    }
+
+    fn read_head(&self, path: &str, size: usize) -> Option<Vec<u8>> {
+        match self.split(path, false) {
+            Some((mount, rest)) => mount.read_head(&rest, size),
+            None => self.inner.read_head(path, size),
+        }
+    }
+
+    fn native_path(&self, path: &str) -> Option<PathBuf> {
+        match self.split(path, false) {
+            Some(_) => None,
+            None => self.inner.native_path(path),
+        }
+    }
+
+    fn get_dir(&self, path: &str) -> Gd<NebulaDir> {
+        if !self.dir_exists(path) {
+            return NebulaDir::new_gd();
+        }
+        NebulaDir::new(Arc::new(self.clone()), path.to_string())
+    }
+
+    fn get_file_size(&self, path: &str) -> u64 {
+        match self.split(path, false) {
+            Some((mount, rest)) => mount.get_file_size(&rest),
+            None => self.inner.get_file_size(path),
+        }
+    }
+
+    fn get_file(&self, path: &str) -> Gd<NebulaFile> {
+        match self.split(path, false) {
+            Some((mount, rest)) => mount.get_file(&rest),
+            None => self.inner.get_file(path),
+        }
+    }
+
+    fn create_dir(&self, path: &str) -> bool {
+        match self.split(path, false) {
+            Some((mount, rest)) => mount.create_dir(&rest),
+            None => self.inner.create_dir(path),
+        }
+    }
+
+    fn create_file(&self, path: &str) -> Gd<NebulaFile> {
+        match self.split(path, false) {
+            Some((mount, rest)) => mount.create_file(&rest),
+            None => {
+                self.unmount(path);
+                self.inner.create_file(path)
+            }
+        }
+    }
+
+    fn create_empty_file(&self, path: &str) -> bool {
+        match self.split(path, false) {
+            Some((mount, rest)) => mount.create_empty_file(&rest),
+            None => {
+                self.unmount(path);
+                self.inner.create_empty_file(path)
+            }
+        }
+    }
+
+    fn remove_file(&self, path: &str) -> bool {
+        match self.split(path, false) {
+            Some((mount, rest)) => mount.remove_file(&rest),
+            None => {
+                self.unmount(path);
+                self.inner.remove_file(path)
+            }
+        }
+    }
+
+    fn remove_dir(&self, path: &str) -> bool {
+        match self.split(path, false) {
+            Some((mount, rest)) => mount.remove_dir(&rest),
+            None => {
+                self.unmount(path);
+                self.inner.remove_dir(path)
+            }
+        }
+    }
+
+    fn rename_path(&self, from: &str, to: &str) -> bool {
+        match (self.split(from, false), self.split(to, false)) {
+            (None, None) => {
+                self.unmount(from);
+                self.unmount(to);
+                self.inner.rename_path(from, to)
+            }
+            (Some((mount, from_rest)), Some((to_mount, to_rest))) if Arc::ptr_eq(&mount.mounts, &to_mount.mounts) => {
+                mount.rename_path(&from_rest, &to_rest)
+            }
+            _ => {
+                godot_error!("MountFs: cannot move '{}' to '{}': paths are in different archives", from, to);
+                false
+            }
+        }
+    }
+
+    fn show_archives(&self, show: bool) -> Option<Arc<dyn NebulaFs>> {
+        Some(Arc::new(self.view(show)))
+    }
+}
+
+/// Returns `true` if `name` has one of the [`ARCHIVE_EXTENSIONS`].
+fn is_archive_name(name: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+    ARCHIVE_EXTENSIONS.iter().any(|ext| name.ends_with(ext))
+}
+
+/// Returns `true` if `name` has the extension of LZ11-compressed archives.
+fn is_compressed_name(name: &str) -> bool {
+    name.to_ascii_lowercase().ends_with(LZ_EXTENSION)
+}
+
+/// Indexes the archive at `path`, decompressing it first if it is LZ11-compressed.
+/// Writes to its files are kept in memory, as the archive file may be read-only.
+fn open_archive(path: &str, source: Arc<dyn ByteSource>) -> Result<ArcFs, String> {
+    if !is_compressed_name(path) {
+        return ArcFs::new(Arc::new(OverlaySource::new(source)));
+    }
+
+    let data = source.read_range(0, source.len() as usize)
+        .map_err(|err| format!("failed to read '{}': {}", path, err))?;
+    let data = lzss::decompress(&data)?;
+    ArcFs::new(Arc::new(MemoryByteSource::from_vec(data)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::wii::arc::tests::sample_archive;
+
+    fn memory(data: &[u8]) -> Arc<dyn ByteSource> {
+        Arc::new(MemoryByteSource::from_vec(data.to_vec()))
+    }
+
+    /// An archive holding `inner.arc`, a valid archive, and `junk.arc`, which is not one.
+    fn nested() -> MountFs {
+        let outer = ArcFs::new(memory(&sample_archive())).unwrap();
+        outer.replace_file("inner.arc", memory(&sample_archive())).unwrap();
+        outer.replace_file("junk.arc", memory(b"not an archive")).unwrap();
+        MountFs::new(Arc::new(outer), true)
+    }
+
+    #[test]
+    fn lists_archives_without_mounting_them() {
+        let fs = nested();
+        assert_eq!(fs.entries(""), ["a.bin", "dir/", "inner.arc/", "junk.arc"]);
+        assert!(fs.mounts.lock().unwrap().is_empty());
+
+        assert_eq!(fs.entries("inner.arc"), ["a.bin", "dir/"]);
+        assert!(fs.file_exists("inner.arc/dir/b.bin"));
+        assert!(!fs.dir_exists("junk.arc"));
+        assert_eq!(fs.mounts.lock().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn views_share_mounts() {
+        let fs = nested();
+        let hidden = fs.show_archives(false).unwrap();
+        assert_eq!(hidden.entries(""), ["a.bin", "dir/", "inner.arc", "junk.arc"]);
+        assert_eq!(hidden.get_file_size("inner.arc/a.bin"), 3);
+        assert_eq!(fs.mounts.lock().unwrap().len(), 1);
+
+        assert_eq!(fs.entries(""), ["a.bin", "dir/", "inner.arc/", "junk.arc"]);
+        assert!(fs.rename_path("inner.arc/a.bin", "inner.arc/c.bin"));
+        assert!(hidden.file_exists("inner.arc/c.bin"));
+
+        assert!(fs.remove_file("inner.arc"));
+        assert!(fs.mounts.lock().unwrap().is_empty());
+        assert!(!hidden.file_exists("inner.arc/c.bin"));
+    }
+
+    #[test]
+    fn packing_a_hidden_view_keeps_archives_as_files() {
+        use crate::io::{dir::DetachedDir, wii::arc::build_u8};
+
+        let fs = Arc::new(nested()).show_archives(false).unwrap();
+        let packed = build_u8(&DetachedDir::new(fs, String::new()), 0x20, &|_, _| true).unwrap();
+        let packed = MountFs::new(Arc::new(ArcFs::new(memory(&packed)).unwrap()), false);
+        assert_eq!(packed.entries(""), ["a.bin", "dir/", "inner.arc", "junk.arc"]);
+        assert_eq!(packed.get_file_size("inner.arc"), sample_archive().len() as u64);
+    }
+
+    #[test]
+    fn files_in_archives_on_disk_are_writable_in_memory() {
+        use crate::io::native::NativeFs;
+
+        let root = std::env::temp_dir().join(format!("nebula_mount_{}", std::process::id()));
+        std::fs::create_dir_all(&root).unwrap();
+        std::fs::write(root.join("x.arc"), sample_archive()).unwrap();
+
+        let fs = MountFs::new(Arc::new(NativeFs::new(&root)), false);
+        let file = fs.open_source("x.arc/a.bin").unwrap();
+        file.write_range(1, b"ZZ").unwrap();
+        assert_eq!(file.read_range(0, 3).unwrap(), b"AZZ");
+
+        let reopened = fs.open_source("x.arc/a.bin").unwrap();
+        assert_eq!(reopened.read_range(0, 3).unwrap(), b"AZZ");
+        assert_eq!(fs.open_source("x.arc/dir/b.bin").unwrap().read_range(0, 2).unwrap(), b"BB");
+        assert_eq!(std::fs::read(root.join("x.arc")).unwrap(), sample_archive());
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,222 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+use godot::prelude::*;
+use crate::io::{
+    buffer::NebulaBuffer,
+    bytesource::{open_file_source, ByteSource, OpenMode, OverlaySource},
+    dir::NebulaDir,
+    file::NebulaFile,
+    fs::NebulaFs,
+};
+
+/// A folder on disk seen through [`NebulaFs`], so native and virtual directories share one code path.
+///
+/// Paths are `/`-separated and relative to the root. Paths that would leave the root through `..`
+/// or name a drive are rejected. Symbolic links inside the root are followed.
+#[derive(Clone)]
+pub struct NativeFs {
+    root: PathBuf,
+}
+
+impl NativeFs {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Returns the native path of `path`, or `None` if it escapes the root.
+    pub fn resolve(&self, path: &str) -> Option<PathBuf> {
+        let mut parts: Vec<&str> = Vec::new();
+        for part in path.split(['/', '\\']) {
+            match part {
+                "" | "." => {}
+                ".." => {
+                    parts.pop()?;
+                }
+                part if part.contains(':') => return None,
+                part => parts.push(part),
+            }
+        }
+
+        Some(parts.iter().fold(self.root.clone(), |native, part| native.join(part)))
+    }
+
+    /// Like [`NativeFs::resolve`], but reports paths that escape the root.
+    fn resolve_or_error(&self, path: &str) -> Option<PathBuf> {
+        let native = self.resolve(path);
+        if native.is_none() {
+            godot_error!("NativeFs: '{}' is outside of '{}'", path, self.root.display());
+        }
+        native
+    }
+}
+
+impl NebulaFs for NativeFs {
+    fn entries(&self, path: &str) -> Vec<String> {
+        let Some(native) = self.resolve_or_error(path) else {
+            return Vec::new();
+        };
+        let read_dir = match std::fs::read_dir(&native) {
+            Ok(read_dir) => read_dir,
+            Err(err) => {
+                godot_error!("NativeFs: cannot read folder '{}': {}", native.display(), err);
+                return Vec::new();
+            }
+        };
+
+        let mut names: Vec<String> = read_dir
+            .filter_map(|entry| {
+                let entry = entry
+                    .map_err(|err| godot_error!("NativeFs: cannot read folder '{}': {}", native.display(), err))
+                    .ok()?;
+                let name = entry.file_name().into_string().ok()?;
+                let is_dir = entry.path().is_dir();
+                Some(if is_dir { format!("{}/", name) } else { name })
+            })
+            .collect();
+        names.sort_by_key(|name| name.to_ascii_lowercase());
+        names
+    }
+
+    fn file_exists(&self, path: &str) -> bool {
+        self.resolve(path).is_some_and(|native| native.is_file())
+    }
+
+    fn dir_exists(&self, path: &str) -> bool {
+        self.resolve(path).is_some_and(|native| native.is_dir())
+    }
+
+    fn open_source(&self, path: &str) -> Option<Arc<dyn ByteSource>> {
+        let native = self.resolve(path).filter(|native| native.is_file())?;
+        open_file_source(&native.to_string_lossy(), OpenMode::Read).ok()
+    }
+
+    fn native_path(&self, path: &str) -> Option<PathBuf> {
+        self.resolve(path)
+    }
+
+    fn get_dir(&self, path: &str) -> Gd<NebulaDir> {
+        if !self.dir_exists(path) {
+            return NebulaDir::new_gd();
+        }
+        NebulaDir::new(Arc::new(self.clone()), path.to_string())
+    }
+
+    fn get_file_size(&self, path: &str) -> u64 {
+        self.resolve(path)
+            .and_then(|native| std::fs::metadata(native).ok())
+            .map_or(0, |metadata| metadata.len())
+    }
+
+    /// Writes to the returned file stay in memory; the file on disk is not modified.
+    fn get_file(&self, path: &str) -> Gd<NebulaFile> {
+        let Some(native) = self.resolve_or_error(path) else {
+            return NebulaFile::new_gd();
+        };
+
+        match open_file_source(&native.to_string_lossy(), OpenMode::Read) {
+            Ok(source) => {
+                let mut buffer = NebulaBuffer::new_gd();
+                buffer.bind_mut().set_source(Arc::new(OverlaySource::new(source)));
+                NebulaFile::from_buffer(buffer)
+            }
+            Err(err) => {
+                godot_error!("NativeFs: cannot open '{}': {}", native.display(), err);
+                NebulaFile::new_gd()
+            }
+        }
+    }
+
+    fn create_dir(&self, path: &str) -> bool {
+        let Some(native) = self.resolve_or_error(path) else {
+            return false;
+        };
+
+        match std::fs::create_dir_all(&native) {
+            Ok(()) => true,
+            Err(err) => {
+                godot_error!("NativeFs: cannot create '{}': {}", native.display(), err);
+                false
+            }
+        }
+    }
+
+    fn create_file(&self, path: &str) -> Gd<NebulaFile> {
+        if !self.create_empty_file(path) {
+            return NebulaFile::new_gd();
+        }
+        self.get_file(path)
+    }
+
+    fn create_empty_file(&self, path: &str) -> bool {
+        let Some(native) = self.resolve_or_error(path) else {
+            return false;
+        };
+
+        match std::fs::File::create(&native) {
+            Ok(_) => true,
+            Err(err) => {
+                godot_error!("NativeFs: cannot create '{}': {}", native.display(), err);
+                false
+            }
+        }
+    }
+
+    fn remove_file(&self, path: &str) -> bool {
+        let Some(native) = self.resolve_or_error(path).filter(|native| native.is_file()) else {
+            return false;
+        };
+
+        match std::fs::remove_file(&native) {
+            Ok(()) => true,
+            Err(err) => {
+                godot_error!("NativeFs: cannot remove '{}': {}", native.display(), err);
+                false
+            }
+        }
+    }
+
+    fn remove_dir(&self, path: &str) -> bool {
+        let Some(native) = self.resolve_or_error(path).filter(|native| native.is_dir()) else {
+            return false;
+        };
+        if native == self.root {
+            godot_error!("NativeFs: cannot remove the root folder");
+            return false;
+        }
+
+        match std::fs::remove_dir(&native) {
+            Ok(()) => true,
+            Err(err) => {
+                godot_error!("NativeFs: cannot remove '{}': {}", native.display(), err);
+                false
+            }
+        }
+    }
+
+    fn rename_path(&self, from: &str, to: &str) -> bool {
+        let (Some(from_native), Some(to_native)) = (self.resolve_or_error(from), self.resolve_or_error(to)) else {
+            return false;
+        };
+        if !from_native.exists() {
+            return false;
+        }
+        if from_native == self.root || to_native == self.root {
+            godot_error!("NativeFs: cannot move '{}' to '{}'", from, to);
+            return false;
+        }
+
+        match std::fs::rename(&from_native, &to_native) {
+            Ok(()) => true,
+            Err(err) => {
+                godot_error!("NativeFs: cannot move '{}' to '{}': {}", from_native.display(), to_native.display(), err);
+                false
+            }
+        }
+    }
+}
@@ -0,0 +1,426 @@
+use std::{path::PathBuf, sync::Arc};
+use godot::prelude::*;
+use crate::io::{
+    bytesource::ByteSource,
+    dir::{join_path, NebulaDir},
+    file::NebulaFile,
+    fs::NebulaFs,
+    mount::MountFs,
+};
+
+/// Prefix of the marker files that hide an entry of the layers below, e.g. `.wh.course1.bin`.
+pub const WHITEOUT_PREFIX: &str = ".wh.";
+
+/// A folder of another filesystem used as one layer of an [`OverlayFs`].
+#[derive(Clone)]
+struct Layer {
+    fs: Arc<dyn NebulaFs>,
+    root: String,
+}
+
+impl Layer {
+    fn path(&self, path: &str) -> String {
+        join_path(&self.root, path)
+    }
+
+    fn file_exists(&self, path: &str) -> bool {
+        self.fs.file_exists(&self.path(path))
+    }
+
+    fn dir_exists(&self, path: &str) -> bool {
+        self.fs.dir_exists(&self.path(path))
+    }
+
+    /// Returns `true` if this layer hides `path` in the layers below, either with a whiteout
+    /// on it or one of its folders, or with a file in place of one of its folders.
+    fn covers(&self, path: &str) -> bool {
+        if path.is_empty() {
+            return false;
+        }
+
+        let mut prefix = String::new();
+        for name in path.split('/') {
+            if self.file_exists(&whiteout_path(&prefix, name)) {
+                return true;
+            }
+
+            prefix = join_path(&prefix, name);
+            if prefix != path && self.file_exists(&prefix) {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+/// Stacks filesystems on top of each other, e.g. a native folder with replacement files over a disc.
+///
+/// Lookups go from the top layer down: the topmost file wins, and folders that exist in several
+/// layers show the entries of all of them. A `.wh.<name>` file in a layer hides `<name>` in the
+/// layers below it; a folder next to its own whiteout hides the contents of the folders below.
+/// Changes are made to the top layer only; deleting an entry of a lower layer adds a whiteout.
+#[derive(Clone)]
+pub struct OverlayFs {
+    /// Top layer first.
+    layers: Vec<Layer>,
+}
+
+impl OverlayFs {
+    /// Creates an overlay from `(filesystem, folder)` pairs, top layer first.
+    pub fn new(layers: Vec<(Arc<dyn NebulaFs>, String)>) -> Self {
+        Self { layers: layers.into_iter().map(|(fs, root)| Layer { fs, root }).collect() }
+    }
+
+    /// Returns the indices of the layers that provide `path`, top first, with whether each has it as
+    /// a folder. Only the first entry can be a file.
+    fn lookup(&self, path: &str) -> Vec<(usize, bool)> {
+        let mut hits = Vec::new();
+        for (index, layer) in self.layers.iter().enumerate() {
+            if layer.file_exists(path) {
+                if hits.is_empty() {
+                    hits.push((index, false));
+                }
+                break;
+            }
+            if layer.dir_exists(path) {
+                hits.push((index, true));
+            }
+            if layer.covers(path) {
+                break;
+            }
+        }
+        hits
+    }
+
+    fn file_layer(&self, path: &str) -> Option<&Layer> {
+        match self.lookup(path).first() {
+            Some(&(index, false)) => Some(&self.layers[index]),
+            _ => None,
+        }
+    }
+
+    fn top(&self) -> &Layer {
+        &self.layers[0]
+    }
+
+    /// Returns `true` if a layer below the top one still provides `path`.
+    fn in_lower_layer(&self, path: &str) -> bool {
+        self.lookup(path).iter().any(|&(index, _)| index > 0)
+    }
+
+    /// Hides `path` in the lower layers by adding a whiteout to the top layer.
+    fn add_whiteout(&self, path: &str) -> bool {
+        let top = self.top();
+        let (parent, name) = split_path(path);
+        if !top.dir_exists(parent) && !top.fs.create_dir(&top.path(parent)) {
+            return false;
+        }
+
+        top.fs.create_empty_file(&top.path(&whiteout_path(parent, name)))
+    }
+
+    /// Makes room for a new file at `path` in the top layer: creates its folder and removes its whiteout.
+    fn prepare_file(&self, path: &str) -> bool {
+        let (parent, _) = split_path(path);
+        if self.dir_exists(path) || !self.create_dir(parent) {
+            godot_error!("OverlayFs: cannot create '{}'", path);
+            return false;
+        }
+
+        self.remove_whiteout(path);
+        true
+    }
+
+    /// Removes the whiteout of `path` from the top layer, if there is one.
+    fn remove_whiteout(&self, path: &str) {
+        let top = self.top();
+        let (parent, name) = split_path(path);
+        let whiteout = whiteout_path(parent, name);
+        if top.file_exists(&whiteout) {
+            top.fs.remove_file(&top.path(&whiteout));
+        }
+    }
+}
+
+impl NebulaFs for OverlayFs {
+    fn entries(&self, path: &str) -> Vec<String> {
+        let mut out: Vec<String> = Vec::new();
+        let position = |out: &[String], name: &str| out.iter().position(|e| e.trim_end_matches('/') == name);
+
+        // Go bottom-up so that the order of the lowest layer is kept and higher layers take precedence.
+        for &(index, is_dir) in self.lookup(path).iter().rev() {
+            if !is_dir {
+                return Vec::new();
+            }
+
+            let layer = &self.layers[index];
+            let entries = layer.fs.entries(&layer.path(path));
+            for hidden in entries.iter().filter_map(|e| e.strip_prefix(WHITEOUT_PREFIX)) {
+                if let Some(at) = position(&out, hidden.trim_end_matches('/')) {
+                    out.remove(at);
+                }
+            }
+            for entry in entries.into_iter().filter(|e| !e.starts_with(WHITEOUT_PREFIX)) {
+                match position(&out, entry.trim_end_matches('/')) {
+                    Some(at) => out[at] = entry,
+                    None => out.push(entry),
+                }
+            }
+        }
+
+        out
+    }
+
+    fn file_exists(&self, path: &str) -> bool {
+        self.file_layer(path).is_some()
+    }
+
+    fn dir_exists(&self, path: &str) -> bool {
+        matches!(self.lookup(path).first(), Some((_, true)))
+    }
+
+    fn open_source(&self, path: &str) -> Option<Arc<dyn ByteSource>> {
+        let layer = self.file_layer(path)?;
+        layer.fs.open_source(&layer.path(path))
+    }
+
+    fn read_head(&self, path: &str, size: usize) -> Option<Vec<u8>> {
+        let layer = self.file_layer(path)?;
+        layer.fs.read_head(&layer.path(path), size)
+    }
+
+    fn native_path(&self, path: &str) -> Option<PathBuf> {
+        let &(index, _) = self.lookup(path).first()?;
+        let layer = &self.layers[index];
+        layer.fs.native_path(&layer.path(path))
+    }
+
+    fn get_dir(&self, path: &str) -> Gd<NebulaDir> {
+        if !self.dir_exists(path) {
+            return NebulaDir::new_gd();
+        }
+        NebulaDir::new(Arc::new(self.clone()), path.to_string())
+    }
+
+    fn get_file_size(&self, path: &str) -> u64 {
+        self.file_layer(path).map_or(0, |layer| layer.fs.get_file_size(&layer.path(path)))
+    }
+
+    fn get_file(&self, path: &str) -> Gd<NebulaFile> {
+        match self.file_layer(path) {
+            Some(layer) => layer.fs.get_file(&layer.path(path)),
+            None => NebulaFile::new_gd(),
+        }
+    }
+
+    fn create_dir(&self, path: &str) -> bool {
+        // Folders that only exist in lower layers are created in the top one, so files can go in them.
+        let path = path.trim_end_matches('/');
+        if path.is_empty() || self.top().dir_exists(path) {
+            return true;
+        }
+        if self.file_exists(path) {
+            godot_error!("OverlayFs: cannot create '{}': a file with that name exists", path);
+            return false;
+        }
+
+        // A whiteout left next to the new folder keeps the contents of deleted lower folders hidden.
+        let (parent, _) = split_path(path);
+        self.create_dir(parent) && self.top().fs.create_dir(&self.top().path(path))
+    }
+
+    fn create_file(&self, path: &str) -> Gd<NebulaFile> {
+        if !self.prepare_file(path) {
+            return NebulaFile::new_gd();
+        }
+        self.top().fs.create_file(&self.top().path(path))
+    }
+
+    fn create_empty_file(&self, path: &str) -> bool {
+        self.prepare_file(path) && self.top().fs.create_empty_file(&self.top().path(path))
+    }
+
+    fn remove_file(&self, path: &str) -> bool {
+        let Some(&(index, false)) = self.lookup(path).first() else {
+            return false;
+        };
+
+        if index == 0 && !self.top().fs.remove_file(&self.top().path(path)) {
+            return false;
+        }
+        !self.in_lower_layer(path) || self.add_whiteout(path)
+    }
+
+    fn remove_dir(&self, path: &str) -> bool {
+        let Some(&(index, true)) = self.lookup(path).first() else {
+            return false;
+        };
+        if path.is_empty() || !self.entries(path).is_empty() {
+            godot_error!("OverlayFs: cannot remove '{}': folder is not empty", path);
+            return false;
+        }
+
+        if index == 0 {
+            // The folder is empty in the merged view, so the top layer only holds whiteouts in it.
+            let top = self.top();
+            for whiteout in top.fs.entries(&top.path(path)) {
+                top.fs.remove_file(&top.path(&join_path(path, &whiteout)));
+            }
+            if !top.fs.remove_dir(&top.path(path)) {
+                return false;
+            }
+        }
+        !self.in_lower_layer(path) || self.add_whiteout(path)
+    }
+
+    fn rename_path(&self, from: &str, to: &str) -> bool {
+        let hits = self.lookup(from);
+        if hits.is_empty() {
+            return false;
+        }
+        if hits.iter().any(|&(index, _)| index > 0) {
+            godot_error!("OverlayFs: cannot move '{}': it comes from a lower layer", from);
+            return false;
+        }
+        if self.file_exists(to) || self.dir_exists(to) {
+            godot_error!("OverlayFs: cannot move '{}': '{}' already exists", from, to);
+            return false;
+        }
+
+        let (parent, _) = split_path(to);
+        let top = self.top();
+        self.create_dir(parent) && top.fs.rename_path(&top.path(from), &top.path(to))
+    }
+
+    fn show_archives(&self, show: bool) -> Option<Arc<dyn NebulaFs>> {
+        let layers = self.layers.iter().map(|layer| {
+            let fs = layer.fs.show_archives(show).unwrap_or_else(|| Arc::new(MountFs::new(layer.fs.clone(), show)));
+            Layer { fs, root: layer.root.clone() }
+        });
+        Some(Arc::new(OverlayFs { layers: layers.collect() }))
+    }
+}
+
+/// Splits `path` into its folder and name.
+fn split_path(path: &str) -> (&str, &str) {
+    match path.rfind('/') {
+        Some(slash) => (&path[..slash], &path[slash + 1..]),
+        None => ("", path),
+    }
+}
+
+/// Returns the path of the whiteout that hides `name` inside `parent`.
+fn whiteout_path(parent: &str, name: &str) -> String {
+    join_path(parent, &format!("{}{}", WHITEOUT_PREFIX, name))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::{
+        bytesource::MemoryByteSource,
+        wii::arc::{tests::{empty_archive, sample_archive}, ArcFs},
+    };
+
+    fn archive(data: Vec<u8>) -> Arc<ArcFs> {
+        Arc::new(ArcFs::new(Arc::new(MemoryByteSource::from_vec(data))).unwrap())
+    }
+
+    fn write(fs: &ArcFs, path: &str, data: &[u8]) {
+        fs.replace_file(path, Arc::new(MemoryByteSource::from_vec(data.to_vec()))).unwrap();
+    }
+
+    fn read(fs: &OverlayFs, path: &str) -> Vec<u8> {
+        let source = fs.open_source(path).unwrap();
+        source.read_range(0, source.len() as usize).unwrap()
+    }
+
+    /// An empty upper layer over the sample archive, which holds `a.bin` and `dir/b.bin`.
+    fn layers() -> (Arc<ArcFs>, Arc<ArcFs>, OverlayFs) {
+        let upper = archive(empty_archive());
+        let lower = archive(sample_archive());
+        let overlay = OverlayFs::new(vec![(upper.clone(), String::new()), (lower.clone(), String::new())]);
+        (upper, lower, overlay)
+    }
+
+    #[test]
+    fn upper_files_shadow_lower_ones() {
+        let (upper, _, fs) = layers();
+        assert_eq!(read(&fs, "a.bin"), b"AAA");
+
+        write(&upper, "a.bin", b"upper");
+        assert_eq!(read(&fs, "a.bin"), b"upper");
+        assert_eq!(fs.get_file_size("a.bin"), 5);
+        assert_eq!(fs.entries(""), ["a.bin", "dir/"]);
+    }
+
+    #[test]
+    fn whiteouts_hide_lower_entries() {
+        let (upper, _, fs) = layers();
+        write(&upper, ".wh.a.bin", b"");
+        assert!(!fs.file_exists("a.bin"));
+        assert_eq!(fs.entries(""), ["dir/"]);
+
+        write(&upper, ".wh.dir", b"");
+        assert!(!fs.dir_exists("dir"));
+        assert!(!fs.file_exists("dir/b.bin"));
+        assert!(fs.entries("").is_empty());
+    }
+
+    #[test]
+    fn folders_and_files_replace_each_other() {
+        let (upper, _, fs) = layers();
+        write(&upper, "dir", b"file");
+        assert!(fs.file_exists("dir"));
+        assert!(!fs.dir_exists("dir"));
+        assert!(!fs.file_exists("dir/b.bin"));
+        assert_eq!(fs.entries(""), ["a.bin", "dir"]);
+
+        assert!(upper.create_dir("a.bin"));
+        write(&upper, "a.bin/c.bin", b"C");
+        assert!(fs.dir_exists("a.bin"));
+        assert!(!fs.file_exists("a.bin"));
+        assert_eq!(fs.entries("a.bin"), ["c.bin"]);
+        assert_eq!(fs.entries(""), ["a.bin/", "dir"]);
+    }
+
+    #[test]
+    fn removing_and_recreating_records_whiteouts() {
+        let (upper, lower, fs) = layers();
+        assert!(fs.remove_file("a.bin"));
+        assert!(!fs.file_exists("a.bin"));
+        assert!(upper.file_exists(".wh.a.bin"));
+        assert!(lower.file_exists("a.bin"));
+
+        assert!(fs.create_empty_file("a.bin"));
+        assert!(!upper.file_exists(".wh.a.bin"));
+        assert_eq!(read(&fs, "a.bin"), b"");
+
+        assert!(!fs.remove_dir("dir"));
+        assert!(fs.remove_file("dir/b.bin"));
+        assert!(fs.remove_dir("dir"));
+        assert!(!fs.dir_exists("dir"));
+        assert!(upper.file_exists(".wh.dir"));
+
+        // The whiteout stays next to the new folder, so the old contents remain hidden.
+        assert!(fs.create_dir("dir"));
+        assert!(fs.dir_exists("dir"));
+        assert!(fs.entries("dir").is_empty());
+        assert!(!fs.file_exists("dir/b.bin"));
+    }
+
+    #[test]
+    fn renames_stay_in_the_top_layer() {
+        let (upper, _, fs) = layers();
+        assert!(!fs.rename_path("a.bin", "c.bin"));
+        assert!(!fs.rename_path("dir", "other"));
+        assert!(fs.file_exists("a.bin"));
+
+        write(&upper, "new.bin", b"N");
+        assert!(!fs.rename_path("new.bin", "a.bin"));
+        assert!(fs.rename_path("new.bin", "dir/new.bin"));
+        assert_eq!(read(&fs, "dir/new.bin"), b"N");
+        assert_eq!(fs.entries("dir"), ["b.bin", "new.bin"]);
+    }
+}
@@ -0,0 +1,242 @@
+use std::collections::HashMap;
+
+/// What the value stored in a placeholder is relative to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RelocationBase {
+    /// The start of the buffer.
+    Absolute,
+    /// The placeholder itself.
+    Field,
+    /// A label.
+    Label(String),
+}
+
+impl RelocationBase {
+    /// Parses the `base` argument of the buffer methods: `""` is absolute, `"."` the field itself,
+    /// anything else a label.
+    pub fn from_name(name: &str) -> Self {
+        match name {
+            "" => Self::Absolute,
+            "." => Self::Field,
+            label => Self::Label(label.to_string()),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Target {
+    Label(String),
+    /// Offset of a string inside the pool.
+    String(u64),
+}
+
+/// A placeholder written to the buffer that is filled in by [`Relocations::resolve`].
+#[derive(Clone, Debug)]
+struct Fixup {
+    pos: u64,
+    width: u8,
+    big_endian: bool,
+    target: Target,
+    base: RelocationBase,
+}
+
+/// Labels, placeholders and a deduplicated string pool used while building a binary file,
+/// see [method NebulaBuffer.finish].
+#[derive(Default)]
+pub struct Relocations {
+    labels: HashMap<String, u64>,
+    fixups: Vec<Fixup>,
+    pool: Vec<u8>,
+    pooled: HashMap<String, u64>,
+    /// Position and size of the string pool once it has been written.
+    table: Option<(u64, u64)>,
+}
+
+impl Relocations {
+    pub fn set_label(&mut self, name: &str, pos: u64) {
+        self.labels.insert(name.to_string(), pos);
+    }
+
+    pub fn label(&self, name: &str) -> Option<u64> {
+        self.labels.get(name).copied()
+    }
+
+    pub fn add_label_fixup(&mut self, pos: u64, width: u8, big_endian: bool, label: &str, base: RelocationBase) {
+        let target = Target::Label(label.to_string());
+        self.fixups.push(Fixup { pos, width, big_endian, target, base });
+    }
+
+    /// Adds `text` to the pool unless it is already there and references it from a placeholder at `pos`.
+    pub fn add_string_fixup(&mut self, pos: u64, width: u8, big_endian: bool, text: &str, base: RelocationBase) {
+        let offset = match self.pooled.get(text) {
+            Some(&offset) => offset,
+            None => {
+                let offset = self.pool.len() as u64;
+                self.pool.extend_from_slice(text.as_bytes());
+                self.pool.push(0);
+                self.pooled.insert(text.to_string(), offset);
+                offset
+            }
+        };
+
+        self.fixups.push(Fixup { pos, width, big_endian, target: Target::String(offset), base });
+    }
+
+    /// Returns `true` if the string pool has already been handed out by [`Relocations::take_table`].
+    pub fn has_table(&self) -> bool {
+        self.table.is_some()
+    }
+
+    /// Records that the string pool is written at `pos` and returns its null-terminated strings.
+    pub fn take_table(&mut self, pos: u64) -> Vec<u8> {
+        self.table = Some((pos, self.pool.len() as u64));
+        self.pool.clone()
+    }
+
+    /// Moves labels, placeholders and the string table after `removed` bytes at `pos` were replaced
+    /// by `inserted` bytes. Labels and the table stay at `pos` when data is inserted there and move to
+    /// `pos` when their position is removed; placeholders move past inserted data and are dropped when removed.
+    pub fn shift(&mut self, pos: u64, removed: u64, inserted: u64) {
+        let moved = |p: u64| if p <= pos { p } else if p < pos + removed { pos } else { p - removed + inserted };
+        for label in self.labels.values_mut() {
+            *label = moved(*label);
+        }
+        if let Some((table, _)) = &mut self.table {
+            *table = moved(*table);
+        }
+
+        self.fixups.retain(|fixup| fixup.pos < pos || fixup.pos >= pos + removed);
+        for fixup in &mut self.fixups {
+            if fixup.pos >= pos {
+                fixup.pos = fixup.pos - removed + inserted;
+            }
+        }
+    }
+
+    /// Computes the bytes of every placeholder as `(position, bytes)`. Placeholders that cannot be
+    /// resolved are left out and described in the returned errors.
+    pub fn resolve(&self) -> (Vec<(u64, Vec<u8>)>, Vec<String>) {
+        let mut patches = Vec::with_capacity(self.fixups.len());
+        let mut errors = Vec::new();
+
+        for fixup in &self.fixups {
+            let target = match &fixup.target {
+                Target::Label(name) => self.label(name).ok_or_else(|| format!("undefined label '{}'", name)),
+                Target::String(offset) => match self.table {
+                    Some((table, size)) if *offset < size => Ok(table + offset),
+                    Some(_) => Err("string pooled after the string table was written".to_string()),
+                    None => Err("string table was not written".to_string()),
+                },
+            };
+            let base = match &fixup.base {
+                RelocationBase::Absolute => Ok(0),
+                RelocationBase::Field => Ok(fixup.pos),
+                RelocationBase::Label(name) => self.label(name).ok_or_else(|| format!("undefined label '{}'", name)),
+            };
+
+            let (target, base) = match (target, base) {
+                (Ok(target), Ok(base)) => (target, base),
+                (Err(err), _) | (_, Err(err)) => {
+                    errors.push(format!("placeholder at 0x{:X}: {}", fixup.pos, err));
+                    continue;
+                }
+            };
+
+            let value = target as i64 - base as i64;
+            let bits = fixup.width as u32 * 8;
+            if bits < 64 && (value < -(1i64 << (bits - 1)) || value >= 1i64 << bits) {
+                errors.push(format!("placeholder at 0x{:X}: offset {} does not fit in {} bytes", fixup.pos, value, fixup.width));
+                continue;
+            }
+
+            let width = fixup.width as usize;
+            let bytes = if fixup.big_endian {
+                value.to_be_bytes()[8 - width..].to_vec()
+            } else {
+                value.to_le_bytes()[..width].to_vec()
+            };
+            patches.push((fixup.pos, bytes));
+        }
+
+        (patches, errors)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resolve_one(width: u8, big_endian: bool, target: u64, base: RelocationBase) -> Result<Vec<u8>, String> {
+        let mut relocations = Relocations::default();
+        relocations.set_label("target", target);
+        relocations.set_label("base", 0x100);
+        relocations.add_label_fixup(0x10, width, big_endian, "target", base);
+        let (mut patches, mut errors) = relocations.resolve();
+        match (patches.pop(), errors.pop()) {
+            (Some((0x10, bytes)), None) => Ok(bytes),
+            (None, Some(err)) => Err(err),
+            other => panic!("unexpected result {:?}", other),
+        }
+    }
+
+    #[test]
+    fn resolves_against_each_base() {
+        assert_eq!(resolve_one(4, true, 0x1234, RelocationBase::Absolute).unwrap(), [0, 0, 0x12, 0x34]);
+        assert_eq!(resolve_one(2, false, 0x30, RelocationBase::Field).unwrap(), [0x20, 0]);
+        assert_eq!(resolve_one(1, true, 0x128, RelocationBase::Label("base".to_string())).unwrap(), [0x28]);
+        assert_eq!(resolve_one(3, true, 0x08, RelocationBase::Field).unwrap(), [0xFF, 0xFF, 0xF8]);
+    }
+
+    #[test]
+    fn checks_that_offsets_fit() {
+        assert_eq!(resolve_one(1, true, 0xFF, RelocationBase::Absolute).unwrap(), [0xFF]);
+        assert!(resolve_one(1, true, 0x100, RelocationBase::Absolute).is_err());
+        let base = || RelocationBase::Label("base".to_string());
+        assert_eq!(resolve_one(1, true, 0x100 - 0x80, base()).unwrap(), [0x80]);
+        assert!(resolve_one(1, true, 0x100 - 0x81, base()).is_err());
+        assert_eq!(resolve_one(2, true, 0xFFFF, RelocationBase::Absolute).unwrap(), [0xFF, 0xFF]);
+        assert!(resolve_one(2, true, 0x10000, RelocationBase::Absolute).is_err());
+        assert_eq!(resolve_one(8, false, u32::MAX as u64 + 1, RelocationBase::Absolute).unwrap(), [0, 0, 0, 0, 1, 0, 0, 0]);
+    }
+
+    #[test]
+    fn reports_undefined_labels_and_late_strings() {
+        let mut relocations = Relocations::default();
+        relocations.add_label_fixup(0, 4, true, "missing", RelocationBase::Absolute);
+        relocations.add_string_fixup(4, 4, true, "early", RelocationBase::Absolute);
+        let table = relocations.take_table(0x40);
+        relocations.add_string_fixup(8, 4, true, "late", RelocationBase::Absolute);
+        relocations.add_string_fixup(12, 4, true, "early", RelocationBase::Absolute);
+
+        let (patches, errors) = relocations.resolve();
+        assert_eq!(table, b"early\0");
+        assert_eq!(patches, [(4, vec![0, 0, 0, 0x40]), (12, vec![0, 0, 0, 0x40])]);
+        assert_eq!(errors.len(), 2);
+        assert!(errors[0].contains("undefined label 'missing'"));
+        assert!(errors[1].contains("after the string table"));
+    }
+
+    #[test]
+    fn shifts_with_edits() {
+        let mut relocations = Relocations::default();
+        relocations.set_label("before", 0x04);
+        relocations.set_label("at", 0x10);
+        relocations.set_label("after", 0x20);
+        relocations.add_label_fixup(0x10, 4, true, "after", RelocationBase::Absolute);
+        relocations.add_label_fixup(0x18, 4, true, "before", RelocationBase::Absolute);
+
+        relocations.shift(0x10, 0, 8);
+        assert_eq!(relocations.label("at"), Some(0x10));
+        assert_eq!(relocations.label("after"), Some(0x28));
+        // Removes the placeholder pointing at "after", which was pushed to 0x18.
+        relocations.shift(0x18, 0x8, 0);
+        assert_eq!(relocations.label("before"), Some(0x04));
+        assert_eq!(relocations.label("after"), Some(0x20));
+
+        relocations.shift(0x1C, 0x8, 2);
+        assert_eq!(relocations.label("after"), Some(0x1C));
+        let (patches, errors) = relocations.resolve();
+        assert!(errors.is_empty());
+        assert_eq!(patches, [(0x18, vec![0, 0, 0, 0x04])]);
+    }
+}
@@ -0,0 +1,1004 @@
+use std::{collections::{HashMap, VecDeque}, sync::Arc};
+use godot::prelude::*;
+use crate::io::{buffer::NebulaBuffer, text::TextEncoding};
+
+/// Alignment of pointer targets written by [`NebulaBuffer::write_struct`].
+const POINTER_TARGET_ALIGN: u64 = 4;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Scalar {
+    U8,
+    I8,
+    U16,
+    I16,
+    U32,
+    I32,
+    U64,
+    I64,
+    F32,
+    F64,
+    Bool,
+}
+
+impl Scalar {
+    fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "u8" => Self::U8,
+            "i8" => Self::I8,
+            "u16" => Self::U16,
+            "i16" => Self::I16,
+            "u32" => Self::U32,
+            "i32" => Self::I32,
+            "u64" => Self::U64,
+            "i64" => Self::I64,
+            "f32" => Self::F32,
+            "f64" => Self::F64,
+            "bool" => Self::Bool,
+            _ => return None,
+        })
+    }
+
+    fn is_integer(self) -> bool {
+        !matches!(self, Self::F32 | Self::F64 | Self::Bool)
+    }
+
+    fn read_int(self, buf: &mut NebulaBuffer) -> i64 {
+        match self {
+            Self::U8 | Self::Bool => buf.read_u8() as i64,
+            Self::I8 => buf.read_i8() as i64,
+            Self::U16 => buf.read_u16() as i64,
+            Self::I16 => buf.read_i16() as i64,
+            Self::U32 => buf.read_u32() as i64,
+            Self::I32 => buf.read_i32() as i64,
+            Self::U64 => buf.read_u64() as i64,
+            Self::I64 => buf.read_i64(),
+            Self::F32 => buf.read_f32() as i64,
+            Self::F64 => buf.read_f64() as i64,
+        }
+    }
+
+    fn write_int(self, buf: &mut NebulaBuffer, value: i64) {
+        match self {
+            Self::U8 | Self::I8 | Self::Bool => buf.store_u8(value as u8),
+            Self::U16 | Self::I16 => buf.store_u16(value as u16),
+            Self::U32 | Self::I32 => buf.store_u32(value as u32),
+            Self::U64 | Self::I64 => buf.store_u64(value as u64),
+            Self::F32 => buf.store_f32(value as f32),
+            Self::F64 => buf.store_f64(value as f64),
+        }
+    }
+
+    fn read(self, buf: &mut NebulaBuffer) -> Variant {
+        match self {
+            Self::F32 => buf.read_f32().to_variant(),
+            Self::F64 => buf.read_f64().to_variant(),
+            Self::Bool => (buf.read_u8() != 0).to_variant(),
+            Self::U64 => buf.read_u64().to_variant(),
+            _ => self.read_int(buf).to_variant(),
+        }
+    }
+
+    fn write(self, buf: &mut NebulaBuffer, value: &Variant) {
+        match self {
+            Self::F32 => buf.store_f32(value.try_to::<f32>().unwrap_or(0.0)),
+            Self::F64 => buf.store_f64(value.try_to::<f64>().unwrap_or(0.0)),
+            Self::Bool => buf.store_u8(value.try_to::<bool>().unwrap_or(false) as u8),
+            _ => self.write_int(buf, value.try_to::<i64>().unwrap_or(0)),
+        }
+    }
+}
+
+/// Maps a schema string type name to its encoding.
+fn encoding_from_name(name: &str) -> Option<TextEncoding> {
+    Some(match name {
+        "ascii" => TextEncoding::Ascii,
+        "utf8" => TextEncoding::Utf8,
+        "utf16" => TextEncoding::Utf16,
+        "sjis" => TextEncoding::ShiftJis,
+        "cp1252" => TextEncoding::Windows1252,
+        _ => return None,
+    })
+}
+
+/// Number of elements or code units, either fixed or taken from a field read earlier.
+#[derive(Clone, Debug)]
+enum Count {
+    Fixed(u64),
+    Field(String),
+    Terminated,
+}
+
+/// What a pointer's stored offset is relative to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum PointerBase {
+    File,
+    Struct,
+    Field,
+}
+
+#[derive(Clone, Debug)]
+enum Type {
+    Scalar(Scalar),
+    Enum(Scalar, Vec<(String, i64)>),
+    String(TextEncoding, Count),
+    Bytes(Count),
+    Struct(Vec<Field>),
+    Array(Box<Type>, Count),
+    Pointer { width: Scalar, base: PointerBase, target: Box<Type> },
+    Pad(u64),
+    Align(u64),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum CmpOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    And,
+    Truthy,
+}
+
+#[derive(Clone, Debug)]
+struct Condition {
+    field: String,
+    op: CmpOp,
+    value: i64,
+}
+
+impl Condition {
+    fn parse(text: &str) -> Result<Self, String> {
+        const OPS: [(&str, CmpOp); 7] = [
+            (">=", CmpOp::Ge),
+            ("<=", CmpOp::Le),
+            ("==", CmpOp::Eq),
+            ("!=", CmpOp::Ne),
+            (">", CmpOp::Gt),
+            ("<", CmpOp::Lt),
+            ("&", CmpOp::And),
+        ];
+
+        for (token, op) in OPS {
+            if let Some((field, value)) = text.split_once(token) {
+                return Ok(Self {
+                    field: field.trim().to_string(),
+                    op,
+                    value: parse_int(value.trim())
+                        .ok_or_else(|| format!("invalid value in condition '{}'", text))?,
+                });
+            }
+        }
+
+        Ok(Self { field: text.trim().to_string(), op: CmpOp::Truthy, value: 0 })
+    }
+
+    fn holds(&self, lhs: i64) -> bool {
+        match self.op {
+            CmpOp::Eq => lhs == self.value,
+            CmpOp::Ne => lhs != self.value,
+            CmpOp::Lt => lhs < self.value,
+            CmpOp::Le => lhs <= self.value,
+            CmpOp::Gt => lhs > self.value,
+            CmpOp::Ge => lhs >= self.value,
+            CmpOp::And => lhs & self.value != 0,
+            CmpOp::Truthy => lhs != 0,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Field {
+    /// Empty for padding and alignment.
+    name: String,
+    ty: Type,
+    condition: Option<Condition>,
+}
+
+/// A parsed binary layout, see [`NebulaSchema`].
+#[derive(Debug)]
+pub struct Schema {
+    big_endian: Option<bool>,
+    fields: Vec<Field>,
+}
+
+fn parse_int(text: &str) -> Option<i64> {
+    let (negative, digits) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text),
+    };
+
+    let value = match digits.strip_prefix("0x").or_else(|| digits.strip_prefix("0X")) {
+        Some(hex) => i64::from_str_radix(hex, 16).ok()?,
+        None => digits.parse().ok()?,
+    };
+
+    Some(if negative { -value } else { value })
+}
+
+fn parse_count(text: &str) -> Count {
+    match parse_int(text) {
+        Some(n) => Count::Fixed(n.max(0) as u64),
+        None => Count::Field(text.to_string()),
+    }
+}
+
+fn parse_base(text: &str) -> Result<PointerBase, String> {
+    match text {
+        "file" => Ok(PointerBase::File),
+        "struct" => Ok(PointerBase::Struct),
+        "self" => Ok(PointerBase::Field),
+        _ => Err(format!("unknown pointer base '{}'", text)),
+    }
+}
+
+/// Builds a type from its name and modifiers. Shared by the Dictionary and text forms.
+struct TypeSpec<'a> {
+    name: &'a str,
+    /// Bracketed counts in order: for strings and bytes the first one is the length.
+    counts: Vec<Count>,
+    pointer: Option<(Type, PointerBase)>,
+    enum_values: Option<Vec<(String, i64)>>,
+}
+
+impl Schema {
+    /// Parses a schema from a Dictionary or a text definition.
+    pub fn from_variant(definition: &Variant) -> Result<Self, String> {
+        if let Ok(dict) = definition.try_to::<VarDictionary>() {
+            return Self::from_dict(&dict);
+        }
+
+        if let Ok(text) = definition.try_to::<GString>() {
+            return Self::from_text(&text.to_string());
+        }
+
+        Err("schema must be a Dictionary or a String".to_string())
+    }
+
+    fn from_dict(dict: &VarDictionary) -> Result<Self, String> {
+        let big_endian = match dict.get("endian") {
+            Some(endian) => Some(parse_endian(&endian.to_string())?),
+            None => None,
+        };
+
+        let mut types = HashMap::new();
+        if let Some(defs) = dict.get("types") {
+            let defs = defs.try_to::<VarDictionary>()
+                .map_err(|_| "'types' must be a Dictionary".to_string())?;
+            for (name, def) in defs.iter_shared() {
+                let ty = dict_type(&types, &def)?;
+                types.insert(name.to_string(), ty);
+            }
+        }
+
+        let fields = dict_fields(&types, dict)?;
+        Ok(Self { big_endian, fields })
+    }
+
+    fn from_text(text: &str) -> Result<Self, String> {
+        let mut big_endian = None;
+        let mut types: HashMap<String, Type> = HashMap::new();
+        let mut fields = Vec::new();
+        let mut block: Option<(String, Option<Scalar>, Vec<String>)> = None;
+
+        for (index, raw) in text.lines().enumerate() {
+            let line = raw.split('#').next().unwrap_or("").trim();
+            if line.is_empty() {
+                continue;
+            }
+            let err = |message: String| format!("line {}: {}", index + 1, message);
+
+            if let Some((name, scalar, body)) = &mut block {
+                if line != "}" {
+                    body.push(line.to_string());
+                    continue;
+                }
+
+                let ty = match scalar {
+                    Some(scalar) => text_enum(*scalar, body).map_err(err)?,
+                    None => Type::Struct(
+                        body.iter()
+                            .map(|line| text_field(&types, line))
+                            .collect::<Result<_, _>>()
+                            .map_err(err)?,
+                    ),
+                };
+                types.insert(name.clone(), ty);
+                block = None;
+                continue;
+            }
+
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            match tokens.as_slice() {
+                ["endian", endian] => big_endian = Some(parse_endian(endian).map_err(err)?),
+                ["struct", name, "{"] => block = Some((name.to_string(), None, Vec::new())),
+                ["enum", name, ":", scalar, "{"] => {
+                    let scalar = Scalar::from_name(scalar)
+                        .filter(|scalar| scalar.is_integer())
+                        .ok_or_else(|| err(format!("invalid enum type '{}'", scalar)))?;
+                    block = Some((name.to_string(), Some(scalar), Vec::new()));
+                }
+                _ => fields.push(text_field(&types, line).map_err(err)?),
+            }
+        }
+
+        if let Some((name, _, _)) = block {
+            return Err(format!("unterminated block '{}'", name));
+        }
+
+        Ok(Self { big_endian, fields })
+    }
+}
+
+fn parse_endian(text: &str) -> Result<bool, String> {
+    match text {
+        "big" | ">" => Ok(true),
+        "little" | "<" => Ok(false),
+        _ => Err(format!("unknown endianness '{}'", text)),
+    }
+}
+
+fn build_type(types: &HashMap<String, Type>, spec: TypeSpec) -> Result<Type, String> {
+    let mut counts = spec.counts.into_iter();
+
+    let mut ty = if let Some(encoding) = encoding_from_name(spec.name) {
+        Type::String(encoding, counts.next().unwrap_or(Count::Terminated))
+    } else if spec.name == "bytes" {
+        match counts.next() {
+            Some(count) => Type::Bytes(count),
+            None => return Err("bytes need a length".to_string()),
+        }
+    } else if let Some(scalar) = Scalar::from_name(spec.name) {
+        match spec.enum_values {
+            Some(values) if scalar.is_integer() => Type::Enum(scalar, values),
+            Some(_) => return Err(format!("enum over non-integer type '{}'", spec.name)),
+            None => Type::Scalar(scalar),
+        }
+    } else if let Some(ty) = types.get(spec.name) {
+        ty.clone()
+    } else {
+        return Err(format!("unknown type '{}'", spec.name));
+    };
+
+    if let Some((target, base)) = spec.pointer {
+        let width = match ty {
+            Type::Scalar(width @ (Scalar::U16 | Scalar::U32 | Scalar::U64 | Scalar::I16 | Scalar::I32 | Scalar::I64)) => width,
+            _ => return Err(format!("pointer must be stored as an integer, not '{}'", spec.name)),
+        };
+        ty = Type::Pointer { width, base, target: Box::new(target) };
+    }
+
+    for count in counts {
+        if matches!(count, Count::Terminated) {
+            return Err("arrays need a count".to_string());
+        }
+        ty = Type::Array(Box::new(ty), count);
+    }
+
+    Ok(ty)
+}
+
+/// Parses a type spec Dictionary: `type` plus the optional `length`, `count`, `to`, `from` and `enum`.
+fn dict_type(types: &HashMap<String, Type>, def: &Variant) -> Result<Type, String> {
+    if let Ok(name) = def.try_to::<GString>() {
+        return build_type(types, TypeSpec { name: &name.to_string(), counts: Vec::new(), pointer: None, enum_values: None });
+    }
+
+    let dict = def.try_to::<VarDictionary>()
+        .map_err(|_| "type must be a String or a Dictionary".to_string())?;
+
+    let count_of = |key: &str| dict.get(key).map(|value| parse_count(&value.to_string()));
+
+    if dict.contains_key("fields") {
+        let ty = Type::Struct(dict_fields(types, &dict)?);
+        return Ok(match count_of("count") {
+            Some(count) => Type::Array(Box::new(ty), count),
+            None => ty,
+        });
+    }
+
+    let name = dict.get("type")
+        .ok_or_else(|| "type spec without 'type'".to_string())?;
+
+    if let Ok(inline) = name.try_to::<VarDictionary>() {
+        let ty = dict_type(types, &inline.to_variant())?;
+        return Ok(match count_of("count") {
+            Some(count) => Type::Array(Box::new(ty), count),
+            None => ty,
+        });
+    }
+
+    let name = name.to_string();
+    match name.as_str() {
+        "pad" | "align" => {
+            let size = dict.get("size")
+                .and_then(|size| size.try_to::<i64>().ok())
+                .filter(|size| *size > 0)
+                .ok_or_else(|| format!("'{}' needs a positive 'size'", name))? as u64;
+            return Ok(if name == "pad" { Type::Pad(size) } else { Type::Align(size) });
+        }
+        _ => {}
+    }
+
+    let pointer = match dict.get("to") {
+        Some(target) => {
+            let base = match dict.get("from") {
+                Some(base) => parse_base(&base.to_string())?,
+                None => PointerBase::File,
+            };
+            Some((dict_type(types, &target)?, base))
+        }
+        None => None,
+    };
+
+    let enum_values = match dict.get("enum") {
+        Some(values) => {
+            let values = values.try_to::<VarDictionary>()
+                .map_err(|_| "'enum' must be a Dictionary".to_string())?;
+            Some(values.iter_shared()
+                .map(|(key, value)| (key.to_string(), value.try_to::<i64>().unwrap_or(0)))
+                .collect())
+        }
+        None => None,
+    };
+
+    let counts = count_of("length").into_iter().chain(count_of("count")).collect();
+    build_type(types, TypeSpec { name: &name, counts, pointer, enum_values })
+}
+
+fn dict_fields(types: &HashMap<String, Type>, dict: &VarDictionary) -> Result<Vec<Field>, String> {
+    let list = dict.get("fields")
+        .and_then(|fields| fields.try_to::<VarArray>().ok())
+        .ok_or_else(|| "'fields' must be an Array".to_string())?;
+
+    let mut fields = Vec::new();
+    for def in list.iter_shared() {
+        let spec = def.try_to::<VarDictionary>()
+            .map_err(|_| "each field must be a Dictionary".to_string())?;
+        let name = spec.get("name").map(|name| name.to_string()).unwrap_or_default();
+        let ty = dict_type(types, &def).map_err(|e| format!("field '{}': {}", name, e))?;
+        if name.is_empty() && !matches!(ty, Type::Pad(_) | Type::Align(_)) {
+            return Err("field without a name".to_string());
+        }
+
+        let condition = match spec.get("if") {
+            Some(condition) => Some(Condition::parse(&condition.to_string())?),
+            None => None,
+        };
+        fields.push(Field { name, ty, condition });
+    }
+
+    Ok(fields)
+}
+
+/// Splits `name[a][b]` into the name and its bracketed counts.
+fn split_counts(expr: &str) -> Result<(&str, Vec<Count>), String> {
+    let (name, mut rest) = match expr.find('[') {
+        Some(at) => (&expr[..at], &expr[at..]),
+        None => return Ok((expr, Vec::new())),
+    };
+
+    let mut counts = Vec::new();
+    while let Some(inner) = rest.strip_prefix('[') {
+        let close = inner.find(']').ok_or_else(|| format!("unclosed '[' in '{}'", expr))?;
+        counts.push(parse_count(inner[..close].trim()));
+        rest = &inner[close + 1..];
+    }
+
+    if !rest.is_empty() {
+        return Err(format!("unexpected '{}' in '{}'", rest, expr));
+    }
+
+    Ok((name, counts))
+}
+
+/// Parses a text field line: `type[counts] name [-> target [from base]] [if condition]`,
+/// or `pad N` / `align N`.
+fn text_field(types: &HashMap<String, Type>, line: &str) -> Result<Field, String> {
+    let (line, condition) = match line.split_once(" if ") {
+        Some((line, condition)) => (line.trim(), Some(Condition::parse(condition)?)),
+        None => (line, None),
+    };
+
+    let tokens: Vec<&str> = line.split_whitespace().collect();
+    if let [kind @ ("pad" | "align"), size] = tokens.as_slice() {
+        let size = parse_int(size).filter(|size| *size > 0)
+            .ok_or_else(|| format!("'{}' needs a positive size", kind))? as u64;
+        let ty = if *kind == "pad" { Type::Pad(size) } else { Type::Align(size) };
+        return Ok(Field { name: String::new(), ty, condition });
+    }
+
+    let (type_expr, name, rest) = match tokens.as_slice() {
+        [type_expr, name, rest @ ..] => (*type_expr, name.to_string(), rest),
+        _ => return Err(format!("expected '<type> <name>' in '{}'", line)),
+    };
+
+    let pointer = match rest {
+        [] => None,
+        ["->", target] => Some((*target, PointerBase::File)),
+        ["->", target, "from", base] => Some((*target, parse_base(base)?)),
+        _ => return Err(format!("unexpected '{}'", rest.join(" "))),
+    };
+
+    let pointer = match pointer {
+        Some((target, base)) => {
+            let (target_name, target_counts) = split_counts(target)?;
+            let target = build_type(types, TypeSpec { name: target_name, counts: target_counts, pointer: None, enum_values: None })?;
+            Some((target, base))
+        }
+        None => None,
+    };
+
+    let (type_name, counts) = split_counts(type_expr)?;
+    let ty = build_type(types, TypeSpec { name: type_name, counts, pointer, enum_values: None })
+        .map_err(|e| format!("field '{}': {}", name, e))?;
+
+    Ok(Field { name, ty, condition })
+}
+
+/// Parses the body of an `enum Name : type { ... }` block.
+fn text_enum(scalar: Scalar, body: &[String]) -> Result<Type, String> {
+    let mut values = Vec::new();
+    for entry in body.iter().flat_map(|line| line.split(',')) {
+        let entry = entry.trim();
+        if entry.is_empty() {
+            continue;
+        }
+
+        let (name, value) = entry.split_once('=')
+            .ok_or_else(|| format!("expected 'NAME = value' in '{}'", entry))?;
+        let value = parse_int(value.trim())
+            .ok_or_else(|| format!("invalid enum value in '{}'", entry))?;
+        values.push((name.trim().to_string(), value));
+    }
+
+    Ok(Type::Enum(scalar, values))
+}
+
+/// Looks up `path` (`name` or `name.child`) in the innermost scope that defines its first part.
+fn lookup(scopes: &[VarDictionary], path: &str) -> Option<Variant> {
+    let mut parts = path.split('.');
+    let first = parts.next()?;
+    let mut value = scopes.iter().rev().find_map(|scope| scope.get(first))?;
+
+    for part in parts {
+        value = value.try_to::<VarDictionary>().ok()?.get(part)?;
+    }
+
+    Some(value)
+}
+
+fn lookup_int(scopes: &[VarDictionary], path: &str) -> Result<i64, String> {
+    let value = lookup(scopes, path)
+        .ok_or_else(|| format!("field '{}' not found", path))?;
+    value.try_to::<i64>()
+        .or_else(|_| value.try_to::<bool>().map(i64::from))
+        .map_err(|_| format!("field '{}' is not an integer", path))
+}
+
+fn resolve_count(scopes: &[VarDictionary], count: &Count) -> Result<u64, String> {
+    match count {
+        Count::Fixed(n) => Ok(*n),
+        Count::Field(path) => Ok(lookup_int(scopes, path)?.max(0) as u64),
+        Count::Terminated => Err("count is not known".to_string()),
+    }
+}
+
+fn condition_holds(scopes: &[VarDictionary], condition: &Option<Condition>) -> Result<bool, String> {
+    match condition {
+        Some(condition) => Ok(condition.holds(lookup_int(scopes, &condition.field)?)),
+        None => Ok(true),
+    }
+}
+
+struct Reader<'a> {
+    buf: &'a mut NebulaBuffer,
+    scopes: Vec<VarDictionary>,
+}
+
+impl Reader<'_> {
+    fn read_struct(&mut self, fields: &[Field]) -> Result<VarDictionary, String> {
+        let start = self.buf.get_offset() as u64;
+        self.scopes.push(VarDictionary::new());
+
+        for field in fields {
+            if !condition_holds(&self.scopes, &field.condition)? {
+                continue;
+            }
+
+            let value = self.read_type(&field.ty, start)
+                .and_then(|value| self.check_error().map(|_| value))
+                .map_err(|e| format!("{}: {}", field.name, e))?;
+            if let Some(value) = value {
+                self.scopes.last_mut().unwrap().set(field.name.as_str(), value);
+            }
+        }
+
+        Ok(self.scopes.pop().unwrap())
+    }
+
+    /// Resolves a count read from the data and fails if `count` items of `item_size` bytes
+    /// would not fit in the rest of the buffer.
+    fn bounded_count(&self, count: &Count, item_size: u64) -> Result<u64, String> {
+        let count = resolve_count(&self.scopes, count)?;
+        let remaining = self.buf.remaining();
+        if count.saturating_mul(item_size) > remaining {
+            return Err(format!("count {} exceeds the {} bytes left in the buffer", count, remaining));
+        }
+        Ok(count)
+    }
+
+    /// Fails if a read has run past the end of the buffer or otherwise failed.
+    fn check_error(&self) -> Result<(), String> {
+        match self.buf.has_error() {
+            true => Err(self.buf.get_error_message().to_string()),
+            false => Ok(()),
+        }
+    }
+
+    /// Reads one value. Returns `None` for padding and alignment.
+    fn read_type(&mut self, ty: &Type, struct_start: u64) -> Result<Option<Variant>, String> {
+        let value = match ty {
+            Type::Scalar(scalar) => scalar.read(self.buf),
+            Type::Enum(scalar, values) => {
+                let raw = scalar.read_int(self.buf);
+                match values.iter().find(|(_, value)| *value == raw) {
+                    Some((name, _)) => name.to_variant(),
+                    None => raw.to_variant(),
+                }
+            }
+            Type::String(encoding, count) => {
+                let length = match count {
+                    Count::Terminated => None,
+                    count => Some(self.bounded_count(count, encoding.unit_size() as u64)?),
+                };
+                GString::from(&self.buf.read_text(*encoding, length)).to_variant()
+            }
+            Type::Bytes(count) => {
+                let size = self.bounded_count(count, 1)?;
+                let pos = self.buf.get_offset();
+                let bytes = self.buf.read_bytes(pos, size as i64);
+                self.buf.goto(pos + size as i64);
+                bytes.to_variant()
+            }
+            Type::Struct(fields) => self.read_struct(fields)?.to_variant(),
+            Type::Array(element, count) => {
+                // Elements take at least one byte each, so a count larger than the rest of the buffer is corrupt.
+                let count = self.bounded_count(count, 1)?;
+                let mut out = VarArray::new();
+                for _ in 0..count {
+                    let value = self.read_type(element, struct_start)?;
+                    self.check_error()?;
+                    if let Some(value) = value {
+                        out.push(&value);
+                    }
+                }
+                out.to_variant()
+            }
+            Type::Pointer { width, base, target } => {
+                let pos = self.buf.get_offset() as u64;
+                let raw = width.read_int(self.buf);
+                if raw == 0 {
+                    return Ok(Some(Variant::nil()));
+                }
+
+                let base = match base {
+                    PointerBase::File => 0,
+                    PointerBase::Struct => struct_start,
+                    PointerBase::Field => pos,
+                };
+
+                let saved = self.buf.get_offset();
+                self.buf.goto(base.wrapping_add_signed(raw) as i64);
+                let value = self.read_type(target, struct_start)?;
+                self.buf.goto(saved);
+                value.unwrap_or_default()
+            }
+            Type::Pad(size) => {
+                self.buf.seek(*size as i64);
+                return Ok(None);
+            }
+            Type::Align(size) => {
+                let pos = self.buf.get_offset() as u64;
+                self.buf.goto(pos.next_multiple_of(*size) as i64);
+                return Ok(None);
+            }
+        };
+
+        Ok(Some(value))
+    }
+}
+
+/// A pointer target waiting to be written after the struct that references it.
+struct Deferred {
+    pointer_pos: u64,
+    width: Scalar,
+    base: u64,
+    target: Type,
+    value: Variant,
+    scopes: Vec<VarDictionary>,
+}
+
+struct Writer<'a> {
+    buf: &'a mut NebulaBuffer,
+    scopes: Vec<VarDictionary>,
+    deferred: VecDeque<Deferred>,
+}
+
+/// Returns the number of elements or code units `value` has when stored as `ty`.
+fn stored_len(ty: &Type, value: &Variant) -> Option<i64> {
+    match ty {
+        Type::Array(..) => value.try_to::<VarArray>().ok().map(|array| array.len() as i64),
+        Type::Bytes(_) => value.try_to::<PackedByteArray>().ok().map(|bytes| bytes.len() as i64),
+        Type::String(encoding, _) => Some(encoding.units(&value.to_string()) as i64),
+        _ => None,
+    }
+}
+
+impl Writer<'_> {
+    fn write_struct(&mut self, fields: &[Field], value: &VarDictionary) -> Result<(), String> {
+        let start = self.buf.get_offset() as u64;
+
+        // Counts stored in the same struct follow the data they describe.
+        let mut scope = value.duplicate_shallow();
+        for field in fields {
+            let (Type::Array(_, Count::Field(count)) | Type::String(_, Count::Field(count)) | Type::Bytes(Count::Field(count))) = &field.ty else {
+                continue;
+            };
+            if count.contains('.') {
+                continue;
+            }
+            if let Some(len) = scope.get(field.name.as_str()).and_then(|v| stored_len(&field.ty, &v)) {
+                scope.set(count.as_str(), len);
+            }
+        }
+
+        self.scopes.push(scope.clone());
+        for field in fields {
+            if !condition_holds(&self.scopes, &field.condition)? {
+                continue;
+            }
+
+            let value = scope.get(field.name.as_str()).unwrap_or_default();
+            self.write_type(&field.ty, &value, start)
+                .map_err(|e| format!("{}: {}", field.name, e))?;
+        }
+        self.scopes.pop();
+
+        Ok(())
+    }
+
+    fn write_type(&mut self, ty: &Type, value: &Variant, struct_start: u64) -> Result<(), String> {
+        match ty {
+            Type::Scalar(scalar) => scalar.write(self.buf, value),
+            Type::Enum(scalar, values) => {
+                let raw = match value.try_to::<i64>() {
+                    Ok(raw) => raw,
+                    Err(_) => {
+                        let name = value.to_string();
+                        values.iter()
+                            .find(|(value_name, _)| *value_name == name)
+                            .map(|(_, raw)| *raw)
+                            .ok_or_else(|| format!("unknown enum value '{}'", name))?
+                    }
+                };
+                scalar.write_int(self.buf, raw);
+            }
+            Type::String(encoding, count) => {
+                let length = match count {
+                    Count::Terminated => None,
+                    count => Some(resolve_count(&self.scopes, count)?),
+                };
+                self.buf.store_text(*encoding, &value.to_string(), length);
+            }
+            Type::Bytes(count) => {
+                let size = resolve_count(&self.scopes, count)? as usize;
+                let mut bytes = value.try_to::<PackedByteArray>().map(|b| b.to_vec()).unwrap_or_default();
+                bytes.resize(size, 0);
+                self.buf.store_bytes(PackedByteArray::from(bytes));
+            }
+            Type::Struct(fields) => {
+                let dict = value.try_to::<VarDictionary>().unwrap_or_default();
+                self.write_struct(fields, &dict)?;
+            }
+            Type::Array(element, count) => {
+                let count = resolve_count(&self.scopes, count)?;
+                let items = value.try_to::<VarArray>().unwrap_or_default();
+                for i in 0..count as usize {
+                    let item = items.get(i).unwrap_or_default();
+                    self.write_type(element, &item, struct_start)?;
+                }
+            }
+            Type::Pointer { width, base, target } => {
+                let pos = self.buf.get_offset() as u64;
+                width.write_int(self.buf, 0);
+                if value.is_nil() {
+                    return Ok(());
+                }
+
+                let base = match base {
+                    PointerBase::File => 0,
+                    PointerBase::Struct => struct_start,
+                    PointerBase::Field => pos,
+                };
+
+                self.deferred.push_back(Deferred {
+                    pointer_pos: pos,
+                    width: *width,
+                    base,
+                    target: (**target).clone(),
+                    value: value.clone(),
+                    scopes: self.scopes.clone(),
+                });
+            }
+            Type::Pad(size) => {
+                for _ in 0..*size {
+                    self.buf.store_u8(0);
+                }
+            }
+            Type::Align(size) => {
+                let pos = self.buf.get_offset() as u64;
+                for _ in pos..pos.next_multiple_of(*size) {
+                    self.buf.store_u8(0);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Writes queued pointer targets one after another and patches their pointers.
+    fn write_deferred(&mut self) -> Result<(), String> {
+        while let Some(deferred) = self.deferred.pop_front() {
+            let pos = self.buf.get_offset() as u64;
+            let target_pos = pos.next_multiple_of(POINTER_TARGET_ALIGN);
+            for _ in pos..target_pos {
+                self.buf.store_u8(0);
+            }
+
+            self.scopes = deferred.scopes;
+            self.write_type(&deferred.target, &deferred.value, target_pos)?;
+            let end = self.buf.get_offset();
+
+            self.buf.goto(deferred.pointer_pos as i64);
+            deferred.width.write_int(self.buf, target_pos.wrapping_sub(deferred.base) as i64);
+            self.buf.goto(end);
+        }
+
+        Ok(())
+    }
+}
+
+impl Schema {
+    pub fn read(&self, buf: &mut NebulaBuffer) -> Result<VarDictionary, String> {
+        let saved_endian = buf.big_endian;
+        buf.big_endian = self.big_endian.unwrap_or(saved_endian);
+
+        let result = buf.with_own_errors(|buf| Reader { buf, scopes: Vec::new() }.read_struct(&self.fields));
+
+        buf.big_endian = saved_endian;
+        result
+    }
+
+    pub fn write(&self, buf: &mut NebulaBuffer, value: &VarDictionary) -> Result<(), String> {
+        let saved_endian = buf.big_endian;
+        buf.big_endian = self.big_endian.unwrap_or(saved_endian);
+
+        let mut writer = Writer { buf: &mut *buf, scopes: Vec::new(), deferred: VecDeque::new() };
+        let result = writer.write_struct(&self.fields, value)
+            .and_then(|_| writer.write_deferred());
+
+        buf.big_endian = saved_endian;
+        result
+    }
+}
+
+#[derive(GodotClass)]
+/// A binary layout that [method NebulaBuffer.read_struct] and [method NebulaBuffer.write_struct]
+/// turn into nested Dictionaries and back.
+///
+/// A schema is described either as a Dictionary or as text. Both support the same features:
+/// - Integer and float fields: `u8`, `i8`, `u16`, `i16`, `u32`, `i32`, `u64`, `i64`, `f32`, `f64`, `bool`.
+/// - Strings in `ascii`, `utf8`, `utf16`, `sjis` (Shift-JIS) or `cp1252` (Windows-1252), null-terminated or with a fixed or field-driven length.
+/// - Raw `bytes` with a fixed or field-driven length.
+/// - Nested structs, named types and arrays counted by a number or an earlier field
+///   (`count` or `header.count`; the innermost struct defining it wins).
+/// - Pointers stored as an integer, relative to the `file` (default), the enclosing `struct`
+///   or the pointer field itself (`self`). A zero pointer reads as `null`.
+/// - Conditional fields (`version >= 2`, `flags & 0x4`, `has_name`), using `==`, `!=`, `<`, `<=`, `>`, `>=` or `&`.
+/// - Enums, read as the name of the matching value or as the number if none matches.
+/// - `pad N` skips bytes and `align N` moves to the next multiple of `N`.
+///
+/// **Text form**:
+/// ```text
+/// endian big
+///
+/// enum Kind : u8 {
+///     NONE = 0, SPRITE = 1
+/// }
+///
+/// struct Entry {
+///     u16 id
+///     Kind kind
+///     pad 1
+/// }
+///
+/// u32 magic
+/// u16 version
+/// u16 entry_count
+/// ascii[16] name
+/// Entry[entry_count] entries
+/// u32 extra if version >= 2
+/// u32 header -> Entry from file
+/// align 32
+/// ```
+/// **Dictionary form** of the same schema:
+/// ```gdscript
+/// {
+///     "endian": "big",
+///     "types": {
+///         "Kind": {"type": "u8", "enum": {"NONE": 0, "SPRITE": 1}},
+///         "Entry": {"fields": [
+///             {"name": "id", "type": "u16"},
+///             {"name": "kind", "type": "Kind"},
+///             {"type": "pad", "size": 1},
+///         ]},
+///     },
+///     "fields": [
+///         {"name": "magic", "type": "u32"},
+///         {"name": "version", "type": "u16"},
+///         {"name": "entry_count", "type": "u16"},
+///         {"name": "name", "type": "ascii", "length": 16},
+///         {"name": "entries", "type": "Entry", "count": "entry_count"},
+///         {"name": "extra", "type": "u32", "if": "version >= 2"},
+///         {"name": "header", "type": "u32", "to": "Entry", "from": "file"},
+///         {"type": "align", "size": 32},
+///     ],
+/// }
+/// ```
+/// Inline structs are written as `{"fields": [...]}` in place of a type name.
+#[class(base=RefCounted)]
+pub struct NebulaSchema {
+    schema: Option<Arc<Schema>>,
+
+    #[base]
+    base: Base<RefCounted>,
+}
+
+#[godot_api]
+impl IRefCounted for NebulaSchema {
+    fn init(base: Base<RefCounted>) -> Self {
+        Self { schema: None, base }
+    }
+}
+
+#[godot_api]
+impl NebulaSchema {
+    #[func]
+    /// Parses a schema from a Dictionary or a text definition so it can be reused.
+    /// Logs an error and returns `null` if the definition is invalid.
+    pub fn parse(definition: Variant) -> Option<Gd<NebulaSchema>> {
+        match Schema::from_variant(&definition) {
+            Ok(schema) => Some(Gd::from_init_fn(|base| Self {
+                schema: Some(Arc::new(schema)),
+                base,
+            })),
+            Err(err) => {
+                godot_error!("NebulaSchema.parse: {}", err);
+                None
+            }
+        }
+    }
+}
+
+impl NebulaSchema {
+    /// Resolves a [NebulaSchema], Dictionary or text definition.
+    pub(crate) fn resolve(definition: &Variant) -> Result<Arc<Schema>, String> {
+        if let Ok(schema) = definition.try_to::<Gd<NebulaSchema>>() {
+            return schema.bind().schema.clone().ok_or_else(|| "empty NebulaSchema".to_string());
+        }
+
+        Schema::from_variant(definition).map(Arc::new)
+    }
+}
@@ -0,0 +1,230 @@
+use std::io;
+use crate::io::bytesource::ByteSource;
+
+/// Size of the chunks read from the source while searching.
+const SEARCH_CHUNK: usize = 1 << 20;
+
+/// A byte pattern in which some positions match any byte.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Pattern {
+    bytes: Vec<u8>,
+    /// `true` where the byte must match, `false` for wildcards.
+    mask: Vec<bool>,
+}
+
+impl Pattern {
+    /// A pattern that matches `bytes` exactly.
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        Self { bytes: bytes.to_vec(), mask: vec![true; bytes.len()] }
+    }
+
+    /// Parses hex bytes with `??` wildcards, e.g. `"55 AA ?? 2D"` or `"55AA??2D"`.
+    pub fn parse_hex(text: &str) -> Result<Self, String> {
+        let digits: Vec<char> = text.chars().filter(|c| !c.is_whitespace()).collect();
+        if !digits.len().is_multiple_of(2) {
+            return Err(format!("odd number of hex digits in pattern '{}'", text));
+        }
+
+        let mut bytes = Vec::with_capacity(digits.len() / 2);
+        let mut mask = Vec::with_capacity(digits.len() / 2);
+        for pair in digits.chunks(2) {
+            if pair == ['?', '?'] {
+                bytes.push(0);
+                mask.push(false);
+                continue;
+            }
+
+            let byte = pair[0].to_digit(16).zip(pair[1].to_digit(16))
+                .map(|(hi, lo)| (hi * 16 + lo) as u8)
+                .ok_or_else(|| format!("invalid byte '{}{}' in pattern '{}'", pair[0], pair[1], text))?;
+            bytes.push(byte);
+            mask.push(true);
+        }
+
+        Ok(Self { bytes, mask })
+    }
+
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    fn matches(&self, data: &[u8]) -> bool {
+        self.bytes.iter().zip(&self.mask).zip(data).all(|((&b, &m), &d)| !m || b == d)
+    }
+
+    /// The first byte that must match exactly, used to skip ahead quickly.
+    fn anchor(&self) -> Option<(usize, u8)> {
+        self.mask.iter().position(|&m| m).map(|i| (i, self.bytes[i]))
+    }
+}
+
+/// Calls `on_match` with the position of every match of `pattern` that lies entirely within
+/// `start..end` and starts at a multiple of `align`, in ascending order and including overlapping
+/// matches, until it returns `false`. The source is read in chunks rather than all at once.
+pub fn find_each(
+    source: &dyn ByteSource,
+    pattern: &Pattern,
+    start: u64,
+    end: u64,
+    align: u64,
+    mut on_match: impl FnMut(u64) -> bool,
+) -> io::Result<()> {
+    let len = pattern.len();
+    let end = end.min(source.len());
+    if len == 0 {
+        return Ok(());
+    }
+
+    let align = align.max(1);
+    let anchor = pattern.anchor();
+    let mut chunk = vec![0u8; SEARCH_CHUNK.min(end.saturating_sub(start) as usize) + len - 1];
+    let mut pos = start;
+
+    while pos + len as u64 <= end {
+        let want = chunk.len().min((end - pos) as usize);
+        let read = source.read_into(pos, &mut chunk[..want])?;
+        if read < len {
+            break;
+        }
+
+        let data = &chunk[..read];
+        let last = read - len;
+        let mut i = 0;
+        while i <= last {
+            // Jump to the next occurrence of the anchor byte, if the pattern has one.
+            if let Some((index, byte)) = anchor {
+                match data[i + index..=last + index].iter().position(|&b| b == byte) {
+                    Some(skip) => i += skip,
+                    None => break,
+                }
+            }
+
+            let at = pos + i as u64;
+            if at.is_multiple_of(align) && pattern.matches(&data[i..]) && !on_match(at) {
+                return Ok(());
+            }
+            i += 1;
+        }
+
+        pos += (last + 1) as u64;
+    }
+
+    Ok(())
+}
+
+/// Returns the position of the first match of `pattern` within `start..end`, see [`find_each`].
+pub fn find(source: &dyn ByteSource, pattern: &Pattern, start: u64, end: u64, align: u64) -> io::Result<Option<u64>> {
+    let mut found = None;
+    find_each(source, pattern, start, end, align, |at| {
+        found = Some(at);
+        false
+    })?;
+    Ok(found)
+}
+
+/// Returns the position of the last match of `pattern` within `start..end`, reading the source
+/// backwards in chunks.
+pub fn rfind(source: &dyn ByteSource, pattern: &Pattern, start: u64, end: u64, align: u64) -> io::Result<Option<u64>> {
+    let len = pattern.len() as u64;
+    let end = end.min(source.len());
+    if len == 0 {
+        return Ok(None);
+    }
+
+    let align = align.max(1);
+    let mut chunk = vec![0u8; SEARCH_CHUNK.min(end.saturating_sub(start) as usize) + len as usize - 1];
+    let mut hi = end;
+
+    while hi >= start + len {
+        let lo = hi.saturating_sub(chunk.len() as u64).max(start);
+        let want = (hi - lo) as usize;
+        let read = source.read_into(lo, &mut chunk[..want])?;
+        if read < want {
+            return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "search range past end of source"));
+        }
+
+        let data = &chunk[..read];
+        for i in (0..=read - len as usize).rev() {
+            let at = lo + i as u64;
+            if at.is_multiple_of(align) && pattern.matches(&data[i..]) {
+                return Ok(Some(at));
+            }
+        }
+
+        if lo == start {
+            break;
+        }
+        hi = lo + len - 1;
+    }
+
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::bytesource::MemoryByteSource;
+
+    fn source_with(len: usize, at: &[(usize, &[u8])]) -> MemoryByteSource {
+        let mut data = vec![0u8; len];
+        for &(pos, bytes) in at {
+            data[pos..pos + bytes.len()].copy_from_slice(bytes);
+        }
+        MemoryByteSource::from_vec(data)
+    }
+
+    #[test]
+    fn parses_hex_with_wildcards() {
+        let pattern = Pattern::parse_hex("55 AA ??2d").unwrap();
+        assert_eq!(pattern.len(), 4);
+        assert!(pattern.matches(&[0x55, 0xAA, 0x00, 0x2D]));
+        assert!(!pattern.matches(&[0x55, 0xAB, 0x00, 0x2D]));
+        assert!(Pattern::parse_hex("55A").is_err());
+        assert!(Pattern::parse_hex("5G").is_err());
+    }
+
+    #[test]
+    fn finds_matches_across_chunk_boundaries() {
+        let magic = [0x55, 0xAA, 0x38, 0x2D];
+        let first = SEARCH_CHUNK - 2;
+        let second = 2 * SEARCH_CHUNK - 1;
+        let source = source_with(3 * SEARCH_CHUNK, &[(first, &magic), (second, &magic)]);
+        let pattern = Pattern::from_bytes(&magic);
+        let len = source.len();
+
+        let mut found = Vec::new();
+        find_each(&source, &pattern, 0, len, 1, |at| {
+            found.push(at);
+            true
+        }).unwrap();
+        assert_eq!(found, [first as u64, second as u64]);
+
+        assert_eq!(find(&source, &pattern, first as u64 + 1, len, 1).unwrap(), Some(second as u64));
+        assert_eq!(rfind(&source, &pattern, 0, len, 1).unwrap(), Some(second as u64));
+        assert_eq!(rfind(&source, &pattern, 0, second as u64 + 3, 1).unwrap(), Some(first as u64));
+        assert_eq!(find(&source, &pattern, 0, first as u64 + 3, 1).unwrap(), None);
+    }
+
+    #[test]
+    fn honours_wildcards_alignment_and_overlaps() {
+        let source = source_with(64, &[(6, &[1, 1, 1, 9, 1, 7, 1])]);
+        let len = source.len();
+
+        let mut found = Vec::new();
+        find_each(&source, &Pattern::from_bytes(&[1, 1]), 0, len, 1, |at| {
+            found.push(at);
+            true
+        }).unwrap();
+        assert_eq!(found, [6, 7]);
+
+        let wildcard = Pattern::parse_hex("01 ?? 01").unwrap();
+        assert_eq!(find(&source, &wildcard, 0, len, 1).unwrap(), Some(6));
+        assert_eq!(find(&source, &wildcard, 0, len, 4).unwrap(), Some(8));
+        assert_eq!(rfind(&source, &wildcard, 0, len, 1).unwrap(), Some(10));
+        assert_eq!(find(&source, &Pattern::parse_hex("?? ??").unwrap(), 63, len, 1).unwrap(), None);
+    }
+}
@@ -0,0 +1,221 @@
+use std::{
+    panic::AssertUnwindSafe,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+};
+use godot::{
+    classes::{Engine, SceneTree},
+    register::ConnectHandle,
+    prelude::*,
+};
+
+/// Progress callback handed to long-running loaders. Receives `(done, total)` and
+/// returns `false` once the work should stop because the task was cancelled.
+pub type Progress<'a> = &'a dyn Fn(u64, u64) -> bool;
+
+/// Builds the Godot-side result of a task. Runs on the main thread.
+type Finisher = Box<dyn FnOnce() -> Variant + Send>;
+
+/// State shared between a [`NebulaTask`] and its worker thread.
+struct TaskState {
+    cancelled: AtomicBool,
+    done: AtomicU64,
+    total: AtomicU64,
+    outcome: Mutex<Option<Result<Finisher, String>>>,
+}
+
+#[derive(GodotClass)]
+/// Handle to work running on a background thread, as returned by [method WBFS.open_async],
+/// [method ARC.open_async] and [method NebulaDir.extract_async].
+///
+/// The task is polled once per frame while it runs. [signal progress] is emitted whenever the
+/// worker reports progress, and [signal completed] is emitted exactly once when it finishes,
+/// fails, panics or is cancelled. Without a [SceneTree] to poll from, the task fails at once
+/// and [signal completed] is emitted with [method Object.call_deferred]. The task keeps itself alive until then, so callers do not need to
+/// hold on to it.
+#[class(base=RefCounted)]
+pub struct NebulaTask {
+    state: Arc<TaskState>,
+    reported: (u64, u64),
+    finished: bool,
+    result: Variant,
+    error_message: String,
+    poll_handle: Option<ConnectHandle>,
+
+    #[base]
+    base: Base<RefCounted>,
+}
+
+#[godot_api]
+impl IRefCounted for NebulaTask {
+    fn init(base: Base<RefCounted>) -> Self {
+        Self {
+            state: Arc::new(TaskState {
+                cancelled: AtomicBool::new(false),
+                done: AtomicU64::new(0),
+                total: AtomicU64::new(0),
+                outcome: Mutex::new(None),
+            }),
+            reported: (0, 0),
+            finished: false,
+            result: Variant::nil(),
+            error_message: String::new(),
+            poll_handle: None,
+            base,
+        }
+    }
+}
+
+#[godot_api]
+impl NebulaTask {
+    /// Emitted on the main thread when the worker reports progress. The unit of `done` and
+    /// `total` depends on the task (bytes, entries…).
+    #[signal] fn progress(done: i64, total: i64);
+
+    /// Emitted once when the task ends. `result` is `null` if the task failed or was cancelled,
+    /// see [method get_error_message].
+    #[signal] fn completed(result: Variant);
+
+    #[func]
+    /// Asks the worker to stop at its next progress checkpoint.
+    /// [signal completed] is still emitted, with a `null` result.
+    pub fn cancel(&self) {
+        self.state.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    #[func]
+    /// Returns `true` if [method cancel] was called.
+    pub fn is_cancelled(&self) -> bool {
+        self.state.cancelled.load(Ordering::Relaxed)
+    }
+
+    #[func]
+    /// Returns `true` once [signal completed] has been emitted.
+    pub fn is_done(&self) -> bool {
+        self.finished
+    }
+
+    #[func]
+    /// Returns the result passed to [signal completed], or `null` while the task is running.
+    pub fn get_result(&self) -> Variant {
+        self.result.clone()
+    }
+
+    #[func]
+    /// Returns the reason the task failed, or an empty string.
+    pub fn get_error_message(&self) -> GString {
+        self.error_message.to_godot()
+    }
+
+    #[func]
+    /// Returns the last reported progress as `[done, total]`.
+    pub fn get_progress(&self) -> PackedInt64Array {
+        PackedInt64Array::from(&[self.reported.0 as i64, self.reported.1 as i64])
+    }
+}
+
+impl NebulaTask {
+    /// Runs `work` on a new thread and returns the task tracking it.
+    ///
+    /// `work` must not touch Godot objects; anything that needs the engine goes into `finish`,
+    /// which receives the worker's output on the main thread and builds the task's result.
+    pub(crate) fn spawn<T, W, F>(work: W, finish: F) -> Gd<Self>
+    where
+        T: Send + 'static,
+        W: FnOnce(Progress) -> Result<T, String> + Send + 'static,
+        F: FnOnce(T) -> Variant + Send + 'static,
+    {
+        let task = NebulaTask::new_gd();
+        let state = task.bind().state.clone();
+
+        let Some(tree) = Engine::singleton()
+            .get_main_loop()
+            .and_then(|main_loop| main_loop.try_cast::<SceneTree>().ok())
+        else {
+            // Nothing would poll the worker, so fail right away. The signal is deferred so that
+            // callers can still connect to it after this returns.
+            godot_error!("NebulaTask: no SceneTree to poll background work from");
+            {
+                let mut task = task.clone();
+                let mut this = task.bind_mut();
+                this.error_message = "No SceneTree".to_string();
+                this.finished = true;
+            }
+            task.clone().upcast::<Object>().call_deferred("emit_signal", &["completed".to_variant(), Variant::nil()]);
+            return task;
+        };
+
+        let handle = tree.signals()
+            .process_frame()
+            .builder()
+            .connect_other_gd(&task, |task: Gd<Self>| Self::poll(task));
+        task.clone().bind_mut().poll_handle = Some(handle);
+
+        std::thread::spawn(move || {
+            let progress = |done: u64, total: u64| {
+                state.done.store(done, Ordering::Relaxed);
+                state.total.store(total, Ordering::Relaxed);
+                !state.cancelled.load(Ordering::Relaxed)
+            };
+
+            // A panicking job still has to complete the task, or it would be polled forever.
+            let outcome = std::panic::catch_unwind(AssertUnwindSafe(|| work(&progress)))
+                .unwrap_or_else(|_| Err("task panicked".to_string()))
+                .map(|value| Box::new(move || finish(value)) as Finisher);
+
+            if let Ok(mut slot) = state.outcome.lock() {
+                *slot = Some(outcome);
+            }
+        });
+
+        task
+    }
+
+    /// Forwards the worker's progress and, once it is done, publishes the result.
+    fn poll(mut task: Gd<Self>) {
+        let (progress, outcome) = {
+            let mut this = task.bind_mut();
+            let state = this.state.clone();
+
+            let current = (state.done.load(Ordering::Relaxed), state.total.load(Ordering::Relaxed));
+            let progress = (current != this.reported).then_some(current);
+            this.reported = current;
+
+            let outcome = state.outcome.lock().ok().and_then(|mut slot| slot.take());
+            (progress, outcome)
+        };
+
+        if let Some((done, total)) = progress {
+            task.signals().progress().emit(done as i64, total as i64);
+        }
+
+        let Some(outcome) = outcome else {
+            return;
+        };
+
+        let result = {
+            let mut this = task.bind_mut();
+            if let Some(handle) = this.poll_handle.take() {
+                handle.disconnect();
+            }
+
+            match outcome {
+                Ok(finish) if !this.is_cancelled() => this.result = finish(),
+                Ok(_) => this.error_message = "Cancelled".to_string(),
+                Err(err) => {
+                    if !this.is_cancelled() {
+                        godot_error!("{}", err);
+                    }
+                    this.error_message = err;
+                }
+            }
+
+            this.finished = true;
+            this.result.clone()
+        };
+
+        task.signals().completed().emit(&result);
+    }
+}
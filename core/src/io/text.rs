@@ -0,0 +1,144 @@
+use encoding_rs::{Encoding, SHIFT_JIS, WINDOWS_1252};
+
+/// Text encodings understood by the buffer string readers, [method NebulaBuffer.unpack] and schemas.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TextEncoding {
+    Ascii,
+    Utf8,
+    Utf16,
+    ShiftJis,
+    Windows1252,
+}
+
+impl TextEncoding {
+    /// Size of one code unit in bytes. Strings are terminated by a zero unit.
+    pub fn unit_size(self) -> usize {
+        match self {
+            Self::Utf16 => 2,
+            _ => 1,
+        }
+    }
+
+    /// Number of code units `text` takes in this encoding, without a terminator.
+    pub fn units(self, text: &str) -> usize {
+        match self {
+            Self::Ascii => text.chars().count(),
+            Self::Utf8 => text.len(),
+            Self::Utf16 => text.encode_utf16().count(),
+            _ => self.encode(text, false).len(),
+        }
+    }
+
+    /// Decodes `bytes`, which must not include the terminator.
+    /// Invalid sequences are replaced with U+FFFD.
+    pub fn decode(self, bytes: &[u8], big_endian: bool) -> String {
+        match self {
+            Self::Ascii | Self::Utf8 => String::from_utf8_lossy(bytes).into_owned(),
+            Self::Utf16 => {
+                let units: Vec<u16> = bytes
+                    .chunks_exact(2)
+                    .map(|unit| {
+                        if big_endian {
+                            u16::from_be_bytes([unit[0], unit[1]])
+                        } else {
+                            u16::from_le_bytes([unit[0], unit[1]])
+                        }
+                    })
+                    .collect();
+                String::from_utf16_lossy(&units)
+            }
+            Self::ShiftJis => SHIFT_JIS.decode_without_bom_handling(bytes).0.into_owned(),
+            Self::Windows1252 => WINDOWS_1252.decode_without_bom_handling(bytes).0.into_owned(),
+        }
+    }
+
+    /// Encodes `text` without a terminator. Characters the encoding cannot represent become `?`.
+    pub fn encode(self, text: &str, big_endian: bool) -> Vec<u8> {
+        match self {
+            Self::Ascii => text.chars().map(|ch| if ch.is_ascii() { ch as u8 } else { b'?' }).collect(),
+            Self::Utf8 => text.as_bytes().to_vec(),
+            Self::Utf16 => text
+                .encode_utf16()
+                .flat_map(|unit| if big_endian { unit.to_be_bytes() } else { unit.to_le_bytes() })
+                .collect(),
+            Self::ShiftJis => encode_legacy(SHIFT_JIS, text),
+            Self::Windows1252 => encode_legacy(WINDOWS_1252, text),
+        }
+    }
+
+    /// Encodes as much of `text` as fits in `max_bytes` without splitting a character.
+    pub fn encode_truncated(self, text: &str, max_bytes: usize, big_endian: bool) -> Vec<u8> {
+        let bytes = self.encode(text, big_endian);
+        if bytes.len() <= max_bytes {
+            return bytes;
+        }
+
+        let mut out = Vec::with_capacity(max_bytes);
+        let mut utf8 = [0u8; 4];
+        for ch in text.chars() {
+            let encoded = self.encode(ch.encode_utf8(&mut utf8), big_endian);
+            if out.len() + encoded.len() > max_bytes {
+                break;
+            }
+            out.extend_from_slice(&encoded);
+        }
+        out
+    }
+}
+
+/// Encodes `text` with a legacy encoding, replacing unmappable characters with `?`.
+fn encode_legacy(encoding: &'static Encoding, text: &str) -> Vec<u8> {
+    let (bytes, _, lossy) = encoding.encode(text);
+    if !lossy {
+        return bytes.into_owned();
+    }
+
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut utf8 = [0u8; 4];
+    for ch in text.chars() {
+        match encoding.encode(ch.encode_utf8(&mut utf8)) {
+            (bytes, _, false) => out.extend_from_slice(&bytes),
+            (_, _, true) => out.push(b'?'),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_every_encoding() {
+        let cases = [
+            (TextEncoding::Ascii, "course1"),
+            (TextEncoding::Utf8, "スター★"),
+            (TextEncoding::Utf16, "スター😀"),
+            (TextEncoding::ShiftJis, "スーパーマリオ"),
+            (TextEncoding::Windows1252, "Pokémon"),
+        ];
+        for (encoding, text) in cases {
+            for big_endian in [false, true] {
+                let bytes = encoding.encode(text, big_endian);
+                assert_eq!(bytes.len(), encoding.units(text) * encoding.unit_size());
+                assert_eq!(encoding.decode(&bytes, big_endian), text);
+            }
+        }
+    }
+
+    #[test]
+    fn unmappable_characters_become_question_marks() {
+        assert_eq!(TextEncoding::Ascii.encode("añb", false), b"a?b");
+        assert_eq!(TextEncoding::Ascii.units("añb"), 3);
+        assert_eq!(TextEncoding::Windows1252.encode("a★b", false), b"a?b");
+        assert_eq!(TextEncoding::ShiftJis.encode("aéb", false), b"a?b");
+    }
+
+    #[test]
+    fn truncation_keeps_whole_characters() {
+        assert_eq!(TextEncoding::Utf8.encode_truncated("aé", 2, false), b"a");
+        assert_eq!(TextEncoding::ShiftJis.encode_truncated("aス", 2, false), b"a");
+        assert_eq!(TextEncoding::Utf16.encode_truncated("a😀", 4, true), [0, b'a']);
+        assert_eq!(TextEncoding::ShiftJis.encode_truncated("スス", 4, false), TextEncoding::ShiftJis.encode("スス", false));
+    }
+}
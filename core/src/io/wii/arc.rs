@@ -1,32 +1,167 @@
-use std::sync::Arc;
+use std::{
+    path::Path,
+    sync::{Arc, RwLock},
+};
 use godot::{classes::ProjectSettings, prelude::*};
 use crate::io::{
     buffer::NebulaBuffer, 
-    bytesource::{ByteSource, DiskFileSource, SubrangeSource}, 
-    dir::NebulaDir, 
-    file::NebulaFile, 
-    fs::NebulaFs
+    bytesource::{open_file_source, ByteSource, MemoryByteSource, OpenMode, OverlaySource, SubrangeSource}, 
+    dir::{DetachedDir, NebulaDir}, 
+    file::NebulaFile,
+    fs::NebulaFs,
+    search::{self, Pattern},
+    task::{NebulaTask, Progress},
+    text::TextEncoding,
 };
 
-const U8_HEADER: [u8; 4] = [0x55, 0xAA, 0x38, 0x2D];
+/// Magic number at the start of a U8 archive.
+pub(crate) const U8_HEADER: [u8; 4] = [0x55, 0xAA, 0x38, 0x2D];
+/// Offset of the root node, right after the header.
+const U8_ROOT_OFFSET: u32 = 0x20;
+const U8_NODE_SIZE: usize = 12;
+/// Alignment of the data section, which follows the node and string tables.
+const U8_DATA_ALIGNMENT: u64 = 0x40;
 
 #[derive(Clone)]
 struct ArcEntry {
     path: String,
-    offset: u64,
-    size: u64,
-    is_dir: bool,
+    /// Contents of a file: a range of the archive, or memory for files created or replaced since.
+    /// `None` for directories.
+    data: Option<Arc<dyn ByteSource>>,
+}
+
+impl ArcEntry {
+    fn is_dir(&self) -> bool {
+        self.data.is_none()
+    }
 }
 
+/// The tree of an ARC. Entries are kept in node order: every directory is followed by its contents.
+///
+/// Clones, including those behind the [`NebulaDir`]s returned by [`NebulaFs::get_dir`], share the
+/// same tree, so changes made through one are visible through all of them.
+#[derive(Clone)]
 pub struct ArcFs {
-    source: Arc<dyn ByteSource>,
-    entries: Vec<ArcEntry>,
+    entries: Arc<RwLock<Vec<ArcEntry>>>,
 }
 
 impl ArcFs {
     pub fn new(source: Arc<dyn ByteSource>) -> Result<Self, String> {
-        let entries = parse_arc_index(&source)?;
-        Ok(Self { source, entries })
+        Self::load(source, &|_, _| true)
+    }
+
+    /// Indexes an archive without touching the engine, so it can run on a worker thread.
+    /// `progress` is called with the number of indexed nodes.
+    pub fn load(source: Arc<dyn ByteSource>, progress: Progress) -> Result<Self, String> {
+        let entries = parse_arc_index(&source, progress)?;
+        Ok(Self { entries: Arc::new(RwLock::new(entries)) })
+    }
+
+    /// Replaces the contents of the file at `path`, or creates it if its folder exists.
+    pub fn replace_file(&self, path: &str, data: Arc<dyn ByteSource>) -> Result<(), String> {
+        let mut entries = self.entries.write().unwrap();
+        if let Some(entry) = entries.iter_mut().find(|e| e.path == path) {
+            if entry.is_dir() {
+                return Err(format!("'{}' is a directory", path));
+            }
+            entry.data = Some(data);
+            return Ok(());
+        }
+
+        let index = insert_position(&entries, parent_path(path))
+            .ok_or_else(|| format!("folder of '{}' does not exist", path))?;
+        entries.insert(index, ArcEntry { path: path.to_string(), data: Some(data) });
+        Ok(())
+    }
+
+    /// Builds a U8 archive from the current tree, see [`build_u8`].
+    pub fn save(&self, alignment: u64) -> Result<Vec<u8>, String> {
+        let root = DetachedDir::new(Arc::new(self.clone()), String::new());
+        build_u8(&root, alignment, &|_, _| true)
+    }
+
+    /// Takes over the tree of `other`, e.g. after the archive was written and reopened.
+    /// Directories handed out by [`NebulaDir::root`] see the new tree as well.
+    fn rebase(&self, other: ArcFs) {
+        let entries = std::mem::take(&mut *other.entries.write().unwrap());
+        *self.entries.write().unwrap() = entries;
+    }
+
+    fn find(&self, path: &str, dir: bool) -> Option<ArcEntry> {
+        self.entries.read().unwrap().iter()
+            .find(|e| e.is_dir() == dir && e.path == path)
+            .cloned()
+    }
+}
+
+/// Returns the folder part of `path`, or `""` for top-level entries.
+fn parent_path(path: &str) -> &str {
+    path.rfind('/').map_or("", |slash| &path[..slash])
+}
+
+/// Returns `true` if `path` is `dir` or lies below it.
+fn is_within(path: &str, dir: &str) -> bool {
+    dir.is_empty() || path == dir || path.strip_prefix(dir).is_some_and(|rest| rest.starts_with('/'))
+}
+
+/// Returns the index after the last entry inside the directory `dir`, where a new child goes,
+/// or `None` if there is no such directory.
+fn insert_position(entries: &[ArcEntry], dir: &str) -> Option<usize> {
+    let start = entries.iter().position(|e| e.is_dir() && e.path == dir)?;
+    let len = entries[start + 1..].iter().take_while(|e| is_within(&e.path, dir)).count();
+    Some(start + 1 + len)
+}
+
+/// An archive opened from disk, ready to be wrapped in an [`ARC`].
+struct OpenedArc {
+    path: String,
+    mode: i32,
+    fs: ArcFs,
+    source: Arc<dyn ByteSource>,
+    overlay: Option<Arc<OverlaySource>>,
+}
+
+fn open_arc(path: &str, mode: i32, progress: Progress) -> Result<OpenedArc, String> {
+    let open_mode = match NebulaBuffer::open_mode(mode) {
+        Some(OpenMode::Create) | None => return Err(format!("invalid open mode {}", mode)),
+        Some(open_mode) => open_mode,
+    };
+
+    let file = open_file_source(path, open_mode)
+        .map_err(|err| format!("failed to open '{}': {}", path, err))?;
+
+    let (source, overlay) = if open_mode == OpenMode::Read {
+        let overlay = Arc::new(OverlaySource::new(file));
+        (overlay.clone() as Arc<dyn ByteSource>, Some(overlay))
+    } else {
+        (file, None)
+    };
+
+    let fs = ArcFs::load(source.clone(), progress)
+        .map_err(|err| format!("invalid ARC '{}': {}", path, err))?;
+
+    Ok(OpenedArc { path: path.to_string(), mode, fs, source, overlay })
+}
+
+/// Writes `data` to a temporary file next to `path` and moves it over `path`, so that an archive
+/// still reading from the old file, possibly through a memory map, never sees it truncated.
+fn write_replacing(path: &str, data: &[u8]) -> Result<(), String> {
+    let target = Path::new(path);
+    let name = target.file_name().ok_or_else(|| format!("invalid path '{}'", path))?;
+    let temp = target.with_file_name(format!(".{}.tmp", name.to_string_lossy()));
+
+    let result = std::fs::write(&temp, data).and_then(|()| std::fs::rename(&temp, target));
+    if result.is_err() {
+        let _ = std::fs::remove_file(&temp);
+    }
+    result.map_err(|err| format!("failed to write '{}': {}", path, err))
+}
+
+/// Returns `true` if `a` and `b` name the same existing file.
+fn same_file(a: &str, b: &str) -> bool {
+    match (std::fs::canonicalize(a), std::fs::canonicalize(b)) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => false,
     }
 }
 
@@ -50,39 +185,38 @@ fn read_u32_be(data: &[u8], offset: usize) -> u32 {
     ])
 }
 
-fn parse_arc_index(source: &Arc<dyn ByteSource>) -> Result<Vec<ArcEntry>, String> {
+fn parse_arc_index(source: &Arc<dyn ByteSource>, progress: Progress) -> Result<Vec<ArcEntry>, String> {
     let file_size = source.len();
     if file_size < 0x20 {
         return Err("Invalid or empty ARC file".to_string());
     }
 
-    let raw_data = source
-        .read_range(0, file_size as usize)
+    let header = search::find(source.as_ref(), &Pattern::from_bytes(&U8_HEADER), 0, file_size, 1)
         .map_err(|e| format!("Failed to read ARC file: {}", e))?;
 
-    let mut offset = 0;
-    let mut found = false;
-    while offset <= raw_data.len().saturating_sub(4) {
-        if &raw_data[offset..offset + 4] == &U8_HEADER {
-            found = true;
-            break;
-        }
-        offset += 1;
-    }
-
-    if !found || offset + 16 > raw_data.len() {
-        return Err("U8 header incomplete or corrupted".to_string());
-    }
+    let offset = match header {
+        Some(offset) if offset + 16 <= file_size => offset as usize,
+        _ => return Err("U8 header incomplete or corrupted".to_string()),
+    };
+    let header_data = source
+        .read_range(offset as u64, 16)
+        .map_err(|e| format!("Failed to read ARC file: {}", e))?;
 
-    let rootnode_offset = read_u32_be(&raw_data, offset + 4) as usize;
-    let data_offset = read_u32_be(&raw_data, offset + 12) as usize;
-    let node_base = offset + rootnode_offset;
+    let rootnode_offset = read_u32_be(&header_data, 4) as u64;
+    let data_offset = read_u32_be(&header_data, 12) as u64;
+    let node_base = offset as u64 + rootnode_offset;
 
-    if node_base + 12 > raw_data.len() {
+    if node_base + 12 > file_size {
         return Err("Invalid node base offset".to_string());
     }
 
-    let root_node_size = read_u32_be(&raw_data, node_base + 8) as usize;
+    // Only the node and string tables are read; they end where the file data starts.
+    let table_end = (offset as u64 + data_offset).clamp(node_base + 12, file_size);
+    let table = source
+        .read_range(node_base, (table_end - node_base) as usize)
+        .map_err(|e| format!("Failed to read ARC file: {}", e))?;
+
+    let root_node_size = read_u32_be(&table, 8) as usize;
 
     #[derive(Debug)]
     struct Node {
@@ -93,27 +227,25 @@ fn parse_arc_index(source: &Arc<dyn ByteSource>) -> Result<Vec<ArcEntry>, String
     }
 
     let mut nodes = Vec::new();
-    let mut node_offset = node_base + 12;
+    let mut node_offset = U8_NODE_SIZE;
 
-    for _ in 0..(root_node_size - 1) {
-        if node_offset + 12 > raw_data.len() {
+    for _ in 0..root_node_size.saturating_sub(1) {
+        if node_offset + U8_NODE_SIZE > table.len() {
             break;
         }
 
         let node = Node {
-            node_type: read_u16_be(&raw_data, node_offset),
-            name_offset: read_u16_be(&raw_data, node_offset + 2),
-            data_offset: read_u32_be(&raw_data, node_offset + 4),
-            size: read_u32_be(&raw_data, node_offset + 8),
+            node_type: read_u16_be(&table, node_offset),
+            name_offset: read_u16_be(&table, node_offset + 2),
+            data_offset: read_u32_be(&table, node_offset + 4),
+            size: read_u32_be(&table, node_offset + 8),
         };
 
         nodes.push(node);
-        node_offset += 12;
+        node_offset += U8_NODE_SIZE;
     }
 
-    let string_table_offset = node_offset;
-    let string_table_size = data_offset.saturating_sub(string_table_offset - offset);
-    let string_table = &raw_data[string_table_offset..string_table_offset + string_table_size];
+    let string_table = &table[node_offset..];
 
     let mut entries = Vec::new();
     let mut path_stack: Vec<String> = Vec::new();
@@ -122,25 +254,20 @@ fn parse_arc_index(source: &Arc<dyn ByteSource>) -> Result<Vec<ArcEntry>, String
 
     entries.push(ArcEntry {
         path: String::new(),
-        offset: 0,
-        size: 0,
-        is_dir: true,
+        data: None,
     });
 
+    let node_count = nodes.len() as u64;
     for node in nodes {
         current_index += 1;
-
-        let mut name = String::new();
-        let mut name_pos = node.name_offset as usize;
-        while name_pos < string_table.len() {
-            let byte = string_table[name_pos];
-            if byte == 0 {
-                break;
-            }
-            name.push(byte as char);
-            name_pos += 1;
+        if current_index % 100 == 0 && !progress(current_index as u64, node_count) {
+            return Err("Cancelled".to_string());
         }
 
+        let name_bytes = string_table.get(node.name_offset as usize..).unwrap_or_default();
+        let name_len = name_bytes.iter().position(|&b| b == 0).unwrap_or(name_bytes.len());
+        let name = TextEncoding::ShiftJis.decode(&name_bytes[..name_len], true);
+
         let full_path = if path_stack.is_empty() {
             name.clone()
         } else {
@@ -150,18 +277,15 @@ fn parse_arc_index(source: &Arc<dyn ByteSource>) -> Result<Vec<ArcEntry>, String
         if node.node_type == 0x0100 {
             entries.push(ArcEntry {
                 path: full_path.clone(),
-                offset: 0,
-                size: 0,
-                is_dir: true,
+                data: None,
             });
             path_stack.push(name);
             count_stack.push(node.size as usize);
         } else if node.node_type == 0x0000 {
+            let data_start = (offset + node.data_offset as usize) as u64;
             entries.push(ArcEntry {
                 path: full_path,
-                offset: (offset + node.data_offset as usize) as u64,
-                size: node.size as u64,
-                is_dir: false,
+                data: Some(Arc::new(SubrangeSource::new(source.clone(), data_start, node.size as u64))),
             });
         }
 
@@ -173,20 +297,151 @@ fn parse_arc_index(source: &Arc<dyn ByteSource>) -> Result<Vec<ArcEntry>, String
         }
     }
 
+    progress(node_count, node_count);
     Ok(entries)
 }
 
+/// A node of an archive being built by [`build_u8`].
+struct BuildNode {
+    name: String,
+    /// Path relative to the packed directory, or the opened file for file nodes.
+    rel: String,
+    file: Option<Arc<dyn ByteSource>>,
+    /// For directories: index of the parent node.
+    parent: u32,
+    /// For directories: index of the first node after its contents.
+    next: u32,
+}
+
+/// Appends the nodes below `rel` to `nodes` in listing order, directories followed by their contents.
+fn collect_u8_nodes(root: &DetachedDir, rel: &str, parent: u32, nodes: &mut Vec<BuildNode>) -> Result<(), String> {
+    let names = root.list(rel)
+        .map_err(|err| format!("failed to list '{}': {}", rel, err))?;
+
+    for name in names {
+        let (name, is_dir) = match name.strip_suffix('/') {
+            Some(dir) => (dir.to_string(), true),
+            None => (name, false),
+        };
+        let path = if rel.is_empty() { name.clone() } else { format!("{}/{}", rel, name) };
+
+        if is_dir {
+            let index = nodes.len();
+            nodes.push(BuildNode { name, rel: path.clone(), file: None, parent, next: 0 });
+            collect_u8_nodes(root, &path, index as u32, nodes)?;
+            nodes[index].next = nodes.len() as u32;
+        } else {
+            let file = root.open(&path)
+                .map_err(|err| format!("failed to open '{}': {}", path, err))?;
+            nodes.push(BuildNode { name, rel: path, file: Some(file), parent: 0, next: 0 });
+        }
+    }
+
+    Ok(())
+}
+
+/// Builds a U8 archive from every file and folder in `root`, keeping their listing order.
+///
+/// The layout matches Nintendo's tools: a 0x20-byte header, the node table, the string table,
+/// then the file data. The data section starts at a multiple of 0x40 bytes and every file at
+/// a multiple of `alignment`.
+/// `progress` is called with the number of file bytes copied.
+pub(crate) fn build_u8(root: &DetachedDir, alignment: u64, progress: Progress) -> Result<Vec<u8>, String> {
+    let mut nodes = vec![BuildNode { name: String::new(), rel: String::new(), file: None, parent: 0, next: 0 }];
+    collect_u8_nodes(root, "", 0, &mut nodes)?;
+    nodes[0].next = nodes.len() as u32;
+
+    let mut strings = Vec::new();
+    let mut name_offsets = Vec::with_capacity(nodes.len());
+    for node in &nodes {
+        name_offsets.push(strings.len() as u32);
+        strings.extend(TextEncoding::ShiftJis.encode(&node.name, true));
+        strings.push(0);
+    }
+    if strings.len() > 0xFF_FFFF {
+        return Err("string table exceeds 16 MiB".to_string());
+    }
+
+    let header_size = nodes.len() * U8_NODE_SIZE + strings.len();
+    let data_start = (U8_ROOT_OFFSET as u64 + header_size as u64).next_multiple_of(U8_DATA_ALIGNMENT);
+
+    let mut file_offsets = Vec::with_capacity(nodes.len());
+    let mut end = data_start;
+    let mut total = 0;
+    for node in &nodes {
+        let size = node.file.as_ref().map_or(0, |file| file.len());
+        if size > u32::MAX as u64 {
+            return Err(format!("'{}' is larger than 4 GiB", node.rel));
+        }
+
+        let start = end.next_multiple_of(alignment);
+        file_offsets.push(start);
+        if node.file.is_some() {
+            end = start + size;
+            total += size;
+        }
+    }
+    if end > u32::MAX as u64 {
+        return Err("archive is larger than 4 GiB".to_string());
+    }
+
+    let mut out = Vec::with_capacity(end as usize);
+    out.extend_from_slice(&U8_HEADER);
+    out.extend_from_slice(&U8_ROOT_OFFSET.to_be_bytes());
+    out.extend_from_slice(&(header_size as u32).to_be_bytes());
+    out.extend_from_slice(&(data_start as u32).to_be_bytes());
+    out.resize(U8_ROOT_OFFSET as usize, 0);
+
+    for (index, node) in nodes.iter().enumerate() {
+        let (kind, data, size) = match &node.file {
+            Some(file) => (0u32, file_offsets[index] as u32, file.len() as u32),
+            None => (1u32, node.parent, node.next),
+        };
+        out.extend_from_slice(&(kind << 24 | name_offsets[index]).to_be_bytes());
+        out.extend_from_slice(&data.to_be_bytes());
+        out.extend_from_slice(&size.to_be_bytes());
+    }
+    out.extend_from_slice(&strings);
+
+    let mut done = 0;
+    if !progress(done, total) {
+        return Err("Cancelled".to_string());
+    }
+
+    for (index, node) in nodes.iter().enumerate() {
+        let Some(file) = &node.file else {
+            continue;
+        };
+
+        out.resize(file_offsets[index] as usize, 0);
+        let start = out.len();
+        out.resize(start + file.len() as usize, 0);
+        let read = file.read_into(0, &mut out[start..])
+            .map_err(|err| format!("failed to read '{}': {}", node.rel, err))?;
+        if read as u64 != file.len() {
+            return Err(format!("failed to read '{}': file is shorter than expected", node.rel));
+        }
+
+        done += read as u64;
+        if !progress(done, total) {
+            return Err("Cancelled".to_string());
+        }
+    }
+
+    Ok(out)
+}
+
 impl NebulaFs for ArcFs {
-    fn get_entries(&self, path: &str) -> PackedStringArray {
-        let mut out = PackedStringArray::new();
+    fn entries(&self, path: &str) -> Vec<String> {
+        let mut out = Vec::new();
         let prefix = if path.is_empty() { String::new() } else { format!("{}/", path) };
 
-        for entry in &self.entries {
+        for entry in self.entries.read().unwrap().iter() {
             if entry.path.starts_with(&prefix) && entry.path != path {
                 let rest = &entry.path[prefix.len()..];
                 if !rest.is_empty() && !rest.contains('/') {
-                    let name = if entry.is_dir { format!("{}/", rest) } else { rest.to_string() };
-                    out.push(&name);
+                    let name = if entry.is_dir() { format!("{}/", rest) } else { rest.to_string() };
+                    out.push(name);
                 }
             }
         }
@@ -194,47 +449,16 @@ impl NebulaFs for ArcFs {
         out
     }
 
-    // fn get_files(&self) -> PackedStringArray {
-    //     let entries = self.get_entries("");
-    //     let mut out = PackedStringArray::new();
-    //     for s in entries.to_vec() {
-    //         if !s.ends_with("/") {
-    //             out.push(&s);
-    //         }
-    //     }
-    //     out
-    // }
-
-    // fn get_dirs(&self) -> PackedStringArray {
-    //     let entries = self.get_entries("");
-    //     let mut out = PackedStringArray::new();
-    //     for s in entries.to_vec() {
-    //         if s.ends_with("/") {
-    //             out.push(&s);
-    //         }
-    //     }
-    //     out
-    // }
-
     fn file_exists(&self, path: &str) -> bool {
-        self.entries.iter().any(|e| !e.is_dir && e.path == path)
+        self.find(path, false).is_some()
     }
 
     fn dir_exists(&self, path: &str) -> bool {
-        self.entries.iter().any(|e| e.is_dir && e.path == path)
+        self.find(path, true).is_some()
     }
 
-    fn get_file(&self, path: &str) -> Gd<NebulaFile> {
-        let entry = self.entries.iter().find(|e| !e.is_dir && e.path == path);
-        match entry {
-            Some(entry) => {
-                let subrange = Arc::new(SubrangeSource::new(self.source.clone(), entry.offset, entry.size));
-                let mut buffer = NebulaBuffer::new_gd();
-                buffer.bind_mut().set_source(subrange);
-                NebulaFile::from_buffer(buffer)
-            }
-            None => NebulaFile::from_buffer(NebulaBuffer::new_gd()),
-        }
+    fn open_source(&self, path: &str) -> Option<Arc<dyn ByteSource>> {
+        self.find(path, false)?.data
     }
 
     fn get_dir(&self, path: &str) -> Gd<NebulaDir> {
@@ -245,23 +469,100 @@ impl NebulaFs for ArcFs {
     }
     
     fn get_file_size(&self, path: &str) -> u64 {
-        self.entries
-            .iter()
-            .find(|e| !e.is_dir && e.path == path)
-            .map(|e| e.size)
-            .unwrap_or(0)
+        self.open_source(path).map_or(0, |data| data.len())
     }
 
-}
+    fn create_dir(&self, path: &str) -> bool {
+        let path = path.trim_end_matches('/');
+        if path.is_empty() || self.dir_exists(path) {
+            return !path.is_empty();
+        }
 
+        let parent = parent_path(path);
+        if !self.dir_exists(parent) && !self.create_dir(parent) {
+            return false;
+        }
 
+        let mut entries = self.entries.write().unwrap();
+        if entries.iter().any(|e| e.path == path) {
+            godot_error!("ARC: '{}' already exists as a file", path);
+            return false;
+        }
+
+        let index = insert_position(&entries, parent).unwrap_or(entries.len());
+        entries.insert(index, ArcEntry { path: path.to_string(), data: None });
+        true
+    }
 
-impl Clone for ArcFs {
-    fn clone(&self) -> Self {
-        Self {
-            source: self.source.clone(),
-            entries: self.entries.clone(),
+    fn create_file(&self, path: &str) -> Gd<NebulaFile> {
+        if !self.create_empty_file(path) {
+            return NebulaFile::new_gd();
         }
+        self.get_file(path)
+    }
+
+    fn create_empty_file(&self, path: &str) -> bool {
+        match self.replace_file(path, Arc::new(MemoryByteSource::new())) {
+            Ok(()) => true,
+            Err(err) => {
+                godot_error!("ARC: cannot create '{}': {}", path, err);
+                false
+            }
+        }
+    }
+
+    fn remove_file(&self, path: &str) -> bool {
+        let mut entries = self.entries.write().unwrap();
+        match entries.iter().position(|e| !e.is_dir() && e.path == path) {
+            Some(index) => {
+                entries.remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn remove_dir(&self, path: &str) -> bool {
+        let mut entries = self.entries.write().unwrap();
+        let Some(index) = entries.iter().position(|e| e.is_dir() && e.path == path) else {
+            return false;
+        };
+        if path.is_empty() || entries.get(index + 1).is_some_and(|e| is_within(&e.path, path)) {
+            godot_error!("ARC: cannot remove '{}': folder is not empty", path);
+            return false;
+        }
+
+        entries.remove(index);
+        true
+    }
+
+    fn rename_path(&self, from: &str, to: &str) -> bool {
+        let mut entries = self.entries.write().unwrap();
+        let Some(start) = entries.iter().position(|e| e.path == from) else {
+            return false;
+        };
+        if from.is_empty() || to.is_empty() || is_within(to, from) {
+            godot_error!("ARC: cannot move '{}' to '{}'", from, to);
+            return false;
+        }
+        if entries.iter().any(|e| e.path == to) {
+            godot_error!("ARC: cannot move '{}': '{}' already exists", from, to);
+            return false;
+        }
+        if !entries.iter().any(|e| e.is_dir() && e.path == parent_path(to)) {
+            godot_error!("ARC: cannot move '{}': folder of '{}' does not exist", from, to);
+            return false;
+        }
+
+        let len = 1 + entries[start + 1..].iter().take_while(|e| is_within(&e.path, from)).count();
+        let mut moved: Vec<ArcEntry> = entries.drain(start..start + len).collect();
+        for entry in &mut moved {
+            entry.path = format!("{}{}", to, &entry.path[from.len()..]);
+        }
+
+        let index = insert_position(&entries, parent_path(to)).unwrap_or(entries.len());
+        entries.splice(index..index, moved);
+        true
     }
 }
 
@@ -271,46 +572,170 @@ impl Clone for ArcFs {
 pub struct ARC {
     #[base]
     base: Base<RefCounted>,
-    fs: Option<Arc<dyn NebulaFs>>,
+    fs: Option<Arc<ArcFs>>,
+    source: Option<Arc<dyn ByteSource>>,
+    overlay: Option<Arc<OverlaySource>>,
+    /// Path and open mode of the file the archive was opened from, if any.
+    file: Option<(String, i32)>,
 }
 
 #[godot_api]
 impl IRefCounted for ARC {
     fn init(base: Base<RefCounted>) -> Self {
-        Self { base, fs: None }
+        Self { base, fs: None, source: None, overlay: None, file: None }
     }
 }
 
 #[godot_api]
 impl ARC {
     #[func]
-    pub fn open(path: GString) -> Option<Gd<ARC>> {
+    /// Opens an ARC file from the given path.
+    ///
+    /// With [constant NebulaBuffer.OPEN_READ] (the default) the file on disk is never modified:
+    /// writes to files inside the archive are kept in a copy-on-write layer which can be
+    /// committed or discarded through [method get_buffer].
+    /// With [constant NebulaBuffer.OPEN_READ_WRITE] writes go straight to the file.
+    pub fn open(path: GString, #[opt(default = 0)] mode: i32) -> Option<Gd<ARC>> {
         let path = ProjectSettings::singleton().globalize_path(&path).to_string();
-        let disk = match DiskFileSource::new(&path) {
-            Ok(f) => f,
+        match open_arc(&path, mode, &|_, _| true) {
+            Ok(opened) => Some(Self::from_opened(opened)),
             Err(err) => {
-                godot_error!("ARC.open: failed to open '{}': {}", path, err);
-                return None;
+                godot_error!("ARC.open: {}", err);
+                None
             }
-        };
-        let source: Arc<dyn ByteSource> = Arc::new(disk);
-        let fs = match ArcFs::new(source) {
-            Ok(fs) => fs,
+        }
+    }
+
+    #[func]
+    /// Like [method open], but reads and indexes the archive on a worker thread.
+    /// The returned [NebulaTask] reports the number of indexed nodes and completes with the
+    /// `ARC` instance, or `null` if the file cannot be opened or is invalid.
+    pub fn open_async(path: GString, #[opt(default = 0)] mode: i32) -> Gd<NebulaTask> {
+        let path = ProjectSettings::singleton().globalize_path(&path).to_string();
+        NebulaTask::spawn(
+            move |progress| open_arc(&path, mode, progress).map_err(|err| format!("ARC.open_async: {}", err)),
+            |opened| Self::from_opened(opened).to_variant(),
+        )
+    }
+
+    #[func]
+    /// Builds a U8 archive from the files and folders in `dir`, which may be a folder on disk or a
+    /// directory inside a disc or another archive. Entries keep the order in which `dir` lists them;
+    /// folders on disk are sorted by name. Every file starts at a multiple of `alignment` bytes.
+    /// Archives inside `dir` are packed as files, even if [method NebulaDir.show_archives] lists them as folders.
+    ///
+    /// Returns a buffer holding the archive, or `null` if a file cannot be read.
+    pub fn pack(dir: Gd<NebulaDir>, #[opt(default = 32)] alignment: i64) -> Option<Gd<NebulaBuffer>> {
+        match Self::pack_dir(&dir, alignment) {
+            Ok(data) => Some(NebulaBuffer::from_bytes(data.as_slice().into())),
+            Err(err) => {
+                godot_error!("ARC.pack: {}", err);
+                None
+            }
+        }
+    }
+
+    #[func]
+    /// Like [method pack], but writes the archive to `path`. Returns `false` on failure.
+    pub fn pack_to_file(dir: Gd<NebulaDir>, path: GString, #[opt(default = 32)] alignment: i64) -> bool {
+        let path = ProjectSettings::singleton().globalize_path(&path).to_string();
+        let result = Self::pack_dir(&dir, alignment).and_then(|data| {
+            write_replacing(&path, &data)
+        });
+
+        match result {
+            Ok(()) => true,
             Err(err) => {
-                godot_error!("ARC.open: invalid ARC '{}': {}", path, err);
-                return None;
+                godot_error!("ARC.pack_to_file: {}", err);
+                false
             }
+        }
+    }
+
+    #[func]
+    /// Returns a [NebulaBuffer] over the whole archive including any changes made to its files.
+    /// If the archive was opened read-only, use [method NebulaBuffer.commit_overlay] to write
+    /// the modified archive to a new file.
+    pub fn get_buffer(&self) -> Gd<NebulaBuffer> {
+        let mut buffer = NebulaBuffer::new_gd();
+        if let Some(overlay) = &self.overlay {
+            buffer.bind_mut().set_overlay(overlay.clone());
+        } else if let Some(source) = &self.source {
+            buffer.bind_mut().set_source(source.clone());
+        }
+        buffer
+    }
+
+    #[func]
+    /// Replaces the contents of the file at `path` with a copy of `buffer`, or adds the file if its
+    /// folder exists. Like the other changes made through [method to_dir], this only affects the
+    /// archive in memory until it is written with [method save] or [method save_to_buffer].
+    pub fn replace_file(&self, path: GString, buffer: Gd<NebulaBuffer>) -> bool {
+        let Some(fs) = &self.fs else {
+            godot_error!("ARC.replace_file: archive is not open");
+            return false;
         };
 
-        let mut arc_instance = ARC::new_gd();
-        arc_instance.bind_mut().fs = Some(Arc::new(fs));
-        Some(arc_instance)
+        let data = match buffer.bind().source.as_ref().map(|src| src.read_range(0, src.len() as usize)) {
+            Some(Ok(data)) => data,
+            Some(Err(err)) => {
+                godot_error!("ARC.replace_file: failed to read buffer: {}", err);
+                return false;
+            }
+            None => Vec::new(),
+        };
+
+        match fs.replace_file(&path.to_string(), Arc::new(MemoryByteSource::from_vec(data))) {
+            Ok(()) => true,
+            Err(err) => {
+                godot_error!("ARC.replace_file: {}", err);
+                false
+            }
+        }
+    }
+
+    #[func]
+    /// Writes the archive, including files and folders created, removed, renamed or replaced through
+    /// [method to_dir] and [method replace_file], as a new U8 archive at `path`. Unchanged files keep
+    /// their order. Every file starts at a multiple of `alignment` bytes. Returns `false` on failure.
+    ///
+    /// `path` may be the file the archive was opened from: it is then replaced as a whole and reopened
+    /// in the same mode, and directories from [method to_dir] show the saved archive.
+    pub fn save(&mut self, path: GString, #[opt(default = 32)] alignment: i64) -> bool {
+        let path = ProjectSettings::singleton().globalize_path(&path).to_string();
+        let reopen = self.file.clone().filter(|(open_path, _)| same_file(open_path, &path));
+        let result = self.save_impl(alignment)
+            .and_then(|data| write_replacing(&path, &data))
+            .and_then(|()| match reopen {
+                Some((open_path, mode)) => self.reopen(&open_path, mode),
+                None => Ok(()),
+            });
+
+        match result {
+            Ok(()) => true,
+            Err(err) => {
+                godot_error!("ARC.save: {}", err);
+                false
+            }
+        }
+    }
+
+    #[func]
+    /// Like [method save], but returns the archive in a new buffer, or `null` on failure.
+    pub fn save_to_buffer(&self, #[opt(default = 32)] alignment: i64) -> Option<Gd<NebulaBuffer>> {
+        match self.save_impl(alignment) {
+            Ok(data) => Some(NebulaBuffer::from_bytes(data.as_slice().into())),
+            Err(err) => {
+                godot_error!("ARC.save_to_buffer: {}", err);
+                None
+            }
+        }
     }
 
     #[func]
     pub fn to_dir(&self) -> Gd<NebulaDir> {
         match &self.fs {
-            Some(fs) => NebulaDir::new(fs.clone(), String::new()),
+            Some(fs) => NebulaDir::root(fs.clone()),
             None => NebulaDir::new_gd(),
         }
     }
@@ -336,7 +761,191 @@ impl ARC {
             }
         };
 
-        NebulaDir::new(fs, String::new())
+        NebulaDir::root(fs)
+    }
+
+}
+
+impl ARC {
+    fn save_impl(&self, alignment: i64) -> Result<Vec<u8>, String> {
+        let fs = self.fs.as_ref().ok_or("archive is not open")?;
+        if alignment <= 0 {
+            return Err(format!("invalid alignment {}", alignment));
+        }
+        fs.save(alignment as u64)
+    }
+
+    fn pack_dir(dir: &Gd<NebulaDir>, alignment: i64) -> Result<Vec<u8>, String> {
+        if alignment <= 0 {
+            return Err(format!("invalid alignment {}", alignment));
+        }
+
+        // Archives inside `dir` are packed as files, even if `dir` shows them as folders.
+        let root = dir.bind().detach_archives_as_files().ok_or("directory is not open")?;
+        build_u8(&root, alignment as u64, &|_, _| true)
+    }
+
+    /// Opens the file at `path` again after it was replaced, keeping the current [`ArcFs`].
+    fn reopen(&mut self, path: &str, mode: i32) -> Result<(), String> {
+        let opened = open_arc(path, mode, &|_, _| true)
+            .map_err(|err| format!("saved, but failed to reopen: {}", err))?;
+        match &self.fs {
+            Some(fs) => fs.rebase(opened.fs),
+            None => self.fs = Some(Arc::new(opened.fs)),
+        }
+        self.source = Some(opened.source);
+        self.overlay = opened.overlay;
+        Ok(())
+    }
+
+    fn from_opened(opened: OpenedArc) -> Gd<Self> {
+        let mut arc_instance = ARC::new_gd();
+        {
+            let mut arc = arc_instance.bind_mut();
+            arc.fs = Some(Arc::new(opened.fs));
+            arc.source = Some(opened.source);
+            arc.overlay = opened.overlay;
+            arc.file = Some((opened.path, opened.mode));
+        }
+        arc_instance
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// Lays out a U8 archive by hand from its string table, `(type and name, offset, size)` nodes,
+    /// data offset and `(offset, contents)` files.
+    fn u8_archive(strings: &[u8], nodes: &[(u32, u32, u32)], data_offset: u32, files: &[(usize, &[u8])]) -> Vec<u8> {
+        let header_size = (nodes.len() * U8_NODE_SIZE + strings.len()) as u32;
+
+        let mut data = U8_HEADER.to_vec();
+        for value in [U8_ROOT_OFFSET, header_size, data_offset] {
+            data.extend_from_slice(&value.to_be_bytes());
+        }
+        data.resize(U8_ROOT_OFFSET as usize, 0);
+        for &(kind_name, offset, size) in nodes {
+            for value in [kind_name, offset, size] {
+                data.extend_from_slice(&value.to_be_bytes());
+            }
+        }
+        data.extend_from_slice(strings);
+        for &(offset, contents) in files {
+            data.resize(offset, 0);
+            data.extend_from_slice(contents);
+        }
+        data
+    }
+
+    /// A U8 archive with `a.bin` and `dir/b.bin`, with files aligned to 0x20 bytes.
+    pub(crate) fn sample_archive() -> Vec<u8> {
+        u8_archive(
+            b"\0a.bin\0dir\0b.bin\0",
+            &[(0x0100_0000, 0, 4), (1, 0x80, 3), (0x0100_0000 | 7, 0, 4), (11, 0xA0, 2)],
+            0x80,
+            &[(0x80, b"AAA"), (0xA0, b"BB")],
+        )
+    }
+
+    /// A U8 archive holding only its root folder.
+    pub(crate) fn empty_archive() -> Vec<u8> {
+        u8_archive(b"\0", &[(0x0100_0000, 0, 1)], 0x40, &[])
+    }
+
+    /// A U8 archive whose tables end at 0x85, so its data section starts at 0xC0 rather than
+    /// the 0xA0 that aligning to the file alignment of 0x20 would give.
+    fn wide_archive() -> Vec<u8> {
+        u8_archive(
+            b"\0file0.bin\0file1.bin\0file2.bin\0file3.bin\0",
+            &[(0x0100_0000, 0, 5), (1, 0xC0, 1), (11, 0xE0, 2), (21, 0x100, 3), (31, 0x120, 4)],
+            0xC0,
+            &[(0xC0, b"0"), (0xE0, b"11"), (0x100, b"222"), (0x120, b"3333")],
+        )
+    }
+
+    fn index(data: Vec<u8>) -> Vec<(String, Option<Vec<u8>>)> {
+        let source: Arc<dyn ByteSource> = Arc::new(MemoryByteSource::from_vec(data));
+        parse_arc_index(&source, &|_, _| true).unwrap()
+            .into_iter()
+            .map(|entry| (entry.path, entry.data.map(|data| data.read_range(0, data.len() as usize).unwrap())))
+            .collect()
+    }
+
+    #[test]
+    fn indexes_archive() {
+        let entries = index(sample_archive());
+        let expected = [
+            ("", None),
+            ("a.bin", Some(b"AAA".to_vec())),
+            ("dir", None),
+            ("dir/b.bin", Some(b"BB".to_vec())),
+        ];
+        assert_eq!(entries, expected.map(|(path, data)| (path.to_string(), data)));
+    }
+
+    #[test]
+    fn finds_header_after_leading_data() {
+        let mut data = vec![0xFF; 0x40];
+        data.extend(sample_archive());
+        assert_eq!(index(data), index(sample_archive()));
     }
 
-}
\ No newline at end of file
+    #[test]
+    fn rejects_data_without_header() {
+        let source: Arc<dyn ByteSource> = Arc::new(MemoryByteSource::from_vec(vec![0; 0x100]));
+        assert!(parse_arc_index(&source, &|_, _| true).is_err());
+    }
+
+    fn load(data: Vec<u8>) -> ArcFs {
+        ArcFs::new(Arc::new(MemoryByteSource::from_vec(data))).unwrap()
+    }
+
+    #[test]
+    fn rebuilds_archive_byte_for_byte() {
+        for original in [sample_archive(), wide_archive()] {
+            assert_eq!(load(original.clone()).save(0x20).unwrap(), original);
+        }
+    }
+
+    #[test]
+    fn rebuilt_archive_round_trips() {
+        let packed = load(sample_archive()).save(1).unwrap();
+        assert_eq!(index(packed.clone()), index(sample_archive()));
+        assert_eq!(load(packed.clone()).save(1).unwrap(), packed);
+    }
+
+    #[test]
+    fn rebuild_keeps_replaced_and_added_files() {
+        let fs = load(sample_archive());
+        fs.replace_file("a.bin", Arc::new(MemoryByteSource::from_vec(b"new data".to_vec()))).unwrap();
+        fs.replace_file("dir/c.bin", Arc::new(MemoryByteSource::from_vec(b"C".to_vec()))).unwrap();
+        assert!(fs.replace_file("missing/d.bin", Arc::new(MemoryByteSource::from_vec(Vec::new()))).is_err());
+
+        let entries = index(fs.save(0x20).unwrap());
+        let files: Vec<(&str, &[u8])> = entries.iter()
+            .filter_map(|(path, data)| Some((path.as_str(), data.as_deref()?)))
+            .collect();
+        assert_eq!(files, [("a.bin", &b"new data"[..]), ("dir/b.bin", b"BB"), ("dir/c.bin", b"C")]);
+    }
+
+    #[test]
+    fn replacing_keeps_open_sources_readable() {
+        let path = std::env::temp_dir().join(format!("nebula_arc_{}.arc", std::process::id()));
+        let path = path.to_str().unwrap();
+        std::fs::write(path, sample_archive()).unwrap();
+
+        let source = open_file_source(path, OpenMode::Read).unwrap();
+        let fs = ArcFs::new(source).unwrap();
+        fs.replace_file("a.bin", Arc::new(MemoryByteSource::from_vec(b"new".to_vec()))).unwrap();
+        let saved = fs.save(0x20).unwrap();
+        write_replacing(path, &saved).unwrap();
+
+        let alias = std::env::temp_dir().join(".").join(Path::new(path).file_name().unwrap());
+        assert!(same_file(path, alias.to_str().unwrap()));
+        assert!(!same_file(path, &format!("{}.missing", path)));
+        assert_eq!(std::fs::read(path).unwrap(), saved);
+        assert_eq!(fs.save(0x20).unwrap(), saved);
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -1,10 +1,15 @@
 use godot::prelude::*;
 use crate::io::buffer::NebulaBuffer;
 
+/// Compression type byte of LZ11 data.
+pub const LZ11_TYPE: u8 = 0x11;
+/// Largest decompressed size that is accepted.
+const MAX_DECOMPRESSED_SIZE: usize = 0x800000;
+
 #[derive(GodotClass)]
 #[class(base=RefCounted)]
 pub struct LZSS {
-    #[base] 
+    #[base]
     base: Base<RefCounted>,
 }
 
@@ -20,11 +25,11 @@ impl LZSS {
     #[func]
     fn decompress(mut buffer: Gd<NebulaBuffer>) -> Gd<NebulaBuffer> {
         let mut buf = buffer.bind_mut();
-        
+
         let compression_type = buf.read_u8();
-        
+
         match compression_type {
-            0x11 => Self::decompress_lz11(&mut buf),
+            LZ11_TYPE => Self::decompress_lz11(&mut buf),
             _ => {
                 godot_error!("LZSS::decompress: unsupported compression type 0x{:02X}", compression_type);
                 NebulaBuffer::new_gd()
@@ -33,149 +38,226 @@ impl LZSS {
     }
 
     fn decompress_lz11(buf: &mut NebulaBuffer) -> Gd<NebulaBuffer> {
-        let data_size = buf.size() as usize;
-        let mut source_index = buf.get_offset() as usize;
-
-        if source_index + 2 >= data_size {
-            godot_error!("LZSS::decompress_lz11: insufficient data for size header");
-            return NebulaBuffer::new_gd();
+        let start = buf.get_offset() as u64;
+        let mut input = vec![0u8; (buf.size() as u64).saturating_sub(start) as usize];
+        let data_size = buf.read_into(start, &mut input);
+
+        match decompress_lz11(&input[..data_size]) {
+            Ok((decompressed_data, consumed)) => {
+                buf.goto((start + consumed as u64) as i64);
+                NebulaBuffer::from_bytes(PackedByteArray::from(decompressed_data.as_slice()))
+            }
+            Err(err) => {
+                godot_error!("LZSS::decompress_lz11: {}", err);
+                NebulaBuffer::new_gd()
+            }
         }
+    }
+}
 
-        let mut decompressed_size = buf.read_u8() as usize
-            | ((buf.read_u8() as usize) << 8)
-            | ((buf.read_u8() as usize) << 16);
-        source_index += 3;
+/// Decompresses `data`, which starts with the compression type byte. Only LZ11 is supported.
+pub fn decompress(data: &[u8]) -> Result<Vec<u8>, String> {
+    match data.first() {
+        Some(&LZ11_TYPE) => decompress_lz11(&data[1..]).map(|(decompressed, _)| decompressed),
+        Some(&other) => Err(format!("unsupported compression type 0x{:02X}", other)),
+        None => Err("no data".to_string()),
+    }
+}
 
-        if decompressed_size == 0 {
-            if source_index + 3 >= data_size {
-                godot_error!("LZSS::decompress_lz11: insufficient data for extended size");
-                return NebulaBuffer::new_gd();
-            }
+/// Decompresses LZ11 `input`, which starts right after the compression type byte.
+/// Returns the decompressed data and the number of input bytes used.
+pub fn decompress_lz11(input: &[u8]) -> Result<(Vec<u8>, usize), String> {
+    let data_size = input.len();
+    let mut source_index = 0;
 
-            decompressed_size = buf.read_u8() as usize
-                | ((buf.read_u8() as usize) << 8)
-                | ((buf.read_u8() as usize) << 16)
-                | ((buf.read_u8() as usize) << 24);
-            source_index += 4;
-        }
+    if source_index + 2 >= data_size {
+        return Err("insufficient data for size header".to_string());
+    }
 
-        if decompressed_size > 0x800000 || decompressed_size == 0 {
-            godot_error!("LZSS::decompress_lz11: invalid decompressed size {}", decompressed_size);
-            return NebulaBuffer::new_gd();
+    let mut decompressed_size = input[0] as usize
+        | ((input[1] as usize) << 8)
+        | ((input[2] as usize) << 16);
+    source_index += 3;
+
+    if decompressed_size == 0 {
+        if source_index + 3 >= data_size {
+            return Err("insufficient data for extended size".to_string());
         }
 
-        let mut decompressed_data = vec![0u8; decompressed_size];
-        let mut current_size = 0;
+        decompressed_size = input[3] as usize
+            | ((input[4] as usize) << 8)
+            | ((input[5] as usize) << 16)
+            | ((input[6] as usize) << 24);
+        source_index += 4;
+    }
+
+    if decompressed_size > MAX_DECOMPRESSED_SIZE || decompressed_size == 0 {
+        return Err(format!("invalid decompressed size {}", decompressed_size));
+    }
 
-        while current_size < decompressed_size && source_index < data_size {
-            buf.goto(source_index as i32);
-            let flags = buf.read_u8();
-            source_index += 1;
+    let mut decompressed_data = vec![0u8; decompressed_size];
+    let mut current_size = 0;
+
+    while current_size < decompressed_size && source_index < data_size {
+        let flags = input[source_index];
+        source_index += 1;
+
+        let mut bit_mask = 0x80u8;
+        for _ in 0..8 {
+            if current_size >= decompressed_size {
+                break;
+            }
 
-            let mut bit_mask = 0x80u8;
-            for _ in 0..8 {
-                if current_size >= decompressed_size {
-                    break;
+            if (flags & bit_mask) != 0 {
+                if source_index >= data_size {
+                    return Err("unexpected end of data".to_string());
                 }
 
-                if (flags & bit_mask) != 0 {
-                    if source_index >= data_size {
-                        godot_error!("LZSS::decompress_lz11: unexpected end of data");
-                        return NebulaBuffer::new_gd();
-                    }
+                let byte_one = input[source_index];
+                source_index += 1;
+                let first_nibble = byte_one >> 4;
 
-                    buf.goto(source_index as i32);
-                    let byte_one = buf.read_u8();
-                    source_index += 1;
-                    let first_nibble = byte_one >> 4;
-
-                    let (length, displacement) = if first_nibble == 0 {
-                        if source_index + 1 >= data_size {
-                            godot_error!("LZSS::decompress_lz11: insufficient data for type 0");
-                            return NebulaBuffer::new_gd();
-                        }
-
-                        let byte_temp = buf.read_u8();
-                        let byte_two = buf.read_u8();
-                        source_index += 2;
-
-                        let length = (((byte_one as usize) << 4) | ((byte_temp as usize) >> 4)) + 0x11;
-                        let displacement = (((byte_temp & 0x0F) as usize) << 8) | (byte_two as usize);
-
-                        (length, displacement)
-                    } else if first_nibble == 1 {
-                        if source_index + 2 >= data_size {
-                            godot_error!("LZSS::decompress_lz11: insufficient data for type 1");
-                            return NebulaBuffer::new_gd();
-                        }
-
-                        let byte_temp = buf.read_u8();
-                        let byte_two = buf.read_u8();
-                        let byte_three = buf.read_u8();
-                        source_index += 3;
-
-                        let length = ((((byte_one & 0x0F) as usize) << 12)
-                            | ((byte_temp as usize) << 4)
-                            | ((byte_two as usize) >> 4)) + 0x111;
-                        let displacement = (((byte_two & 0x0F) as usize) << 8) | (byte_three as usize);
-
-                        (length, displacement)
-                    } else {
-                        if source_index >= data_size {
-                            godot_error!("LZSS::decompress_lz11: insufficient data for type 2");
-                            return NebulaBuffer::new_gd();
-                        }
-
-                        let byte_two = buf.read_u8();
-                        source_index += 1;
-
-                        let length = (first_nibble as usize) + 1;
-                        let displacement = (((byte_one & 0x0F) as usize) << 8) | (byte_two as usize);
-
-                        (length, displacement)
-                    };
-
-                    if displacement >= current_size {
-                        godot_error!("LZSS::decompress_lz11: invalid displacement");
-                        return NebulaBuffer::new_gd();
+                let (length, displacement) = if first_nibble == 0 {
+                    if source_index + 1 >= data_size {
+                        return Err("insufficient data for type 0".to_string());
                     }
 
-                    let copy_source = current_size - displacement - 1;
-                    let copy_end = (current_size + length).min(decompressed_size);
-
-                    if displacement < length {
-                        for i in 0..(copy_end - current_size) {
-                            decompressed_data[current_size + i] = 
-                                decompressed_data[copy_source + (i % (displacement + 1))];
-                        }
-                    } else {
-                        for i in 0..(copy_end - current_size) {
-                            decompressed_data[current_size + i] = decompressed_data[copy_source + i];
-                        }
+                    let byte_temp = input[source_index];
+                    let byte_two = input[source_index + 1];
+                    source_index += 2;
+
+                    let length = (((byte_one as usize) << 4) | ((byte_temp as usize) >> 4)) + 0x11;
+                    let displacement = (((byte_temp & 0x0F) as usize) << 8) | (byte_two as usize);
+
+                    (length, displacement)
+                } else if first_nibble == 1 {
+                    if source_index + 2 >= data_size {
+                        return Err("insufficient data for type 1".to_string());
                     }
 
-                    current_size = copy_end;
+                    let byte_temp = input[source_index];
+                    let byte_two = input[source_index + 1];
+                    let byte_three = input[source_index + 2];
+                    source_index += 3;
+
+                    let length = ((((byte_one & 0x0F) as usize) << 12)
+                        | ((byte_temp as usize) << 4)
+                        | ((byte_two as usize) >> 4)) + 0x111;
+                    let displacement = (((byte_two & 0x0F) as usize) << 8) | (byte_three as usize);
+
+                    (length, displacement)
                 } else {
                     if source_index >= data_size {
-                        godot_error!("LZSS::decompress_lz11: unexpected end of data (literal)");
-                        return NebulaBuffer::new_gd();
+                        return Err("insufficient data for type 2".to_string());
                     }
 
-                    buf.goto(source_index as i32);
-                    decompressed_data[current_size] = buf.read_u8();
+                    let byte_two = input[source_index];
                     source_index += 1;
-                    current_size += 1;
+
+                    let length = (first_nibble as usize) + 1;
+                    let displacement = (((byte_one & 0x0F) as usize) << 8) | (byte_two as usize);
+
+                    (length, displacement)
+                };
+
+                if displacement >= current_size {
+                    return Err("invalid displacement".to_string());
                 }
 
-                bit_mask >>= 1;
+                let copy_source = current_size - displacement - 1;
+                let copy_end = (current_size + length).min(decompressed_size);
+
+                if displacement < length {
+                    for i in 0..(copy_end - current_size) {
+                        decompressed_data[current_size + i] =
+                            decompressed_data[copy_source + (i % (displacement + 1))];
+                    }
+                } else {
+                    for i in 0..(copy_end - current_size) {
+                        decompressed_data[current_size + i] = decompressed_data[copy_source + i];
+                    }
+                }
 
-                if current_size >= decompressed_size {
-                    break;
+                current_size = copy_end;
+            } else {
+                if source_index >= data_size {
+                    return Err("unexpected end of data (literal)".to_string());
                 }
+
+                decompressed_data[current_size] = input[source_index];
+                source_index += 1;
+                current_size += 1;
+            }
+
+            bit_mask >>= 1;
+
+            if current_size >= decompressed_size {
+                break;
             }
         }
+    }
 
-        let packed_bytes = PackedByteArray::from(decompressed_data.as_slice());
-        NebulaBuffer::from_bytes(packed_bytes)
+    Ok((decompressed_data, source_index))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Encodes `data` as LZ11 using literals only.
+    fn compress_literals(data: &[u8]) -> Vec<u8> {
+        let mut out = vec![LZ11_TYPE];
+        match data.len() {
+            len @ 1..=0xFF_FFFF => out.extend_from_slice(&(len as u32).to_le_bytes()[..3]),
+            len => {
+                out.extend_from_slice(&[0, 0, 0]);
+                out.extend_from_slice(&(len as u32).to_le_bytes());
+            }
+        }
+        for block in data.chunks(8) {
+            out.push(0);
+            out.extend_from_slice(block);
+        }
+        out
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn literals_round_trip() {
+        let data: Vec<u8> = (0..1000u32).map(|i| (i * 7 + i / 13) as u8).collect();
+        assert_eq!(decompress(&compress_literals(&data)).unwrap(), data);
+        assert_eq!(decompress(&compress_literals(b"x")).unwrap(), b"x");
+    }
+
+    #[test]
+    fn copies_back_references_of_every_size() {
+        let input = [
+            LZ11_TYPE, 0x26, 0x02, 0x00,
+            0b0011_1000, b'a', b'b',
+            0x30, 0x01,
+            0x00, 0xF0, 0x01,
+            0x10, 0x0E, 0xF0, 0x01,
+        ];
+        let expected: Vec<u8> = b"ab".iter().copied().cycle().take(0x226).collect();
+        assert_eq!(decompress(&input).unwrap(), expected);
+
+        let (_, consumed) = decompress_lz11(&input[1..]).unwrap();
+        assert_eq!(consumed, input.len() - 1);
+    }
+
+    #[test]
+    fn reads_extended_size_header() {
+        let data = vec![0x5A; 0x10];
+        let mut input = vec![LZ11_TYPE, 0, 0, 0, 0x10, 0, 0, 0];
+        input.extend_from_slice(&compress_literals(&data)[4..]);
+        assert_eq!(decompress(&input).unwrap(), data);
+    }
+
+    #[test]
+    fn rejects_invalid_data() {
+        assert!(decompress(&[]).is_err());
+        assert!(decompress(&[0x10, 1, 0, 0, 0, b'a']).is_err());
+        assert!(decompress(&[LZ11_TYPE, 4, 0, 0, 0b1000_0000, 0x30, 0x00]).is_err());
+        assert!(decompress(&[LZ11_TYPE, 4, 0, 0, 0b0000_0000, b'a']).is_err());
+        assert!(decompress(&[LZ11_TYPE, 0xFF, 0xFF, 0xFF, 0]).is_err());
+    }
+}
@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::path::Path;
 use std::sync::RwLock;
 use std::sync::Arc;
 use godot::{classes::ProjectSettings, prelude::*};
@@ -6,10 +7,11 @@ use aes::Aes128;
 use cbc::{Decryptor, cipher::{BlockDecryptMut, KeyIvInit}};
 use crate::io::{
     buffer::NebulaBuffer,
-    bytesource::{ByteSource, DiskFileSource, SubrangeSource, MemoryByteSource},
+    bytesource::{open_file_source, ByteSource, ConcatSource, OpenMode, MemoryByteSource},
     dir::NebulaDir,
-    file::NebulaFile,
-    fs::NebulaFs
+    fs::NebulaFs,
+    task::{NebulaTask, Progress},
+    text::TextEncoding,
 };
 use crate::runtime::utils::singleton::Singleton;
 
@@ -37,11 +39,30 @@ pub struct WbfsFs {
     filesystem: std::collections::HashMap<String, FsEntry>,
     game_name: String,
     game_id: String,
-    cluster_cache: RwLock<HashMap<usize, Vec<u8>>>,
+    cluster_cache: RwLock<HashMap<usize, Arc<Vec<u8>>>>,
 }
 
 impl WbfsFs {
     pub fn new(source: Arc<dyn ByteSource>) -> Result<Self, String> {
+        Self::load(source, &Self::common_key()?, &|_, _| true)
+    }
+
+    /// Reads the Wii common key from the key store. Must be called on the main thread.
+    fn common_key() -> Result<Vec<u8>, String> {
+        let common_key_hex = Singleton::get_key("WII_COMMON".to_godot()).get_string_from_ascii().to_string();
+        let common_key = hex::decode(&common_key_hex)
+            .map_err(|e| format!("Failed to decode common key from hex!: {}", e))?;
+
+        if common_key.len() != 16 {
+            return Err(format!("Common key has invalid length: {} (expected 16)", common_key.len()));
+        }
+
+        Ok(common_key)
+    }
+
+    /// Parses a WBFS image without touching the engine, so it can run on a worker thread.
+    /// `progress` is called with the number of parsed filesystem entries.
+    pub fn load(source: Arc<dyn ByteSource>, common_key: &[u8], progress: Progress) -> Result<Self, String> {
         let file_size = source.len();
         if file_size < 0x200 {
             return Err("File too small to be WBFS".to_string());
@@ -71,9 +92,9 @@ impl WbfsFs {
             .map_err(|e| format!("Failed to read disc header: {}", e))?;
 
         let game_id = String::from_utf8_lossy(&disc_header[0..6]).to_string();
-        let game_name = String::from_utf8_lossy(&disc_header[0x20..0x60])
-            .trim_end_matches('\0')
-            .to_string();
+        let title = &disc_header[0x20..0x60];
+        let title_len = title.iter().position(|&b| b == 0).unwrap_or(title.len());
+        let game_name = TextEncoding::ShiftJis.decode(&title[..title_len], true);
 
         let wlba_offset = hd_sector_size as u64 + 0x100;
         let wlba_count = blocks_per_disc;
@@ -106,14 +127,6 @@ impl WbfsFs {
         let mut title_key_iv = ticket_data[0x1DC..0x1E4].to_vec();
         title_key_iv.extend_from_slice(&[0u8; 8]);
 
-        let common_key_hex = Singleton::get_key("WII_COMMON".to_godot()).get_string_from_ascii().to_string();
-        let common_key = hex::decode(&common_key_hex)
-            .map_err(|e| format!("Failed to decode common key from hex!: {}", e))?;
-
-        if common_key.len() != 16 {
-            return Err(format!("Common key has invalid length: {} (expected 16)", common_key.len()));
-        }
-
         if encrypted_title_key.len() != 16 {
             return Err(format!("Encrypted title key has invalid length: {} (expected 16)", encrypted_title_key.len()));
         }
@@ -122,7 +135,7 @@ impl WbfsFs {
             return Err(format!("Title key IV has invalid length: {} (expected 16)", title_key_iv.len()));
         }
 
-        let decryption_key = aes_cbc_decrypt(encrypted_title_key, &common_key, &title_key_iv)?;
+        let decryption_key = aes_cbc_decrypt(encrypted_title_key, common_key, &title_key_iv)?;
 
         let partition_info = Self::get_iso_data(&source, &wlba_map, wbfs_sector_size, partition_offset + 0x2B8, 8)?;
         let partition_data_offset = (u32::from_be_bytes([
@@ -151,7 +164,7 @@ impl WbfsFs {
         let fs_info = wbfs.get_decrypted_data(0x424, 12)?;
         let filesystem_offset = (u32::from_be_bytes([fs_info[0], fs_info[1], fs_info[2], fs_info[3]]) << 2) as u64;
         
-        wbfs.filesystem = wbfs.parse_filesystem(filesystem_offset)?;
+        wbfs.filesystem = wbfs.parse_filesystem(filesystem_offset, progress)?;
 
         Ok(wbfs)
     }
@@ -163,6 +176,18 @@ impl WbfsFs {
         address: u64,
         size: usize
     ) -> Result<Vec<u8>, String> {
+        let mut data = vec![0u8; size];
+        Self::get_iso_data_into(source, wlba_map, sector_size, address, &mut data)?;
+        Ok(data)
+    }
+
+    fn get_iso_data_into(
+        source: &Arc<dyn ByteSource>,
+        wlba_map: &std::collections::HashMap<u16, u16>,
+        sector_size: u32,
+        address: u64,
+        out: &mut [u8]
+    ) -> Result<(), String> {
         let sector_index = address / sector_size as u64;
         let sector_offset = address % sector_size as u64;
         let iso_block_index = sector_index as u16;
@@ -175,36 +200,47 @@ impl WbfsFs {
             .and_then(|base| base.checked_add(sector_offset))
             .ok_or_else(|| format!("Address calculation overflow for block {}", wbfs_block))?;
 
-        source.read_range(wbfs_address, size)
-            .map_err(|e| format!("Failed to read ISO data: {}", e))
+        let n = source.read_into(wbfs_address, out)
+            .map_err(|e| format!("Failed to read ISO data: {}", e))?;
+        if n < out.len() {
+            return Err(format!("Unexpected end of image at 0x{:X}", wbfs_address + n as u64));
+        }
+
+        Ok(())
     }
 
     fn get_decrypted_data(&self, offset: u64, size: usize) -> Result<Vec<u8>, String> {
-        let mut result = Vec::with_capacity(size);
+        let mut result = vec![0u8; size];
+        self.get_decrypted_into(offset, &mut result)?;
+        Ok(result)
+    }
+
+    fn get_decrypted_into(&self, offset: u64, out: &mut [u8]) -> Result<(), String> {
         let mut current_cluster = (offset / DATA_BLOCK_SIZE as u64) as usize;
         let mut cluster_data_offset = (offset % DATA_BLOCK_SIZE as u64) as usize;
-        let mut bytes_remaining = size;
+        let mut written = 0;
 
         let mut iteration = 0;
-        while bytes_remaining > 0 {
+        while written < out.len() {
             iteration += 1;
             if iteration > 1000 {
-                return Err(format!("Too many iterations in get_decrypted_data: iteration={}, bytes_remaining={}", iteration, bytes_remaining));
+                return Err(format!("Too many iterations in get_decrypted_data: iteration={}, bytes_remaining={}", iteration, out.len() - written));
             }
             
             let cluster_data = self.decrypt_cluster(current_cluster)?;
-            let bytes_to_take = bytes_remaining.min(cluster_data.len() - cluster_data_offset);
-            result.extend_from_slice(&cluster_data[cluster_data_offset..cluster_data_offset + bytes_to_take]);
+            let bytes_to_take = (out.len() - written).min(cluster_data.len() - cluster_data_offset);
+            out[written..written + bytes_to_take]
+                .copy_from_slice(&cluster_data[cluster_data_offset..cluster_data_offset + bytes_to_take]);
             
             cluster_data_offset = 0;
-            bytes_remaining -= bytes_to_take;
+            written += bytes_to_take;
             current_cluster += 1;
         }
 
-        Ok(result)
+        Ok(())
     }
 
-    fn decrypt_cluster(&self, cluster_index: usize) -> Result<Vec<u8>, String> {
+    fn decrypt_cluster(&self, cluster_index: usize) -> Result<Arc<Vec<u8>>, String> {
         if let Ok(cache) = self.cluster_cache.read() {
             if let Some(cached) = cache.get(&cluster_index) {
                 return Ok(cached.clone());
@@ -215,10 +251,13 @@ impl WbfsFs {
         let iv_offset = self.partition_offset + self.partition_data_offset + cluster_start + 0x3D0;
         let data_offset = self.partition_offset + self.partition_data_offset + cluster_start + SHA1_BLOCK_SIZE as u64;
 
-        let iv = Self::get_iso_data(&self.source, &self.wlba_map, self.sector_size, iv_offset, 16)?;
-        let encrypted = Self::get_iso_data(&self.source, &self.wlba_map, self.sector_size, data_offset, DATA_BLOCK_SIZE)?;
+        let mut iv = [0u8; 16];
+        Self::get_iso_data_into(&self.source, &self.wlba_map, self.sector_size, iv_offset, &mut iv)?;
+        let mut data = vec![0u8; DATA_BLOCK_SIZE];
+        Self::get_iso_data_into(&self.source, &self.wlba_map, self.sector_size, data_offset, &mut data)?;
 
-        let decrypted = aes_cbc_decrypt(&encrypted, &self.decryption_key, &iv)?;
+        aes_cbc_decrypt_in_place(&mut data, &self.decryption_key, &iv)?;
+        let decrypted = Arc::new(data);
         
         if let Ok(mut cache) = self.cluster_cache.write() {
             cache.insert(cluster_index, decrypted.clone());
@@ -227,7 +266,7 @@ impl WbfsFs {
         Ok(decrypted)
     }
 
-    fn parse_filesystem(&self, fs_offset: u64) -> Result<std::collections::HashMap<String, FsEntry>, String> {
+    fn parse_filesystem(&self, fs_offset: u64, progress: Progress) -> Result<std::collections::HashMap<String, FsEntry>, String> {
         let header = self.get_decrypted_data(fs_offset, 12)?;
         let total_entries = u32::from_be_bytes([header[8], header[9], header[10], header[11]]) as usize;
 
@@ -242,7 +281,8 @@ impl WbfsFs {
         let mut dir_stack = vec![("".to_string(), total_entries)];
 
         for i in 1..total_entries {
-            if i % 100 == 0 {
+            if i % 100 == 0 && !progress(i as u64, total_entries as u64) {
+                return Err("Cancelled".to_string());
             }
             
             while i >= dir_stack.last().unwrap().1 {
@@ -281,28 +321,29 @@ impl WbfsFs {
             }
         }
 
+        progress(total_entries as u64, total_entries as u64);
         Ok(filesystem)
     }
 
+    /// Reads a null-terminated Shift-JIS name from the FST string table.
     fn read_null_string(&self, offset: u64) -> Result<String, String> {
         const CHUNK_SIZE: usize = 256;
-        let mut result = String::new();
+        let mut bytes = Vec::new();
         let mut current = offset;
         
         loop {
             let chunk = self.get_decrypted_data(current, CHUNK_SIZE)?;
             
-            for (_, &byte) in chunk.iter().enumerate() {
+            for &byte in chunk.iter() {
                 if byte == 0 {
-                    return Ok(result);
+                    return Ok(TextEncoding::ShiftJis.decode(&bytes, true));
                 }
-                if byte >= 32 && byte < 127 || byte >= 160 {
-                    result.push(byte as char);
-                } else if byte != 0 {
+                if byte < 32 {
                     return Err(format!("Invalid character in string at offset {:x}: byte={}", offset, byte));
                 }
+                bytes.push(byte);
                 
-                if result.len() > 255 {
+                if bytes.len() > 255 {
                     return Err(format!("String too long at offset {:x}", offset));
                 }
             }
@@ -324,22 +365,72 @@ impl WbfsFs {
     }
 }
 
+/// Returns the part files of a split WBFS dump, starting with `path` itself.
+/// Parts use the case of the `.wbfs` extension, e.g. `GAME.WBF1` next to `GAME.WBFS`,
+/// falling back to lowercase and uppercase.
+fn split_parts(path: &str) -> Vec<String> {
+    let mut parts = vec![path.to_string()];
+    let split = path.len().checked_sub(5).filter(|&at| path.is_char_boundary(at));
+    let Some((stem, ext)) = split.map(|at| path.split_at(at)).filter(|(_, ext)| ext.eq_ignore_ascii_case(".wbfs")) else {
+        return parts;
+    };
+
+    let mut prefixes = vec![ext[..4].to_string()];
+    for fallback in [".wbf", ".WBF"] {
+        if !prefixes.iter().any(|prefix| prefix == fallback) {
+            prefixes.push(fallback.to_string());
+        }
+    }
+
+    for index in 1.. {
+        let part = prefixes
+            .iter()
+            .map(|prefix| format!("{}{}{}", stem, prefix, index))
+            .find(|part| Path::new(part).is_file());
+        match part {
+            Some(part) => parts.push(part),
+            None => break,
+        }
+    }
+
+    parts
+}
+
+/// Opens a WBFS image, concatenating split parts when they are present.
+fn open_wbfs_parts(path: &str, mode: OpenMode) -> std::io::Result<Arc<dyn ByteSource>> {
+    let parts = split_parts(path);
+    if parts.len() == 1 {
+        return open_file_source(path, mode);
+    }
+
+    let sources = parts
+        .iter()
+        .map(|part| open_file_source(part, mode))
+        .collect::<std::io::Result<Vec<_>>>()?;
+    Ok(Arc::new(ConcatSource::new(sources)))
+}
+
 fn aes_cbc_decrypt(data: &[u8], key: &[u8], iv: &[u8]) -> Result<Vec<u8>, String> {
+    let mut buffer = data.to_vec();
+    aes_cbc_decrypt_in_place(&mut buffer, key, iv)?;
+    Ok(buffer)
+}
+
+fn aes_cbc_decrypt_in_place(data: &mut [u8], key: &[u8], iv: &[u8]) -> Result<(), String> {
     type Aes128CbcDec = Decryptor<Aes128>;
     
     let cipher = Aes128CbcDec::new_from_slices(key, iv)
         .map_err(|e| format!("Invalid key/IV length: {:?}", e))?;
     
-    let mut buffer = data.to_vec();
-    cipher.decrypt_padded_mut::<cbc::cipher::block_padding::NoPadding>(&mut buffer)
+    cipher.decrypt_padded_mut::<cbc::cipher::block_padding::NoPadding>(data)
         .map_err(|e| format!("Decryption failed: {:?}", e))?;
     
-    Ok(buffer)
+    Ok(())
 }
 
 impl NebulaFs for WbfsFs {
-    fn get_entries(&self, path: &str) -> PackedStringArray {
-        let mut out = PackedStringArray::new();
+    fn entries(&self, path: &str) -> Vec<String> {
+        let mut out = Vec::new();
         let prefix = if path.is_empty() { 
             String::new() 
         } else { 
@@ -359,7 +450,7 @@ impl NebulaFs for WbfsFs {
                     } else {
                         first_component.to_string()
                     };
-                    out.push(&name);
+                    out.push(name);
                 }
             }
         }
@@ -402,24 +493,16 @@ impl NebulaFs for WbfsFs {
         self.filesystem.keys().any(|p| p.starts_with(&prefix))
     }
 
-    fn get_file(&self, path: &str) -> Gd<NebulaFile> {
-        match self.filesystem.get(path) {
-            Some(entry) => {
-                let data = self.get_decrypted_data(entry.offset, entry.size as usize)
-                    .unwrap_or_default();
-                let memory_source = MemoryByteSource::with_capacity(data.len());
-                let _ = memory_source.write_range(0, &data);
-                let subrange = Arc::new(SubrangeSource::new(
-                    Arc::new(memory_source),
-                    0,
-                    entry.size
-                ));
-                let mut buffer = NebulaBuffer::new_gd();
-                buffer.bind_mut().set_source(subrange);
-                NebulaFile::from_buffer(buffer)
-            }
-            None => NebulaFile::from_buffer(NebulaBuffer::new_gd()),
-        }
+    fn open_source(&self, path: &str) -> Option<Arc<dyn ByteSource>> {
+        let entry = self.filesystem.get(path)?;
+        let data = self.get_decrypted_data(entry.offset, entry.size as usize)
+            .unwrap_or_default();
+        Some(Arc::new(MemoryByteSource::from_vec(data)))
+    }
+
+    fn read_head(&self, path: &str, size: usize) -> Option<Vec<u8>> {
+        let entry = self.filesystem.get(path)?;
+        self.get_decrypted_data(entry.offset, size.min(entry.size as usize)).ok()
     }
 
     fn get_dir(&self, path: &str) -> Gd<NebulaDir> {
@@ -475,18 +558,28 @@ impl IRefCounted for WBFS {
 impl WBFS {
     #[func]
     /// Opens a WBFS file from the given path and returns a `WBFS` instance.
+    /// Split dumps are picked up automatically: when opening `game.wbfs`, the sibling
+    /// `game.wbf1`, `game.wbf2`… parts are appended in order.
+    /// `mode` is [constant NebulaBuffer.OPEN_READ] or [constant NebulaBuffer.OPEN_READ_WRITE].
     /// Logs an error and returns `null` if the file cannot be opened or is invalid.
-    pub fn open(path: GString) -> Option<Gd<WBFS>> {
+    pub fn open(path: GString, #[opt(default = 0)] mode: i32) -> Option<Gd<WBFS>> {
         let path = ProjectSettings::singleton().globalize_path(&path).to_string();
-        let disk = match DiskFileSource::new(&path) {
-            Ok(f) => f,
+        let open_mode = match NebulaBuffer::open_mode(mode) {
+            Some(OpenMode::Create) | None => {
+                godot_error!("WBFS.open: invalid open mode {}", mode);
+                return None;
+            }
+            Some(open_mode) => open_mode,
+        };
+
+        let source = match open_wbfs_parts(&path, open_mode) {
+            Ok(src) => src,
             Err(err) => {
                 godot_error!("WBFS.open: failed to open '{}': {}", path, err);
                 return None;
             }
         };
 
-        let source: Arc<dyn ByteSource> = Arc::new(disk);
         let fs = match WbfsFs::new(source) {
             Ok(fs) => fs,
             Err(err) => {
@@ -495,9 +588,37 @@ impl WBFS {
             }
         };
 
-        let mut wbfs_instance = WBFS::new_gd();
-        wbfs_instance.bind_mut().fs = Some(Arc::new(fs));
-        Some(wbfs_instance)
+        Some(Self::from_fs(fs))
+    }
+
+    #[func]
+    /// Like [method open], but reads and parses the image on a worker thread.
+    /// The returned [NebulaTask] reports the number of parsed filesystem entries and completes
+    /// with the `WBFS` instance, or `null` if the file cannot be opened or is invalid.
+    pub fn open_async(path: GString, #[opt(default = 0)] mode: i32) -> Gd<NebulaTask> {
+        let path = ProjectSettings::singleton().globalize_path(&path).to_string();
+        let open_mode = NebulaBuffer::open_mode(mode);
+        let common_key = WbfsFs::common_key();
+
+        NebulaTask::spawn(
+            move |progress| {
+                let open_mode = match open_mode {
+                    Some(OpenMode::Create) | None => {
+                        return Err(format!("WBFS.open_async: invalid open mode {}", mode));
+                    }
+                    Some(open_mode) => open_mode,
+                };
+                let common_key = common_key
+                    .map_err(|err| format!("WBFS.open_async: invalid WBFS '{}': {}", path, err))?;
+
+                let source = open_wbfs_parts(&path, open_mode)
+                    .map_err(|err| format!("WBFS.open_async: failed to open '{}': {}", path, err))?;
+
+                WbfsFs::load(source, &common_key, progress)
+                    .map_err(|err| format!("WBFS.open_async: invalid WBFS '{}': {}", path, err))
+            },
+            |fs| Self::from_fs(fs).to_variant(),
+        )
     }
 
     #[func]
@@ -511,7 +632,7 @@ impl WBFS {
     /// Returns the root directory of the WBFS file as a [NebulaDir].
     pub fn to_dir(&self) -> Gd<NebulaDir> {
         match &self.fs {
-            Some(fs) => NebulaDir::new(fs.clone(), String::new()),
+            Some(fs) => NebulaDir::root(fs.clone()),
             None => NebulaDir::new_gd(),
         }
     }
@@ -649,4 +770,21 @@ impl WBFS {
 
         id.chars().take(2).collect::<String>().to_godot()
     }
+
+    #[func]
+    /// Returns the read cache statistics of the underlying image, see [method NebulaBuffer.get_cache_stats].
+    pub fn get_cache_stats(&self) -> VarDictionary {
+        match &self.fs {
+            Some(fs) => NebulaBuffer::cache_stats_to_dict(fs.source.cache_stats()),
+            None => VarDictionary::new(),
+        }
+    }
+}
+
+impl WBFS {
+    fn from_fs(fs: WbfsFs) -> Gd<Self> {
+        let mut wbfs_instance = WBFS::new_gd();
+        wbfs_instance.bind_mut().fs = Some(Arc::new(fs));
+        wbfs_instance
+    }
 }
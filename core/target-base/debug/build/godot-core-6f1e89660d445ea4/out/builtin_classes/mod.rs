@@ -0,0 +1,68 @@
+mod string;
+pub use string::InnerString;
+mod vector2;
+pub use vector2::InnerVector2;
+mod vector2i;
+pub use vector2i::InnerVector2i;
+mod rect2;
+pub use rect2::InnerRect2;
+mod rect2i;
+pub use rect2i::InnerRect2i;
+mod vector3;
+pub use vector3::InnerVector3;
+mod vector3i;
+pub use vector3i::InnerVector3i;
+mod transform_2d;
+pub use transform_2d::InnerTransform2D;
+mod vector4;
+pub use vector4::InnerVector4;
+mod vector4i;
+pub use vector4i::InnerVector4i;
+mod plane;
+pub use plane::InnerPlane;
+mod quaternion;
+pub use quaternion::InnerQuaternion;
+mod aabb;
+pub use aabb::InnerAabb;
+mod basis;
+pub use basis::InnerBasis;
+mod transform_3d;
+pub use transform_3d::InnerTransform3D;
+mod projection;
+pub use projection::InnerProjection;
+mod color;
+pub use color::InnerColor;
+mod string_name;
+pub use string_name::InnerStringName;
+mod node_path;
+pub use node_path::InnerNodePath;
+mod rid;
+pub use rid::InnerRid;
+mod callable;
+pub use callable::InnerCallable;
+mod signal;
+pub use signal::InnerSignal;
+mod dictionary;
+pub use dictionary::InnerDictionary;
+mod array;
+pub use array::InnerArray;
+mod packed_byte_array;
+pub use packed_byte_array::InnerPackedByteArray;
+mod packed_int32_array;
+pub use packed_int32_array::InnerPackedInt32Array;
+mod packed_int64_array;
+pub use packed_int64_array::InnerPackedInt64Array;
+mod packed_float32_array;
+pub use packed_float32_array::InnerPackedFloat32Array;
+mod packed_float64_array;
+pub use packed_float64_array::InnerPackedFloat64Array;
+mod packed_string_array;
+pub use packed_string_array::InnerPackedStringArray;
+mod packed_vector2_array;
+pub use packed_vector2_array::InnerPackedVector2Array;
+mod packed_vector3_array;
+pub use packed_vector3_array::InnerPackedVector3Array;
+mod packed_color_array;
+pub use packed_color_array::InnerPackedColorArray;
+mod packed_vector4_array;
+pub use packed_vector4_array::InnerPackedVector4Array;
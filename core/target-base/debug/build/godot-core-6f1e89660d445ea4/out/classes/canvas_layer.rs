@@ -0,0 +1,497 @@
+#![doc = "Sidecar module for class [`CanvasLayer`][crate::classes::CanvasLayer].\n\nDefines related flag and enum types. In GDScript, those are nested under the class scope.\n\nSee also [Godot docs for `CanvasLayer` enums](https://docs.godotengine.org/en/stable/classes/class_canvaslayer.html#enumerations).\n\n"]
+use godot_ffi as sys;
+use crate::builtin::*;
+use crate::meta::{
+    AsArg, ClassId, CowArg, InParamTuple, OutParamTuple, ParamTuple, RefArg, Signature
+};
+use crate::classes::native::*;
+use crate::classes::Object;
+use crate::obj::Gd;
+use crate::sys::GodotFfi as _;
+use crate::classes::notify::*;
+use std::ffi::c_void;
+pub(super) mod re_export {
+    use super::*;
+    #[doc = "Godot class `CanvasLayer.`\n\nInherits [`Node`][crate::classes::Node].\n\nRelated symbols:\n\n* [`canvas_layer`][crate::classes::canvas_layer]: sidecar module with related enum/flag types\n* [`ICanvasLayer`][crate::classes::ICanvasLayer]: virtual methods\n* [`SignalsOfCanvasLayer`][crate::classes::canvas_layer::SignalsOfCanvasLayer]: signal collection\n\n\nSee also [Godot docs for `CanvasLayer`](https://docs.godotengine.org/en/stable/classes/class_canvaslayer.html).\n\n"]
+    #[doc = "# Construction\n\nThis class is manually managed. You can create a new instance using [`CanvasLayer::new_alloc()`][crate::obj::NewAlloc::new_alloc].\n\nDo not forget to call [`free()`][crate::obj::Gd::free] or hand over ownership to Godot."]
+    #[derive(Debug)]
+    #[repr(C)]
+    pub struct CanvasLayer {
+        object_ptr: sys::GDExtensionObjectPtr, rtti: Option < crate::private::ObjectRtti >,
+    }
+    #[doc = "# Interface trait for class [`CanvasLayer`][crate::classes::CanvasLayer].\n\nFunctions in this trait represent constructors (`init`) or virtual method callbacks invoked by the engine.\n\n\n\n# Related symbols\n\nBase interfaces: [`INode`][crate::classes::INode] > [`IObject`][crate::classes::IObject].\n\nSee also [Godot docs for `CanvasLayer` methods](https://docs.godotengine.org/en/stable/classes/class_canvaslayer.html#methods)."]
+    #[doc = ""]
+    #[allow(unused_variables)]
+    #[allow(clippy::unimplemented)]
+    pub trait ICanvasLayer: crate::obj::GodotClass < Base = CanvasLayer > + crate::private::You_forgot_the_attribute__godot_api {
+        #[doc(hidden)]
+        fn register_class(builder: &mut crate::builder::ClassBuilder < Self >) {
+            unimplemented !()
+        }
+        #[doc = r" Godot constructor, accepting an injected `base` object."]
+        #[doc = r""]
+        #[doc = r" `base` refers to the base instance of the class, which can either be stored in a `Base<T>` field or discarded."]
+        #[doc = r" This method returns a fully-constructed instance, which will then be moved into a [`Gd<T>`][crate::obj::Gd] pointer."]
+        #[doc = r""]
+        #[doc = r" If the class has a `#[class(init)]` attribute, this method will be auto-generated and must not be overridden."]
+        fn init(base: crate::obj::Base < Self::Base >) -> Self {
+            unimplemented !()
+        }
+        #[doc = r" String representation of the Godot instance."]
+        #[doc = r""]
+        #[doc = r" Override this method to define how the instance is represented as a string."]
+        #[doc = r" Used by `impl Display for Gd<T>`, as well as `str()` and `print()` in GDScript."]
+        fn to_string(&self) -> crate::builtin::GString {
+            unimplemented !()
+        }
+        #[doc = r" Called when the object receives a Godot notification."]
+        #[doc = r""]
+        #[doc = r" The type of notification can be identified through `what`. The enum is designed to hold all possible `NOTIFICATION_*`"]
+        #[doc = r" constants that the current class can handle. However, this is not validated in Godot, so an enum variant `Unknown` exists"]
+        #[doc = r" to represent integers out of known constants (mistakes or future additions)."]
+        #[doc = r""]
+        #[doc = r" This method is named `_notification` in Godot, but `on_notification` in Rust. To _send_ notifications, use the"]
+        #[doc = r" [`Object::notify`][crate::classes::Object::notify] method."]
+        #[doc = r""]
+        #[doc = r" See also in Godot docs:"]
+        #[doc = r" * [`Object::_notification`](https://docs.godotengine.org/en/stable/classes/class_object.html#class-object-method-notification)."]
+        #[doc = r" * [Notifications tutorial](https://docs.godotengine.org/en/stable/tutorials/best_practices/godot_notifications.html)."]
+        fn on_notification(&mut self, what: NodeNotification) {
+            unimplemented !()
+        }
+        #[doc = r" Called whenever [`get()`](crate::classes::Object::get) is called or Godot gets the value of a property."]
+        #[doc = r""]
+        #[doc = r" Should return the given `property`'s value as `Some(value)`, or `None` if the property should be handled normally."]
+        #[doc = r""]
+        #[doc = r" See also in Godot docs:"]
+        #[doc = r" * [`Object::_get`](https://docs.godotengine.org/en/stable/classes/class_object.html#class-object-private-method-get)."]
+        fn get_property(&self, property: StringName) -> Option < Variant > {
+            unimplemented !()
+        }
+        #[doc = r" Called whenever Godot [`set()`](crate::classes::Object::set) is called or Godot sets the value of a property."]
+        #[doc = r""]
+        #[doc = r" Should set `property` to the given `value` and return `true`, or return `false` to indicate the `property`"]
+        #[doc = r" should be handled normally."]
+        #[doc = r""]
+        #[doc = r" See also in Godot docs:"]
+        #[doc = r" * [`Object::_set`](https://docs.godotengine.org/en/stable/classes/class_object.html#class-object-private-method-set)."]
+        fn set_property(&mut self, property: StringName, value: Variant) -> bool {
+            unimplemented !()
+        }
+        #[doc = r" Called whenever Godot [`get_property_list()`](crate::classes::Object::get_property_list) is called, the returned vector here is"]
+        #[doc = r" appended to the existing list of properties."]
+        #[doc = r""]
+        #[doc = r" This should mainly be used for advanced purposes, such as dynamically updating the property list in the editor."]
+        #[doc = r""]
+        #[doc = r" See also in Godot docs:"]
+        #[doc = r" * [`Object::_get_property_list`](https://docs.godotengine.org/en/latest/classes/class_object.html#class-object-private-method-get-property-list)"]
+        #[cfg(since_api = "4.3")]
+        #[cfg_attr(published_docs, doc(cfg(since_api = "4.3")))]
+        fn get_property_list(&mut self) -> Vec < crate::meta::PropertyInfo > {
+            unimplemented !()
+        }
+        #[doc = r" Called whenever Godot retrieves value of property. Allows to customize existing properties."]
+        #[doc = r" Every property info goes through this method, except properties **added** with `get_property_list()`."]
+        #[doc = r""]
+        #[doc = r" Exposed `property` here is a shared mutable reference obtained (and returned to) from Godot."]
+        #[doc = r""]
+        #[doc = r" See also in the Godot docs:"]
+        #[doc = r" * [`Object::_validate_property`](https://docs.godotengine.org/en/stable/classes/class_object.html#class-object-private-method-validate-property)"]
+        fn validate_property(&self, property: &mut crate::meta::PropertyInfo) {
+            unimplemented !()
+        }
+        #[doc = r" Called by Godot to tell if a property has a custom revert or not."]
+        #[doc = r""]
+        #[doc = r" Return `None` for no custom revert, and return `Some(value)` to specify the custom revert."]
+        #[doc = r""]
+        #[doc = r" This is a combination of Godot's [`Object::_property_get_revert`] and [`Object::_property_can_revert`]. This means that this"]
+        #[doc = r" function will usually be called twice by Godot to find the revert."]
+        #[doc = r""]
+        #[doc = r" Note that this should be a _pure_ function. That is, it should always return the same value for a property as long as `self`"]
+        #[doc = r" remains unchanged. Otherwise, this may lead to unexpected (safe) behavior."]
+        #[doc = r""]
+        #[doc = r" [`Object::_property_get_revert`]: https://docs.godotengine.org/en/latest/classes/class_object.html#class-object-private-method-property-get-revert"]
+        #[doc = r" [`Object::_property_can_revert`]: https://docs.godotengine.org/en/latest/classes/class_object.html#class-object-private-method-property-can-revert"]
+        #[doc(alias = "property_can_revert")]
+        fn property_get_revert(&self, property: StringName) -> Option < Variant > {
+            unimplemented !()
+        }
+        fn process(&mut self, delta: f64,) {
+            unimplemented !()
+        }
+        fn physics_process(&mut self, delta: f64,) {
+            unimplemented !()
+        }
+        fn enter_tree(&mut self,) {
+            unimplemented !()
+        }
+        fn exit_tree(&mut self,) {
+            unimplemented !()
+        }
+        fn ready(&mut self,) {
+            unimplemented !()
+        }
+        fn get_configuration_warnings(&self,) -> PackedStringArray {
+            unimplemented !()
+        }
+        fn get_accessibility_configuration_warnings(&self,) -> PackedStringArray {
+            unimplemented !()
+        }
+        fn input(&mut self, event: Gd < crate::classes::InputEvent >,) {
+            unimplemented !()
+        }
+        fn shortcut_input(&mut self, event: Gd < crate::classes::InputEvent >,) {
+            unimplemented !()
+        }
+        fn unhandled_input(&mut self, event: Gd < crate::classes::InputEvent >,) {
+            unimplemented !()
+        }
+        fn unhandled_key_input(&mut self, event: Gd < crate::classes::InputEvent >,) {
+            unimplemented !()
+        }
+        fn get_focused_accessibility_element(&self,) -> Rid {
+            unimplemented !()
+        }
+    }
+    impl CanvasLayer {
+        pub fn set_layer(&mut self, layer: i32,) {
+            type CallRet = ();
+            type CallParams = (i32,);
+            let args = (layer,);
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(1918usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "CanvasLayer", "set_layer", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn get_layer(&self,) -> i32 {
+            type CallRet = i32;
+            type CallParams = ();
+            let args = ();
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(1919usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "CanvasLayer", "get_layer", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn set_visible(&mut self, visible: bool,) {
+            type CallRet = ();
+            type CallParams = (bool,);
+            let args = (visible,);
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(1920usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "CanvasLayer", "set_visible", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn is_visible(&self,) -> bool {
+            type CallRet = bool;
+            type CallParams = ();
+            let args = ();
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(1921usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "CanvasLayer", "is_visible", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn show(&mut self,) {
+            type CallRet = ();
+            type CallParams = ();
+            let args = ();
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(1922usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "CanvasLayer", "show", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn hide(&mut self,) {
+            type CallRet = ();
+            type CallParams = ();
+            let args = ();
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(1923usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "CanvasLayer", "hide", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn set_transform(&mut self, transform: Transform2D,) {
+            type CallRet = ();
+            type CallParams = (Transform2D,);
+            let args = (transform,);
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(1924usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "CanvasLayer", "set_transform", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn get_transform(&self,) -> Transform2D {
+            type CallRet = Transform2D;
+            type CallParams = ();
+            let args = ();
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(1925usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "CanvasLayer", "get_transform", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn get_final_transform(&self,) -> Transform2D {
+            type CallRet = Transform2D;
+            type CallParams = ();
+            let args = ();
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(1926usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "CanvasLayer", "get_final_transform", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn set_offset(&mut self, offset: Vector2,) {
+            type CallRet = ();
+            type CallParams = (Vector2,);
+            let args = (offset,);
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(1927usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "CanvasLayer", "set_offset", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn get_offset(&self,) -> Vector2 {
+            type CallRet = Vector2;
+            type CallParams = ();
+            let args = ();
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(1928usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "CanvasLayer", "get_offset", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn set_rotation(&mut self, radians: f32,) {
+            type CallRet = ();
+            type CallParams = (f32,);
+            let args = (radians,);
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(1929usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "CanvasLayer", "set_rotation", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn get_rotation(&self,) -> f32 {
+            type CallRet = f32;
+            type CallParams = ();
+            let args = ();
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(1930usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "CanvasLayer", "get_rotation", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn set_scale(&mut self, scale: Vector2,) {
+            type CallRet = ();
+            type CallParams = (Vector2,);
+            let args = (scale,);
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(1931usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "CanvasLayer", "set_scale", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn get_scale(&self,) -> Vector2 {
+            type CallRet = Vector2;
+            type CallParams = ();
+            let args = ();
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(1932usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "CanvasLayer", "get_scale", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn set_follow_viewport(&mut self, enable: bool,) {
+            type CallRet = ();
+            type CallParams = (bool,);
+            let args = (enable,);
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(1933usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "CanvasLayer", "set_follow_viewport", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn is_following_viewport(&self,) -> bool {
+            type CallRet = bool;
+            type CallParams = ();
+            let args = ();
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(1934usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "CanvasLayer", "is_following_viewport", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn set_follow_viewport_scale(&mut self, scale: f32,) {
+            type CallRet = ();
+            type CallParams = (f32,);
+            let args = (scale,);
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(1935usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "CanvasLayer", "set_follow_viewport_scale", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn get_follow_viewport_scale(&self,) -> f32 {
+            type CallRet = f32;
+            type CallParams = ();
+            let args = ();
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(1936usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "CanvasLayer", "get_follow_viewport_scale", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn set_custom_viewport(&mut self, viewport: impl AsArg < Option < Gd < crate::classes::Node >> >,) {
+            type CallRet = ();
+            type CallParams < 'a0, > = (CowArg < 'a0, Option < Gd < crate::classes::Node > > >,);
+            let args = (viewport.into_arg(),);
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(1937usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "CanvasLayer", "set_custom_viewport", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn get_custom_viewport(&self,) -> Option < Gd < crate::classes::Node > > {
+            type CallRet = Option < Gd < crate::classes::Node > >;
+            type CallParams = ();
+            let args = ();
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(1938usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "CanvasLayer", "get_custom_viewport", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn get_canvas(&self,) -> Rid {
+            type CallRet = Rid;
+            type CallParams = ();
+            let args = ();
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(1939usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "CanvasLayer", "get_canvas", Some(self.__validated_obj()), args,)
+            }
+        }
+        #[doc = r" Creates a validated object for FFI boundary crossing."]
+        #[doc = r""]
+        #[doc = r" Low-level internal method. Validation (liveness/type checks) depend on safeguard level."]
+        fn __validated_obj(&self) -> crate::obj::ValidatedObject {
+            let raw_gd = unsafe {
+                std::mem::transmute::< &Self, &crate::obj::RawGd < Self >> (self)
+            };
+            raw_gd.validated_object()
+        }
+        #[doc(hidden)]
+        pub fn __object_ptr(&self) -> sys::GDExtensionObjectPtr {
+            self.object_ptr
+        }
+    }
+    impl crate::obj::GodotClass for CanvasLayer {
+        type Base = crate::classes::Node;
+        fn class_id() -> ClassId {
+            static CLASS_ID: std::sync::OnceLock < ClassId > = std::sync::OnceLock::new();
+            let name: &'static ClassId = CLASS_ID.get_or_init(|| ClassId::__alloc_next_unicode("CanvasLayer"));
+            * name
+        }
+        const INIT_LEVEL: crate::init::InitLevel = crate::init::InitLevel::Scene;
+        
+    }
+    unsafe impl crate::obj::Bounds for CanvasLayer {
+        type Memory = crate::obj::bounds::MemManual;
+        type DynMemory = crate::obj::bounds::MemManual;
+        type Declarer = crate::obj::bounds::DeclEngine;
+        type Exportable = crate::obj::bounds::Yes;
+        
+    }
+    unsafe impl crate::obj::Inherits < crate::classes::Node > for CanvasLayer {
+        
+    }
+    unsafe impl crate::obj::Inherits < crate::classes::Object > for CanvasLayer {
+        
+    }
+    impl crate::obj::cap::GodotDefault for CanvasLayer {
+        fn __godot_default() -> crate::obj::Gd < Self > {
+            crate::classes::construct_engine_object::< Self > ()
+        }
+    }
+    impl std::ops::Deref for CanvasLayer {
+        type Target = crate::classes::Node;
+        fn deref(&self) -> &Self::Target {
+            unsafe {
+                std::mem::transmute::< &Self, &Self::Target > (self)
+            }
+        }
+    }
+    impl std::ops::DerefMut for CanvasLayer {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            unsafe {
+                std::mem::transmute::< &mut Self, &mut Self::Target > (self)
+            }
+        }
+    }
+    #[doc = r" # Safety"]
+    #[doc = r""]
+    #[doc = "The provided class must be a subclass of all the superclasses of [`CanvasLayer`]"]
+    #[macro_export]
+    #[allow(non_snake_case)]
+    macro_rules !inherit_from_CanvasLayer__ensure_class_exists {
+        ($Class: ident) => {
+            unsafe impl::godot::obj::Inherits < ::godot::classes::CanvasLayer > for $Class {
+                
+            }
+            unsafe impl::godot::obj::Inherits < ::godot::classes::Node > for $Class {
+                
+            }
+            unsafe impl::godot::obj::Inherits < ::godot::classes::Object > for $Class {
+                
+            }
+        }
+    }
+}
+pub use signals::*;
+mod signals {
+    use crate::obj::{
+        Gd, GodotClass
+    };
+    use super::re_export::CanvasLayer;
+    use crate::registry::signal::TypedSignal;
+    use super::*;
+    #[doc = "A collection of signals for the [`CanvasLayer`][crate::classes::CanvasLayer] class."]
+    pub struct SignalsOfCanvasLayer < 'c, C: WithSignals > {
+        #[doc(hidden)]
+        pub(crate) __internal_obj: Option < C::__SignalObj < 'c >>,
+    }
+    impl < 'c, C: WithSignals > SignalsOfCanvasLayer < 'c, C > {
+        #[doc = "Signature: `()`"]
+        pub fn visibility_changed(&mut self) -> SigVisibilityChanged < 'c, C > {
+            SigVisibilityChanged {
+                typed: TypedSignal::extract(&mut self.__internal_obj, "visibility_changed")
+            }
+        }
+    }
+    type TypedSigVisibilityChanged < 'c, C > = TypedSignal < 'c, C, () >;
+    pub struct SigVisibilityChanged < 'c, C: WithSignals > {
+        typed: TypedSigVisibilityChanged < 'c, C >,
+    }
+    impl < 'c, C: WithSignals > SigVisibilityChanged < 'c, C > {
+        pub fn emit(&mut self,) {
+            self.typed.emit_tuple(());
+            
+        }
+    }
+    impl < 'c, C: WithSignals > std::ops::Deref for SigVisibilityChanged < 'c, C > {
+        type Target = TypedSigVisibilityChanged < 'c, C >;
+        fn deref(&self) -> &Self::Target {
+            &self.typed
+        }
+    }
+    impl < C: WithSignals > std::ops::DerefMut for SigVisibilityChanged < '_, C > {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            &mut self.typed
+        }
+    }
+    use crate::obj::WithSignals;
+    impl WithSignals for CanvasLayer {
+        type SignalCollection < 'c, C: WithSignals > = SignalsOfCanvasLayer < 'c, C >;
+        type __SignalObj < 'c > = Gd < Self >;
+        #[doc(hidden)]
+        fn __signals_from_external(gd_ref: &Gd < Self >) -> Self::SignalCollection < '_, Self > {
+            Self::SignalCollection {
+                __internal_obj: Some(gd_ref.clone()),
+            }
+        }
+    }
+    impl < 'c, C: WithSignals > std::ops::Deref for SignalsOfCanvasLayer < 'c, C > {
+        type Target = < < CanvasLayer as crate::obj::GodotClass > ::Base as WithSignals > ::SignalCollection < 'c, C >;
+        fn deref(&self) -> &Self::Target {
+            type Derived = CanvasLayer;
+            crate::private::signal_collection_to_base::< C, Derived > (self)
+        }
+    }
+    impl < 'c, C: WithSignals > std::ops::DerefMut for SignalsOfCanvasLayer < 'c, C > {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            type Derived = CanvasLayer;
+            crate::private::signal_collection_to_base_mut::< C, Derived > (self)
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,1085 @@
+#![doc = "Sidecar module for class [`EditorProperty`][crate::classes::EditorProperty].\n\nDefines related flag and enum types. In GDScript, those are nested under the class scope.\n\nSee also [Godot docs for `EditorProperty` enums](https://docs.godotengine.org/en/stable/classes/class_editorproperty.html#enumerations).\n\n"]
+use godot_ffi as sys;
+use crate::builtin::*;
+use crate::meta::{
+    AsArg, ClassId, CowArg, InParamTuple, OutParamTuple, ParamTuple, RefArg, Signature
+};
+use crate::classes::native::*;
+use crate::classes::Object;
+use crate::obj::Gd;
+use crate::sys::GodotFfi as _;
+use crate::classes::notify::*;
+use std::ffi::c_void;
+pub(super) mod re_export {
+    use super::*;
+    #[doc = "Godot class `EditorProperty.`\n\nInherits [`Container`][crate::classes::Container].\n\nRelated symbols:\n\n* [`editor_property`][crate::classes::editor_property]: sidecar module with related enum/flag types\n* [`IEditorProperty`][crate::classes::IEditorProperty]: virtual methods\n* [`SignalsOfEditorProperty`][crate::classes::editor_property::SignalsOfEditorProperty]: signal collection\n\n\nSee also [Godot docs for `EditorProperty`](https://docs.godotengine.org/en/stable/classes/class_editorproperty.html).\n\n"]
+    #[doc = "# Construction\n\nThis class is manually managed. You can create a new instance using [`EditorProperty::new_alloc()`][crate::obj::NewAlloc::new_alloc].\n\nDo not forget to call [`free()`][crate::obj::Gd::free] or hand over ownership to Godot."]
+    #[derive(Debug)]
+    #[repr(C)]
+    pub struct EditorProperty {
+        object_ptr: sys::GDExtensionObjectPtr, rtti: Option < crate::private::ObjectRtti >,
+    }
+    #[doc = "# Interface trait for class [`EditorProperty`][crate::classes::EditorProperty].\n\nFunctions in this trait represent constructors (`init`) or virtual method callbacks invoked by the engine.\n\n\n\n# Related symbols\n\nBase interfaces: [`IContainer`][crate::classes::IContainer] > [`IControl`][crate::classes::IControl] > ~~`ICanvasItem`~~ > [`INode`][crate::classes::INode] > [`IObject`][crate::classes::IObject].  \n(Strike-through means some intermediate Godot classes are marked final, and can thus not be inherited by GDExtension.)\n\n\n\nSee also [Godot docs for `EditorProperty` methods](https://docs.godotengine.org/en/stable/classes/class_editorproperty.html#methods)."]
+    #[doc = ""]
+    #[allow(unused_variables)]
+    #[allow(clippy::unimplemented)]
+    pub trait IEditorProperty: crate::obj::GodotClass < Base = EditorProperty > + crate::private::You_forgot_the_attribute__godot_api {
+        #[doc(hidden)]
+        fn register_class(builder: &mut crate::builder::ClassBuilder < Self >) {
+            unimplemented !()
+        }
+        #[doc = r" Godot constructor, accepting an injected `base` object."]
+        #[doc = r""]
+        #[doc = r" `base` refers to the base instance of the class, which can either be stored in a `Base<T>` field or discarded."]
+        #[doc = r" This method returns a fully-constructed instance, which will then be moved into a [`Gd<T>`][crate::obj::Gd] pointer."]
+        #[doc = r""]
+        #[doc = r" If the class has a `#[class(init)]` attribute, this method will be auto-generated and must not be overridden."]
+        fn init(base: crate::obj::Base < Self::Base >) -> Self {
+            unimplemented !()
+        }
+        #[doc = r" String representation of the Godot instance."]
+        #[doc = r""]
+        #[doc = r" Override this method to define how the instance is represented as a string."]
+        #[doc = r" Used by `impl Display for Gd<T>`, as well as `str()` and `print()` in GDScript."]
+        fn to_string(&self) -> crate::builtin::GString {
+            unimplemented !()
+        }
+        #[doc = r" Called when the object receives a Godot notification."]
+        #[doc = r""]
+        #[doc = r" The type of notification can be identified through `what`. The enum is designed to hold all possible `NOTIFICATION_*`"]
+        #[doc = r" constants that the current class can handle. However, this is not validated in Godot, so an enum variant `Unknown` exists"]
+        #[doc = r" to represent integers out of known constants (mistakes or future additions)."]
+        #[doc = r""]
+        #[doc = r" This method is named `_notification` in Godot, but `on_notification` in Rust. To _send_ notifications, use the"]
+        #[doc = r" [`Object::notify`][crate::classes::Object::notify] method."]
+        #[doc = r""]
+        #[doc = r" See also in Godot docs:"]
+        #[doc = r" * [`Object::_notification`](https://docs.godotengine.org/en/stable/classes/class_object.html#class-object-method-notification)."]
+        #[doc = r" * [Notifications tutorial](https://docs.godotengine.org/en/stable/tutorials/best_practices/godot_notifications.html)."]
+        fn on_notification(&mut self, what: ContainerNotification) {
+            unimplemented !()
+        }
+        #[doc = r" Called whenever [`get()`](crate::classes::Object::get) is called or Godot gets the value of a property."]
+        #[doc = r""]
+        #[doc = r" Should return the given `property`'s value as `Some(value)`, or `None` if the property should be handled normally."]
+        #[doc = r""]
+        #[doc = r" See also in Godot docs:"]
+        #[doc = r" * [`Object::_get`](https://docs.godotengine.org/en/stable/classes/class_object.html#class-object-private-method-get)."]
+        fn get_property(&self, property: StringName) -> Option < Variant > {
+            unimplemented !()
+        }
+        #[doc = r" Called whenever Godot [`set()`](crate::classes::Object::set) is called or Godot sets the value of a property."]
+        #[doc = r""]
+        #[doc = r" Should set `property` to the given `value` and return `true`, or return `false` to indicate the `property`"]
+        #[doc = r" should be handled normally."]
+        #[doc = r""]
+        #[doc = r" See also in Godot docs:"]
+        #[doc = r" * [`Object::_set`](https://docs.godotengine.org/en/stable/classes/class_object.html#class-object-private-method-set)."]
+        fn set_property(&mut self, property: StringName, value: Variant) -> bool {
+            unimplemented !()
+        }
+        #[doc = r" Called whenever Godot [`get_property_list()`](crate::classes::Object::get_property_list) is called, the returned vector here is"]
+        #[doc = r" appended to the existing list of properties."]
+        #[doc = r""]
+        #[doc = r" This should mainly be used for advanced purposes, such as dynamically updating the property list in the editor."]
+        #[doc = r""]
+        #[doc = r" See also in Godot docs:"]
+        #[doc = r" * [`Object::_get_property_list`](https://docs.godotengine.org/en/latest/classes/class_object.html#class-object-private-method-get-property-list)"]
+        #[cfg(since_api = "4.3")]
+        #[cfg_attr(published_docs, doc(cfg(since_api = "4.3")))]
+        fn get_property_list(&mut self) -> Vec < crate::meta::PropertyInfo > {
+            unimplemented !()
+        }
+        #[doc = r" Called whenever Godot retrieves value of property. Allows to customize existing properties."]
+        #[doc = r" Every property info goes through this method, except properties **added** with `get_property_list()`."]
+        #[doc = r""]
+        #[doc = r" Exposed `property` here is a shared mutable reference obtained (and returned to) from Godot."]
+        #[doc = r""]
+        #[doc = r" See also in the Godot docs:"]
+        #[doc = r" * [`Object::_validate_property`](https://docs.godotengine.org/en/stable/classes/class_object.html#class-object-private-method-validate-property)"]
+        fn validate_property(&self, property: &mut crate::meta::PropertyInfo) {
+            unimplemented !()
+        }
+        #[doc = r" Called by Godot to tell if a property has a custom revert or not."]
+        #[doc = r""]
+        #[doc = r" Return `None` for no custom revert, and return `Some(value)` to specify the custom revert."]
+        #[doc = r""]
+        #[doc = r" This is a combination of Godot's [`Object::_property_get_revert`] and [`Object::_property_can_revert`]. This means that this"]
+        #[doc = r" function will usually be called twice by Godot to find the revert."]
+        #[doc = r""]
+        #[doc = r" Note that this should be a _pure_ function. That is, it should always return the same value for a property as long as `self`"]
+        #[doc = r" remains unchanged. Otherwise, this may lead to unexpected (safe) behavior."]
+        #[doc = r""]
+        #[doc = r" [`Object::_property_get_revert`]: https://docs.godotengine.org/en/latest/classes/class_object.html#class-object-private-method-property-get-revert"]
+        #[doc = r" [`Object::_property_can_revert`]: https://docs.godotengine.org/en/latest/classes/class_object.html#class-object-private-method-property-can-revert"]
+        #[doc(alias = "property_can_revert")]
+        fn property_get_revert(&self, property: StringName) -> Option < Variant > {
+            unimplemented !()
+        }
+        fn update_property(&mut self,) {
+            unimplemented !()
+        }
+        fn set_read_only(&mut self, read_only: bool,) {
+            unimplemented !()
+        }
+        fn get_allowed_size_flags_horizontal(&self,) -> PackedInt32Array {
+            unimplemented !()
+        }
+        fn get_allowed_size_flags_vertical(&self,) -> PackedInt32Array {
+            unimplemented !()
+        }
+        fn has_point(&self, point: Vector2,) -> bool {
+            unimplemented !()
+        }
+        fn structured_text_parser(&self, args: VarArray, text: GString,) -> Array < Vector3i > {
+            unimplemented !()
+        }
+        fn get_minimum_size(&self,) -> Vector2 {
+            unimplemented !()
+        }
+        fn get_tooltip(&self, at_position: Vector2,) -> GString {
+            unimplemented !()
+        }
+        fn get_drag_data(&mut self, at_position: Vector2,) -> Variant {
+            unimplemented !()
+        }
+        fn can_drop_data(&self, at_position: Vector2, data: Variant,) -> bool {
+            unimplemented !()
+        }
+        fn drop_data(&mut self, at_position: Vector2, data: Variant,) {
+            unimplemented !()
+        }
+        fn make_custom_tooltip(&self, for_text: GString,) -> Option < Gd < crate::classes::Object > > {
+            unimplemented !()
+        }
+        fn accessibility_get_contextual_info(&self,) -> GString {
+            unimplemented !()
+        }
+        fn get_accessibility_container_name(&self, node: Option < Gd < crate::classes::Node > >,) -> GString {
+            unimplemented !()
+        }
+        fn gui_input(&mut self, event: Gd < crate::classes::InputEvent >,) {
+            unimplemented !()
+        }
+        fn draw(&mut self,) {
+            unimplemented !()
+        }
+        fn process(&mut self, delta: f64,) {
+            unimplemented !()
+        }
+        fn physics_process(&mut self, delta: f64,) {
+            unimplemented !()
+        }
+        fn enter_tree(&mut self,) {
+            unimplemented !()
+        }
+        fn exit_tree(&mut self,) {
+            unimplemented !()
+        }
+        fn ready(&mut self,) {
+            unimplemented !()
+        }
+        fn get_configuration_warnings(&self,) -> PackedStringArray {
+            unimplemented !()
+        }
+        fn get_accessibility_configuration_warnings(&self,) -> PackedStringArray {
+            unimplemented !()
+        }
+        fn input(&mut self, event: Gd < crate::classes::InputEvent >,) {
+            unimplemented !()
+        }
+        fn shortcut_input(&mut self, event: Gd < crate::classes::InputEvent >,) {
+            unimplemented !()
+        }
+        fn unhandled_input(&mut self, event: Gd < crate::classes::InputEvent >,) {
+            unimplemented !()
+        }
+        fn unhandled_key_input(&mut self, event: Gd < crate::classes::InputEvent >,) {
+            unimplemented !()
+        }
+        fn get_focused_accessibility_element(&self,) -> Rid {
+            unimplemented !()
+        }
+    }
+    impl EditorProperty {
+        pub fn set_label(&mut self, text: impl AsArg < GString >,) {
+            type CallRet = ();
+            type CallParams < 'a0, > = (CowArg < 'a0, GString >,);
+            let args = (text.into_arg(),);
+            unsafe {
+                let method_bind = sys::class_editor_api() . fptr_by_index(308usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "EditorProperty", "set_label", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn get_label(&self,) -> GString {
+            type CallRet = GString;
+            type CallParams = ();
+            let args = ();
+            unsafe {
+                let method_bind = sys::class_editor_api() . fptr_by_index(309usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "EditorProperty", "get_label", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn set_read_only(&mut self, read_only: bool,) {
+            type CallRet = ();
+            type CallParams = (bool,);
+            let args = (read_only,);
+            unsafe {
+                let method_bind = sys::class_editor_api() . fptr_by_index(310usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "EditorProperty", "set_read_only", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn is_read_only(&self,) -> bool {
+            type CallRet = bool;
+            type CallParams = ();
+            let args = ();
+            unsafe {
+                let method_bind = sys::class_editor_api() . fptr_by_index(311usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "EditorProperty", "is_read_only", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn set_draw_label(&mut self, draw_label: bool,) {
+            type CallRet = ();
+            type CallParams = (bool,);
+            let args = (draw_label,);
+            unsafe {
+                let method_bind = sys::class_editor_api() . fptr_by_index(312usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "EditorProperty", "set_draw_label", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn is_draw_label(&self,) -> bool {
+            type CallRet = bool;
+            type CallParams = ();
+            let args = ();
+            unsafe {
+                let method_bind = sys::class_editor_api() . fptr_by_index(313usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "EditorProperty", "is_draw_label", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn set_draw_background(&mut self, draw_background: bool,) {
+            type CallRet = ();
+            type CallParams = (bool,);
+            let args = (draw_background,);
+            unsafe {
+                let method_bind = sys::class_editor_api() . fptr_by_index(314usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "EditorProperty", "set_draw_background", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn is_draw_background(&self,) -> bool {
+            type CallRet = bool;
+            type CallParams = ();
+            let args = ();
+            unsafe {
+                let method_bind = sys::class_editor_api() . fptr_by_index(315usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "EditorProperty", "is_draw_background", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn set_checkable(&mut self, checkable: bool,) {
+            type CallRet = ();
+            type CallParams = (bool,);
+            let args = (checkable,);
+            unsafe {
+                let method_bind = sys::class_editor_api() . fptr_by_index(316usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "EditorProperty", "set_checkable", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn is_checkable(&self,) -> bool {
+            type CallRet = bool;
+            type CallParams = ();
+            let args = ();
+            unsafe {
+                let method_bind = sys::class_editor_api() . fptr_by_index(317usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "EditorProperty", "is_checkable", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn set_checked(&mut self, checked: bool,) {
+            type CallRet = ();
+            type CallParams = (bool,);
+            let args = (checked,);
+            unsafe {
+                let method_bind = sys::class_editor_api() . fptr_by_index(318usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "EditorProperty", "set_checked", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn is_checked(&self,) -> bool {
+            type CallRet = bool;
+            type CallParams = ();
+            let args = ();
+            unsafe {
+                let method_bind = sys::class_editor_api() . fptr_by_index(319usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "EditorProperty", "is_checked", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn set_draw_warning(&mut self, draw_warning: bool,) {
+            type CallRet = ();
+            type CallParams = (bool,);
+            let args = (draw_warning,);
+            unsafe {
+                let method_bind = sys::class_editor_api() . fptr_by_index(320usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "EditorProperty", "set_draw_warning", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn is_draw_warning(&self,) -> bool {
+            type CallRet = bool;
+            type CallParams = ();
+            let args = ();
+            unsafe {
+                let method_bind = sys::class_editor_api() . fptr_by_index(321usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "EditorProperty", "is_draw_warning", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn set_keying(&mut self, keying: bool,) {
+            type CallRet = ();
+            type CallParams = (bool,);
+            let args = (keying,);
+            unsafe {
+                let method_bind = sys::class_editor_api() . fptr_by_index(322usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "EditorProperty", "set_keying", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn is_keying(&self,) -> bool {
+            type CallRet = bool;
+            type CallParams = ();
+            let args = ();
+            unsafe {
+                let method_bind = sys::class_editor_api() . fptr_by_index(323usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "EditorProperty", "is_keying", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn set_deletable(&mut self, deletable: bool,) {
+            type CallRet = ();
+            type CallParams = (bool,);
+            let args = (deletable,);
+            unsafe {
+                let method_bind = sys::class_editor_api() . fptr_by_index(324usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "EditorProperty", "set_deletable", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn is_deletable(&self,) -> bool {
+            type CallRet = bool;
+            type CallParams = ();
+            let args = ();
+            unsafe {
+                let method_bind = sys::class_editor_api() . fptr_by_index(325usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "EditorProperty", "is_deletable", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn get_edited_property(&self,) -> StringName {
+            type CallRet = StringName;
+            type CallParams = ();
+            let args = ();
+            unsafe {
+                let method_bind = sys::class_editor_api() . fptr_by_index(326usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "EditorProperty", "get_edited_property", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn get_edited_object(&mut self,) -> Option < Gd < crate::classes::Object > > {
+            type CallRet = Option < Gd < crate::classes::Object > >;
+            type CallParams = ();
+            let args = ();
+            unsafe {
+                let method_bind = sys::class_editor_api() . fptr_by_index(327usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "EditorProperty", "get_edited_object", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn update_property(&mut self,) {
+            type CallRet = ();
+            type CallParams = ();
+            let args = ();
+            unsafe {
+                let method_bind = sys::class_editor_api() . fptr_by_index(328usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "EditorProperty", "update_property", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn add_focusable(&mut self, control: impl AsArg < Option < Gd < crate::classes::Control >> >,) {
+            type CallRet = ();
+            type CallParams < 'a0, > = (CowArg < 'a0, Option < Gd < crate::classes::Control > > >,);
+            let args = (control.into_arg(),);
+            unsafe {
+                let method_bind = sys::class_editor_api() . fptr_by_index(329usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "EditorProperty", "add_focusable", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn set_bottom_editor(&mut self, editor: impl AsArg < Option < Gd < crate::classes::Control >> >,) {
+            type CallRet = ();
+            type CallParams < 'a0, > = (CowArg < 'a0, Option < Gd < crate::classes::Control > > >,);
+            let args = (editor.into_arg(),);
+            unsafe {
+                let method_bind = sys::class_editor_api() . fptr_by_index(330usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "EditorProperty", "set_bottom_editor", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn set_selectable(&mut self, selectable: bool,) {
+            type CallRet = ();
+            type CallParams = (bool,);
+            let args = (selectable,);
+            unsafe {
+                let method_bind = sys::class_editor_api() . fptr_by_index(331usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "EditorProperty", "set_selectable", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn is_selectable(&self,) -> bool {
+            type CallRet = bool;
+            type CallParams = ();
+            let args = ();
+            unsafe {
+                let method_bind = sys::class_editor_api() . fptr_by_index(332usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "EditorProperty", "is_selectable", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn set_use_folding(&mut self, use_folding: bool,) {
+            type CallRet = ();
+            type CallParams = (bool,);
+            let args = (use_folding,);
+            unsafe {
+                let method_bind = sys::class_editor_api() . fptr_by_index(333usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "EditorProperty", "set_use_folding", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn is_using_folding(&self,) -> bool {
+            type CallRet = bool;
+            type CallParams = ();
+            let args = ();
+            unsafe {
+                let method_bind = sys::class_editor_api() . fptr_by_index(334usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "EditorProperty", "is_using_folding", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn set_name_split_ratio(&mut self, ratio: f32,) {
+            type CallRet = ();
+            type CallParams = (f32,);
+            let args = (ratio,);
+            unsafe {
+                let method_bind = sys::class_editor_api() . fptr_by_index(335usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "EditorProperty", "set_name_split_ratio", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn get_name_split_ratio(&self,) -> f32 {
+            type CallRet = f32;
+            type CallParams = ();
+            let args = ();
+            unsafe {
+                let method_bind = sys::class_editor_api() . fptr_by_index(336usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "EditorProperty", "get_name_split_ratio", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn deselect(&mut self,) {
+            type CallRet = ();
+            type CallParams = ();
+            let args = ();
+            unsafe {
+                let method_bind = sys::class_editor_api() . fptr_by_index(337usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "EditorProperty", "deselect", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn is_selected(&self,) -> bool {
+            type CallRet = bool;
+            type CallParams = ();
+            let args = ();
+            unsafe {
+                let method_bind = sys::class_editor_api() . fptr_by_index(338usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "EditorProperty", "is_selected", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub(crate) fn select_full(&mut self, focusable: i32,) {
+            type CallRet = ();
+            type CallParams = (i32,);
+            let args = (focusable,);
+            unsafe {
+                let method_bind = sys::class_editor_api() . fptr_by_index(339usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "EditorProperty", "select", Some(self.__validated_obj()), args,)
+            }
+        }
+        #[doc = "To set the default parameters, use [`Self::select_ex`] and its builder methods.  See [the book](https://godot-rust.github.io/book/godot-api/functions.html#default-parameters) for detailed usage instructions."]
+        #[inline]
+        pub fn select(&mut self,) {
+            self.select_ex() . done()
+        }
+        #[inline]
+        pub fn select_ex < 'a > (&'a mut self,) -> ExSelect < 'a > {
+            ExSelect::new(self,)
+        }
+        pub fn set_object_and_property(&mut self, object: impl AsArg < Option < Gd < crate::classes::Object >> >, property: impl AsArg < StringName >,) {
+            type CallRet = ();
+            type CallParams < 'a0, 'a1, > = (CowArg < 'a0, Option < Gd < crate::classes::Object > > >, CowArg < 'a1, StringName >,);
+            let args = (object.into_arg(), property.into_arg(),);
+            unsafe {
+                let method_bind = sys::class_editor_api() . fptr_by_index(340usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "EditorProperty", "set_object_and_property", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn set_label_reference(&mut self, control: impl AsArg < Option < Gd < crate::classes::Control >> >,) {
+            type CallRet = ();
+            type CallParams < 'a0, > = (CowArg < 'a0, Option < Gd < crate::classes::Control > > >,);
+            let args = (control.into_arg(),);
+            unsafe {
+                let method_bind = sys::class_editor_api() . fptr_by_index(341usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "EditorProperty", "set_label_reference", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub(crate) fn emit_changed_full(&mut self, property: CowArg < StringName >, value: RefArg < Variant >, field: CowArg < StringName >, changing: bool,) {
+            type CallRet = ();
+            type CallParams < 'a0, 'a1, 'a2, > = (CowArg < 'a0, StringName >, RefArg < 'a1, Variant >, CowArg < 'a2, StringName >, bool,);
+            let args = (property, value, field, changing,);
+            unsafe {
+                let method_bind = sys::class_editor_api() . fptr_by_index(342usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "EditorProperty", "emit_changed", Some(self.__validated_obj()), args,)
+            }
+        }
+        #[doc = "To set the default parameters, use [`Self::emit_changed_ex`] and its builder methods.  See [the book](https://godot-rust.github.io/book/godot-api/functions.html#default-parameters) for detailed usage instructions."]
+        #[inline]
+        pub fn emit_changed(&mut self, property: impl AsArg < StringName >, value: &Variant,) {
+            self.emit_changed_ex(property, value,) . done()
+        }
+        #[inline]
+        pub fn emit_changed_ex < 'a > (&'a mut self, property: impl AsArg < StringName > + 'a, value: &'a Variant,) -> ExEmitChanged < 'a > {
+            ExEmitChanged::new(self, property, value,)
+        }
+        #[doc = r" Creates a validated object for FFI boundary crossing."]
+        #[doc = r""]
+        #[doc = r" Low-level internal method. Validation (liveness/type checks) depend on safeguard level."]
+        fn __validated_obj(&self) -> crate::obj::ValidatedObject {
+            let raw_gd = unsafe {
+                std::mem::transmute::< &Self, &crate::obj::RawGd < Self >> (self)
+            };
+            raw_gd.validated_object()
+        }
+        #[doc(hidden)]
+        pub fn __object_ptr(&self) -> sys::GDExtensionObjectPtr {
+            self.object_ptr
+        }
+    }
+    impl crate::obj::GodotClass for EditorProperty {
+        type Base = crate::classes::Container;
+        fn class_id() -> ClassId {
+            static CLASS_ID: std::sync::OnceLock < ClassId > = std::sync::OnceLock::new();
+            let name: &'static ClassId = CLASS_ID.get_or_init(|| ClassId::__alloc_next_unicode("EditorProperty"));
+            * name
+        }
+        const INIT_LEVEL: crate::init::InitLevel = crate::init::InitLevel::Editor;
+        
+    }
+    unsafe impl crate::obj::Bounds for EditorProperty {
+        type Memory = crate::obj::bounds::MemManual;
+        type DynMemory = crate::obj::bounds::MemManual;
+        type Declarer = crate::obj::bounds::DeclEngine;
+        type Exportable = crate::obj::bounds::Yes;
+        
+    }
+    unsafe impl crate::obj::Inherits < crate::classes::Container > for EditorProperty {
+        
+    }
+    unsafe impl crate::obj::Inherits < crate::classes::Control > for EditorProperty {
+        
+    }
+    unsafe impl crate::obj::Inherits < crate::classes::CanvasItem > for EditorProperty {
+        
+    }
+    unsafe impl crate::obj::Inherits < crate::classes::Node > for EditorProperty {
+        
+    }
+    unsafe impl crate::obj::Inherits < crate::classes::Object > for EditorProperty {
+        
+    }
+    impl crate::obj::cap::GodotDefault for EditorProperty {
+        fn __godot_default() -> crate::obj::Gd < Self > {
+            crate::classes::construct_engine_object::< Self > ()
+        }
+    }
+    impl std::ops::Deref for EditorProperty {
+        type Target = crate::classes::Container;
+        fn deref(&self) -> &Self::Target {
+            unsafe {
+                std::mem::transmute::< &Self, &Self::Target > (self)
+            }
+        }
+    }
+    impl std::ops::DerefMut for EditorProperty {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            unsafe {
+                std::mem::transmute::< &mut Self, &mut Self::Target > (self)
+            }
+        }
+    }
+    #[doc = r" # Safety"]
+    #[doc = r""]
+    #[doc = "The provided class must be a subclass of all the superclasses of [`EditorProperty`]"]
+    #[macro_export]
+    #[allow(non_snake_case)]
+    macro_rules !inherit_from_EditorProperty__ensure_class_exists {
+        ($Class: ident) => {
+            unsafe impl::godot::obj::Inherits < ::godot::classes::EditorProperty > for $Class {
+                
+            }
+            unsafe impl::godot::obj::Inherits < ::godot::classes::Container > for $Class {
+                
+            }
+            unsafe impl::godot::obj::Inherits < ::godot::classes::Control > for $Class {
+                
+            }
+            unsafe impl::godot::obj::Inherits < ::godot::classes::CanvasItem > for $Class {
+                
+            }
+            unsafe impl::godot::obj::Inherits < ::godot::classes::Node > for $Class {
+                
+            }
+            unsafe impl::godot::obj::Inherits < ::godot::classes::Object > for $Class {
+                
+            }
+        }
+    }
+}
+#[doc = "Default-param extender for [`EditorProperty::select_ex`][super::EditorProperty::select_ex]."]
+#[must_use]
+pub struct ExSelect < 'a > {
+    _phantom: std::marker::PhantomData < &'a() >, surround_object: &'a mut re_export::EditorProperty, focusable: i32,
+}
+#[allow(clippy::wrong_self_convention, clippy::redundant_field_names, clippy::needless_update)]
+impl < 'a > ExSelect < 'a > {
+    fn new(surround_object: &'a mut re_export::EditorProperty,) -> Self {
+        let focusable = - 1i32;
+        Self {
+            _phantom: std::marker::PhantomData, surround_object: surround_object, focusable: focusable,
+        }
+    }
+    #[inline]
+    pub fn focusable(self, focusable: i32) -> Self {
+        Self {
+            focusable: focusable, .. self
+        }
+    }
+    #[inline]
+    pub fn done(self) {
+        let Self {
+            _phantom, surround_object, focusable,
+        }
+        = self;
+        re_export::EditorProperty::select_full(surround_object, focusable,)
+    }
+}
+#[doc = "Default-param extender for [`EditorProperty::emit_changed_ex`][super::EditorProperty::emit_changed_ex]."]
+#[must_use]
+pub struct ExEmitChanged < 'a > {
+    _phantom: std::marker::PhantomData < &'a() >, surround_object: &'a mut re_export::EditorProperty, property: CowArg < 'a, StringName >, value: CowArg < 'a, Variant >, field: CowArg < 'a, StringName >, changing: bool,
+}
+#[allow(clippy::wrong_self_convention, clippy::redundant_field_names, clippy::needless_update)]
+impl < 'a > ExEmitChanged < 'a > {
+    fn new(surround_object: &'a mut re_export::EditorProperty, property: impl AsArg < StringName > + 'a, value: &'a Variant,) -> Self {
+        let field = StringName::from("");
+        let changing = false;
+        Self {
+            _phantom: std::marker::PhantomData, surround_object: surround_object, property: property.into_arg(), value: CowArg::Borrowed(value), field: CowArg::Owned(field), changing: changing,
+        }
+    }
+    #[inline]
+    pub fn field(self, field: impl AsArg < StringName > + 'a) -> Self {
+        Self {
+            field: field.into_arg(), .. self
+        }
+    }
+    #[inline]
+    pub fn changing(self, changing: bool) -> Self {
+        Self {
+            changing: changing, .. self
+        }
+    }
+    #[inline]
+    pub fn done(self) {
+        let Self {
+            _phantom, surround_object, property, value, field, changing,
+        }
+        = self;
+        re_export::EditorProperty::emit_changed_full(surround_object, property, value.cow_as_arg(), field, changing,)
+    }
+}
+pub use signals::*;
+mod signals {
+    use crate::obj::{
+        Gd, GodotClass
+    };
+    use super::re_export::EditorProperty;
+    use crate::registry::signal::TypedSignal;
+    use super::*;
+    #[doc = "A collection of signals for the [`EditorProperty`][crate::classes::EditorProperty] class."]
+    pub struct SignalsOfEditorProperty < 'c, C: WithSignals > {
+        #[doc(hidden)]
+        pub(crate) __internal_obj: Option < C::__SignalObj < 'c >>,
+    }
+    impl < 'c, C: WithSignals > SignalsOfEditorProperty < 'c, C > {
+        #[doc = "Signature: `(property: StringName, value: Variant, field: StringName, changing: bool)`"]
+        pub fn property_changed(&mut self) -> SigPropertyChanged < 'c, C > {
+            SigPropertyChanged {
+                typed: TypedSignal::extract(&mut self.__internal_obj, "property_changed")
+            }
+        }
+        #[doc = "Signature: `(properties: PackedStringArray, value: VarArray)`"]
+        pub fn multiple_properties_changed(&mut self) -> SigMultiplePropertiesChanged < 'c, C > {
+            SigMultiplePropertiesChanged {
+                typed: TypedSignal::extract(&mut self.__internal_obj, "multiple_properties_changed")
+            }
+        }
+        #[doc = "Signature: `(property: StringName)`"]
+        pub fn property_keyed(&mut self) -> SigPropertyKeyed < 'c, C > {
+            SigPropertyKeyed {
+                typed: TypedSignal::extract(&mut self.__internal_obj, "property_keyed")
+            }
+        }
+        #[doc = "Signature: `(property: StringName)`"]
+        pub fn property_deleted(&mut self) -> SigPropertyDeleted < 'c, C > {
+            SigPropertyDeleted {
+                typed: TypedSignal::extract(&mut self.__internal_obj, "property_deleted")
+            }
+        }
+        #[doc = "Signature: `(property: StringName, value: Variant)`"]
+        pub fn property_keyed_with_value(&mut self) -> SigPropertyKeyedWithValue < 'c, C > {
+            SigPropertyKeyedWithValue {
+                typed: TypedSignal::extract(&mut self.__internal_obj, "property_keyed_with_value")
+            }
+        }
+        #[doc = "Signature: `(property: StringName, checked: bool)`"]
+        pub fn property_checked(&mut self) -> SigPropertyChecked < 'c, C > {
+            SigPropertyChecked {
+                typed: TypedSignal::extract(&mut self.__internal_obj, "property_checked")
+            }
+        }
+        #[doc = "Signature: `()`"]
+        pub fn property_overridden(&mut self) -> SigPropertyOverridden < 'c, C > {
+            SigPropertyOverridden {
+                typed: TypedSignal::extract(&mut self.__internal_obj, "property_overridden")
+            }
+        }
+        #[doc = "Signature: `(property: StringName, favorited: bool)`"]
+        pub fn property_favorited(&mut self) -> SigPropertyFavorited < 'c, C > {
+            SigPropertyFavorited {
+                typed: TypedSignal::extract(&mut self.__internal_obj, "property_favorited")
+            }
+        }
+        #[doc = "Signature: `(property: StringName, pinned: bool)`"]
+        pub fn property_pinned(&mut self) -> SigPropertyPinned < 'c, C > {
+            SigPropertyPinned {
+                typed: TypedSignal::extract(&mut self.__internal_obj, "property_pinned")
+            }
+        }
+        #[doc = "Signature: `(property: StringName, can_revert: bool)`"]
+        pub fn property_can_revert_changed(&mut self) -> SigPropertyCanRevertChanged < 'c, C > {
+            SigPropertyCanRevertChanged {
+                typed: TypedSignal::extract(&mut self.__internal_obj, "property_can_revert_changed")
+            }
+        }
+        #[doc = "Signature: `(path: GString, resource: Gd<Resource>)`"]
+        pub fn resource_selected(&mut self) -> SigResourceSelected < 'c, C > {
+            SigResourceSelected {
+                typed: TypedSignal::extract(&mut self.__internal_obj, "resource_selected")
+            }
+        }
+        #[doc = "Signature: `(property: StringName, id: i64)`"]
+        pub fn object_id_selected(&mut self) -> SigObjectIdSelected < 'c, C > {
+            SigObjectIdSelected {
+                typed: TypedSignal::extract(&mut self.__internal_obj, "object_id_selected")
+            }
+        }
+        #[doc = "Signature: `(path: GString, focusable_idx: i64)`"]
+        pub fn selected(&mut self) -> SigSelected < 'c, C > {
+            SigSelected {
+                typed: TypedSignal::extract(&mut self.__internal_obj, "selected")
+            }
+        }
+    }
+    type TypedSigPropertyChanged < 'c, C > = TypedSignal < 'c, C, (StringName, Variant, StringName, bool,) >;
+    pub struct SigPropertyChanged < 'c, C: WithSignals > {
+        typed: TypedSigPropertyChanged < 'c, C >,
+    }
+    impl < 'c, C: WithSignals > SigPropertyChanged < 'c, C > {
+        pub fn emit(&mut self, property: StringName, value: Variant, field: StringName, changing: bool,) {
+            self.typed.emit_tuple((property, value, field, changing,));
+            
+        }
+    }
+    impl < 'c, C: WithSignals > std::ops::Deref for SigPropertyChanged < 'c, C > {
+        type Target = TypedSigPropertyChanged < 'c, C >;
+        fn deref(&self) -> &Self::Target {
+            &self.typed
+        }
+    }
+    impl < C: WithSignals > std::ops::DerefMut for SigPropertyChanged < '_, C > {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            &mut self.typed
+        }
+    }
+    type TypedSigMultiplePropertiesChanged < 'c, C > = TypedSignal < 'c, C, (PackedStringArray, VarArray,) >;
+    pub struct SigMultiplePropertiesChanged < 'c, C: WithSignals > {
+        typed: TypedSigMultiplePropertiesChanged < 'c, C >,
+    }
+    impl < 'c, C: WithSignals > SigMultiplePropertiesChanged < 'c, C > {
+        pub fn emit(&mut self, properties: PackedStringArray, value: VarArray,) {
+            self.typed.emit_tuple((properties, value,));
+            
+        }
+    }
+    impl < 'c, C: WithSignals > std::ops::Deref for SigMultiplePropertiesChanged < 'c, C > {
+        type Target = TypedSigMultiplePropertiesChanged < 'c, C >;
+        fn deref(&self) -> &Self::Target {
+            &self.typed
+        }
+    }
+    impl < C: WithSignals > std::ops::DerefMut for SigMultiplePropertiesChanged < '_, C > {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            &mut self.typed
+        }
+    }
+    type TypedSigPropertyKeyed < 'c, C > = TypedSignal < 'c, C, (StringName,) >;
+    pub struct SigPropertyKeyed < 'c, C: WithSignals > {
+        typed: TypedSigPropertyKeyed < 'c, C >,
+    }
+    impl < 'c, C: WithSignals > SigPropertyKeyed < 'c, C > {
+        pub fn emit(&mut self, property: StringName,) {
+            self.typed.emit_tuple((property,));
+            
+        }
+    }
+    impl < 'c, C: WithSignals > std::ops::Deref for SigPropertyKeyed < 'c, C > {
+        type Target = TypedSigPropertyKeyed < 'c, C >;
+        fn deref(&self) -> &Self::Target {
+            &self.typed
+        }
+    }
+    impl < C: WithSignals > std::ops::DerefMut for SigPropertyKeyed < '_, C > {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            &mut self.typed
+        }
+    }
+    type TypedSigPropertyDeleted < 'c, C > = TypedSignal < 'c, C, (StringName,) >;
+    pub struct SigPropertyDeleted < 'c, C: WithSignals > {
+        typed: TypedSigPropertyDeleted < 'c, C >,
+    }
+    impl < 'c, C: WithSignals > SigPropertyDeleted < 'c, C > {
+        pub fn emit(&mut self, property: StringName,) {
+            self.typed.emit_tuple((property,));
+            
+        }
+    }
+    impl < 'c, C: WithSignals > std::ops::Deref for SigPropertyDeleted < 'c, C > {
+        type Target = TypedSigPropertyDeleted < 'c, C >;
+        fn deref(&self) -> &Self::Target {
+            &self.typed
+        }
+    }
+    impl < C: WithSignals > std::ops::DerefMut for SigPropertyDeleted < '_, C > {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            &mut self.typed
+        }
+    }
+    type TypedSigPropertyKeyedWithValue < 'c, C > = TypedSignal < 'c, C, (StringName, Variant,) >;
+    pub struct SigPropertyKeyedWithValue < 'c, C: WithSignals > {
+        typed: TypedSigPropertyKeyedWithValue < 'c, C >,
+    }
+    impl < 'c, C: WithSignals > SigPropertyKeyedWithValue < 'c, C > {
+        pub fn emit(&mut self, property: StringName, value: Variant,) {
+            self.typed.emit_tuple((property, value,));
+            
+        }
+    }
+    impl < 'c, C: WithSignals > std::ops::Deref for SigPropertyKeyedWithValue < 'c, C > {
+        type Target = TypedSigPropertyKeyedWithValue < 'c, C >;
+        fn deref(&self) -> &Self::Target {
+            &self.typed
+        }
+    }
+    impl < C: WithSignals > std::ops::DerefMut for SigPropertyKeyedWithValue < '_, C > {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            &mut self.typed
+        }
+    }
+    type TypedSigPropertyChecked < 'c, C > = TypedSignal < 'c, C, (StringName, bool,) >;
+    pub struct SigPropertyChecked < 'c, C: WithSignals > {
+        typed: TypedSigPropertyChecked < 'c, C >,
+    }
+    impl < 'c, C: WithSignals > SigPropertyChecked < 'c, C > {
+        pub fn emit(&mut self, property: StringName, checked: bool,) {
+            self.typed.emit_tuple((property, checked,));
+            
+        }
+    }
+    impl < 'c, C: WithSignals > std::ops::Deref for SigPropertyChecked < 'c, C > {
+        type Target = TypedSigPropertyChecked < 'c, C >;
+        fn deref(&self) -> &Self::Target {
+            &self.typed
+        }
+    }
+    impl < C: WithSignals > std::ops::DerefMut for SigPropertyChecked < '_, C > {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            &mut self.typed
+        }
+    }
+    type TypedSigPropertyOverridden < 'c, C > = TypedSignal < 'c, C, () >;
+    pub struct SigPropertyOverridden < 'c, C: WithSignals > {
+        typed: TypedSigPropertyOverridden < 'c, C >,
+    }
+    impl < 'c, C: WithSignals > SigPropertyOverridden < 'c, C > {
+        pub fn emit(&mut self,) {
+            self.typed.emit_tuple(());
+            
+        }
+    }
+    impl < 'c, C: WithSignals > std::ops::Deref for SigPropertyOverridden < 'c, C > {
+        type Target = TypedSigPropertyOverridden < 'c, C >;
+        fn deref(&self) -> &Self::Target {
+            &self.typed
+        }
+    }
+    impl < C: WithSignals > std::ops::DerefMut for SigPropertyOverridden < '_, C > {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            &mut self.typed
+        }
+    }
+    type TypedSigPropertyFavorited < 'c, C > = TypedSignal < 'c, C, (StringName, bool,) >;
+    pub struct SigPropertyFavorited < 'c, C: WithSignals > {
+        typed: TypedSigPropertyFavorited < 'c, C >,
+    }
+    impl < 'c, C: WithSignals > SigPropertyFavorited < 'c, C > {
+        pub fn emit(&mut self, property: StringName, favorited: bool,) {
+            self.typed.emit_tuple((property, favorited,));
+            
+        }
+    }
+    impl < 'c, C: WithSignals > std::ops::Deref for SigPropertyFavorited < 'c, C > {
+        type Target = TypedSigPropertyFavorited < 'c, C >;
+        fn deref(&self) -> &Self::Target {
+            &self.typed
+        }
+    }
+    impl < C: WithSignals > std::ops::DerefMut for SigPropertyFavorited < '_, C > {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            &mut self.typed
+        }
+    }
+    type TypedSigPropertyPinned < 'c, C > = TypedSignal < 'c, C, (StringName, bool,) >;
+    pub struct SigPropertyPinned < 'c, C: WithSignals > {
+        typed: TypedSigPropertyPinned < 'c, C >,
+    }
+    impl < 'c, C: WithSignals > SigPropertyPinned < 'c, C > {
+        pub fn emit(&mut self, property: StringName, pinned: bool,) {
+            self.typed.emit_tuple((property, pinned,));
+            
+        }
+    }
+    impl < 'c, C: WithSignals > std::ops::Deref for SigPropertyPinned < 'c, C > {
+        type Target = TypedSigPropertyPinned < 'c, C >;
+        fn deref(&self) -> &Self::Target {
+            &self.typed
+        }
+    }
+    impl < C: WithSignals > std::ops::DerefMut for SigPropertyPinned < '_, C > {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            &mut self.typed
+        }
+    }
+    type TypedSigPropertyCanRevertChanged < 'c, C > = TypedSignal < 'c, C, (StringName, bool,) >;
+    pub struct SigPropertyCanRevertChanged < 'c, C: WithSignals > {
+        typed: TypedSigPropertyCanRevertChanged < 'c, C >,
+    }
+    impl < 'c, C: WithSignals > SigPropertyCanRevertChanged < 'c, C > {
+        pub fn emit(&mut self, property: StringName, can_revert: bool,) {
+            self.typed.emit_tuple((property, can_revert,));
+            
+        }
+    }
+    impl < 'c, C: WithSignals > std::ops::Deref for SigPropertyCanRevertChanged < 'c, C > {
+        type Target = TypedSigPropertyCanRevertChanged < 'c, C >;
+        fn deref(&self) -> &Self::Target {
+            &self.typed
+        }
+    }
+    impl < C: WithSignals > std::ops::DerefMut for SigPropertyCanRevertChanged < '_, C > {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            &mut self.typed
+        }
+    }
+    type TypedSigResourceSelected < 'c, C > = TypedSignal < 'c, C, (GString, Gd < crate::classes::Resource >,) >;
+    pub struct SigResourceSelected < 'c, C: WithSignals > {
+        typed: TypedSigResourceSelected < 'c, C >,
+    }
+    impl < 'c, C: WithSignals > SigResourceSelected < 'c, C > {
+        pub fn emit(&mut self, path: GString, resource: Gd < crate::classes::Resource >,) {
+            self.typed.emit_tuple((path, resource,));
+            
+        }
+    }
+    impl < 'c, C: WithSignals > std::ops::Deref for SigResourceSelected < 'c, C > {
+        type Target = TypedSigResourceSelected < 'c, C >;
+        fn deref(&self) -> &Self::Target {
+            &self.typed
+        }
+    }
+    impl < C: WithSignals > std::ops::DerefMut for SigResourceSelected < '_, C > {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            &mut self.typed
+        }
+    }
+    type TypedSigObjectIdSelected < 'c, C > = TypedSignal < 'c, C, (StringName, i64,) >;
+    pub struct SigObjectIdSelected < 'c, C: WithSignals > {
+        typed: TypedSigObjectIdSelected < 'c, C >,
+    }
+    impl < 'c, C: WithSignals > SigObjectIdSelected < 'c, C > {
+        pub fn emit(&mut self, property: StringName, id: i64,) {
+            self.typed.emit_tuple((property, id,));
+            
+        }
+    }
+    impl < 'c, C: WithSignals > std::ops::Deref for SigObjectIdSelected < 'c, C > {
+        type Target = TypedSigObjectIdSelected < 'c, C >;
+        fn deref(&self) -> &Self::Target {
+            &self.typed
+        }
+    }
+    impl < C: WithSignals > std::ops::DerefMut for SigObjectIdSelected < '_, C > {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            &mut self.typed
+        }
+    }
+    type TypedSigSelected < 'c, C > = TypedSignal < 'c, C, (GString, i64,) >;
+    pub struct SigSelected < 'c, C: WithSignals > {
+        typed: TypedSigSelected < 'c, C >,
+    }
+    impl < 'c, C: WithSignals > SigSelected < 'c, C > {
+        pub fn emit(&mut self, path: GString, focusable_idx: i64,) {
+            self.typed.emit_tuple((path, focusable_idx,));
+            
+        }
+    }
+    impl < 'c, C: WithSignals > std::ops::Deref for SigSelected < 'c, C > {
+        type Target = TypedSigSelected < 'c, C >;
+        fn deref(&self) -> &Self::Target {
+            &self.typed
+        }
+    }
+    impl < C: WithSignals > std::ops::DerefMut for SigSelected < '_, C > {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            &mut self.typed
+        }
+    }
+    use crate::obj::WithSignals;
+    impl WithSignals for EditorProperty {
+        type SignalCollection < 'c, C: WithSignals > = SignalsOfEditorProperty < 'c, C >;
+        type __SignalObj < 'c > = Gd < Self >;
+        #[doc(hidden)]
+        fn __signals_from_external(gd_ref: &Gd < Self >) -> Self::SignalCollection < '_, Self > {
+            Self::SignalCollection {
+                __internal_obj: Some(gd_ref.clone()),
+            }
+        }
+    }
+    impl < 'c, C: WithSignals > std::ops::Deref for SignalsOfEditorProperty < 'c, C > {
+        type Target = < < EditorProperty as crate::obj::GodotClass > ::Base as WithSignals > ::SignalCollection < 'c, C >;
+        fn deref(&self) -> &Self::Target {
+            type Derived = EditorProperty;
+            crate::private::signal_collection_to_base::< C, Derived > (self)
+        }
+    }
+    impl < 'c, C: WithSignals > std::ops::DerefMut for SignalsOfEditorProperty < 'c, C > {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            type Derived = EditorProperty;
+            crate::private::signal_collection_to_base_mut::< C, Derived > (self)
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,572 @@
+#![doc = "Sidecar module for class [`EditorSpinSlider`][crate::classes::EditorSpinSlider].\n\nDefines related flag and enum types. In GDScript, those are nested under the class scope.\n\nSee also [Godot docs for `EditorSpinSlider` enums](https://docs.godotengine.org/en/stable/classes/class_editorspinslider.html#enumerations).\n\n"]
+use godot_ffi as sys;
+use crate::builtin::*;
+use crate::meta::{
+    AsArg, ClassId, CowArg, InParamTuple, OutParamTuple, ParamTuple, RefArg, Signature
+};
+use crate::classes::native::*;
+use crate::classes::Object;
+use crate::obj::Gd;
+use crate::sys::GodotFfi as _;
+use crate::classes::notify::*;
+use std::ffi::c_void;
+pub(super) mod re_export {
+    use super::*;
+    #[doc = "Godot class `EditorSpinSlider.`\n\nInherits [`Range`][crate::classes::Range].\n\nRelated symbols:\n\n* [`editor_spin_slider`][crate::classes::editor_spin_slider]: sidecar module with related enum/flag types\n* [`IEditorSpinSlider`][crate::classes::IEditorSpinSlider]: virtual methods\n* [`SignalsOfEditorSpinSlider`][crate::classes::editor_spin_slider::SignalsOfEditorSpinSlider]: signal collection\n\n\nSee also [Godot docs for `EditorSpinSlider`](https://docs.godotengine.org/en/stable/classes/class_editorspinslider.html).\n\n"]
+    #[doc = "# Construction\n\nThis class is manually managed. You can create a new instance using [`EditorSpinSlider::new_alloc()`][crate::obj::NewAlloc::new_alloc].\n\nDo not forget to call [`free()`][crate::obj::Gd::free] or hand over ownership to Godot."]
+    #[derive(Debug)]
+    #[repr(C)]
+    pub struct EditorSpinSlider {
+        object_ptr: sys::GDExtensionObjectPtr, rtti: Option < crate::private::ObjectRtti >,
+    }
+    #[doc = "# Interface trait for class [`EditorSpinSlider`][crate::classes::EditorSpinSlider].\n\nFunctions in this trait represent constructors (`init`) or virtual method callbacks invoked by the engine.\n\n\n\n# Related symbols\n\nBase interfaces: [`IRange`][crate::classes::IRange] > [`IControl`][crate::classes::IControl] > ~~`ICanvasItem`~~ > [`INode`][crate::classes::INode] > [`IObject`][crate::classes::IObject].  \n(Strike-through means some intermediate Godot classes are marked final, and can thus not be inherited by GDExtension.)\n\n\n\nSee also [Godot docs for `EditorSpinSlider` methods](https://docs.godotengine.org/en/stable/classes/class_editorspinslider.html#methods)."]
+    #[doc = ""]
+    #[allow(unused_variables)]
+    #[allow(clippy::unimplemented)]
+    pub trait IEditorSpinSlider: crate::obj::GodotClass < Base = EditorSpinSlider > + crate::private::You_forgot_the_attribute__godot_api {
+        #[doc(hidden)]
+        fn register_class(builder: &mut crate::builder::ClassBuilder < Self >) {
+            unimplemented !()
+        }
+        #[doc = r" Godot constructor, accepting an injected `base` object."]
+        #[doc = r""]
+        #[doc = r" `base` refers to the base instance of the class, which can either be stored in a `Base<T>` field or discarded."]
+        #[doc = r" This method returns a fully-constructed instance, which will then be moved into a [`Gd<T>`][crate::obj::Gd] pointer."]
+        #[doc = r""]
+        #[doc = r" If the class has a `#[class(init)]` attribute, this method will be auto-generated and must not be overridden."]
+        fn init(base: crate::obj::Base < Self::Base >) -> Self {
+            unimplemented !()
+        }
+        #[doc = r" String representation of the Godot instance."]
+        #[doc = r""]
+        #[doc = r" Override this method to define how the instance is represented as a string."]
+        #[doc = r" Used by `impl Display for Gd<T>`, as well as `str()` and `print()` in GDScript."]
+        fn to_string(&self) -> crate::builtin::GString {
+            unimplemented !()
+        }
+        #[doc = r" Called when the object receives a Godot notification."]
+        #[doc = r""]
+        #[doc = r" The type of notification can be identified through `what`. The enum is designed to hold all possible `NOTIFICATION_*`"]
+        #[doc = r" constants that the current class can handle. However, this is not validated in Godot, so an enum variant `Unknown` exists"]
+        #[doc = r" to represent integers out of known constants (mistakes or future additions)."]
+        #[doc = r""]
+        #[doc = r" This method is named `_notification` in Godot, but `on_notification` in Rust. To _send_ notifications, use the"]
+        #[doc = r" [`Object::notify`][crate::classes::Object::notify] method."]
+        #[doc = r""]
+        #[doc = r" See also in Godot docs:"]
+        #[doc = r" * [`Object::_notification`](https://docs.godotengine.org/en/stable/classes/class_object.html#class-object-method-notification)."]
+        #[doc = r" * [Notifications tutorial](https://docs.godotengine.org/en/stable/tutorials/best_practices/godot_notifications.html)."]
+        fn on_notification(&mut self, what: ControlNotification) {
+            unimplemented !()
+        }
+        #[doc = r" Called whenever [`get()`](crate::classes::Object::get) is called or Godot gets the value of a property."]
+        #[doc = r""]
+        #[doc = r" Should return the given `property`'s value as `Some(value)`, or `None` if the property should be handled normally."]
+        #[doc = r""]
+        #[doc = r" See also in Godot docs:"]
+        #[doc = r" * [`Object::_get`](https://docs.godotengine.org/en/stable/classes/class_object.html#class-object-private-method-get)."]
+        fn get_property(&self, property: StringName) -> Option < Variant > {
+            unimplemented !()
+        }
+        #[doc = r" Called whenever Godot [`set()`](crate::classes::Object::set) is called or Godot sets the value of a property."]
+        #[doc = r""]
+        #[doc = r" Should set `property` to the given `value` and return `true`, or return `false` to indicate the `property`"]
+        #[doc = r" should be handled normally."]
+        #[doc = r""]
+        #[doc = r" See also in Godot docs:"]
+        #[doc = r" * [`Object::_set`](https://docs.godotengine.org/en/stable/classes/class_object.html#class-object-private-method-set)."]
+        fn set_property(&mut self, property: StringName, value: Variant) -> bool {
+            unimplemented !()
+        }
+        #[doc = r" Called whenever Godot [`get_property_list()`](crate::classes::Object::get_property_list) is called, the returned vector here is"]
+        #[doc = r" appended to the existing list of properties."]
+        #[doc = r""]
+        #[doc = r" This should mainly be used for advanced purposes, such as dynamically updating the property list in the editor."]
+        #[doc = r""]
+        #[doc = r" See also in Godot docs:"]
+        #[doc = r" * [`Object::_get_property_list`](https://docs.godotengine.org/en/latest/classes/class_object.html#class-object-private-method-get-property-list)"]
+        #[cfg(since_api = "4.3")]
+        #[cfg_attr(published_docs, doc(cfg(since_api = "4.3")))]
+        fn get_property_list(&mut self) -> Vec < crate::meta::PropertyInfo > {
+            unimplemented !()
+        }
+        #[doc = r" Called whenever Godot retrieves value of property. Allows to customize existing properties."]
+        #[doc = r" Every property info goes through this method, except properties **added** with `get_property_list()`."]
+        #[doc = r""]
+        #[doc = r" Exposed `property` here is a shared mutable reference obtained (and returned to) from Godot."]
+        #[doc = r""]
+        #[doc = r" See also in the Godot docs:"]
+        #[doc = r" * [`Object::_validate_property`](https://docs.godotengine.org/en/stable/classes/class_object.html#class-object-private-method-validate-property)"]
+        fn validate_property(&self, property: &mut crate::meta::PropertyInfo) {
+            unimplemented !()
+        }
+        #[doc = r" Called by Godot to tell if a property has a custom revert or not."]
+        #[doc = r""]
+        #[doc = r" Return `None` for no custom revert, and return `Some(value)` to specify the custom revert."]
+        #[doc = r""]
+        #[doc = r" This is a combination of Godot's [`Object::_property_get_revert`] and [`Object::_property_can_revert`]. This means that this"]
+        #[doc = r" function will usually be called twice by Godot to find the revert."]
+        #[doc = r""]
+        #[doc = r" Note that this should be a _pure_ function. That is, it should always return the same value for a property as long as `self`"]
+        #[doc = r" remains unchanged. Otherwise, this may lead to unexpected (safe) behavior."]
+        #[doc = r""]
+        #[doc = r" [`Object::_property_get_revert`]: https://docs.godotengine.org/en/latest/classes/class_object.html#class-object-private-method-property-get-revert"]
+        #[doc = r" [`Object::_property_can_revert`]: https://docs.godotengine.org/en/latest/classes/class_object.html#class-object-private-method-property-can-revert"]
+        #[doc(alias = "property_can_revert")]
+        fn property_get_revert(&self, property: StringName) -> Option < Variant > {
+            unimplemented !()
+        }
+        fn value_changed(&mut self, new_value: f64,) {
+            unimplemented !()
+        }
+        fn has_point(&self, point: Vector2,) -> bool {
+            unimplemented !()
+        }
+        fn structured_text_parser(&self, args: VarArray, text: GString,) -> Array < Vector3i > {
+            unimplemented !()
+        }
+        fn get_minimum_size(&self,) -> Vector2 {
+            unimplemented !()
+        }
+        fn get_tooltip(&self, at_position: Vector2,) -> GString {
+            unimplemented !()
+        }
+        fn get_drag_data(&mut self, at_position: Vector2,) -> Variant {
+            unimplemented !()
+        }
+        fn can_drop_data(&self, at_position: Vector2, data: Variant,) -> bool {
+            unimplemented !()
+        }
+        fn drop_data(&mut self, at_position: Vector2, data: Variant,) {
+            unimplemented !()
+        }
+        fn make_custom_tooltip(&self, for_text: GString,) -> Option < Gd < crate::classes::Object > > {
+            unimplemented !()
+        }
+        fn accessibility_get_contextual_info(&self,) -> GString {
+            unimplemented !()
+        }
+        fn get_accessibility_container_name(&self, node: Option < Gd < crate::classes::Node > >,) -> GString {
+            unimplemented !()
+        }
+        fn gui_input(&mut self, event: Gd < crate::classes::InputEvent >,) {
+            unimplemented !()
+        }
+        fn draw(&mut self,) {
+            unimplemented !()
+        }
+        fn process(&mut self, delta: f64,) {
+            unimplemented !()
+        }
+        fn physics_process(&mut self, delta: f64,) {
+            unimplemented !()
+        }
+        fn enter_tree(&mut self,) {
+            unimplemented !()
+        }
+        fn exit_tree(&mut self,) {
+            unimplemented !()
+        }
+        fn ready(&mut self,) {
+            unimplemented !()
+        }
+        fn get_configuration_warnings(&self,) -> PackedStringArray {
+            unimplemented !()
+        }
+        fn get_accessibility_configuration_warnings(&self,) -> PackedStringArray {
+            unimplemented !()
+        }
+        fn input(&mut self, event: Gd < crate::classes::InputEvent >,) {
+            unimplemented !()
+        }
+        fn shortcut_input(&mut self, event: Gd < crate::classes::InputEvent >,) {
+            unimplemented !()
+        }
+        fn unhandled_input(&mut self, event: Gd < crate::classes::InputEvent >,) {
+            unimplemented !()
+        }
+        fn unhandled_key_input(&mut self, event: Gd < crate::classes::InputEvent >,) {
+            unimplemented !()
+        }
+        fn get_focused_accessibility_element(&self,) -> Rid {
+            unimplemented !()
+        }
+    }
+    impl EditorSpinSlider {
+        pub fn set_label(&mut self, label: impl AsArg < GString >,) {
+            type CallRet = ();
+            type CallParams < 'a0, > = (CowArg < 'a0, GString >,);
+            let args = (label.into_arg(),);
+            unsafe {
+                let method_bind = sys::class_editor_api() . fptr_by_index(392usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "EditorSpinSlider", "set_label", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn get_label(&self,) -> GString {
+            type CallRet = GString;
+            type CallParams = ();
+            let args = ();
+            unsafe {
+                let method_bind = sys::class_editor_api() . fptr_by_index(393usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "EditorSpinSlider", "get_label", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn set_suffix(&mut self, suffix: impl AsArg < GString >,) {
+            type CallRet = ();
+            type CallParams < 'a0, > = (CowArg < 'a0, GString >,);
+            let args = (suffix.into_arg(),);
+            unsafe {
+                let method_bind = sys::class_editor_api() . fptr_by_index(394usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "EditorSpinSlider", "set_suffix", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn get_suffix(&self,) -> GString {
+            type CallRet = GString;
+            type CallParams = ();
+            let args = ();
+            unsafe {
+                let method_bind = sys::class_editor_api() . fptr_by_index(395usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "EditorSpinSlider", "get_suffix", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn set_read_only(&mut self, read_only: bool,) {
+            type CallRet = ();
+            type CallParams = (bool,);
+            let args = (read_only,);
+            unsafe {
+                let method_bind = sys::class_editor_api() . fptr_by_index(396usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "EditorSpinSlider", "set_read_only", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn is_read_only(&self,) -> bool {
+            type CallRet = bool;
+            type CallParams = ();
+            let args = ();
+            unsafe {
+                let method_bind = sys::class_editor_api() . fptr_by_index(397usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "EditorSpinSlider", "is_read_only", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn set_flat(&mut self, flat: bool,) {
+            type CallRet = ();
+            type CallParams = (bool,);
+            let args = (flat,);
+            unsafe {
+                let method_bind = sys::class_editor_api() . fptr_by_index(398usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "EditorSpinSlider", "set_flat", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn is_flat(&self,) -> bool {
+            type CallRet = bool;
+            type CallParams = ();
+            let args = ();
+            unsafe {
+                let method_bind = sys::class_editor_api() . fptr_by_index(399usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "EditorSpinSlider", "is_flat", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn set_hide_slider(&mut self, hide_slider: bool,) {
+            type CallRet = ();
+            type CallParams = (bool,);
+            let args = (hide_slider,);
+            unsafe {
+                let method_bind = sys::class_editor_api() . fptr_by_index(400usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "EditorSpinSlider", "set_hide_slider", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn is_hiding_slider(&self,) -> bool {
+            type CallRet = bool;
+            type CallParams = ();
+            let args = ();
+            unsafe {
+                let method_bind = sys::class_editor_api() . fptr_by_index(401usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "EditorSpinSlider", "is_hiding_slider", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn set_editing_integer(&mut self, editing_integer: bool,) {
+            type CallRet = ();
+            type CallParams = (bool,);
+            let args = (editing_integer,);
+            unsafe {
+                let method_bind = sys::class_editor_api() . fptr_by_index(402usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "EditorSpinSlider", "set_editing_integer", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn is_editing_integer(&self,) -> bool {
+            type CallRet = bool;
+            type CallParams = ();
+            let args = ();
+            unsafe {
+                let method_bind = sys::class_editor_api() . fptr_by_index(403usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "EditorSpinSlider", "is_editing_integer", Some(self.__validated_obj()), args,)
+            }
+        }
+        #[doc = r" Creates a validated object for FFI boundary crossing."]
+        #[doc = r""]
+        #[doc = r" Low-level internal method. Validation (liveness/type checks) depend on safeguard level."]
+        fn __validated_obj(&self) -> crate::obj::ValidatedObject {
+            let raw_gd = unsafe {
+                std::mem::transmute::< &Self, &crate::obj::RawGd < Self >> (self)
+            };
+            raw_gd.validated_object()
+        }
+        #[doc(hidden)]
+        pub fn __object_ptr(&self) -> sys::GDExtensionObjectPtr {
+            self.object_ptr
+        }
+    }
+    impl crate::obj::GodotClass for EditorSpinSlider {
+        type Base = crate::classes::Range;
+        fn class_id() -> ClassId {
+            static CLASS_ID: std::sync::OnceLock < ClassId > = std::sync::OnceLock::new();
+            let name: &'static ClassId = CLASS_ID.get_or_init(|| ClassId::__alloc_next_unicode("EditorSpinSlider"));
+            * name
+        }
+        const INIT_LEVEL: crate::init::InitLevel = crate::init::InitLevel::Editor;
+        
+    }
+    unsafe impl crate::obj::Bounds for EditorSpinSlider {
+        type Memory = crate::obj::bounds::MemManual;
+        type DynMemory = crate::obj::bounds::MemManual;
+        type Declarer = crate::obj::bounds::DeclEngine;
+        type Exportable = crate::obj::bounds::Yes;
+        
+    }
+    unsafe impl crate::obj::Inherits < crate::classes::Range > for EditorSpinSlider {
+        
+    }
+    unsafe impl crate::obj::Inherits < crate::classes::Control > for EditorSpinSlider {
+        
+    }
+    unsafe impl crate::obj::Inherits < crate::classes::CanvasItem > for EditorSpinSlider {
+        
+    }
+    unsafe impl crate::obj::Inherits < crate::classes::Node > for EditorSpinSlider {
+        
+    }
+    unsafe impl crate::obj::Inherits < crate::classes::Object > for EditorSpinSlider {
+        
+    }
+    impl crate::obj::cap::GodotDefault for EditorSpinSlider {
+        fn __godot_default() -> crate::obj::Gd < Self > {
+            crate::classes::construct_engine_object::< Self > ()
+        }
+    }
+    impl std::ops::Deref for EditorSpinSlider {
+        type Target = crate::classes::Range;
+        fn deref(&self) -> &Self::Target {
+            unsafe {
+                std::mem::transmute::< &Self, &Self::Target > (self)
+            }
+        }
+    }
+    impl std::ops::DerefMut for EditorSpinSlider {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            unsafe {
+                std::mem::transmute::< &mut Self, &mut Self::Target > (self)
+            }
+        }
+    }
+    #[doc = r" # Safety"]
+    #[doc = r""]
+    #[doc = "The provided class must be a subclass of all the superclasses of [`EditorSpinSlider`]"]
+    #[macro_export]
+    #[allow(non_snake_case)]
+    macro_rules !inherit_from_EditorSpinSlider__ensure_class_exists {
+        ($Class: ident) => {
+            unsafe impl::godot::obj::Inherits < ::godot::classes::EditorSpinSlider > for $Class {
+                
+            }
+            unsafe impl::godot::obj::Inherits < ::godot::classes::Range > for $Class {
+                
+            }
+            unsafe impl::godot::obj::Inherits < ::godot::classes::Control > for $Class {
+                
+            }
+            unsafe impl::godot::obj::Inherits < ::godot::classes::CanvasItem > for $Class {
+                
+            }
+            unsafe impl::godot::obj::Inherits < ::godot::classes::Node > for $Class {
+                
+            }
+            unsafe impl::godot::obj::Inherits < ::godot::classes::Object > for $Class {
+                
+            }
+        }
+    }
+}
+pub use signals::*;
+mod signals {
+    use crate::obj::{
+        Gd, GodotClass
+    };
+    use super::re_export::EditorSpinSlider;
+    use crate::registry::signal::TypedSignal;
+    use super::*;
+    #[doc = "A collection of signals for the [`EditorSpinSlider`][crate::classes::EditorSpinSlider] class."]
+    pub struct SignalsOfEditorSpinSlider < 'c, C: WithSignals > {
+        #[doc(hidden)]
+        pub(crate) __internal_obj: Option < C::__SignalObj < 'c >>,
+    }
+    impl < 'c, C: WithSignals > SignalsOfEditorSpinSlider < 'c, C > {
+        #[doc = "Signature: `()`"]
+        pub fn grabbed(&mut self) -> SigGrabbed < 'c, C > {
+            SigGrabbed {
+                typed: TypedSignal::extract(&mut self.__internal_obj, "grabbed")
+            }
+        }
+        #[doc = "Signature: `()`"]
+        pub fn ungrabbed(&mut self) -> SigUngrabbed < 'c, C > {
+            SigUngrabbed {
+                typed: TypedSignal::extract(&mut self.__internal_obj, "ungrabbed")
+            }
+        }
+        #[doc = "Signature: `()`"]
+        pub fn updown_pressed(&mut self) -> SigUpdownPressed < 'c, C > {
+            SigUpdownPressed {
+                typed: TypedSignal::extract(&mut self.__internal_obj, "updown_pressed")
+            }
+        }
+        #[doc = "Signature: `()`"]
+        pub fn value_focus_entered(&mut self) -> SigValueFocusEntered < 'c, C > {
+            SigValueFocusEntered {
+                typed: TypedSignal::extract(&mut self.__internal_obj, "value_focus_entered")
+            }
+        }
+        #[doc = "Signature: `()`"]
+        pub fn value_focus_exited(&mut self) -> SigValueFocusExited < 'c, C > {
+            SigValueFocusExited {
+                typed: TypedSignal::extract(&mut self.__internal_obj, "value_focus_exited")
+            }
+        }
+    }
+    type TypedSigGrabbed < 'c, C > = TypedSignal < 'c, C, () >;
+    pub struct SigGrabbed < 'c, C: WithSignals > {
+        typed: TypedSigGrabbed < 'c, C >,
+    }
+    impl < 'c, C: WithSignals > SigGrabbed < 'c, C > {
+        pub fn emit(&mut self,) {
+            self.typed.emit_tuple(());
+            
+        }
+    }
+    impl < 'c, C: WithSignals > std::ops::Deref for SigGrabbed < 'c, C > {
+        type Target = TypedSigGrabbed < 'c, C >;
+        fn deref(&self) -> &Self::Target {
+            &self.typed
+        }
+    }
+    impl < C: WithSignals > std::ops::DerefMut for SigGrabbed < '_, C > {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            &mut self.typed
+        }
+    }
+    type TypedSigUngrabbed < 'c, C > = TypedSignal < 'c, C, () >;
+    pub struct SigUngrabbed < 'c, C: WithSignals > {
+        typed: TypedSigUngrabbed < 'c, C >,
+    }
+    impl < 'c, C: WithSignals > SigUngrabbed < 'c, C > {
+        pub fn emit(&mut self,) {
+            self.typed.emit_tuple(());
+            
+        }
+    }
+    impl < 'c, C: WithSignals > std::ops::Deref for SigUngrabbed < 'c, C > {
+        type Target = TypedSigUngrabbed < 'c, C >;
+        fn deref(&self) -> &Self::Target {
+            &self.typed
+        }
+    }
+    impl < C: WithSignals > std::ops::DerefMut for SigUngrabbed < '_, C > {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            &mut self.typed
+        }
+    }
+    type TypedSigUpdownPressed < 'c, C > = TypedSignal < 'c, C, () >;
+    pub struct SigUpdownPressed < 'c, C: WithSignals > {
+        typed: TypedSigUpdownPressed < 'c, C >,
+    }
+    impl < 'c, C: WithSignals > SigUpdownPressed < 'c, C > {
+        pub fn emit(&mut self,) {
+            self.typed.emit_tuple(());
+            
+        }
+    }
+    impl < 'c, C: WithSignals > std::ops::Deref for SigUpdownPressed < 'c, C > {
+        type Target = TypedSigUpdownPressed < 'c, C >;
+        fn deref(&self) -> &Self::Target {
+            &self.typed
+        }
+    }
+    impl < C: WithSignals > std::ops::DerefMut for SigUpdownPressed < '_, C > {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            &mut self.typed
+        }
+    }
+    type TypedSigValueFocusEntered < 'c, C > = TypedSignal < 'c, C, () >;
+    pub struct SigValueFocusEntered < 'c, C: WithSignals > {
+        typed: TypedSigValueFocusEntered < 'c, C >,
+    }
+    impl < 'c, C: WithSignals > SigValueFocusEntered < 'c, C > {
+        pub fn emit(&mut self,) {
+            self.typed.emit_tuple(());
+            
+        }
+    }
+    impl < 'c, C: WithSignals > std::ops::Deref for SigValueFocusEntered < 'c, C > {
+        type Target = TypedSigValueFocusEntered < 'c, C >;
+        fn deref(&self) -> &Self::Target {
+            &self.typed
+        }
+    }
+    impl < C: WithSignals > std::ops::DerefMut for SigValueFocusEntered < '_, C > {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            &mut self.typed
+        }
+    }
+    type TypedSigValueFocusExited < 'c, C > = TypedSignal < 'c, C, () >;
+    pub struct SigValueFocusExited < 'c, C: WithSignals > {
+        typed: TypedSigValueFocusExited < 'c, C >,
+    }
+    impl < 'c, C: WithSignals > SigValueFocusExited < 'c, C > {
+        pub fn emit(&mut self,) {
+            self.typed.emit_tuple(());
+            
+        }
+    }
+    impl < 'c, C: WithSignals > std::ops::Deref for SigValueFocusExited < 'c, C > {
+        type Target = TypedSigValueFocusExited < 'c, C >;
+        fn deref(&self) -> &Self::Target {
+            &self.typed
+        }
+    }
+    impl < C: WithSignals > std::ops::DerefMut for SigValueFocusExited < '_, C > {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            &mut self.typed
+        }
+    }
+    use crate::obj::WithSignals;
+    impl WithSignals for EditorSpinSlider {
+        type SignalCollection < 'c, C: WithSignals > = SignalsOfEditorSpinSlider < 'c, C >;
+        type __SignalObj < 'c > = Gd < Self >;
+        #[doc(hidden)]
+        fn __signals_from_external(gd_ref: &Gd < Self >) -> Self::SignalCollection < '_, Self > {
+            Self::SignalCollection {
+                __internal_obj: Some(gd_ref.clone()),
+            }
+        }
+    }
+    impl < 'c, C: WithSignals > std::ops::Deref for SignalsOfEditorSpinSlider < 'c, C > {
+        type Target = < < EditorSpinSlider as crate::obj::GodotClass > ::Base as WithSignals > ::SignalCollection < 'c, C >;
+        fn deref(&self) -> &Self::Target {
+            type Derived = EditorSpinSlider;
+            crate::private::signal_collection_to_base::< C, Derived > (self)
+        }
+    }
+    impl < 'c, C: WithSignals > std::ops::DerefMut for SignalsOfEditorSpinSlider < 'c, C > {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            type Derived = EditorSpinSlider;
+            crate::private::signal_collection_to_base_mut::< C, Derived > (self)
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,553 @@
+#![doc = "Sidecar module for class [`FlowContainer`][crate::classes::FlowContainer].\n\nDefines related flag and enum types. In GDScript, those are nested under the class scope.\n\nSee also [Godot docs for `FlowContainer` enums](https://docs.godotengine.org/en/stable/classes/class_flowcontainer.html#enumerations).\n\n"]
+use godot_ffi as sys;
+use crate::builtin::*;
+use crate::meta::{
+    AsArg, ClassId, CowArg, InParamTuple, OutParamTuple, ParamTuple, RefArg, Signature
+};
+use crate::classes::native::*;
+use crate::classes::Object;
+use crate::obj::Gd;
+use crate::sys::GodotFfi as _;
+use crate::classes::notify::*;
+use std::ffi::c_void;
+pub(super) mod re_export {
+    use super::*;
+    #[doc = "Godot class `FlowContainer.`\n\nInherits [`Container`][crate::classes::Container].\n\nRelated symbols:\n\n* [`flow_container`][crate::classes::flow_container]: sidecar module with related enum/flag types\n* [`IFlowContainer`][crate::classes::IFlowContainer]: virtual methods\n\n\nSee also [Godot docs for `FlowContainer`](https://docs.godotengine.org/en/stable/classes/class_flowcontainer.html).\n\n"]
+    #[doc = "# Construction\n\nThis class is manually managed. You can create a new instance using [`FlowContainer::new_alloc()`][crate::obj::NewAlloc::new_alloc].\n\nDo not forget to call [`free()`][crate::obj::Gd::free] or hand over ownership to Godot."]
+    #[derive(Debug)]
+    #[repr(C)]
+    pub struct FlowContainer {
+        object_ptr: sys::GDExtensionObjectPtr, rtti: Option < crate::private::ObjectRtti >,
+    }
+    #[doc = "# Interface trait for class [`FlowContainer`][crate::classes::FlowContainer].\n\nFunctions in this trait represent constructors (`init`) or virtual method callbacks invoked by the engine.\n\n\n\n# Related symbols\n\nBase interfaces: [`IContainer`][crate::classes::IContainer] > [`IControl`][crate::classes::IControl] > ~~`ICanvasItem`~~ > [`INode`][crate::classes::INode] > [`IObject`][crate::classes::IObject].  \n(Strike-through means some intermediate Godot classes are marked final, and can thus not be inherited by GDExtension.)\n\n\n\nSee also [Godot docs for `FlowContainer` methods](https://docs.godotengine.org/en/stable/classes/class_flowcontainer.html#methods)."]
+    #[doc = ""]
+    #[allow(unused_variables)]
+    #[allow(clippy::unimplemented)]
+    pub trait IFlowContainer: crate::obj::GodotClass < Base = FlowContainer > + crate::private::You_forgot_the_attribute__godot_api {
+        #[doc(hidden)]
+        fn register_class(builder: &mut crate::builder::ClassBuilder < Self >) {
+            unimplemented !()
+        }
+        #[doc = r" Godot constructor, accepting an injected `base` object."]
+        #[doc = r""]
+        #[doc = r" `base` refers to the base instance of the class, which can either be stored in a `Base<T>` field or discarded."]
+        #[doc = r" This method returns a fully-constructed instance, which will then be moved into a [`Gd<T>`][crate::obj::Gd] pointer."]
+        #[doc = r""]
+        #[doc = r" If the class has a `#[class(init)]` attribute, this method will be auto-generated and must not be overridden."]
+        fn init(base: crate::obj::Base < Self::Base >) -> Self {
+            unimplemented !()
+        }
+        #[doc = r" String representation of the Godot instance."]
+        #[doc = r""]
+        #[doc = r" Override this method to define how the instance is represented as a string."]
+        #[doc = r" Used by `impl Display for Gd<T>`, as well as `str()` and `print()` in GDScript."]
+        fn to_string(&self) -> crate::builtin::GString {
+            unimplemented !()
+        }
+        #[doc = r" Called when the object receives a Godot notification."]
+        #[doc = r""]
+        #[doc = r" The type of notification can be identified through `what`. The enum is designed to hold all possible `NOTIFICATION_*`"]
+        #[doc = r" constants that the current class can handle. However, this is not validated in Godot, so an enum variant `Unknown` exists"]
+        #[doc = r" to represent integers out of known constants (mistakes or future additions)."]
+        #[doc = r""]
+        #[doc = r" This method is named `_notification` in Godot, but `on_notification` in Rust. To _send_ notifications, use the"]
+        #[doc = r" [`Object::notify`][crate::classes::Object::notify] method."]
+        #[doc = r""]
+        #[doc = r" See also in Godot docs:"]
+        #[doc = r" * [`Object::_notification`](https://docs.godotengine.org/en/stable/classes/class_object.html#class-object-method-notification)."]
+        #[doc = r" * [Notifications tutorial](https://docs.godotengine.org/en/stable/tutorials/best_practices/godot_notifications.html)."]
+        fn on_notification(&mut self, what: ContainerNotification) {
+            unimplemented !()
+        }
+        #[doc = r" Called whenever [`get()`](crate::classes::Object::get) is called or Godot gets the value of a property."]
+        #[doc = r""]
+        #[doc = r" Should return the given `property`'s value as `Some(value)`, or `None` if the property should be handled normally."]
+        #[doc = r""]
+        #[doc = r" See also in Godot docs:"]
+        #[doc = r" * [`Object::_get`](https://docs.godotengine.org/en/stable/classes/class_object.html#class-object-private-method-get)."]
+        fn get_property(&self, property: StringName) -> Option < Variant > {
+            unimplemented !()
+        }
+        #[doc = r" Called whenever Godot [`set()`](crate::classes::Object::set) is called or Godot sets the value of a property."]
+        #[doc = r""]
+        #[doc = r" Should set `property` to the given `value` and return `true`, or return `false` to indicate the `property`"]
+        #[doc = r" should be handled normally."]
+        #[doc = r""]
+        #[doc = r" See also in Godot docs:"]
+        #[doc = r" * [`Object::_set`](https://docs.godotengine.org/en/stable/classes/class_object.html#class-object-private-method-set)."]
+        fn set_property(&mut self, property: StringName, value: Variant) -> bool {
+            unimplemented !()
+        }
+        #[doc = r" Called whenever Godot [`get_property_list()`](crate::classes::Object::get_property_list) is called, the returned vector here is"]
+        #[doc = r" appended to the existing list of properties."]
+        #[doc = r""]
+        #[doc = r" This should mainly be used for advanced purposes, such as dynamically updating the property list in the editor."]
+        #[doc = r""]
+        #[doc = r" See also in Godot docs:"]
+        #[doc = r" * [`Object::_get_property_list`](https://docs.godotengine.org/en/latest/classes/class_object.html#class-object-private-method-get-property-list)"]
+        #[cfg(since_api = "4.3")]
+        #[cfg_attr(published_docs, doc(cfg(since_api = "4.3")))]
+        fn get_property_list(&mut self) -> Vec < crate::meta::PropertyInfo > {
+            unimplemented !()
+        }
+        #[doc = r" Called whenever Godot retrieves value of property. Allows to customize existing properties."]
+        #[doc = r" Every property info goes through this method, except properties **added** with `get_property_list()`."]
+        #[doc = r""]
+        #[doc = r" Exposed `property` here is a shared mutable reference obtained (and returned to) from Godot."]
+        #[doc = r""]
+        #[doc = r" See also in the Godot docs:"]
+        #[doc = r" * [`Object::_validate_property`](https://docs.godotengine.org/en/stable/classes/class_object.html#class-object-private-method-validate-property)"]
+        fn validate_property(&self, property: &mut crate::meta::PropertyInfo) {
+            unimplemented !()
+        }
+        #[doc = r" Called by Godot to tell if a property has a custom revert or not."]
+        #[doc = r""]
+        #[doc = r" Return `None` for no custom revert, and return `Some(value)` to specify the custom revert."]
+        #[doc = r""]
+        #[doc = r" This is a combination of Godot's [`Object::_property_get_revert`] and [`Object::_property_can_revert`]. This means that this"]
+        #[doc = r" function will usually be called twice by Godot to find the revert."]
+        #[doc = r""]
+        #[doc = r" Note that this should be a _pure_ function. That is, it should always return the same value for a property as long as `self`"]
+        #[doc = r" remains unchanged. Otherwise, this may lead to unexpected (safe) behavior."]
+        #[doc = r""]
+        #[doc = r" [`Object::_property_get_revert`]: https://docs.godotengine.org/en/latest/classes/class_object.html#class-object-private-method-property-get-revert"]
+        #[doc = r" [`Object::_property_can_revert`]: https://docs.godotengine.org/en/latest/classes/class_object.html#class-object-private-method-property-can-revert"]
+        #[doc(alias = "property_can_revert")]
+        fn property_get_revert(&self, property: StringName) -> Option < Variant > {
+            unimplemented !()
+        }
+        fn get_allowed_size_flags_horizontal(&self,) -> PackedInt32Array {
+            unimplemented !()
+        }
+        fn get_allowed_size_flags_vertical(&self,) -> PackedInt32Array {
+            unimplemented !()
+        }
+        fn has_point(&self, point: Vector2,) -> bool {
+            unimplemented !()
+        }
+        fn structured_text_parser(&self, args: VarArray, text: GString,) -> Array < Vector3i > {
+            unimplemented !()
+        }
+        fn get_minimum_size(&self,) -> Vector2 {
+            unimplemented !()
+        }
+        fn get_tooltip(&self, at_position: Vector2,) -> GString {
+            unimplemented !()
+        }
+        fn get_drag_data(&mut self, at_position: Vector2,) -> Variant {
+            unimplemented !()
+        }
+        fn can_drop_data(&self, at_position: Vector2, data: Variant,) -> bool {
+            unimplemented !()
+        }
+        fn drop_data(&mut self, at_position: Vector2, data: Variant,) {
+            unimplemented !()
+        }
+        fn make_custom_tooltip(&self, for_text: GString,) -> Option < Gd < crate::classes::Object > > {
+            unimplemented !()
+        }
+        fn accessibility_get_contextual_info(&self,) -> GString {
+            unimplemented !()
+        }
+        fn get_accessibility_container_name(&self, node: Option < Gd < crate::classes::Node > >,) -> GString {
+            unimplemented !()
+        }
+        fn gui_input(&mut self, event: Gd < crate::classes::InputEvent >,) {
+            unimplemented !()
+        }
+        fn draw(&mut self,) {
+            unimplemented !()
+        }
+        fn process(&mut self, delta: f64,) {
+            unimplemented !()
+        }
+        fn physics_process(&mut self, delta: f64,) {
+            unimplemented !()
+        }
+        fn enter_tree(&mut self,) {
+            unimplemented !()
+        }
+        fn exit_tree(&mut self,) {
+            unimplemented !()
+        }
+        fn ready(&mut self,) {
+            unimplemented !()
+        }
+        fn get_configuration_warnings(&self,) -> PackedStringArray {
+            unimplemented !()
+        }
+        fn get_accessibility_configuration_warnings(&self,) -> PackedStringArray {
+            unimplemented !()
+        }
+        fn input(&mut self, event: Gd < crate::classes::InputEvent >,) {
+            unimplemented !()
+        }
+        fn shortcut_input(&mut self, event: Gd < crate::classes::InputEvent >,) {
+            unimplemented !()
+        }
+        fn unhandled_input(&mut self, event: Gd < crate::classes::InputEvent >,) {
+            unimplemented !()
+        }
+        fn unhandled_key_input(&mut self, event: Gd < crate::classes::InputEvent >,) {
+            unimplemented !()
+        }
+        fn get_focused_accessibility_element(&self,) -> Rid {
+            unimplemented !()
+        }
+    }
+    impl FlowContainer {
+        pub fn get_line_count(&self,) -> i32 {
+            type CallRet = i32;
+            type CallParams = ();
+            let args = ();
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(3296usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "FlowContainer", "get_line_count", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn set_alignment(&mut self, alignment: crate::classes::flow_container::AlignmentMode,) {
+            type CallRet = ();
+            type CallParams = (crate::classes::flow_container::AlignmentMode,);
+            let args = (alignment,);
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(3297usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "FlowContainer", "set_alignment", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn get_alignment(&self,) -> crate::classes::flow_container::AlignmentMode {
+            type CallRet = crate::classes::flow_container::AlignmentMode;
+            type CallParams = ();
+            let args = ();
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(3298usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "FlowContainer", "get_alignment", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn set_last_wrap_alignment(&mut self, last_wrap_alignment: crate::classes::flow_container::LastWrapAlignmentMode,) {
+            type CallRet = ();
+            type CallParams = (crate::classes::flow_container::LastWrapAlignmentMode,);
+            let args = (last_wrap_alignment,);
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(3299usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "FlowContainer", "set_last_wrap_alignment", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn get_last_wrap_alignment(&self,) -> crate::classes::flow_container::LastWrapAlignmentMode {
+            type CallRet = crate::classes::flow_container::LastWrapAlignmentMode;
+            type CallParams = ();
+            let args = ();
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(3300usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "FlowContainer", "get_last_wrap_alignment", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn set_vertical(&mut self, vertical: bool,) {
+            type CallRet = ();
+            type CallParams = (bool,);
+            let args = (vertical,);
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(3301usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "FlowContainer", "set_vertical", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn is_vertical(&self,) -> bool {
+            type CallRet = bool;
+            type CallParams = ();
+            let args = ();
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(3302usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "FlowContainer", "is_vertical", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn set_reverse_fill(&mut self, reverse_fill: bool,) {
+            type CallRet = ();
+            type CallParams = (bool,);
+            let args = (reverse_fill,);
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(3303usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "FlowContainer", "set_reverse_fill", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn is_reverse_fill(&self,) -> bool {
+            type CallRet = bool;
+            type CallParams = ();
+            let args = ();
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(3304usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "FlowContainer", "is_reverse_fill", Some(self.__validated_obj()), args,)
+            }
+        }
+        #[doc = r" Creates a validated object for FFI boundary crossing."]
+        #[doc = r""]
+        #[doc = r" Low-level internal method. Validation (liveness/type checks) depend on safeguard level."]
+        fn __validated_obj(&self) -> crate::obj::ValidatedObject {
+            let raw_gd = unsafe {
+                std::mem::transmute::< &Self, &crate::obj::RawGd < Self >> (self)
+            };
+            raw_gd.validated_object()
+        }
+        #[doc(hidden)]
+        pub fn __object_ptr(&self) -> sys::GDExtensionObjectPtr {
+            self.object_ptr
+        }
+    }
+    impl crate::obj::GodotClass for FlowContainer {
+        type Base = crate::classes::Container;
+        fn class_id() -> ClassId {
+            static CLASS_ID: std::sync::OnceLock < ClassId > = std::sync::OnceLock::new();
+            let name: &'static ClassId = CLASS_ID.get_or_init(|| ClassId::__alloc_next_unicode("FlowContainer"));
+            * name
+        }
+        const INIT_LEVEL: crate::init::InitLevel = crate::init::InitLevel::Scene;
+        
+    }
+    unsafe impl crate::obj::Bounds for FlowContainer {
+        type Memory = crate::obj::bounds::MemManual;
+        type DynMemory = crate::obj::bounds::MemManual;
+        type Declarer = crate::obj::bounds::DeclEngine;
+        type Exportable = crate::obj::bounds::Yes;
+        
+    }
+    unsafe impl crate::obj::Inherits < crate::classes::Container > for FlowContainer {
+        
+    }
+    unsafe impl crate::obj::Inherits < crate::classes::Control > for FlowContainer {
+        
+    }
+    unsafe impl crate::obj::Inherits < crate::classes::CanvasItem > for FlowContainer {
+        
+    }
+    unsafe impl crate::obj::Inherits < crate::classes::Node > for FlowContainer {
+        
+    }
+    unsafe impl crate::obj::Inherits < crate::classes::Object > for FlowContainer {
+        
+    }
+    impl crate::obj::cap::GodotDefault for FlowContainer {
+        fn __godot_default() -> crate::obj::Gd < Self > {
+            crate::classes::construct_engine_object::< Self > ()
+        }
+    }
+    impl std::ops::Deref for FlowContainer {
+        type Target = crate::classes::Container;
+        fn deref(&self) -> &Self::Target {
+            unsafe {
+                std::mem::transmute::< &Self, &Self::Target > (self)
+            }
+        }
+    }
+    impl std::ops::DerefMut for FlowContainer {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            unsafe {
+                std::mem::transmute::< &mut Self, &mut Self::Target > (self)
+            }
+        }
+    }
+    #[doc = r" # Safety"]
+    #[doc = r""]
+    #[doc = "The provided class must be a subclass of all the superclasses of [`FlowContainer`]"]
+    #[macro_export]
+    #[allow(non_snake_case)]
+    macro_rules !inherit_from_FlowContainer__ensure_class_exists {
+        ($Class: ident) => {
+            unsafe impl::godot::obj::Inherits < ::godot::classes::FlowContainer > for $Class {
+                
+            }
+            unsafe impl::godot::obj::Inherits < ::godot::classes::Container > for $Class {
+                
+            }
+            unsafe impl::godot::obj::Inherits < ::godot::classes::Control > for $Class {
+                
+            }
+            unsafe impl::godot::obj::Inherits < ::godot::classes::CanvasItem > for $Class {
+                
+            }
+            unsafe impl::godot::obj::Inherits < ::godot::classes::Node > for $Class {
+                
+            }
+            unsafe impl::godot::obj::Inherits < ::godot::classes::Object > for $Class {
+                
+            }
+        }
+    }
+}
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+pub struct AlignmentMode {
+    ord: i32
+}
+impl AlignmentMode {
+    #[doc(alias = "ALIGNMENT_BEGIN")]
+    #[doc = "Godot enumerator name: `ALIGNMENT_BEGIN`"]
+    pub const BEGIN: AlignmentMode = AlignmentMode {
+        ord: 0i32
+    };
+    #[doc(alias = "ALIGNMENT_CENTER")]
+    #[doc = "Godot enumerator name: `ALIGNMENT_CENTER`"]
+    pub const CENTER: AlignmentMode = AlignmentMode {
+        ord: 1i32
+    };
+    #[doc(alias = "ALIGNMENT_END")]
+    #[doc = "Godot enumerator name: `ALIGNMENT_END`"]
+    pub const END: AlignmentMode = AlignmentMode {
+        ord: 2i32
+    };
+    
+}
+impl std::fmt::Debug for AlignmentMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter < '_ >) -> std::fmt::Result {
+        use crate::obj::EngineEnum;
+        let enumerator = self.as_str();
+        if enumerator.is_empty() {
+            f.debug_struct("AlignmentMode") . field("ord", &self.ord) . finish() ?;
+            return Ok(());
+            
+        }
+        f.write_str(enumerator)
+    }
+}
+impl crate::obj::EngineEnum for AlignmentMode {
+    fn try_from_ord(ord: i32) -> Option < Self > {
+        match ord {
+            ord @ 0i32 | ord @ 1i32 | ord @ 2i32 => Some(Self {
+                ord
+            }), _ => None,
+        }
+    }
+    fn ord(self) -> i32 {
+        self.ord
+    }
+    #[inline]
+    fn as_str(&self) -> &'static str {
+        #[allow(unreachable_patterns)]
+        match * self {
+            Self::BEGIN => "BEGIN", Self::CENTER => "CENTER", Self::END => "END", _ => "",
+        }
+    }
+    fn values() -> &'static[Self] {
+        &[AlignmentMode::BEGIN, AlignmentMode::CENTER, AlignmentMode::END]
+    }
+    fn all_constants() -> &'static[crate::meta::inspect::EnumConstant < AlignmentMode >] {
+        const {
+            &[crate::meta::inspect::EnumConstant::new("BEGIN", "ALIGNMENT_BEGIN", AlignmentMode::BEGIN), crate::meta::inspect::EnumConstant::new("CENTER", "ALIGNMENT_CENTER", AlignmentMode::CENTER), crate::meta::inspect::EnumConstant::new("END", "ALIGNMENT_END", AlignmentMode::END)]
+        }
+    }
+}
+impl crate::meta::GodotConvert for AlignmentMode {
+    type Via = i32;
+    
+}
+impl crate::meta::ToGodot for AlignmentMode {
+    type Pass = crate::meta::ByValue;
+    fn to_godot(&self) -> Self::Via {
+        < Self as crate::obj::EngineEnum > ::ord(* self)
+    }
+}
+impl crate::meta::FromGodot for AlignmentMode {
+    fn try_from_godot(via: Self::Via) -> std::result::Result < Self, crate::meta::error::ConvertError > {
+        < Self as crate::obj::EngineEnum > ::try_from_ord(via) . ok_or_else(|| crate::meta::error::FromGodotError::InvalidEnum.into_error(via))
+    }
+}
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+pub struct LastWrapAlignmentMode {
+    ord: i32
+}
+impl LastWrapAlignmentMode {
+    #[doc(alias = "LAST_WRAP_ALIGNMENT_INHERIT")]
+    #[doc = "Godot enumerator name: `LAST_WRAP_ALIGNMENT_INHERIT`"]
+    pub const INHERIT: LastWrapAlignmentMode = LastWrapAlignmentMode {
+        ord: 0i32
+    };
+    #[doc(alias = "LAST_WRAP_ALIGNMENT_BEGIN")]
+    #[doc = "Godot enumerator name: `LAST_WRAP_ALIGNMENT_BEGIN`"]
+    pub const BEGIN: LastWrapAlignmentMode = LastWrapAlignmentMode {
+        ord: 1i32
+    };
+    #[doc(alias = "LAST_WRAP_ALIGNMENT_CENTER")]
+    #[doc = "Godot enumerator name: `LAST_WRAP_ALIGNMENT_CENTER`"]
+    pub const CENTER: LastWrapAlignmentMode = LastWrapAlignmentMode {
+        ord: 2i32
+    };
+    #[doc(alias = "LAST_WRAP_ALIGNMENT_END")]
+    #[doc = "Godot enumerator name: `LAST_WRAP_ALIGNMENT_END`"]
+    pub const END: LastWrapAlignmentMode = LastWrapAlignmentMode {
+        ord: 3i32
+    };
+    
+}
+impl std::fmt::Debug for LastWrapAlignmentMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter < '_ >) -> std::fmt::Result {
+        use crate::obj::EngineEnum;
+        let enumerator = self.as_str();
+        if enumerator.is_empty() {
+            f.debug_struct("LastWrapAlignmentMode") . field("ord", &self.ord) . finish() ?;
+            return Ok(());
+            
+        }
+        f.write_str(enumerator)
+    }
+}
+impl crate::obj::EngineEnum for LastWrapAlignmentMode {
+    fn try_from_ord(ord: i32) -> Option < Self > {
+        match ord {
+            ord @ 0i32 | ord @ 1i32 | ord @ 2i32 | ord @ 3i32 => Some(Self {
+                ord
+            }), _ => None,
+        }
+    }
+    fn ord(self) -> i32 {
+        self.ord
+    }
+    #[inline]
+    fn as_str(&self) -> &'static str {
+        #[allow(unreachable_patterns)]
+        match * self {
+            Self::INHERIT => "INHERIT", Self::BEGIN => "BEGIN", Self::CENTER => "CENTER", Self::END => "END", _ => "",
+        }
+    }
+    fn values() -> &'static[Self] {
+        &[LastWrapAlignmentMode::INHERIT, LastWrapAlignmentMode::BEGIN, LastWrapAlignmentMode::CENTER, LastWrapAlignmentMode::END]
+    }
+    fn all_constants() -> &'static[crate::meta::inspect::EnumConstant < LastWrapAlignmentMode >] {
+        const {
+            &[crate::meta::inspect::EnumConstant::new("INHERIT", "LAST_WRAP_ALIGNMENT_INHERIT", LastWrapAlignmentMode::INHERIT), crate::meta::inspect::EnumConstant::new("BEGIN", "LAST_WRAP_ALIGNMENT_BEGIN", LastWrapAlignmentMode::BEGIN), crate::meta::inspect::EnumConstant::new("CENTER", "LAST_WRAP_ALIGNMENT_CENTER", LastWrapAlignmentMode::CENTER), crate::meta::inspect::EnumConstant::new("END", "LAST_WRAP_ALIGNMENT_END", LastWrapAlignmentMode::END)]
+        }
+    }
+}
+impl crate::meta::GodotConvert for LastWrapAlignmentMode {
+    type Via = i32;
+    
+}
+impl crate::meta::ToGodot for LastWrapAlignmentMode {
+    type Pass = crate::meta::ByValue;
+    fn to_godot(&self) -> Self::Via {
+        < Self as crate::obj::EngineEnum > ::ord(* self)
+    }
+}
+impl crate::meta::FromGodot for LastWrapAlignmentMode {
+    fn try_from_godot(via: Self::Via) -> std::result::Result < Self, crate::meta::error::ConvertError > {
+        < Self as crate::obj::EngineEnum > ::try_from_ord(via) . ok_or_else(|| crate::meta::error::FromGodotError::InvalidEnum.into_error(via))
+    }
+}
+pub use signals::*;
+mod signals {
+    use crate::obj::{
+        Gd, GodotClass
+    };
+    use super::re_export::FlowContainer;
+    use crate::registry::signal::TypedSignal;
+    use super::*;
+    use crate::obj::WithSignals;
+    use crate::classes::container::SignalsOfContainer;
+    impl WithSignals for FlowContainer {
+        type SignalCollection < 'c, C: WithSignals > = SignalsOfContainer < 'c, C >;
+        type __SignalObj < 'c > = Gd < Self >;
+        #[doc(hidden)]
+        fn __signals_from_external(gd_ref: &Gd < Self >) -> Self::SignalCollection < '_, Self > {
+            Self::SignalCollection {
+                __internal_obj: Some(gd_ref.clone()),
+            }
+        }
+    }
+}
\ No newline at end of file
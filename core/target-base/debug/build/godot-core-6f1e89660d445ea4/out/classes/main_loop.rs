@@ -0,0 +1,335 @@
+#![doc = "Sidecar module for class [`MainLoop`][crate::classes::MainLoop].\n\nDefines related flag and enum types. In GDScript, those are nested under the class scope.\n\nSee also [Godot docs for `MainLoop` enums](https://docs.godotengine.org/en/stable/classes/class_mainloop.html#enumerations).\n\n"]
+use godot_ffi as sys;
+use crate::builtin::*;
+use crate::meta::{
+    AsArg, ClassId, CowArg, InParamTuple, OutParamTuple, ParamTuple, RefArg, Signature
+};
+use crate::classes::native::*;
+use crate::classes::Object;
+use crate::obj::Gd;
+use crate::sys::GodotFfi as _;
+use crate::classes::notify::*;
+use std::ffi::c_void;
+pub(super) mod re_export {
+    use super::*;
+    #[doc = "Godot class `MainLoop.`\n\nInherits [`Object`][crate::classes::Object].\n\nRelated symbols:\n\n* [`main_loop`][crate::classes::main_loop]: sidecar module with related enum/flag types\n* [`IMainLoop`][crate::classes::IMainLoop]: virtual methods\n* [`SignalsOfMainLoop`][crate::classes::main_loop::SignalsOfMainLoop]: signal collection\n* [`MainLoopNotification`][crate::classes::notify::MainLoopNotification]: notification type\n\n\nSee also [Godot docs for `MainLoop`](https://docs.godotengine.org/en/stable/classes/class_mainloop.html).\n\n"]
+    #[doc = "# Construction\n\nThis class is manually managed. You can create a new instance using [`MainLoop::new_alloc()`][crate::obj::NewAlloc::new_alloc].\n\nDo not forget to call [`free()`][crate::obj::Gd::free] or hand over ownership to Godot."]
+    #[derive(Debug)]
+    #[repr(C)]
+    pub struct MainLoop {
+        object_ptr: sys::GDExtensionObjectPtr, rtti: Option < crate::private::ObjectRtti >,
+    }
+    #[doc = "# Interface trait for class [`MainLoop`][crate::classes::MainLoop].\n\nFunctions in this trait represent constructors (`init`) or virtual method callbacks invoked by the engine.\n\n\n\n# Related symbols\n\nBase interfaces: [`IObject`][crate::classes::IObject].\n\nSee also [Godot docs for `MainLoop` methods](https://docs.godotengine.org/en/stable/classes/class_mainloop.html#methods)."]
+    #[doc = ""]
+    #[allow(unused_variables)]
+    #[allow(clippy::unimplemented)]
+    pub trait IMainLoop: crate::obj::GodotClass < Base = MainLoop > + crate::private::You_forgot_the_attribute__godot_api {
+        #[doc(hidden)]
+        fn register_class(builder: &mut crate::builder::ClassBuilder < Self >) {
+            unimplemented !()
+        }
+        #[doc = r" Godot constructor, accepting an injected `base` object."]
+        #[doc = r""]
+        #[doc = r" `base` refers to the base instance of the class, which can either be stored in a `Base<T>` field or discarded."]
+        #[doc = r" This method returns a fully-constructed instance, which will then be moved into a [`Gd<T>`][crate::obj::Gd] pointer."]
+        #[doc = r""]
+        #[doc = r" If the class has a `#[class(init)]` attribute, this method will be auto-generated and must not be overridden."]
+        fn init(base: crate::obj::Base < Self::Base >) -> Self {
+            unimplemented !()
+        }
+        #[doc = r" String representation of the Godot instance."]
+        #[doc = r""]
+        #[doc = r" Override this method to define how the instance is represented as a string."]
+        #[doc = r" Used by `impl Display for Gd<T>`, as well as `str()` and `print()` in GDScript."]
+        fn to_string(&self) -> crate::builtin::GString {
+            unimplemented !()
+        }
+        #[doc = r" Called when the object receives a Godot notification."]
+        #[doc = r""]
+        #[doc = r" The type of notification can be identified through `what`. The enum is designed to hold all possible `NOTIFICATION_*`"]
+        #[doc = r" constants that the current class can handle. However, this is not validated in Godot, so an enum variant `Unknown` exists"]
+        #[doc = r" to represent integers out of known constants (mistakes or future additions)."]
+        #[doc = r""]
+        #[doc = r" This method is named `_notification` in Godot, but `on_notification` in Rust. To _send_ notifications, use the"]
+        #[doc = r" [`Object::notify`][crate::classes::Object::notify] method."]
+        #[doc = r""]
+        #[doc = r" See also in Godot docs:"]
+        #[doc = r" * [`Object::_notification`](https://docs.godotengine.org/en/stable/classes/class_object.html#class-object-method-notification)."]
+        #[doc = r" * [Notifications tutorial](https://docs.godotengine.org/en/stable/tutorials/best_practices/godot_notifications.html)."]
+        fn on_notification(&mut self, what: MainLoopNotification) {
+            unimplemented !()
+        }
+        #[doc = r" Called whenever [`get()`](crate::classes::Object::get) is called or Godot gets the value of a property."]
+        #[doc = r""]
+        #[doc = r" Should return the given `property`'s value as `Some(value)`, or `None` if the property should be handled normally."]
+        #[doc = r""]
+        #[doc = r" See also in Godot docs:"]
+        #[doc = r" * [`Object::_get`](https://docs.godotengine.org/en/stable/classes/class_object.html#class-object-private-method-get)."]
+        fn get_property(&self, property: StringName) -> Option < Variant > {
+            unimplemented !()
+        }
+        #[doc = r" Called whenever Godot [`set()`](crate::classes::Object::set) is called or Godot sets the value of a property."]
+        #[doc = r""]
+        #[doc = r" Should set `property` to the given `value` and return `true`, or return `false` to indicate the `property`"]
+        #[doc = r" should be handled normally."]
+        #[doc = r""]
+        #[doc = r" See also in Godot docs:"]
+        #[doc = r" * [`Object::_set`](https://docs.godotengine.org/en/stable/classes/class_object.html#class-object-private-method-set)."]
+        fn set_property(&mut self, property: StringName, value: Variant) -> bool {
+            unimplemented !()
+        }
+        #[doc = r" Called whenever Godot [`get_property_list()`](crate::classes::Object::get_property_list) is called, the returned vector here is"]
+        #[doc = r" appended to the existing list of properties."]
+        #[doc = r""]
+        #[doc = r" This should mainly be used for advanced purposes, such as dynamically updating the property list in the editor."]
+        #[doc = r""]
+        #[doc = r" See also in Godot docs:"]
+        #[doc = r" * [`Object::_get_property_list`](https://docs.godotengine.org/en/latest/classes/class_object.html#class-object-private-method-get-property-list)"]
+        #[cfg(since_api = "4.3")]
+        #[cfg_attr(published_docs, doc(cfg(since_api = "4.3")))]
+        fn get_property_list(&mut self) -> Vec < crate::meta::PropertyInfo > {
+            unimplemented !()
+        }
+        #[doc = r" Called whenever Godot retrieves value of property. Allows to customize existing properties."]
+        #[doc = r" Every property info goes through this method, except properties **added** with `get_property_list()`."]
+        #[doc = r""]
+        #[doc = r" Exposed `property` here is a shared mutable reference obtained (and returned to) from Godot."]
+        #[doc = r""]
+        #[doc = r" See also in the Godot docs:"]
+        #[doc = r" * [`Object::_validate_property`](https://docs.godotengine.org/en/stable/classes/class_object.html#class-object-private-method-validate-property)"]
+        fn validate_property(&self, property: &mut crate::meta::PropertyInfo) {
+            unimplemented !()
+        }
+        #[doc = r" Called by Godot to tell if a property has a custom revert or not."]
+        #[doc = r""]
+        #[doc = r" Return `None` for no custom revert, and return `Some(value)` to specify the custom revert."]
+        #[doc = r""]
+        #[doc = r" This is a combination of Godot's [`Object::_property_get_revert`] and [`Object::_property_can_revert`]. This means that this"]
+        #[doc = r" function will usually be called twice by Godot to find the revert."]
+        #[doc = r""]
+        #[doc = r" Note that this should be a _pure_ function. That is, it should always return the same value for a property as long as `self`"]
+        #[doc = r" remains unchanged. Otherwise, this may lead to unexpected (safe) behavior."]
+        #[doc = r""]
+        #[doc = r" [`Object::_property_get_revert`]: https://docs.godotengine.org/en/latest/classes/class_object.html#class-object-private-method-property-get-revert"]
+        #[doc = r" [`Object::_property_can_revert`]: https://docs.godotengine.org/en/latest/classes/class_object.html#class-object-private-method-property-can-revert"]
+        #[doc(alias = "property_can_revert")]
+        fn property_get_revert(&self, property: StringName) -> Option < Variant > {
+            unimplemented !()
+        }
+        fn initialize(&mut self,) {
+            unimplemented !()
+        }
+        fn physics_process(&mut self, delta: f64,) -> bool {
+            unimplemented !()
+        }
+        fn process(&mut self, delta: f64,) -> bool {
+            unimplemented !()
+        }
+        fn finalize(&mut self,) {
+            unimplemented !()
+        }
+    }
+    #[doc = "Notification type for class [`MainLoop`][crate::classes::MainLoop]."]
+    #[doc = r""]
+    #[doc = r" Makes it easier to keep an overview all possible notification variants for a given class, including"]
+    #[doc = r" notifications defined in base classes."]
+    #[doc = r""]
+    #[doc = r" Contains the [`Unknown`][Self::Unknown] variant for forward compatibility."]
+    #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+    #[repr(i32)]
+    #[allow(non_camel_case_types)]
+    pub enum MainLoopNotification {
+        OS_MEMORY_WARNING = 2009i32, TRANSLATION_CHANGED = 2010i32, WM_ABOUT = 2011i32, CRASH = 2012i32, OS_IME_UPDATE = 2013i32, APPLICATION_RESUMED = 2014i32, APPLICATION_PAUSED = 2015i32, APPLICATION_FOCUS_IN = 2016i32, APPLICATION_FOCUS_OUT = 2017i32, TEXT_SERVER_CHANGED = 2018i32, POSTINITIALIZE = 0i32, PREDELETE = 1i32, EXTENSION_RELOADED = 2i32, #[doc = r" Since Godot represents notifications as integers, it's always possible that a notification outside the known types"]
+        #[doc = r" is received. For example, the user can manually issue notifications through `Object::notify()`."]
+        #[doc = r""]
+        #[doc = r" This is also necessary if you develop an extension on a Godot version and want to be forward-compatible with newer"]
+        #[doc = r" versions. If Godot adds new notifications, they will be unknown to your extension, but you can still handle them."]
+        Unknown(i32),
+    }
+    impl From < i32 > for MainLoopNotification {
+        #[doc = r" Always succeeds, mapping unknown integers to the `Unknown` variant."]
+        fn from(enumerator: i32) -> Self {
+            match enumerator {
+                2009i32 => Self::OS_MEMORY_WARNING, 2010i32 => Self::TRANSLATION_CHANGED, 2011i32 => Self::WM_ABOUT, 2012i32 => Self::CRASH, 2013i32 => Self::OS_IME_UPDATE, 2014i32 => Self::APPLICATION_RESUMED, 2015i32 => Self::APPLICATION_PAUSED, 2016i32 => Self::APPLICATION_FOCUS_IN, 2017i32 => Self::APPLICATION_FOCUS_OUT, 2018i32 => Self::TEXT_SERVER_CHANGED, 0i32 => Self::POSTINITIALIZE, 1i32 => Self::PREDELETE, 2i32 => Self::EXTENSION_RELOADED, other_int => Self::Unknown(other_int),
+            }
+        }
+    }
+    impl From < MainLoopNotification > for i32 {
+        fn from(notification: MainLoopNotification) -> i32 {
+            match notification {
+                MainLoopNotification::OS_MEMORY_WARNING => 2009i32, MainLoopNotification::TRANSLATION_CHANGED => 2010i32, MainLoopNotification::WM_ABOUT => 2011i32, MainLoopNotification::CRASH => 2012i32, MainLoopNotification::OS_IME_UPDATE => 2013i32, MainLoopNotification::APPLICATION_RESUMED => 2014i32, MainLoopNotification::APPLICATION_PAUSED => 2015i32, MainLoopNotification::APPLICATION_FOCUS_IN => 2016i32, MainLoopNotification::APPLICATION_FOCUS_OUT => 2017i32, MainLoopNotification::TEXT_SERVER_CHANGED => 2018i32, MainLoopNotification::POSTINITIALIZE => 0i32, MainLoopNotification::PREDELETE => 1i32, MainLoopNotification::EXTENSION_RELOADED => 2i32, MainLoopNotification::Unknown(int) => int,
+            }
+        }
+    }
+    impl MainLoop {
+        #[doc = r" ⚠️ Sends a Godot notification to all classes inherited by the object."]
+        #[doc = r""]
+        #[doc = r" Triggers calls to `on_notification()`, and depending on the notification, also to Godot's lifecycle callbacks such as `ready()`."]
+        #[doc = r""]
+        #[doc = r" Starts from the highest ancestor (the `Object` class) and goes down the hierarchy."]
+        #[doc = r" See also [Godot docs for `Object::notification()`](https://docs.godotengine.org/en/latest/classes/class_object.html#id3)."]
+        #[doc = r""]
+        #[doc = r" # Panics"]
+        #[doc = r""]
+        #[doc = r" If you call this method on a user-defined object while holding a `GdRef` or `GdMut` guard on the instance, you will encounter"]
+        #[doc = r" a panic. The reason is that the receiving virtual method `on_notification()` acquires a `GdMut` lock dynamically, which must"]
+        #[doc = r" be exclusive."]
+        pub fn notify(&mut self, what: MainLoopNotification) {
+            self.notification(i32::from(what), false);
+            
+        }
+        #[doc = r" ⚠️ Like [`Self::notify()`], but starts at the most-derived class and goes up the hierarchy."]
+        #[doc = r""]
+        #[doc = r" See docs of that method, including the panics."]
+        pub fn notify_reversed(&mut self, what: MainLoopNotification) {
+            self.notification(i32::from(what), true);
+            
+        }
+        #[doc = r" Creates a validated object for FFI boundary crossing."]
+        #[doc = r""]
+        #[doc = r" Low-level internal method. Validation (liveness/type checks) depend on safeguard level."]
+        fn __validated_obj(&self) -> crate::obj::ValidatedObject {
+            let raw_gd = unsafe {
+                std::mem::transmute::< &Self, &crate::obj::RawGd < Self >> (self)
+            };
+            raw_gd.validated_object()
+        }
+        #[doc(hidden)]
+        pub fn __object_ptr(&self) -> sys::GDExtensionObjectPtr {
+            self.object_ptr
+        }
+        pub(crate) const NOTIFICATION_OS_MEMORY_WARNING: i32 = 2009i32;
+        pub(crate) const NOTIFICATION_TRANSLATION_CHANGED: i32 = 2010i32;
+        pub(crate) const NOTIFICATION_WM_ABOUT: i32 = 2011i32;
+        pub(crate) const NOTIFICATION_CRASH: i32 = 2012i32;
+        pub(crate) const NOTIFICATION_OS_IME_UPDATE: i32 = 2013i32;
+        pub(crate) const NOTIFICATION_APPLICATION_RESUMED: i32 = 2014i32;
+        pub(crate) const NOTIFICATION_APPLICATION_PAUSED: i32 = 2015i32;
+        pub(crate) const NOTIFICATION_APPLICATION_FOCUS_IN: i32 = 2016i32;
+        pub(crate) const NOTIFICATION_APPLICATION_FOCUS_OUT: i32 = 2017i32;
+        pub(crate) const NOTIFICATION_TEXT_SERVER_CHANGED: i32 = 2018i32;
+        
+    }
+    impl crate::obj::GodotClass for MainLoop {
+        type Base = crate::classes::Object;
+        fn class_id() -> ClassId {
+            static CLASS_ID: std::sync::OnceLock < ClassId > = std::sync::OnceLock::new();
+            let name: &'static ClassId = CLASS_ID.get_or_init(|| ClassId::__alloc_next_unicode("MainLoop"));
+            * name
+        }
+        const INIT_LEVEL: crate::init::InitLevel = crate::init::InitLevel::Core;
+        
+    }
+    unsafe impl crate::obj::Bounds for MainLoop {
+        type Memory = crate::obj::bounds::MemManual;
+        type DynMemory = crate::obj::bounds::MemManual;
+        type Declarer = crate::obj::bounds::DeclEngine;
+        type Exportable = crate::obj::bounds::No;
+        
+    }
+    unsafe impl crate::obj::Inherits < crate::classes::Object > for MainLoop {
+        
+    }
+    impl crate::obj::cap::GodotDefault for MainLoop {
+        fn __godot_default() -> crate::obj::Gd < Self > {
+            crate::classes::construct_engine_object::< Self > ()
+        }
+    }
+    impl std::ops::Deref for MainLoop {
+        type Target = crate::classes::Object;
+        fn deref(&self) -> &Self::Target {
+            unsafe {
+                std::mem::transmute::< &Self, &Self::Target > (self)
+            }
+        }
+    }
+    impl std::ops::DerefMut for MainLoop {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            unsafe {
+                std::mem::transmute::< &mut Self, &mut Self::Target > (self)
+            }
+        }
+    }
+    #[doc = r" # Safety"]
+    #[doc = r""]
+    #[doc = "The provided class must be a subclass of all the superclasses of [`MainLoop`]"]
+    #[macro_export]
+    #[allow(non_snake_case)]
+    macro_rules !inherit_from_MainLoop__ensure_class_exists {
+        ($Class: ident) => {
+            unsafe impl::godot::obj::Inherits < ::godot::classes::MainLoop > for $Class {
+                
+            }
+            unsafe impl::godot::obj::Inherits < ::godot::classes::Object > for $Class {
+                
+            }
+        }
+    }
+}
+pub use signals::*;
+mod signals {
+    use crate::obj::{
+        Gd, GodotClass
+    };
+    use super::re_export::MainLoop;
+    use crate::registry::signal::TypedSignal;
+    use super::*;
+    #[doc = "A collection of signals for the [`MainLoop`][crate::classes::MainLoop] class."]
+    pub struct SignalsOfMainLoop < 'c, C: WithSignals > {
+        #[doc(hidden)]
+        pub(crate) __internal_obj: Option < C::__SignalObj < 'c >>,
+    }
+    impl < 'c, C: WithSignals > SignalsOfMainLoop < 'c, C > {
+        #[doc = "Signature: `(permission: GString, granted: bool)`"]
+        pub fn on_request_permissions_result(&mut self) -> SigOnRequestPermissionsResult < 'c, C > {
+            SigOnRequestPermissionsResult {
+                typed: TypedSignal::extract(&mut self.__internal_obj, "on_request_permissions_result")
+            }
+        }
+    }
+    type TypedSigOnRequestPermissionsResult < 'c, C > = TypedSignal < 'c, C, (GString, bool,) >;
+    pub struct SigOnRequestPermissionsResult < 'c, C: WithSignals > {
+        typed: TypedSigOnRequestPermissionsResult < 'c, C >,
+    }
+    impl < 'c, C: WithSignals > SigOnRequestPermissionsResult < 'c, C > {
+        pub fn emit(&mut self, permission: GString, granted: bool,) {
+            self.typed.emit_tuple((permission, granted,));
+            
+        }
+    }
+    impl < 'c, C: WithSignals > std::ops::Deref for SigOnRequestPermissionsResult < 'c, C > {
+        type Target = TypedSigOnRequestPermissionsResult < 'c, C >;
+        fn deref(&self) -> &Self::Target {
+            &self.typed
+        }
+    }
+    impl < C: WithSignals > std::ops::DerefMut for SigOnRequestPermissionsResult < '_, C > {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            &mut self.typed
+        }
+    }
+    use crate::obj::WithSignals;
+    impl WithSignals for MainLoop {
+        type SignalCollection < 'c, C: WithSignals > = SignalsOfMainLoop < 'c, C >;
+        type __SignalObj < 'c > = Gd < Self >;
+        #[doc(hidden)]
+        fn __signals_from_external(gd_ref: &Gd < Self >) -> Self::SignalCollection < '_, Self > {
+            Self::SignalCollection {
+                __internal_obj: Some(gd_ref.clone()),
+            }
+        }
+    }
+    impl < 'c, C: WithSignals > std::ops::Deref for SignalsOfMainLoop < 'c, C > {
+        type Target = < < MainLoop as crate::obj::GodotClass > ::Base as WithSignals > ::SignalCollection < 'c, C >;
+        fn deref(&self) -> &Self::Target {
+            type Derived = MainLoop;
+            crate::private::signal_collection_to_base::< C, Derived > (self)
+        }
+    }
+    impl < 'c, C: WithSignals > std::ops::DerefMut for SignalsOfMainLoop < 'c, C > {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            type Derived = MainLoop;
+            crate::private::signal_collection_to_base_mut::< C, Derived > (self)
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,3429 @@
+pub mod aes_context;
+pub use aes_context::re_export::AesContext;
+pub use aes_context::re_export::IAesContext;
+pub mod a_star_2d;
+pub use a_star_2d::re_export::AStar2D;
+pub use a_star_2d::re_export::IAStar2D;
+pub mod a_star_3d;
+pub use a_star_3d::re_export::AStar3D;
+pub use a_star_3d::re_export::IAStar3D;
+pub mod a_star_grid_2d;
+pub use a_star_grid_2d::re_export::AStarGrid2D;
+pub use a_star_grid_2d::re_export::IAStarGrid2D;
+pub mod accept_dialog;
+pub use accept_dialog::re_export::AcceptDialog;
+pub use accept_dialog::re_export::IAcceptDialog;
+mod aim_modifier_3d;
+pub use aim_modifier_3d::re_export::AimModifier3D;
+pub use aim_modifier_3d::re_export::IAimModifier3D;
+mod animatable_body_2d;
+pub use animatable_body_2d::re_export::AnimatableBody2D;
+pub use animatable_body_2d::re_export::IAnimatableBody2D;
+mod animatable_body_3d;
+pub use animatable_body_3d::re_export::AnimatableBody3D;
+pub use animatable_body_3d::re_export::IAnimatableBody3D;
+pub mod animated_sprite_2d;
+pub use animated_sprite_2d::re_export::AnimatedSprite2D;
+pub use animated_sprite_2d::re_export::IAnimatedSprite2D;
+pub mod animated_sprite_3d;
+pub use animated_sprite_3d::re_export::AnimatedSprite3D;
+pub use animated_sprite_3d::re_export::IAnimatedSprite3D;
+mod animated_texture;
+pub use animated_texture::re_export::AnimatedTexture;
+pub use animated_texture::re_export::IAnimatedTexture;
+pub mod animation;
+pub use animation::re_export::Animation;
+pub use animation::re_export::IAnimation;
+pub mod animation_library;
+pub use animation_library::re_export::AnimationLibrary;
+pub use animation_library::re_export::IAnimationLibrary;
+pub mod animation_mixer;
+pub use animation_mixer::re_export::AnimationMixer;
+pub mod animation_node;
+pub use animation_node::re_export::AnimationNode;
+pub use animation_node::re_export::IAnimationNode;
+mod animation_node_add2;
+pub use animation_node_add2::re_export::AnimationNodeAdd2;
+pub use animation_node_add2::re_export::IAnimationNodeAdd2;
+mod animation_node_add3;
+pub use animation_node_add3::re_export::AnimationNodeAdd3;
+pub use animation_node_add3::re_export::IAnimationNodeAdd3;
+pub mod animation_node_animation;
+pub use animation_node_animation::re_export::AnimationNodeAnimation;
+pub use animation_node_animation::re_export::IAnimationNodeAnimation;
+mod animation_node_blend2;
+pub use animation_node_blend2::re_export::AnimationNodeBlend2;
+pub use animation_node_blend2::re_export::IAnimationNodeBlend2;
+mod animation_node_blend3;
+pub use animation_node_blend3::re_export::AnimationNodeBlend3;
+pub use animation_node_blend3::re_export::IAnimationNodeBlend3;
+pub mod animation_node_blend_space_1d;
+pub use animation_node_blend_space_1d::re_export::AnimationNodeBlendSpace1D;
+pub use animation_node_blend_space_1d::re_export::IAnimationNodeBlendSpace1D;
+pub mod animation_node_blend_space_2d;
+pub use animation_node_blend_space_2d::re_export::AnimationNodeBlendSpace2D;
+pub use animation_node_blend_space_2d::re_export::IAnimationNodeBlendSpace2D;
+pub mod animation_node_blend_tree;
+pub use animation_node_blend_tree::re_export::AnimationNodeBlendTree;
+pub use animation_node_blend_tree::re_export::IAnimationNodeBlendTree;
+mod animation_node_extension;
+pub use animation_node_extension::re_export::AnimationNodeExtension;
+pub use animation_node_extension::re_export::IAnimationNodeExtension;
+pub mod animation_node_one_shot;
+pub use animation_node_one_shot::re_export::AnimationNodeOneShot;
+pub use animation_node_one_shot::re_export::IAnimationNodeOneShot;
+mod animation_node_output;
+pub use animation_node_output::re_export::AnimationNodeOutput;
+pub use animation_node_output::re_export::IAnimationNodeOutput;
+pub mod animation_node_state_machine;
+pub use animation_node_state_machine::re_export::AnimationNodeStateMachine;
+pub use animation_node_state_machine::re_export::IAnimationNodeStateMachine;
+pub mod animation_node_state_machine_playback;
+pub use animation_node_state_machine_playback::re_export::AnimationNodeStateMachinePlayback;
+pub use animation_node_state_machine_playback::re_export::IAnimationNodeStateMachinePlayback;
+pub mod animation_node_state_machine_transition;
+pub use animation_node_state_machine_transition::re_export::AnimationNodeStateMachineTransition;
+pub use animation_node_state_machine_transition::re_export::IAnimationNodeStateMachineTransition;
+mod animation_node_sub2;
+pub use animation_node_sub2::re_export::AnimationNodeSub2;
+pub use animation_node_sub2::re_export::IAnimationNodeSub2;
+mod animation_node_sync;
+pub use animation_node_sync::re_export::AnimationNodeSync;
+pub use animation_node_sync::re_export::IAnimationNodeSync;
+mod animation_node_time_scale;
+pub use animation_node_time_scale::re_export::AnimationNodeTimeScale;
+pub use animation_node_time_scale::re_export::IAnimationNodeTimeScale;
+mod animation_node_time_seek;
+pub use animation_node_time_seek::re_export::AnimationNodeTimeSeek;
+pub use animation_node_time_seek::re_export::IAnimationNodeTimeSeek;
+mod animation_node_transition;
+pub use animation_node_transition::re_export::AnimationNodeTransition;
+pub use animation_node_transition::re_export::IAnimationNodeTransition;
+pub mod animation_player;
+pub use animation_player::re_export::AnimationPlayer;
+pub use animation_player::re_export::IAnimationPlayer;
+mod animation_root_node;
+pub use animation_root_node::re_export::AnimationRootNode;
+pub use animation_root_node::re_export::IAnimationRootNode;
+pub mod animation_tree;
+pub use animation_tree::re_export::AnimationTree;
+pub use animation_tree::re_export::IAnimationTree;
+pub mod area_2d;
+pub use area_2d::re_export::Area2D;
+pub use area_2d::re_export::IArea2D;
+pub mod area_3d;
+pub use area_3d::re_export::Area3D;
+pub use area_3d::re_export::IArea3D;
+pub mod array_mesh;
+pub use array_mesh::re_export::ArrayMesh;
+pub use array_mesh::re_export::IArrayMesh;
+mod array_occluder_3d;
+pub use array_occluder_3d::re_export::ArrayOccluder3D;
+pub use array_occluder_3d::re_export::IArrayOccluder3D;
+pub mod aspect_ratio_container;
+pub use aspect_ratio_container::re_export::AspectRatioContainer;
+pub use aspect_ratio_container::re_export::IAspectRatioContainer;
+mod atlas_texture;
+pub use atlas_texture::re_export::AtlasTexture;
+pub use atlas_texture::re_export::IAtlasTexture;
+mod audio_bus_layout;
+pub use audio_bus_layout::re_export::AudioBusLayout;
+pub use audio_bus_layout::re_export::IAudioBusLayout;
+mod audio_effect;
+pub use audio_effect::re_export::AudioEffect;
+pub use audio_effect::re_export::IAudioEffect;
+mod audio_effect_amplify;
+pub use audio_effect_amplify::re_export::AudioEffectAmplify;
+pub use audio_effect_amplify::re_export::IAudioEffectAmplify;
+mod audio_effect_band_limit_filter;
+pub use audio_effect_band_limit_filter::re_export::AudioEffectBandLimitFilter;
+pub use audio_effect_band_limit_filter::re_export::IAudioEffectBandLimitFilter;
+mod audio_effect_band_pass_filter;
+pub use audio_effect_band_pass_filter::re_export::AudioEffectBandPassFilter;
+pub use audio_effect_band_pass_filter::re_export::IAudioEffectBandPassFilter;
+mod audio_effect_capture;
+pub use audio_effect_capture::re_export::AudioEffectCapture;
+pub use audio_effect_capture::re_export::IAudioEffectCapture;
+mod audio_effect_chorus;
+pub use audio_effect_chorus::re_export::AudioEffectChorus;
+pub use audio_effect_chorus::re_export::IAudioEffectChorus;
+mod audio_effect_compressor;
+pub use audio_effect_compressor::re_export::AudioEffectCompressor;
+pub use audio_effect_compressor::re_export::IAudioEffectCompressor;
+mod audio_effect_delay;
+pub use audio_effect_delay::re_export::AudioEffectDelay;
+pub use audio_effect_delay::re_export::IAudioEffectDelay;
+pub mod audio_effect_distortion;
+pub use audio_effect_distortion::re_export::AudioEffectDistortion;
+pub use audio_effect_distortion::re_export::IAudioEffectDistortion;
+mod audio_effect_eq;
+pub use audio_effect_eq::re_export::AudioEffectEq;
+pub use audio_effect_eq::re_export::IAudioEffectEq;
+mod audio_effect_eq10;
+pub use audio_effect_eq10::re_export::AudioEffectEq10;
+pub use audio_effect_eq10::re_export::IAudioEffectEq10;
+mod audio_effect_eq21;
+pub use audio_effect_eq21::re_export::AudioEffectEq21;
+pub use audio_effect_eq21::re_export::IAudioEffectEq21;
+mod audio_effect_eq6;
+pub use audio_effect_eq6::re_export::AudioEffectEq6;
+pub use audio_effect_eq6::re_export::IAudioEffectEq6;
+pub mod audio_effect_filter;
+pub use audio_effect_filter::re_export::AudioEffectFilter;
+pub use audio_effect_filter::re_export::IAudioEffectFilter;
+mod audio_effect_hard_limiter;
+pub use audio_effect_hard_limiter::re_export::AudioEffectHardLimiter;
+pub use audio_effect_hard_limiter::re_export::IAudioEffectHardLimiter;
+mod audio_effect_high_pass_filter;
+pub use audio_effect_high_pass_filter::re_export::AudioEffectHighPassFilter;
+pub use audio_effect_high_pass_filter::re_export::IAudioEffectHighPassFilter;
+mod audio_effect_high_shelf_filter;
+pub use audio_effect_high_shelf_filter::re_export::AudioEffectHighShelfFilter;
+pub use audio_effect_high_shelf_filter::re_export::IAudioEffectHighShelfFilter;
+mod audio_effect_instance;
+pub use audio_effect_instance::re_export::AudioEffectInstance;
+pub use audio_effect_instance::re_export::IAudioEffectInstance;
+mod audio_effect_limiter;
+pub use audio_effect_limiter::re_export::AudioEffectLimiter;
+pub use audio_effect_limiter::re_export::IAudioEffectLimiter;
+mod audio_effect_low_pass_filter;
+pub use audio_effect_low_pass_filter::re_export::AudioEffectLowPassFilter;
+pub use audio_effect_low_pass_filter::re_export::IAudioEffectLowPassFilter;
+mod audio_effect_low_shelf_filter;
+pub use audio_effect_low_shelf_filter::re_export::AudioEffectLowShelfFilter;
+pub use audio_effect_low_shelf_filter::re_export::IAudioEffectLowShelfFilter;
+mod audio_effect_notch_filter;
+pub use audio_effect_notch_filter::re_export::AudioEffectNotchFilter;
+pub use audio_effect_notch_filter::re_export::IAudioEffectNotchFilter;
+mod audio_effect_panner;
+pub use audio_effect_panner::re_export::AudioEffectPanner;
+pub use audio_effect_panner::re_export::IAudioEffectPanner;
+mod audio_effect_phaser;
+pub use audio_effect_phaser::re_export::AudioEffectPhaser;
+pub use audio_effect_phaser::re_export::IAudioEffectPhaser;
+pub mod audio_effect_pitch_shift;
+pub use audio_effect_pitch_shift::re_export::AudioEffectPitchShift;
+pub use audio_effect_pitch_shift::re_export::IAudioEffectPitchShift;
+mod audio_effect_record;
+pub use audio_effect_record::re_export::AudioEffectRecord;
+pub use audio_effect_record::re_export::IAudioEffectRecord;
+mod audio_effect_reverb;
+pub use audio_effect_reverb::re_export::AudioEffectReverb;
+pub use audio_effect_reverb::re_export::IAudioEffectReverb;
+pub mod audio_effect_spectrum_analyzer;
+pub use audio_effect_spectrum_analyzer::re_export::AudioEffectSpectrumAnalyzer;
+pub use audio_effect_spectrum_analyzer::re_export::IAudioEffectSpectrumAnalyzer;
+pub mod audio_effect_spectrum_analyzer_instance;
+pub use audio_effect_spectrum_analyzer_instance::re_export::AudioEffectSpectrumAnalyzerInstance;
+mod audio_effect_stereo_enhance;
+pub use audio_effect_stereo_enhance::re_export::AudioEffectStereoEnhance;
+pub use audio_effect_stereo_enhance::re_export::IAudioEffectStereoEnhance;
+mod audio_listener_2d;
+pub use audio_listener_2d::re_export::AudioListener2D;
+pub use audio_listener_2d::re_export::IAudioListener2D;
+pub mod audio_listener_3d;
+pub use audio_listener_3d::re_export::AudioListener3D;
+pub use audio_listener_3d::re_export::IAudioListener3D;
+pub mod audio_server;
+pub use audio_server::re_export::AudioServer;
+pub mod audio_stream;
+pub use audio_stream::re_export::AudioStream;
+pub use audio_stream::re_export::IAudioStream;
+pub mod audio_stream_generator;
+pub use audio_stream_generator::re_export::AudioStreamGenerator;
+pub use audio_stream_generator::re_export::IAudioStreamGenerator;
+mod audio_stream_generator_playback;
+pub use audio_stream_generator_playback::re_export::AudioStreamGeneratorPlayback;
+pub mod audio_stream_interactive;
+pub use audio_stream_interactive::re_export::AudioStreamInteractive;
+pub use audio_stream_interactive::re_export::IAudioStreamInteractive;
+mod audio_stream_mp3;
+pub use audio_stream_mp3::re_export::AudioStreamMp3;
+pub use audio_stream_mp3::re_export::IAudioStreamMp3;
+mod audio_stream_microphone;
+pub use audio_stream_microphone::re_export::AudioStreamMicrophone;
+pub use audio_stream_microphone::re_export::IAudioStreamMicrophone;
+mod audio_stream_ogg_vorbis;
+pub use audio_stream_ogg_vorbis::re_export::AudioStreamOggVorbis;
+pub use audio_stream_ogg_vorbis::re_export::IAudioStreamOggVorbis;
+pub mod audio_stream_playback;
+pub use audio_stream_playback::re_export::AudioStreamPlayback;
+pub use audio_stream_playback::re_export::IAudioStreamPlayback;
+mod audio_stream_playback_interactive;
+pub use audio_stream_playback_interactive::re_export::AudioStreamPlaybackInteractive;
+mod audio_stream_playback_ogg_vorbis;
+pub use audio_stream_playback_ogg_vorbis::re_export::AudioStreamPlaybackOggVorbis;
+pub use audio_stream_playback_ogg_vorbis::re_export::IAudioStreamPlaybackOggVorbis;
+mod audio_stream_playback_playlist;
+pub use audio_stream_playback_playlist::re_export::AudioStreamPlaybackPlaylist;
+pub mod audio_stream_playback_polyphonic;
+pub use audio_stream_playback_polyphonic::re_export::AudioStreamPlaybackPolyphonic;
+mod audio_stream_playback_resampled;
+pub use audio_stream_playback_resampled::re_export::AudioStreamPlaybackResampled;
+pub use audio_stream_playback_resampled::re_export::IAudioStreamPlaybackResampled;
+mod audio_stream_playback_synchronized;
+pub use audio_stream_playback_synchronized::re_export::AudioStreamPlaybackSynchronized;
+pub mod audio_stream_player;
+pub use audio_stream_player::re_export::AudioStreamPlayer;
+pub use audio_stream_player::re_export::IAudioStreamPlayer;
+pub mod audio_stream_player_2d;
+pub use audio_stream_player_2d::re_export::AudioStreamPlayer2D;
+pub use audio_stream_player_2d::re_export::IAudioStreamPlayer2D;
+pub mod audio_stream_player_3d;
+pub use audio_stream_player_3d::re_export::AudioStreamPlayer3D;
+pub use audio_stream_player_3d::re_export::IAudioStreamPlayer3D;
+mod audio_stream_playlist;
+pub use audio_stream_playlist::re_export::AudioStreamPlaylist;
+pub use audio_stream_playlist::re_export::IAudioStreamPlaylist;
+mod audio_stream_polyphonic;
+pub use audio_stream_polyphonic::re_export::AudioStreamPolyphonic;
+pub use audio_stream_polyphonic::re_export::IAudioStreamPolyphonic;
+pub mod audio_stream_randomizer;
+pub use audio_stream_randomizer::re_export::AudioStreamRandomizer;
+pub use audio_stream_randomizer::re_export::IAudioStreamRandomizer;
+mod audio_stream_synchronized;
+pub use audio_stream_synchronized::re_export::AudioStreamSynchronized;
+pub use audio_stream_synchronized::re_export::IAudioStreamSynchronized;
+pub mod audio_stream_wav;
+pub use audio_stream_wav::re_export::AudioStreamWav;
+pub use audio_stream_wav::re_export::IAudioStreamWav;
+pub mod back_buffer_copy;
+pub use back_buffer_copy::re_export::BackBufferCopy;
+pub use back_buffer_copy::re_export::IBackBufferCopy;
+pub mod base_button;
+pub use base_button::re_export::BaseButton;
+pub use base_button::re_export::IBaseButton;
+pub mod base_material_3d;
+pub use base_material_3d::re_export::BaseMaterial3D;
+pub mod bit_map;
+pub use bit_map::re_export::BitMap;
+pub use bit_map::re_export::IBitMap;
+mod bone_2d;
+pub use bone_2d::re_export::Bone2D;
+pub use bone_2d::re_export::IBone2D;
+mod bone_attachment_3d;
+pub use bone_attachment_3d::re_export::BoneAttachment3D;
+pub use bone_attachment_3d::re_export::IBoneAttachment3D;
+mod bone_constraint_3d;
+pub use bone_constraint_3d::re_export::BoneConstraint3D;
+pub use bone_constraint_3d::re_export::IBoneConstraint3D;
+pub mod bone_map;
+pub use bone_map::re_export::BoneMap;
+pub use bone_map::re_export::IBoneMap;
+pub mod box_container;
+pub use box_container::re_export::BoxContainer;
+pub use box_container::re_export::IBoxContainer;
+mod box_mesh;
+pub use box_mesh::re_export::BoxMesh;
+pub use box_mesh::re_export::IBoxMesh;
+mod box_occluder_3d;
+pub use box_occluder_3d::re_export::BoxOccluder3D;
+pub use box_occluder_3d::re_export::IBoxOccluder3D;
+mod box_shape_3d;
+pub use box_shape_3d::re_export::BoxShape3D;
+pub use box_shape_3d::re_export::IBoxShape3D;
+mod button;
+pub use button::re_export::Button;
+pub use button::re_export::IButton;
+pub mod button_group;
+pub use button_group::re_export::ButtonGroup;
+pub use button_group::re_export::IButtonGroup;
+pub mod cpu_particles_2d;
+pub use cpu_particles_2d::re_export::CpuParticles2D;
+pub use cpu_particles_2d::re_export::ICpuParticles2D;
+pub mod cpu_particles_3d;
+pub use cpu_particles_3d::re_export::CpuParticles3D;
+pub use cpu_particles_3d::re_export::ICpuParticles3D;
+mod csg_box_3d;
+pub use csg_box_3d::re_export::CsgBox3D;
+pub use csg_box_3d::re_export::ICsgBox3D;
+mod csg_combiner_3d;
+pub use csg_combiner_3d::re_export::CsgCombiner3D;
+pub use csg_combiner_3d::re_export::ICsgCombiner3D;
+mod csg_cylinder_3d;
+pub use csg_cylinder_3d::re_export::CsgCylinder3D;
+pub use csg_cylinder_3d::re_export::ICsgCylinder3D;
+mod csg_mesh_3d;
+pub use csg_mesh_3d::re_export::CsgMesh3D;
+pub use csg_mesh_3d::re_export::ICsgMesh3D;
+pub mod csg_polygon_3d;
+pub use csg_polygon_3d::re_export::CsgPolygon3D;
+pub use csg_polygon_3d::re_export::ICsgPolygon3D;
+mod csg_primitive_3d;
+pub use csg_primitive_3d::re_export::CsgPrimitive3D;
+pub mod csg_shape_3d;
+pub use csg_shape_3d::re_export::CsgShape3D;
+mod csg_sphere_3d;
+pub use csg_sphere_3d::re_export::CsgSphere3D;
+pub use csg_sphere_3d::re_export::ICsgSphere3D;
+mod csg_torus_3d;
+pub use csg_torus_3d::re_export::CsgTorus3D;
+pub use csg_torus_3d::re_export::ICsgTorus3D;
+mod callback_tweener;
+pub use callback_tweener::re_export::CallbackTweener;
+pub use callback_tweener::re_export::ICallbackTweener;
+pub mod camera_2d;
+pub use camera_2d::re_export::Camera2D;
+pub use camera_2d::re_export::ICamera2D;
+pub mod camera_3d;
+pub use camera_3d::re_export::Camera3D;
+pub use camera_3d::re_export::ICamera3D;
+mod camera_attributes;
+pub use camera_attributes::re_export::CameraAttributes;
+pub use camera_attributes::re_export::ICameraAttributes;
+mod camera_attributes_physical;
+pub use camera_attributes_physical::re_export::CameraAttributesPhysical;
+pub use camera_attributes_physical::re_export::ICameraAttributesPhysical;
+mod camera_attributes_practical;
+pub use camera_attributes_practical::re_export::CameraAttributesPractical;
+pub use camera_attributes_practical::re_export::ICameraAttributesPractical;
+pub mod camera_feed;
+pub use camera_feed::re_export::CameraFeed;
+pub use camera_feed::re_export::ICameraFeed;
+pub mod camera_server;
+pub use camera_server::re_export::CameraServer;
+mod camera_texture;
+pub use camera_texture::re_export::CameraTexture;
+pub use camera_texture::re_export::ICameraTexture;
+mod canvas_group;
+pub use canvas_group::re_export::CanvasGroup;
+pub use canvas_group::re_export::ICanvasGroup;
+pub mod canvas_item;
+pub use canvas_item::re_export::CanvasItem;
+pub mod canvas_item_material;
+pub use canvas_item_material::re_export::CanvasItemMaterial;
+pub use canvas_item_material::re_export::ICanvasItemMaterial;
+pub mod canvas_layer;
+pub use canvas_layer::re_export::CanvasLayer;
+pub use canvas_layer::re_export::ICanvasLayer;
+mod canvas_modulate;
+pub use canvas_modulate::re_export::CanvasModulate;
+pub use canvas_modulate::re_export::ICanvasModulate;
+mod canvas_texture;
+pub use canvas_texture::re_export::CanvasTexture;
+pub use canvas_texture::re_export::ICanvasTexture;
+mod capsule_mesh;
+pub use capsule_mesh::re_export::CapsuleMesh;
+pub use capsule_mesh::re_export::ICapsuleMesh;
+mod capsule_shape_2d;
+pub use capsule_shape_2d::re_export::CapsuleShape2D;
+pub use capsule_shape_2d::re_export::ICapsuleShape2D;
+mod capsule_shape_3d;
+pub use capsule_shape_3d::re_export::CapsuleShape3D;
+pub use capsule_shape_3d::re_export::ICapsuleShape3D;
+mod center_container;
+pub use center_container::re_export::CenterContainer;
+pub use center_container::re_export::ICenterContainer;
+mod char_fx_transform;
+pub use char_fx_transform::re_export::CharFxTransform;
+pub use char_fx_transform::re_export::ICharFxTransform;
+pub mod character_body_2d;
+pub use character_body_2d::re_export::CharacterBody2D;
+pub use character_body_2d::re_export::ICharacterBody2D;
+pub mod character_body_3d;
+pub use character_body_3d::re_export::CharacterBody3D;
+pub use character_body_3d::re_export::ICharacterBody3D;
+mod check_box;
+pub use check_box::re_export::CheckBox;
+pub use check_box::re_export::ICheckBox;
+mod check_button;
+pub use check_button::re_export::CheckButton;
+pub use check_button::re_export::ICheckButton;
+mod circle_shape_2d;
+pub use circle_shape_2d::re_export::CircleShape2D;
+pub use circle_shape_2d::re_export::ICircleShape2D;
+pub mod class_db;
+pub use class_db::re_export::ClassDb;
+pub mod code_edit;
+pub use code_edit::re_export::CodeEdit;
+pub use code_edit::re_export::ICodeEdit;
+pub mod code_highlighter;
+pub use code_highlighter::re_export::CodeHighlighter;
+pub use code_highlighter::re_export::ICodeHighlighter;
+pub mod collision_object_2d;
+pub use collision_object_2d::re_export::CollisionObject2D;
+pub mod collision_object_3d;
+pub use collision_object_3d::re_export::CollisionObject3D;
+pub mod collision_polygon_2d;
+pub use collision_polygon_2d::re_export::CollisionPolygon2D;
+pub use collision_polygon_2d::re_export::ICollisionPolygon2D;
+mod collision_polygon_3d;
+pub use collision_polygon_3d::re_export::CollisionPolygon3D;
+pub use collision_polygon_3d::re_export::ICollisionPolygon3D;
+mod collision_shape_2d;
+pub use collision_shape_2d::re_export::CollisionShape2D;
+pub use collision_shape_2d::re_export::ICollisionShape2D;
+mod collision_shape_3d;
+pub use collision_shape_3d::re_export::CollisionShape3D;
+pub use collision_shape_3d::re_export::ICollisionShape3D;
+mod color_palette;
+pub use color_palette::re_export::ColorPalette;
+pub use color_palette::re_export::IColorPalette;
+pub mod color_picker;
+pub use color_picker::re_export::ColorPicker;
+pub use color_picker::re_export::IColorPicker;
+pub mod color_picker_button;
+pub use color_picker_button::re_export::ColorPickerButton;
+pub use color_picker_button::re_export::IColorPickerButton;
+mod color_rect;
+pub use color_rect::re_export::ColorRect;
+pub use color_rect::re_export::IColorRect;
+mod compressed_cubemap;
+pub use compressed_cubemap::re_export::CompressedCubemap;
+pub use compressed_cubemap::re_export::ICompressedCubemap;
+mod compressed_cubemap_array;
+pub use compressed_cubemap_array::re_export::CompressedCubemapArray;
+pub use compressed_cubemap_array::re_export::ICompressedCubemapArray;
+mod compressed_texture_2d;
+pub use compressed_texture_2d::re_export::CompressedTexture2D;
+pub use compressed_texture_2d::re_export::ICompressedTexture2D;
+mod compressed_texture_2d_array;
+pub use compressed_texture_2d_array::re_export::CompressedTexture2DArray;
+pub use compressed_texture_2d_array::re_export::ICompressedTexture2DArray;
+mod compressed_texture_3d;
+pub use compressed_texture_3d::re_export::CompressedTexture3D;
+pub use compressed_texture_3d::re_export::ICompressedTexture3D;
+mod compressed_texture_layered;
+pub use compressed_texture_layered::re_export::CompressedTextureLayered;
+mod concave_polygon_shape_2d;
+pub use concave_polygon_shape_2d::re_export::ConcavePolygonShape2D;
+pub use concave_polygon_shape_2d::re_export::IConcavePolygonShape2D;
+mod concave_polygon_shape_3d;
+pub use concave_polygon_shape_3d::re_export::ConcavePolygonShape3D;
+pub use concave_polygon_shape_3d::re_export::IConcavePolygonShape3D;
+pub mod cone_twist_joint_3d;
+pub use cone_twist_joint_3d::re_export::ConeTwistJoint3D;
+pub use cone_twist_joint_3d::re_export::IConeTwistJoint3D;
+pub mod config_file;
+pub use config_file::re_export::ConfigFile;
+pub use config_file::re_export::IConfigFile;
+mod confirmation_dialog;
+pub use confirmation_dialog::re_export::ConfirmationDialog;
+pub use confirmation_dialog::re_export::IConfirmationDialog;
+pub mod container;
+pub use container::re_export::Container;
+pub use container::re_export::IContainer;
+pub mod control;
+pub use control::re_export::Control;
+pub use control::re_export::IControl;
+pub mod convert_transform_modifier_3d;
+pub use convert_transform_modifier_3d::re_export::ConvertTransformModifier3D;
+pub use convert_transform_modifier_3d::re_export::IConvertTransformModifier3D;
+mod convex_polygon_shape_2d;
+pub use convex_polygon_shape_2d::re_export::ConvexPolygonShape2D;
+pub use convex_polygon_shape_2d::re_export::IConvexPolygonShape2D;
+mod convex_polygon_shape_3d;
+pub use convex_polygon_shape_3d::re_export::ConvexPolygonShape3D;
+pub use convex_polygon_shape_3d::re_export::IConvexPolygonShape3D;
+pub mod copy_transform_modifier_3d;
+pub use copy_transform_modifier_3d::re_export::CopyTransformModifier3D;
+pub use copy_transform_modifier_3d::re_export::ICopyTransformModifier3D;
+pub mod crypto;
+pub use crypto::re_export::Crypto;
+pub use crypto::re_export::ICrypto;
+pub mod crypto_key;
+pub use crypto_key::re_export::CryptoKey;
+pub use crypto_key::re_export::ICryptoKey;
+mod cubemap;
+pub use cubemap::re_export::Cubemap;
+pub use cubemap::re_export::ICubemap;
+mod cubemap_array;
+pub use cubemap_array::re_export::CubemapArray;
+pub use cubemap_array::re_export::ICubemapArray;
+pub mod curve;
+pub use curve::re_export::Curve;
+pub use curve::re_export::ICurve;
+pub mod curve_2d;
+pub use curve_2d::re_export::Curve2D;
+pub use curve_2d::re_export::ICurve2D;
+pub mod curve_3d;
+pub use curve_3d::re_export::Curve3D;
+pub use curve_3d::re_export::ICurve3D;
+pub mod curve_texture;
+pub use curve_texture::re_export::CurveTexture;
+pub use curve_texture::re_export::ICurveTexture;
+mod curve_xyz_texture;
+pub use curve_xyz_texture::re_export::CurveXyzTexture;
+pub use curve_xyz_texture::re_export::ICurveXyzTexture;
+mod cylinder_mesh;
+pub use cylinder_mesh::re_export::CylinderMesh;
+pub use cylinder_mesh::re_export::ICylinderMesh;
+mod cylinder_shape_3d;
+pub use cylinder_shape_3d::re_export::CylinderShape3D;
+pub use cylinder_shape_3d::re_export::ICylinderShape3D;
+pub mod dpi_texture;
+pub use dpi_texture::re_export::DpiTexture;
+pub use dpi_texture::re_export::IDpiTexture;
+mod dtls_server;
+pub use dtls_server::re_export::DtlsServer;
+pub use dtls_server::re_export::IDtlsServer;
+mod damped_spring_joint_2d;
+pub use damped_spring_joint_2d::re_export::DampedSpringJoint2D;
+pub use damped_spring_joint_2d::re_export::IDampedSpringJoint2D;
+pub mod decal;
+pub use decal::re_export::Decal;
+pub use decal::re_export::IDecal;
+pub mod dir_access;
+pub use dir_access::re_export::DirAccess;
+mod directional_light_2d;
+pub use directional_light_2d::re_export::DirectionalLight2D;
+pub use directional_light_2d::re_export::IDirectionalLight2D;
+pub mod directional_light_3d;
+pub use directional_light_3d::re_export::DirectionalLight3D;
+pub use directional_light_3d::re_export::IDirectionalLight3D;
+pub mod display_server;
+pub use display_server::re_export::DisplayServer;
+pub mod enet_connection;
+pub use enet_connection::re_export::ENetConnection;
+pub use enet_connection::re_export::IENetConnection;
+pub mod enet_multiplayer_peer;
+pub use enet_multiplayer_peer::re_export::ENetMultiplayerPeer;
+pub use enet_multiplayer_peer::re_export::IENetMultiplayerPeer;
+pub mod enet_packet_peer;
+pub use enet_packet_peer::re_export::ENetPacketPeer;
+pub mod editor_command_palette;
+pub use editor_command_palette::re_export::EditorCommandPalette;
+pub use editor_command_palette::re_export::IEditorCommandPalette;
+pub mod editor_context_menu_plugin;
+pub use editor_context_menu_plugin::re_export::EditorContextMenuPlugin;
+pub use editor_context_menu_plugin::re_export::IEditorContextMenuPlugin;
+mod editor_debugger_plugin;
+pub use editor_debugger_plugin::re_export::EditorDebuggerPlugin;
+pub use editor_debugger_plugin::re_export::IEditorDebuggerPlugin;
+pub mod editor_debugger_session;
+pub use editor_debugger_session::re_export::EditorDebuggerSession;
+pub mod editor_export_platform;
+pub use editor_export_platform::re_export::EditorExportPlatform;
+mod editor_export_platform_android;
+pub use editor_export_platform_android::re_export::EditorExportPlatformAndroid;
+pub use editor_export_platform_android::re_export::IEditorExportPlatformAndroid;
+mod editor_export_platform_apple_embedded;
+pub use editor_export_platform_apple_embedded::re_export::EditorExportPlatformAppleEmbedded;
+mod editor_export_platform_extension;
+pub use editor_export_platform_extension::re_export::EditorExportPlatformExtension;
+pub use editor_export_platform_extension::re_export::IEditorExportPlatformExtension;
+mod editor_export_platform_ios;
+pub use editor_export_platform_ios::re_export::EditorExportPlatformIos;
+pub use editor_export_platform_ios::re_export::IEditorExportPlatformIos;
+mod editor_export_platform_linux_bsd;
+pub use editor_export_platform_linux_bsd::re_export::EditorExportPlatformLinuxBsd;
+pub use editor_export_platform_linux_bsd::re_export::IEditorExportPlatformLinuxBsd;
+mod editor_export_platform_mac_os;
+pub use editor_export_platform_mac_os::re_export::EditorExportPlatformMacOs;
+pub use editor_export_platform_mac_os::re_export::IEditorExportPlatformMacOs;
+mod editor_export_platform_pc;
+pub use editor_export_platform_pc::re_export::EditorExportPlatformPc;
+mod editor_export_platform_vision_os;
+pub use editor_export_platform_vision_os::re_export::EditorExportPlatformVisionOs;
+pub use editor_export_platform_vision_os::re_export::IEditorExportPlatformVisionOs;
+mod editor_export_platform_web;
+pub use editor_export_platform_web::re_export::EditorExportPlatformWeb;
+pub use editor_export_platform_web::re_export::IEditorExportPlatformWeb;
+mod editor_export_platform_windows;
+pub use editor_export_platform_windows::re_export::EditorExportPlatformWindows;
+pub use editor_export_platform_windows::re_export::IEditorExportPlatformWindows;
+mod editor_export_plugin;
+pub use editor_export_plugin::re_export::EditorExportPlugin;
+pub use editor_export_plugin::re_export::IEditorExportPlugin;
+pub mod editor_export_preset;
+pub use editor_export_preset::re_export::EditorExportPreset;
+pub mod editor_feature_profile;
+pub use editor_feature_profile::re_export::EditorFeatureProfile;
+pub use editor_feature_profile::re_export::IEditorFeatureProfile;
+pub mod editor_file_dialog;
+pub use editor_file_dialog::re_export::EditorFileDialog;
+pub use editor_file_dialog::re_export::IEditorFileDialog;
+pub mod editor_file_system;
+pub use editor_file_system::re_export::EditorFileSystem;
+mod editor_file_system_directory;
+pub use editor_file_system_directory::re_export::EditorFileSystemDirectory;
+pub use editor_file_system_directory::re_export::IEditorFileSystemDirectory;
+mod editor_file_system_import_format_support_query;
+pub use editor_file_system_import_format_support_query::re_export::EditorFileSystemImportFormatSupportQuery;
+pub use editor_file_system_import_format_support_query::re_export::IEditorFileSystemImportFormatSupportQuery;
+pub mod editor_import_plugin;
+pub use editor_import_plugin::re_export::EditorImportPlugin;
+pub use editor_import_plugin::re_export::IEditorImportPlugin;
+pub mod editor_inspector;
+pub use editor_inspector::re_export::EditorInspector;
+pub use editor_inspector::re_export::IEditorInspector;
+pub mod editor_inspector_plugin;
+pub use editor_inspector_plugin::re_export::EditorInspectorPlugin;
+pub use editor_inspector_plugin::re_export::IEditorInspectorPlugin;
+pub mod editor_interface;
+pub use editor_interface::re_export::EditorInterface;
+pub mod editor_node_3d_gizmo;
+pub use editor_node_3d_gizmo::re_export::EditorNode3DGizmo;
+pub use editor_node_3d_gizmo::re_export::IEditorNode3DGizmo;
+pub mod editor_node_3d_gizmo_plugin;
+pub use editor_node_3d_gizmo_plugin::re_export::EditorNode3DGizmoPlugin;
+pub use editor_node_3d_gizmo_plugin::re_export::IEditorNode3DGizmoPlugin;
+mod editor_paths;
+pub use editor_paths::re_export::EditorPaths;
+pub use editor_paths::re_export::IEditorPaths;
+pub mod editor_plugin;
+pub use editor_plugin::re_export::EditorPlugin;
+pub use editor_plugin::re_export::IEditorPlugin;
+pub mod editor_property;
+pub use editor_property::re_export::EditorProperty;
+pub use editor_property::re_export::IEditorProperty;
+mod editor_resource_conversion_plugin;
+pub use editor_resource_conversion_plugin::re_export::EditorResourceConversionPlugin;
+pub use editor_resource_conversion_plugin::re_export::IEditorResourceConversionPlugin;
+pub mod editor_resource_picker;
+pub use editor_resource_picker::re_export::EditorResourcePicker;
+pub use editor_resource_picker::re_export::IEditorResourcePicker;
+pub mod editor_resource_preview;
+pub use editor_resource_preview::re_export::EditorResourcePreview;
+mod editor_resource_preview_generator;
+pub use editor_resource_preview_generator::re_export::EditorResourcePreviewGenerator;
+pub use editor_resource_preview_generator::re_export::IEditorResourcePreviewGenerator;
+mod editor_resource_tooltip_plugin;
+pub use editor_resource_tooltip_plugin::re_export::EditorResourceTooltipPlugin;
+pub use editor_resource_tooltip_plugin::re_export::IEditorResourceTooltipPlugin;
+pub mod editor_scene_format_importer;
+pub use editor_scene_format_importer::re_export::EditorSceneFormatImporter;
+pub use editor_scene_format_importer::re_export::IEditorSceneFormatImporter;
+mod editor_scene_format_importer_blend;
+pub use editor_scene_format_importer_blend::re_export::EditorSceneFormatImporterBlend;
+pub use editor_scene_format_importer_blend::re_export::IEditorSceneFormatImporterBlend;
+mod editor_scene_format_importer_fbx2gltf;
+pub use editor_scene_format_importer_fbx2gltf::re_export::EditorSceneFormatImporterFbx2gltf;
+pub use editor_scene_format_importer_fbx2gltf::re_export::IEditorSceneFormatImporterFbx2gltf;
+mod editor_scene_format_importer_gltf;
+pub use editor_scene_format_importer_gltf::re_export::EditorSceneFormatImporterGltf;
+pub use editor_scene_format_importer_gltf::re_export::IEditorSceneFormatImporterGltf;
+mod editor_scene_format_importer_ufbx;
+pub use editor_scene_format_importer_ufbx::re_export::EditorSceneFormatImporterUfbx;
+pub use editor_scene_format_importer_ufbx::re_export::IEditorSceneFormatImporterUfbx;
+mod editor_scene_post_import;
+pub use editor_scene_post_import::re_export::EditorScenePostImport;
+pub use editor_scene_post_import::re_export::IEditorScenePostImport;
+pub mod editor_scene_post_import_plugin;
+pub use editor_scene_post_import_plugin::re_export::EditorScenePostImportPlugin;
+pub use editor_scene_post_import_plugin::re_export::IEditorScenePostImportPlugin;
+mod editor_script;
+pub use editor_script::re_export::EditorScript;
+pub use editor_script::re_export::IEditorScript;
+mod editor_script_picker;
+pub use editor_script_picker::re_export::EditorScriptPicker;
+pub use editor_script_picker::re_export::IEditorScriptPicker;
+pub mod editor_selection;
+pub use editor_selection::re_export::EditorSelection;
+pub use editor_selection::re_export::IEditorSelection;
+pub mod editor_settings;
+pub use editor_settings::re_export::EditorSettings;
+pub use editor_settings::re_export::IEditorSettings;
+pub mod editor_spin_slider;
+pub use editor_spin_slider::re_export::EditorSpinSlider;
+pub use editor_spin_slider::re_export::IEditorSpinSlider;
+mod editor_syntax_highlighter;
+pub use editor_syntax_highlighter::re_export::EditorSyntaxHighlighter;
+pub use editor_syntax_highlighter::re_export::IEditorSyntaxHighlighter;
+pub mod editor_toaster;
+pub use editor_toaster::re_export::EditorToaster;
+mod editor_translation_parser_plugin;
+pub use editor_translation_parser_plugin::re_export::EditorTranslationParserPlugin;
+pub use editor_translation_parser_plugin::re_export::IEditorTranslationParserPlugin;
+pub mod editor_undo_redo_manager;
+pub use editor_undo_redo_manager::re_export::EditorUndoRedoManager;
+pub mod editor_vcs_interface;
+pub use editor_vcs_interface::re_export::EditorVcsInterface;
+pub use editor_vcs_interface::re_export::IEditorVcsInterface;
+mod encoded_object_as_id;
+pub use encoded_object_as_id::re_export::EncodedObjectAsId;
+pub use encoded_object_as_id::re_export::IEncodedObjectAsId;
+pub mod engine;
+pub use engine::re_export::Engine;
+pub mod engine_debugger;
+pub use engine_debugger::re_export::EngineDebugger;
+mod engine_profiler;
+pub use engine_profiler::re_export::EngineProfiler;
+pub use engine_profiler::re_export::IEngineProfiler;
+pub mod environment;
+pub use environment::re_export::Environment;
+pub use environment::re_export::IEnvironment;
+pub mod expression;
+pub use expression::re_export::Expression;
+pub use expression::re_export::IExpression;
+mod external_texture;
+pub use external_texture::re_export::ExternalTexture;
+pub use external_texture::re_export::IExternalTexture;
+mod fbx_document;
+pub use fbx_document::re_export::FbxDocument;
+pub use fbx_document::re_export::IFbxDocument;
+mod fbx_state;
+pub use fbx_state::re_export::FbxState;
+pub use fbx_state::re_export::IFbxState;
+pub mod fast_noise_lite;
+pub use fast_noise_lite::re_export::FastNoiseLite;
+pub use fast_noise_lite::re_export::IFastNoiseLite;
+pub mod file_access;
+pub use file_access::re_export::FileAccess;
+pub mod file_dialog;
+pub use file_dialog::re_export::FileDialog;
+pub use file_dialog::re_export::IFileDialog;
+pub mod file_system_dock;
+pub use file_system_dock::re_export::FileSystemDock;
+pub mod flow_container;
+pub use flow_container::re_export::FlowContainer;
+pub use flow_container::re_export::IFlowContainer;
+mod fog_material;
+pub use fog_material::re_export::FogMaterial;
+pub use fog_material::re_export::IFogMaterial;
+mod fog_volume;
+pub use fog_volume::re_export::FogVolume;
+pub use fog_volume::re_export::IFogVolume;
+pub mod foldable_container;
+pub use foldable_container::re_export::FoldableContainer;
+pub use foldable_container::re_export::IFoldableContainer;
+pub mod foldable_group;
+pub use foldable_group::re_export::FoldableGroup;
+pub use foldable_group::re_export::IFoldableGroup;
+pub mod font;
+pub use font::re_export::Font;
+mod font_file;
+pub use font_file::re_export::FontFile;
+pub use font_file::re_export::IFontFile;
+mod font_variation;
+pub use font_variation::re_export::FontVariation;
+pub use font_variation::re_export::IFontVariation;
+mod framebuffer_cache_rd;
+pub use framebuffer_cache_rd::re_export::FramebufferCacheRd;
+pub use framebuffer_cache_rd::re_export::IFramebufferCacheRd;
+pub mod gdextension;
+pub use gdextension::re_export::GDExtension;
+pub use gdextension::re_export::IGDExtension;
+pub mod gdextension_manager;
+pub use gdextension_manager::re_export::GDExtensionManager;
+mod gdscript;
+pub use gdscript::re_export::GDScript;
+pub use gdscript::re_export::IGDScript;
+mod gdscript_syntax_highlighter;
+pub use gdscript_syntax_highlighter::re_export::GDScriptSyntaxHighlighter;
+pub use gdscript_syntax_highlighter::re_export::IGDScriptSyntaxHighlighter;
+pub mod gltf_accessor;
+pub use gltf_accessor::re_export::GltfAccessor;
+pub use gltf_accessor::re_export::IGltfAccessor;
+mod gltf_animation;
+pub use gltf_animation::re_export::GltfAnimation;
+pub use gltf_animation::re_export::IGltfAnimation;
+mod gltf_buffer_view;
+pub use gltf_buffer_view::re_export::GltfBufferView;
+pub use gltf_buffer_view::re_export::IGltfBufferView;
+mod gltf_camera;
+pub use gltf_camera::re_export::GltfCamera;
+pub use gltf_camera::re_export::IGltfCamera;
+pub mod gltf_document;
+pub use gltf_document::re_export::GltfDocument;
+pub use gltf_document::re_export::IGltfDocument;
+mod gltf_document_extension;
+pub use gltf_document_extension::re_export::GltfDocumentExtension;
+pub use gltf_document_extension::re_export::IGltfDocumentExtension;
+mod gltf_document_extension_convert_importer_mesh;
+pub use gltf_document_extension_convert_importer_mesh::re_export::GltfDocumentExtensionConvertImporterMesh;
+pub use gltf_document_extension_convert_importer_mesh::re_export::IGltfDocumentExtensionConvertImporterMesh;
+mod gltf_light;
+pub use gltf_light::re_export::GltfLight;
+pub use gltf_light::re_export::IGltfLight;
+mod gltf_mesh;
+pub use gltf_mesh::re_export::GltfMesh;
+pub use gltf_mesh::re_export::IGltfMesh;
+pub mod gltf_node;
+pub use gltf_node::re_export::GltfNode;
+pub use gltf_node::re_export::IGltfNode;
+pub mod gltf_object_model_property;
+pub use gltf_object_model_property::re_export::GltfObjectModelProperty;
+pub use gltf_object_model_property::re_export::IGltfObjectModelProperty;
+mod gltf_physics_body;
+pub use gltf_physics_body::re_export::GltfPhysicsBody;
+pub use gltf_physics_body::re_export::IGltfPhysicsBody;
+pub mod gltf_physics_shape;
+pub use gltf_physics_shape::re_export::GltfPhysicsShape;
+pub use gltf_physics_shape::re_export::IGltfPhysicsShape;
+mod gltf_skeleton;
+pub use gltf_skeleton::re_export::GltfSkeleton;
+pub use gltf_skeleton::re_export::IGltfSkeleton;
+mod gltf_skin;
+pub use gltf_skin::re_export::GltfSkin;
+pub use gltf_skin::re_export::IGltfSkin;
+mod gltf_spec_gloss;
+pub use gltf_spec_gloss::re_export::GltfSpecGloss;
+pub use gltf_spec_gloss::re_export::IGltfSpecGloss;
+mod gltf_state;
+pub use gltf_state::re_export::GltfState;
+pub use gltf_state::re_export::IGltfState;
+mod gltf_texture;
+pub use gltf_texture::re_export::GltfTexture;
+pub use gltf_texture::re_export::IGltfTexture;
+mod gltf_texture_sampler;
+pub use gltf_texture_sampler::re_export::GltfTextureSampler;
+pub use gltf_texture_sampler::re_export::IGltfTextureSampler;
+pub mod gpu_particles_2d;
+pub use gpu_particles_2d::re_export::GpuParticles2D;
+pub use gpu_particles_2d::re_export::IGpuParticles2D;
+pub mod gpu_particles_3d;
+pub use gpu_particles_3d::re_export::GpuParticles3D;
+pub use gpu_particles_3d::re_export::IGpuParticles3D;
+mod gpu_particles_attractor_3d;
+pub use gpu_particles_attractor_3d::re_export::GpuParticlesAttractor3D;
+mod gpu_particles_attractor_box_3d;
+pub use gpu_particles_attractor_box_3d::re_export::GpuParticlesAttractorBox3D;
+pub use gpu_particles_attractor_box_3d::re_export::IGpuParticlesAttractorBox3D;
+mod gpu_particles_attractor_sphere_3d;
+pub use gpu_particles_attractor_sphere_3d::re_export::GpuParticlesAttractorSphere3D;
+pub use gpu_particles_attractor_sphere_3d::re_export::IGpuParticlesAttractorSphere3D;
+mod gpu_particles_attractor_vector_field_3d;
+pub use gpu_particles_attractor_vector_field_3d::re_export::GpuParticlesAttractorVectorField3D;
+pub use gpu_particles_attractor_vector_field_3d::re_export::IGpuParticlesAttractorVectorField3D;
+mod gpu_particles_collision_3d;
+pub use gpu_particles_collision_3d::re_export::GpuParticlesCollision3D;
+mod gpu_particles_collision_box_3d;
+pub use gpu_particles_collision_box_3d::re_export::GpuParticlesCollisionBox3D;
+pub use gpu_particles_collision_box_3d::re_export::IGpuParticlesCollisionBox3D;
+pub mod gpu_particles_collision_height_field_3d;
+pub use gpu_particles_collision_height_field_3d::re_export::GpuParticlesCollisionHeightField3D;
+pub use gpu_particles_collision_height_field_3d::re_export::IGpuParticlesCollisionHeightField3D;
+pub mod gpu_particles_collision_sdf_3d;
+pub use gpu_particles_collision_sdf_3d::re_export::GpuParticlesCollisionSdf3d;
+pub use gpu_particles_collision_sdf_3d::re_export::IGpuParticlesCollisionSdf3d;
+mod gpu_particles_collision_sphere_3d;
+pub use gpu_particles_collision_sphere_3d::re_export::GpuParticlesCollisionSphere3D;
+pub use gpu_particles_collision_sphere_3d::re_export::IGpuParticlesCollisionSphere3D;
+pub mod generic6_dof_joint_3d;
+pub use generic6_dof_joint_3d::re_export::Generic6DofJoint3D;
+pub use generic6_dof_joint_3d::re_export::IGeneric6DofJoint3D;
+pub mod geometry_2d;
+pub use geometry_2d::re_export::Geometry2D;
+pub mod geometry_3d;
+pub use geometry_3d::re_export::Geometry3D;
+pub mod geometry_instance_3d;
+pub use geometry_instance_3d::re_export::GeometryInstance3D;
+pub use geometry_instance_3d::re_export::IGeometryInstance3D;
+pub mod gradient;
+pub use gradient::re_export::Gradient;
+pub use gradient::re_export::IGradient;
+mod gradient_texture_1d;
+pub use gradient_texture_1d::re_export::GradientTexture1D;
+pub use gradient_texture_1d::re_export::IGradientTexture1D;
+pub mod gradient_texture_2d;
+pub use gradient_texture_2d::re_export::GradientTexture2D;
+pub use gradient_texture_2d::re_export::IGradientTexture2D;
+mod grid_container;
+pub use grid_container::re_export::GridContainer;
+pub use grid_container::re_export::IGridContainer;
+pub mod grid_map;
+pub use grid_map::re_export::GridMap;
+pub use grid_map::re_export::IGridMap;
+mod grid_map_editor_plugin;
+pub use grid_map_editor_plugin::re_export::GridMapEditorPlugin;
+pub use grid_map_editor_plugin::re_export::IGridMapEditorPlugin;
+mod groove_joint_2d;
+pub use groove_joint_2d::re_export::GrooveJoint2D;
+pub use groove_joint_2d::re_export::IGrooveJoint2D;
+mod h_box_container;
+pub use h_box_container::re_export::HBoxContainer;
+pub use h_box_container::re_export::IHBoxContainer;
+mod h_flow_container;
+pub use h_flow_container::re_export::HFlowContainer;
+pub use h_flow_container::re_export::IHFlowContainer;
+mod hmac_context;
+pub use hmac_context::re_export::HmacContext;
+pub use hmac_context::re_export::IHmacContext;
+mod h_scroll_bar;
+pub use h_scroll_bar::re_export::HScrollBar;
+pub use h_scroll_bar::re_export::IHScrollBar;
+mod h_separator;
+pub use h_separator::re_export::HSeparator;
+pub use h_separator::re_export::IHSeparator;
+mod h_slider;
+pub use h_slider::re_export::HSlider;
+pub use h_slider::re_export::IHSlider;
+mod h_split_container;
+pub use h_split_container::re_export::HSplitContainer;
+pub use h_split_container::re_export::IHSplitContainer;
+pub mod http_client;
+pub use http_client::re_export::HttpClient;
+pub use http_client::re_export::IHttpClient;
+pub mod http_request;
+pub use http_request::re_export::HttpRequest;
+pub use http_request::re_export::IHttpRequest;
+pub mod hashing_context;
+pub use hashing_context::re_export::HashingContext;
+pub use hashing_context::re_export::IHashingContext;
+mod height_map_shape_3d;
+pub use height_map_shape_3d::re_export::HeightMapShape3D;
+pub use height_map_shape_3d::re_export::IHeightMapShape3D;
+pub mod hinge_joint_3d;
+pub use hinge_joint_3d::re_export::HingeJoint3D;
+pub use hinge_joint_3d::re_export::IHingeJoint3D;
+pub mod ip;
+pub use ip::re_export::Ip;
+pub mod image;
+pub use image::re_export::Image;
+pub use image::re_export::IImage;
+pub mod image_format_loader;
+pub use image_format_loader::re_export::ImageFormatLoader;
+mod image_format_loader_extension;
+pub use image_format_loader_extension::re_export::ImageFormatLoaderExtension;
+pub use image_format_loader_extension::re_export::IImageFormatLoaderExtension;
+mod image_texture;
+pub use image_texture::re_export::ImageTexture;
+pub use image_texture::re_export::IImageTexture;
+mod image_texture_3d;
+pub use image_texture_3d::re_export::ImageTexture3D;
+pub use image_texture_3d::re_export::IImageTexture3D;
+mod image_texture_layered;
+pub use image_texture_layered::re_export::ImageTextureLayered;
+pub mod immediate_mesh;
+pub use immediate_mesh::re_export::ImmediateMesh;
+pub use immediate_mesh::re_export::IImmediateMesh;
+pub mod importer_mesh;
+pub use importer_mesh::re_export::ImporterMesh;
+pub use importer_mesh::re_export::IImporterMesh;
+mod importer_mesh_instance_3d;
+pub use importer_mesh_instance_3d::re_export::ImporterMeshInstance3D;
+pub use importer_mesh_instance_3d::re_export::IImporterMeshInstance3D;
+pub mod input;
+pub use input::re_export::Input;
+pub mod input_event;
+pub use input_event::re_export::InputEvent;
+mod input_event_action;
+pub use input_event_action::re_export::InputEventAction;
+pub use input_event_action::re_export::IInputEventAction;
+mod input_event_from_window;
+pub use input_event_from_window::re_export::InputEventFromWindow;
+mod input_event_gesture;
+pub use input_event_gesture::re_export::InputEventGesture;
+mod input_event_joypad_button;
+pub use input_event_joypad_button::re_export::InputEventJoypadButton;
+pub use input_event_joypad_button::re_export::IInputEventJoypadButton;
+mod input_event_joypad_motion;
+pub use input_event_joypad_motion::re_export::InputEventJoypadMotion;
+pub use input_event_joypad_motion::re_export::IInputEventJoypadMotion;
+mod input_event_key;
+pub use input_event_key::re_export::InputEventKey;
+pub use input_event_key::re_export::IInputEventKey;
+mod input_event_midi;
+pub use input_event_midi::re_export::InputEventMidi;
+pub use input_event_midi::re_export::IInputEventMidi;
+mod input_event_magnify_gesture;
+pub use input_event_magnify_gesture::re_export::InputEventMagnifyGesture;
+pub use input_event_magnify_gesture::re_export::IInputEventMagnifyGesture;
+mod input_event_mouse;
+pub use input_event_mouse::re_export::InputEventMouse;
+mod input_event_mouse_button;
+pub use input_event_mouse_button::re_export::InputEventMouseButton;
+pub use input_event_mouse_button::re_export::IInputEventMouseButton;
+mod input_event_mouse_motion;
+pub use input_event_mouse_motion::re_export::InputEventMouseMotion;
+pub use input_event_mouse_motion::re_export::IInputEventMouseMotion;
+mod input_event_pan_gesture;
+pub use input_event_pan_gesture::re_export::InputEventPanGesture;
+pub use input_event_pan_gesture::re_export::IInputEventPanGesture;
+mod input_event_screen_drag;
+pub use input_event_screen_drag::re_export::InputEventScreenDrag;
+pub use input_event_screen_drag::re_export::IInputEventScreenDrag;
+mod input_event_screen_touch;
+pub use input_event_screen_touch::re_export::InputEventScreenTouch;
+pub use input_event_screen_touch::re_export::IInputEventScreenTouch;
+mod input_event_shortcut;
+pub use input_event_shortcut::re_export::InputEventShortcut;
+pub use input_event_shortcut::re_export::IInputEventShortcut;
+mod input_event_with_modifiers;
+pub use input_event_with_modifiers::re_export::InputEventWithModifiers;
+pub mod input_map;
+pub use input_map::re_export::InputMap;
+pub mod instance_placeholder;
+pub use instance_placeholder::re_export::InstancePlaceholder;
+mod interval_tweener;
+pub use interval_tweener::re_export::IntervalTweener;
+pub use interval_tweener::re_export::IIntervalTweener;
+pub mod item_list;
+pub use item_list::re_export::ItemList;
+pub use item_list::re_export::IItemList;
+pub mod json;
+pub use json::re_export::Json;
+pub use json::re_export::IJson;
+pub mod json_rpc;
+pub use json_rpc::re_export::JsonRpc;
+pub use json_rpc::re_export::IJsonRpc;
+mod joint_2d;
+pub use joint_2d::re_export::Joint2D;
+mod joint_3d;
+pub use joint_3d::re_export::Joint3D;
+pub mod kinematic_collision_2d;
+pub use kinematic_collision_2d::re_export::KinematicCollision2D;
+pub use kinematic_collision_2d::re_export::IKinematicCollision2D;
+pub mod kinematic_collision_3d;
+pub use kinematic_collision_3d::re_export::KinematicCollision3D;
+pub use kinematic_collision_3d::re_export::IKinematicCollision3D;
+pub mod label;
+pub use label::re_export::Label;
+pub use label::re_export::ILabel;
+pub mod label_3d;
+pub use label_3d::re_export::Label3D;
+pub use label_3d::re_export::ILabel3D;
+pub mod label_settings;
+pub use label_settings::re_export::LabelSettings;
+pub use label_settings::re_export::ILabelSettings;
+pub mod light_2d;
+pub use light_2d::re_export::Light2D;
+pub mod light_3d;
+pub use light_3d::re_export::Light3D;
+mod light_occluder_2d;
+pub use light_occluder_2d::re_export::LightOccluder2D;
+pub use light_occluder_2d::re_export::ILightOccluder2D;
+pub mod lightmap_gi;
+pub use lightmap_gi::re_export::LightmapGi;
+pub use lightmap_gi::re_export::ILightmapGi;
+pub mod lightmap_gi_data;
+pub use lightmap_gi_data::re_export::LightmapGiData;
+pub use lightmap_gi_data::re_export::ILightmapGiData;
+mod lightmap_probe;
+pub use lightmap_probe::re_export::LightmapProbe;
+pub use lightmap_probe::re_export::ILightmapProbe;
+mod lightmapper;
+pub use lightmapper::re_export::Lightmapper;
+mod lightmapper_rd;
+pub use lightmapper_rd::re_export::LightmapperRd;
+pub use lightmapper_rd::re_export::ILightmapperRd;
+pub mod line_2d;
+pub use line_2d::re_export::Line2D;
+pub use line_2d::re_export::ILine2D;
+pub mod line_edit;
+pub use line_edit::re_export::LineEdit;
+pub use line_edit::re_export::ILineEdit;
+pub mod link_button;
+pub use link_button::re_export::LinkButton;
+pub use link_button::re_export::ILinkButton;
+pub mod logger;
+pub use logger::re_export::Logger;
+pub use logger::re_export::ILogger;
+pub mod look_at_modifier_3d;
+pub use look_at_modifier_3d::re_export::LookAtModifier3D;
+pub use look_at_modifier_3d::re_export::ILookAtModifier3D;
+pub mod main_loop;
+pub use main_loop::re_export::MainLoop;
+pub use main_loop::re_export::IMainLoop;
+mod margin_container;
+pub use margin_container::re_export::MarginContainer;
+pub use margin_container::re_export::IMarginContainer;
+mod marker_2d;
+pub use marker_2d::re_export::Marker2D;
+pub use marker_2d::re_export::IMarker2D;
+mod marker_3d;
+pub use marker_3d::re_export::Marker3D;
+pub use marker_3d::re_export::IMarker3D;
+pub mod marshalls;
+pub use marshalls::re_export::Marshalls;
+mod material;
+pub use material::re_export::Material;
+pub use material::re_export::IMaterial;
+mod menu_bar;
+pub use menu_bar::re_export::MenuBar;
+pub use menu_bar::re_export::IMenuBar;
+pub mod menu_button;
+pub use menu_button::re_export::MenuButton;
+pub use menu_button::re_export::IMenuButton;
+pub mod mesh;
+pub use mesh::re_export::Mesh;
+pub use mesh::re_export::IMesh;
+pub mod mesh_convex_decomposition_settings;
+pub use mesh_convex_decomposition_settings::re_export::MeshConvexDecompositionSettings;
+pub use mesh_convex_decomposition_settings::re_export::IMeshConvexDecompositionSettings;
+pub mod mesh_data_tool;
+pub use mesh_data_tool::re_export::MeshDataTool;
+pub use mesh_data_tool::re_export::IMeshDataTool;
+pub mod mesh_instance_2d;
+pub use mesh_instance_2d::re_export::MeshInstance2D;
+pub use mesh_instance_2d::re_export::IMeshInstance2D;
+pub mod mesh_instance_3d;
+pub use mesh_instance_3d::re_export::MeshInstance3D;
+pub use mesh_instance_3d::re_export::IMeshInstance3D;
+mod mesh_library;
+pub use mesh_library::re_export::MeshLibrary;
+pub use mesh_library::re_export::IMeshLibrary;
+mod mesh_texture;
+pub use mesh_texture::re_export::MeshTexture;
+pub use mesh_texture::re_export::IMeshTexture;
+mod method_tweener;
+pub use method_tweener::re_export::MethodTweener;
+pub use method_tweener::re_export::IMethodTweener;
+mod missing_node;
+pub use missing_node::re_export::MissingNode;
+pub use missing_node::re_export::IMissingNode;
+mod missing_resource;
+pub use missing_resource::re_export::MissingResource;
+pub use missing_resource::re_export::IMissingResource;
+mod mobile_vr_interface;
+pub use mobile_vr_interface::re_export::MobileVrInterface;
+pub use mobile_vr_interface::re_export::IMobileVrInterface;
+mod modifier_bone_target_3d;
+pub use modifier_bone_target_3d::re_export::ModifierBoneTarget3D;
+pub use modifier_bone_target_3d::re_export::IModifierBoneTarget3D;
+mod movie_writer;
+pub use movie_writer::re_export::MovieWriter;
+pub use movie_writer::re_export::IMovieWriter;
+pub mod multi_mesh;
+pub use multi_mesh::re_export::MultiMesh;
+pub use multi_mesh::re_export::IMultiMesh;
+pub mod multi_mesh_instance_2d;
+pub use multi_mesh_instance_2d::re_export::MultiMeshInstance2D;
+pub use multi_mesh_instance_2d::re_export::IMultiMeshInstance2D;
+mod multi_mesh_instance_3d;
+pub use multi_mesh_instance_3d::re_export::MultiMeshInstance3D;
+pub use multi_mesh_instance_3d::re_export::IMultiMeshInstance3D;
+pub mod multiplayer_api;
+pub use multiplayer_api::re_export::MultiplayerApi;
+mod multiplayer_api_extension;
+pub use multiplayer_api_extension::re_export::MultiplayerApiExtension;
+pub use multiplayer_api_extension::re_export::IMultiplayerApiExtension;
+pub mod multiplayer_peer;
+pub use multiplayer_peer::re_export::MultiplayerPeer;
+mod multiplayer_peer_extension;
+pub use multiplayer_peer_extension::re_export::MultiplayerPeerExtension;
+pub use multiplayer_peer_extension::re_export::IMultiplayerPeerExtension;
+pub mod multiplayer_spawner;
+pub use multiplayer_spawner::re_export::MultiplayerSpawner;
+pub use multiplayer_spawner::re_export::IMultiplayerSpawner;
+pub mod multiplayer_synchronizer;
+pub use multiplayer_synchronizer::re_export::MultiplayerSynchronizer;
+pub use multiplayer_synchronizer::re_export::IMultiplayerSynchronizer;
+pub mod native_menu;
+pub use native_menu::re_export::NativeMenu;
+mod navigation_mesh_generator;
+pub use navigation_mesh_generator::re_export::NavigationMeshGenerator;
+pub mod nine_patch_rect;
+pub use nine_patch_rect::re_export::NinePatchRect;
+pub use nine_patch_rect::re_export::INinePatchRect;
+pub mod node;
+pub use node::re_export::Node;
+pub use node::re_export::INode;
+pub mod node_2d;
+pub use node_2d::re_export::Node2D;
+pub use node_2d::re_export::INode2D;
+pub mod node_3d;
+pub use node_3d::re_export::Node3D;
+pub use node_3d::re_export::INode3D;
+mod node_3d_gizmo;
+pub use node_3d_gizmo::re_export::Node3DGizmo;
+pub mod noise;
+pub use noise::re_export::Noise;
+mod noise_texture_2d;
+pub use noise_texture_2d::re_export::NoiseTexture2D;
+pub use noise_texture_2d::re_export::INoiseTexture2D;
+mod noise_texture_3d;
+pub use noise_texture_3d::re_export::NoiseTexture3D;
+pub use noise_texture_3d::re_export::INoiseTexture3D;
+mod orm_material_3d;
+pub use orm_material_3d::re_export::OrmMaterial3D;
+pub use orm_material_3d::re_export::IOrmMaterial3D;
+pub mod os;
+pub use os::re_export::Os;
+pub mod object;
+pub use object::re_export::Object;
+pub use object::re_export::IObject;
+mod occluder_3d;
+pub use occluder_3d::re_export::Occluder3D;
+mod occluder_instance_3d;
+pub use occluder_instance_3d::re_export::OccluderInstance3D;
+pub use occluder_instance_3d::re_export::IOccluderInstance3D;
+pub mod occluder_polygon_2d;
+pub use occluder_polygon_2d::re_export::OccluderPolygon2D;
+pub use occluder_polygon_2d::re_export::IOccluderPolygon2D;
+mod offline_multiplayer_peer;
+pub use offline_multiplayer_peer::re_export::OfflineMultiplayerPeer;
+pub use offline_multiplayer_peer::re_export::IOfflineMultiplayerPeer;
+mod ogg_packet_sequence;
+pub use ogg_packet_sequence::re_export::OggPacketSequence;
+pub use ogg_packet_sequence::re_export::IOggPacketSequence;
+mod ogg_packet_sequence_playback;
+pub use ogg_packet_sequence_playback::re_export::OggPacketSequencePlayback;
+pub use ogg_packet_sequence_playback::re_export::IOggPacketSequencePlayback;
+pub mod omni_light_3d;
+pub use omni_light_3d::re_export::OmniLight3D;
+pub use omni_light_3d::re_export::IOmniLight3D;
+pub mod open_xr_api_extension;
+pub use open_xr_api_extension::re_export::OpenXrApiExtension;
+pub use open_xr_api_extension::re_export::IOpenXrApiExtension;
+pub mod open_xr_action;
+pub use open_xr_action::re_export::OpenXrAction;
+pub use open_xr_action::re_export::IOpenXrAction;
+mod open_xr_action_binding_modifier;
+pub use open_xr_action_binding_modifier::re_export::OpenXrActionBindingModifier;
+pub use open_xr_action_binding_modifier::re_export::IOpenXrActionBindingModifier;
+mod open_xr_action_map;
+pub use open_xr_action_map::re_export::OpenXrActionMap;
+pub use open_xr_action_map::re_export::IOpenXrActionMap;
+mod open_xr_action_set;
+pub use open_xr_action_set::re_export::OpenXrActionSet;
+pub use open_xr_action_set::re_export::IOpenXrActionSet;
+mod open_xr_analog_threshold_modifier;
+pub use open_xr_analog_threshold_modifier::re_export::OpenXrAnalogThresholdModifier;
+pub use open_xr_analog_threshold_modifier::re_export::IOpenXrAnalogThresholdModifier;
+mod open_xr_binding_modifier;
+pub use open_xr_binding_modifier::re_export::OpenXrBindingModifier;
+pub mod open_xr_binding_modifier_editor;
+pub use open_xr_binding_modifier_editor::re_export::OpenXrBindingModifierEditor;
+pub use open_xr_binding_modifier_editor::re_export::IOpenXrBindingModifierEditor;
+pub mod open_xr_composition_layer;
+pub use open_xr_composition_layer::re_export::OpenXrCompositionLayer;
+mod open_xr_composition_layer_cylinder;
+pub use open_xr_composition_layer_cylinder::re_export::OpenXrCompositionLayerCylinder;
+pub use open_xr_composition_layer_cylinder::re_export::IOpenXrCompositionLayerCylinder;
+mod open_xr_composition_layer_equirect;
+pub use open_xr_composition_layer_equirect::re_export::OpenXrCompositionLayerEquirect;
+pub use open_xr_composition_layer_equirect::re_export::IOpenXrCompositionLayerEquirect;
+mod open_xr_composition_layer_quad;
+pub use open_xr_composition_layer_quad::re_export::OpenXrCompositionLayerQuad;
+pub use open_xr_composition_layer_quad::re_export::IOpenXrCompositionLayerQuad;
+mod open_xr_dpad_binding_modifier;
+pub use open_xr_dpad_binding_modifier::re_export::OpenXrDpadBindingModifier;
+pub use open_xr_dpad_binding_modifier::re_export::IOpenXrDpadBindingModifier;
+mod open_xr_extension_wrapper;
+pub use open_xr_extension_wrapper::re_export::OpenXrExtensionWrapper;
+pub use open_xr_extension_wrapper::re_export::IOpenXrExtensionWrapper;
+mod open_xr_extension_wrapper_extension;
+pub use open_xr_extension_wrapper_extension::re_export::OpenXrExtensionWrapperExtension;
+pub use open_xr_extension_wrapper_extension::re_export::IOpenXrExtensionWrapperExtension;
+pub mod open_xr_future_extension;
+pub use open_xr_future_extension::re_export::OpenXrFutureExtension;
+pub use open_xr_future_extension::re_export::IOpenXrFutureExtension;
+pub mod open_xr_future_result;
+pub use open_xr_future_result::re_export::OpenXrFutureResult;
+pub mod open_xr_hand;
+pub use open_xr_hand::re_export::OpenXrHand;
+pub use open_xr_hand::re_export::IOpenXrHand;
+mod open_xr_haptic_base;
+pub use open_xr_haptic_base::re_export::OpenXrHapticBase;
+mod open_xr_haptic_vibration;
+pub use open_xr_haptic_vibration::re_export::OpenXrHapticVibration;
+pub use open_xr_haptic_vibration::re_export::IOpenXrHapticVibration;
+mod open_xr_ip_binding;
+pub use open_xr_ip_binding::re_export::OpenXrIpBinding;
+pub use open_xr_ip_binding::re_export::IOpenXrIpBinding;
+mod open_xrip_binding_modifier;
+pub use open_xrip_binding_modifier::re_export::OpenXripBindingModifier;
+pub use open_xrip_binding_modifier::re_export::IOpenXripBindingModifier;
+mod open_xr_interaction_profile;
+pub use open_xr_interaction_profile::re_export::OpenXrInteractionProfile;
+pub use open_xr_interaction_profile::re_export::IOpenXrInteractionProfile;
+mod open_xr_interaction_profile_editor;
+pub use open_xr_interaction_profile_editor::re_export::OpenXrInteractionProfileEditor;
+pub use open_xr_interaction_profile_editor::re_export::IOpenXrInteractionProfileEditor;
+mod open_xr_interaction_profile_editor_base;
+pub use open_xr_interaction_profile_editor_base::re_export::OpenXrInteractionProfileEditorBase;
+mod open_xr_interaction_profile_metadata;
+pub use open_xr_interaction_profile_metadata::re_export::OpenXrInteractionProfileMetadata;
+pub use open_xr_interaction_profile_metadata::re_export::IOpenXrInteractionProfileMetadata;
+pub mod open_xr_interface;
+pub use open_xr_interface::re_export::OpenXrInterface;
+pub use open_xr_interface::re_export::IOpenXrInterface;
+pub mod open_xr_render_model;
+pub use open_xr_render_model::re_export::OpenXrRenderModel;
+pub use open_xr_render_model::re_export::IOpenXrRenderModel;
+pub mod open_xr_render_model_extension;
+pub use open_xr_render_model_extension::re_export::OpenXrRenderModelExtension;
+pub use open_xr_render_model_extension::re_export::IOpenXrRenderModelExtension;
+pub mod open_xr_render_model_manager;
+pub use open_xr_render_model_manager::re_export::OpenXrRenderModelManager;
+pub use open_xr_render_model_manager::re_export::IOpenXrRenderModelManager;
+mod open_xr_visibility_mask;
+pub use open_xr_visibility_mask::re_export::OpenXrVisibilityMask;
+pub use open_xr_visibility_mask::re_export::IOpenXrVisibilityMask;
+mod optimized_translation;
+pub use optimized_translation::re_export::OptimizedTranslation;
+pub use optimized_translation::re_export::IOptimizedTranslation;
+pub mod option_button;
+pub use option_button::re_export::OptionButton;
+pub use option_button::re_export::IOptionButton;
+pub mod pck_packer;
+pub use pck_packer::re_export::PckPacker;
+pub use pck_packer::re_export::IPckPacker;
+mod packed_data_container;
+pub use packed_data_container::re_export::PackedDataContainer;
+pub use packed_data_container::re_export::IPackedDataContainer;
+mod packed_data_container_ref;
+pub use packed_data_container_ref::re_export::PackedDataContainerRef;
+pub mod packed_scene;
+pub use packed_scene::re_export::PackedScene;
+pub use packed_scene::re_export::IPackedScene;
+pub mod packet_peer;
+pub use packet_peer::re_export::PacketPeer;
+pub mod packet_peer_dtls;
+pub use packet_peer_dtls::re_export::PacketPeerDtls;
+pub use packet_peer_dtls::re_export::IPacketPeerDtls;
+mod packet_peer_extension;
+pub use packet_peer_extension::re_export::PacketPeerExtension;
+pub use packet_peer_extension::re_export::IPacketPeerExtension;
+mod packet_peer_stream;
+pub use packet_peer_stream::re_export::PacketPeerStream;
+pub use packet_peer_stream::re_export::IPacketPeerStream;
+pub mod packet_peer_udp;
+pub use packet_peer_udp::re_export::PacketPeerUdp;
+pub use packet_peer_udp::re_export::IPacketPeerUdp;
+mod panel;
+pub use panel::re_export::Panel;
+pub use panel::re_export::IPanel;
+mod panel_container;
+pub use panel_container::re_export::PanelContainer;
+pub use panel_container::re_export::IPanelContainer;
+mod panorama_sky_material;
+pub use panorama_sky_material::re_export::PanoramaSkyMaterial;
+pub use panorama_sky_material::re_export::IPanoramaSkyMaterial;
+mod parallax_background;
+pub use parallax_background::re_export::ParallaxBackground;
+pub use parallax_background::re_export::IParallaxBackground;
+mod parallax_layer;
+pub use parallax_layer::re_export::ParallaxLayer;
+pub use parallax_layer::re_export::IParallaxLayer;
+pub mod particle_process_material;
+pub use particle_process_material::re_export::ParticleProcessMaterial;
+pub use particle_process_material::re_export::IParticleProcessMaterial;
+mod path_2d;
+pub use path_2d::re_export::Path2D;
+pub use path_2d::re_export::IPath2D;
+pub mod path_3d;
+pub use path_3d::re_export::Path3D;
+pub use path_3d::re_export::IPath3D;
+mod path_follow_2d;
+pub use path_follow_2d::re_export::PathFollow2D;
+pub use path_follow_2d::re_export::IPathFollow2D;
+pub mod path_follow_3d;
+pub use path_follow_3d::re_export::PathFollow3D;
+pub use path_follow_3d::re_export::IPathFollow3D;
+pub mod performance;
+pub use performance::re_export::Performance;
+mod physical_bone_2d;
+pub use physical_bone_2d::re_export::PhysicalBone2D;
+pub use physical_bone_2d::re_export::IPhysicalBone2D;
+pub mod physical_bone_3d;
+pub use physical_bone_3d::re_export::PhysicalBone3D;
+pub use physical_bone_3d::re_export::IPhysicalBone3D;
+pub mod physical_bone_simulator_3d;
+pub use physical_bone_simulator_3d::re_export::PhysicalBoneSimulator3D;
+pub use physical_bone_simulator_3d::re_export::IPhysicalBoneSimulator3D;
+mod physical_sky_material;
+pub use physical_sky_material::re_export::PhysicalSkyMaterial;
+pub use physical_sky_material::re_export::IPhysicalSkyMaterial;
+pub mod physics_body_2d;
+pub use physics_body_2d::re_export::PhysicsBody2D;
+pub mod physics_body_3d;
+pub use physics_body_3d::re_export::PhysicsBody3D;
+pub mod physics_direct_body_state_2d;
+pub use physics_direct_body_state_2d::re_export::PhysicsDirectBodyState2D;
+mod physics_direct_body_state_2d_extension;
+pub use physics_direct_body_state_2d_extension::re_export::PhysicsDirectBodyState2DExtension;
+pub use physics_direct_body_state_2d_extension::re_export::IPhysicsDirectBodyState2DExtension;
+pub mod physics_direct_body_state_3d;
+pub use physics_direct_body_state_3d::re_export::PhysicsDirectBodyState3D;
+mod physics_direct_body_state_3d_extension;
+pub use physics_direct_body_state_3d_extension::re_export::PhysicsDirectBodyState3DExtension;
+pub use physics_direct_body_state_3d_extension::re_export::IPhysicsDirectBodyState3DExtension;
+pub mod physics_direct_space_state_2d;
+pub use physics_direct_space_state_2d::re_export::PhysicsDirectSpaceState2D;
+mod physics_direct_space_state_2d_extension;
+pub use physics_direct_space_state_2d_extension::re_export::PhysicsDirectSpaceState2DExtension;
+pub use physics_direct_space_state_2d_extension::re_export::IPhysicsDirectSpaceState2DExtension;
+pub mod physics_direct_space_state_3d;
+pub use physics_direct_space_state_3d::re_export::PhysicsDirectSpaceState3D;
+mod physics_direct_space_state_3d_extension;
+pub use physics_direct_space_state_3d_extension::re_export::PhysicsDirectSpaceState3DExtension;
+pub use physics_direct_space_state_3d_extension::re_export::IPhysicsDirectSpaceState3DExtension;
+mod physics_material;
+pub use physics_material::re_export::PhysicsMaterial;
+pub use physics_material::re_export::IPhysicsMaterial;
+mod physics_point_query_parameters_2d;
+pub use physics_point_query_parameters_2d::re_export::PhysicsPointQueryParameters2D;
+pub use physics_point_query_parameters_2d::re_export::IPhysicsPointQueryParameters2D;
+mod physics_point_query_parameters_3d;
+pub use physics_point_query_parameters_3d::re_export::PhysicsPointQueryParameters3D;
+pub use physics_point_query_parameters_3d::re_export::IPhysicsPointQueryParameters3D;
+pub mod physics_ray_query_parameters_2d;
+pub use physics_ray_query_parameters_2d::re_export::PhysicsRayQueryParameters2D;
+pub use physics_ray_query_parameters_2d::re_export::IPhysicsRayQueryParameters2D;
+pub mod physics_ray_query_parameters_3d;
+pub use physics_ray_query_parameters_3d::re_export::PhysicsRayQueryParameters3D;
+pub use physics_ray_query_parameters_3d::re_export::IPhysicsRayQueryParameters3D;
+pub mod physics_server_2d;
+pub use physics_server_2d::re_export::PhysicsServer2D;
+mod physics_server_2d_extension;
+pub use physics_server_2d_extension::re_export::PhysicsServer2DExtension;
+pub use physics_server_2d_extension::re_export::IPhysicsServer2DExtension;
+mod physics_server_2d_manager;
+pub use physics_server_2d_manager::re_export::PhysicsServer2DManager;
+pub mod physics_server_3d;
+pub use physics_server_3d::re_export::PhysicsServer3D;
+mod physics_server_3d_extension;
+pub use physics_server_3d_extension::re_export::PhysicsServer3DExtension;
+pub use physics_server_3d_extension::re_export::IPhysicsServer3DExtension;
+mod physics_server_3d_manager;
+pub use physics_server_3d_manager::re_export::PhysicsServer3DManager;
+mod physics_server_3d_rendering_server_handler;
+pub use physics_server_3d_rendering_server_handler::re_export::PhysicsServer3DRenderingServerHandler;
+pub use physics_server_3d_rendering_server_handler::re_export::IPhysicsServer3DRenderingServerHandler;
+mod physics_shape_query_parameters_2d;
+pub use physics_shape_query_parameters_2d::re_export::PhysicsShapeQueryParameters2D;
+pub use physics_shape_query_parameters_2d::re_export::IPhysicsShapeQueryParameters2D;
+mod physics_shape_query_parameters_3d;
+pub use physics_shape_query_parameters_3d::re_export::PhysicsShapeQueryParameters3D;
+pub use physics_shape_query_parameters_3d::re_export::IPhysicsShapeQueryParameters3D;
+mod physics_test_motion_parameters_2d;
+pub use physics_test_motion_parameters_2d::re_export::PhysicsTestMotionParameters2D;
+pub use physics_test_motion_parameters_2d::re_export::IPhysicsTestMotionParameters2D;
+mod physics_test_motion_parameters_3d;
+pub use physics_test_motion_parameters_3d::re_export::PhysicsTestMotionParameters3D;
+pub use physics_test_motion_parameters_3d::re_export::IPhysicsTestMotionParameters3D;
+mod physics_test_motion_result_2d;
+pub use physics_test_motion_result_2d::re_export::PhysicsTestMotionResult2D;
+pub use physics_test_motion_result_2d::re_export::IPhysicsTestMotionResult2D;
+pub mod physics_test_motion_result_3d;
+pub use physics_test_motion_result_3d::re_export::PhysicsTestMotionResult3D;
+pub use physics_test_motion_result_3d::re_export::IPhysicsTestMotionResult3D;
+mod pin_joint_2d;
+pub use pin_joint_2d::re_export::PinJoint2D;
+pub use pin_joint_2d::re_export::IPinJoint2D;
+pub mod pin_joint_3d;
+pub use pin_joint_3d::re_export::PinJoint3D;
+pub use pin_joint_3d::re_export::IPinJoint3D;
+mod placeholder_cubemap;
+pub use placeholder_cubemap::re_export::PlaceholderCubemap;
+pub use placeholder_cubemap::re_export::IPlaceholderCubemap;
+mod placeholder_cubemap_array;
+pub use placeholder_cubemap_array::re_export::PlaceholderCubemapArray;
+pub use placeholder_cubemap_array::re_export::IPlaceholderCubemapArray;
+mod placeholder_material;
+pub use placeholder_material::re_export::PlaceholderMaterial;
+pub use placeholder_material::re_export::IPlaceholderMaterial;
+mod placeholder_mesh;
+pub use placeholder_mesh::re_export::PlaceholderMesh;
+pub use placeholder_mesh::re_export::IPlaceholderMesh;
+mod placeholder_texture_2d;
+pub use placeholder_texture_2d::re_export::PlaceholderTexture2D;
+pub use placeholder_texture_2d::re_export::IPlaceholderTexture2D;
+mod placeholder_texture_2d_array;
+pub use placeholder_texture_2d_array::re_export::PlaceholderTexture2DArray;
+pub use placeholder_texture_2d_array::re_export::IPlaceholderTexture2DArray;
+mod placeholder_texture_3d;
+pub use placeholder_texture_3d::re_export::PlaceholderTexture3D;
+pub use placeholder_texture_3d::re_export::IPlaceholderTexture3D;
+mod placeholder_texture_layered;
+pub use placeholder_texture_layered::re_export::PlaceholderTextureLayered;
+pub mod plane_mesh;
+pub use plane_mesh::re_export::PlaneMesh;
+pub use plane_mesh::re_export::IPlaneMesh;
+mod point_light_2d;
+pub use point_light_2d::re_export::PointLight2D;
+pub use point_light_2d::re_export::IPointLight2D;
+mod point_mesh;
+pub use point_mesh::re_export::PointMesh;
+pub use point_mesh::re_export::IPointMesh;
+mod polygon_2d;
+pub use polygon_2d::re_export::Polygon2D;
+pub use polygon_2d::re_export::IPolygon2D;
+mod polygon_occluder_3d;
+pub use polygon_occluder_3d::re_export::PolygonOccluder3D;
+pub use polygon_occluder_3d::re_export::IPolygonOccluder3D;
+mod polygon_path_finder;
+pub use polygon_path_finder::re_export::PolygonPathFinder;
+pub use polygon_path_finder::re_export::IPolygonPathFinder;
+pub mod popup;
+pub use popup::re_export::Popup;
+pub use popup::re_export::IPopup;
+pub mod popup_menu;
+pub use popup_menu::re_export::PopupMenu;
+pub use popup_menu::re_export::IPopupMenu;
+mod popup_panel;
+pub use popup_panel::re_export::PopupPanel;
+pub use popup_panel::re_export::IPopupPanel;
+pub mod portable_compressed_texture_2d;
+pub use portable_compressed_texture_2d::re_export::PortableCompressedTexture2D;
+pub use portable_compressed_texture_2d::re_export::IPortableCompressedTexture2D;
+mod primitive_mesh;
+pub use primitive_mesh::re_export::PrimitiveMesh;
+pub use primitive_mesh::re_export::IPrimitiveMesh;
+mod prism_mesh;
+pub use prism_mesh::re_export::PrismMesh;
+pub use prism_mesh::re_export::IPrismMesh;
+mod procedural_sky_material;
+pub use procedural_sky_material::re_export::ProceduralSkyMaterial;
+pub use procedural_sky_material::re_export::IProceduralSkyMaterial;
+pub mod progress_bar;
+pub use progress_bar::re_export::ProgressBar;
+pub use progress_bar::re_export::IProgressBar;
+pub mod project_settings;
+pub use project_settings::re_export::ProjectSettings;
+mod property_tweener;
+pub use property_tweener::re_export::PropertyTweener;
+pub use property_tweener::re_export::IPropertyTweener;
+mod quad_mesh;
+pub use quad_mesh::re_export::QuadMesh;
+pub use quad_mesh::re_export::IQuadMesh;
+mod quad_occluder_3d;
+pub use quad_occluder_3d::re_export::QuadOccluder3D;
+pub use quad_occluder_3d::re_export::IQuadOccluder3D;
+mod rd_attachment_format;
+pub use rd_attachment_format::re_export::RdAttachmentFormat;
+pub use rd_attachment_format::re_export::IRdAttachmentFormat;
+mod rd_framebuffer_pass;
+pub use rd_framebuffer_pass::re_export::RdFramebufferPass;
+pub use rd_framebuffer_pass::re_export::IRdFramebufferPass;
+mod rd_pipeline_color_blend_state;
+pub use rd_pipeline_color_blend_state::re_export::RdPipelineColorBlendState;
+pub use rd_pipeline_color_blend_state::re_export::IRdPipelineColorBlendState;
+mod rd_pipeline_color_blend_state_attachment;
+pub use rd_pipeline_color_blend_state_attachment::re_export::RdPipelineColorBlendStateAttachment;
+pub use rd_pipeline_color_blend_state_attachment::re_export::IRdPipelineColorBlendStateAttachment;
+mod rd_pipeline_depth_stencil_state;
+pub use rd_pipeline_depth_stencil_state::re_export::RdPipelineDepthStencilState;
+pub use rd_pipeline_depth_stencil_state::re_export::IRdPipelineDepthStencilState;
+mod rd_pipeline_multisample_state;
+pub use rd_pipeline_multisample_state::re_export::RdPipelineMultisampleState;
+pub use rd_pipeline_multisample_state::re_export::IRdPipelineMultisampleState;
+mod rd_pipeline_rasterization_state;
+pub use rd_pipeline_rasterization_state::re_export::RdPipelineRasterizationState;
+pub use rd_pipeline_rasterization_state::re_export::IRdPipelineRasterizationState;
+mod rd_pipeline_specialization_constant;
+pub use rd_pipeline_specialization_constant::re_export::RdPipelineSpecializationConstant;
+pub use rd_pipeline_specialization_constant::re_export::IRdPipelineSpecializationConstant;
+mod rd_sampler_state;
+pub use rd_sampler_state::re_export::RdSamplerState;
+pub use rd_sampler_state::re_export::IRdSamplerState;
+pub mod rd_shader_file;
+pub use rd_shader_file::re_export::RdShaderFile;
+pub use rd_shader_file::re_export::IRdShaderFile;
+mod rd_shader_spirv;
+pub use rd_shader_spirv::re_export::RdShaderSpirv;
+pub use rd_shader_spirv::re_export::IRdShaderSpirv;
+mod rd_shader_source;
+pub use rd_shader_source::re_export::RdShaderSource;
+pub use rd_shader_source::re_export::IRdShaderSource;
+mod rd_texture_format;
+pub use rd_texture_format::re_export::RdTextureFormat;
+pub use rd_texture_format::re_export::IRdTextureFormat;
+mod rd_texture_view;
+pub use rd_texture_view::re_export::RdTextureView;
+pub use rd_texture_view::re_export::IRdTextureView;
+mod rd_uniform;
+pub use rd_uniform::re_export::RdUniform;
+pub use rd_uniform::re_export::IRdUniform;
+mod rd_vertex_attribute;
+pub use rd_vertex_attribute::re_export::RdVertexAttribute;
+pub use rd_vertex_attribute::re_export::IRdVertexAttribute;
+pub mod random_number_generator;
+pub use random_number_generator::re_export::RandomNumberGenerator;
+pub use random_number_generator::re_export::IRandomNumberGenerator;
+pub mod range;
+pub use range::re_export::Range;
+pub use range::re_export::IRange;
+mod ray_cast_2d;
+pub use ray_cast_2d::re_export::RayCast2D;
+pub use ray_cast_2d::re_export::IRayCast2D;
+mod ray_cast_3d;
+pub use ray_cast_3d::re_export::RayCast3D;
+pub use ray_cast_3d::re_export::IRayCast3D;
+mod rectangle_shape_2d;
+pub use rectangle_shape_2d::re_export::RectangleShape2D;
+pub use rectangle_shape_2d::re_export::IRectangleShape2D;
+mod ref_counted;
+pub use ref_counted::re_export::RefCounted;
+pub use ref_counted::re_export::IRefCounted;
+mod reference_rect;
+pub use reference_rect::re_export::ReferenceRect;
+pub use reference_rect::re_export::IReferenceRect;
+pub mod reflection_probe;
+pub use reflection_probe::re_export::ReflectionProbe;
+pub use reflection_probe::re_export::IReflectionProbe;
+pub mod reg_ex;
+pub use reg_ex::re_export::RegEx;
+pub use reg_ex::re_export::IRegEx;
+pub mod reg_ex_match;
+pub use reg_ex_match::re_export::RegExMatch;
+pub use reg_ex_match::re_export::IRegExMatch;
+mod remote_transform_2d;
+pub use remote_transform_2d::re_export::RemoteTransform2D;
+pub use remote_transform_2d::re_export::IRemoteTransform2D;
+mod remote_transform_3d;
+pub use remote_transform_3d::re_export::RemoteTransform3D;
+pub use remote_transform_3d::re_export::IRemoteTransform3D;
+mod render_data;
+pub use render_data::re_export::RenderData;
+mod render_data_extension;
+pub use render_data_extension::re_export::RenderDataExtension;
+pub use render_data_extension::re_export::IRenderDataExtension;
+mod render_data_rd;
+pub use render_data_rd::re_export::RenderDataRd;
+pub use render_data_rd::re_export::IRenderDataRd;
+mod render_scene_buffers;
+pub use render_scene_buffers::re_export::RenderSceneBuffers;
+mod render_scene_buffers_configuration;
+pub use render_scene_buffers_configuration::re_export::RenderSceneBuffersConfiguration;
+pub use render_scene_buffers_configuration::re_export::IRenderSceneBuffersConfiguration;
+mod render_scene_buffers_extension;
+pub use render_scene_buffers_extension::re_export::RenderSceneBuffersExtension;
+pub use render_scene_buffers_extension::re_export::IRenderSceneBuffersExtension;
+pub mod render_scene_buffers_rd;
+pub use render_scene_buffers_rd::re_export::RenderSceneBuffersRd;
+pub use render_scene_buffers_rd::re_export::IRenderSceneBuffersRd;
+mod render_scene_data;
+pub use render_scene_data::re_export::RenderSceneData;
+mod render_scene_data_extension;
+pub use render_scene_data_extension::re_export::RenderSceneDataExtension;
+pub use render_scene_data_extension::re_export::IRenderSceneDataExtension;
+mod render_scene_data_rd;
+pub use render_scene_data_rd::re_export::RenderSceneDataRd;
+pub use render_scene_data_rd::re_export::IRenderSceneDataRd;
+pub mod rendering_device;
+pub use rendering_device::re_export::RenderingDevice;
+pub mod rendering_server;
+pub use rendering_server::re_export::RenderingServer;
+pub mod resource;
+pub use resource::re_export::Resource;
+pub use resource::re_export::IResource;
+pub mod resource_format_loader;
+pub use resource_format_loader::re_export::ResourceFormatLoader;
+pub use resource_format_loader::re_export::IResourceFormatLoader;
+mod resource_format_saver;
+pub use resource_format_saver::re_export::ResourceFormatSaver;
+pub use resource_format_saver::re_export::IResourceFormatSaver;
+pub mod resource_importer;
+pub use resource_importer::re_export::ResourceImporter;
+mod resource_importer_bm_font;
+pub use resource_importer_bm_font::re_export::ResourceImporterBmFont;
+pub use resource_importer_bm_font::re_export::IResourceImporterBmFont;
+mod resource_importer_bit_map;
+pub use resource_importer_bit_map::re_export::ResourceImporterBitMap;
+pub use resource_importer_bit_map::re_export::IResourceImporterBitMap;
+mod resource_importer_csv_translation;
+pub use resource_importer_csv_translation::re_export::ResourceImporterCsvTranslation;
+pub use resource_importer_csv_translation::re_export::IResourceImporterCsvTranslation;
+mod resource_importer_dynamic_font;
+pub use resource_importer_dynamic_font::re_export::ResourceImporterDynamicFont;
+pub use resource_importer_dynamic_font::re_export::IResourceImporterDynamicFont;
+mod resource_importer_image;
+pub use resource_importer_image::re_export::ResourceImporterImage;
+pub use resource_importer_image::re_export::IResourceImporterImage;
+mod resource_importer_image_font;
+pub use resource_importer_image_font::re_export::ResourceImporterImageFont;
+pub use resource_importer_image_font::re_export::IResourceImporterImageFont;
+mod resource_importer_layered_texture;
+pub use resource_importer_layered_texture::re_export::ResourceImporterLayeredTexture;
+pub use resource_importer_layered_texture::re_export::IResourceImporterLayeredTexture;
+mod resource_importer_mp3;
+pub use resource_importer_mp3::re_export::ResourceImporterMp3;
+pub use resource_importer_mp3::re_export::IResourceImporterMp3;
+mod resource_importer_obj;
+pub use resource_importer_obj::re_export::ResourceImporterObj;
+pub use resource_importer_obj::re_export::IResourceImporterObj;
+mod resource_importer_ogg_vorbis;
+pub use resource_importer_ogg_vorbis::re_export::ResourceImporterOggVorbis;
+pub use resource_importer_ogg_vorbis::re_export::IResourceImporterOggVorbis;
+mod resource_importer_svg;
+pub use resource_importer_svg::re_export::ResourceImporterSvg;
+pub use resource_importer_svg::re_export::IResourceImporterSvg;
+mod resource_importer_scene;
+pub use resource_importer_scene::re_export::ResourceImporterScene;
+pub use resource_importer_scene::re_export::IResourceImporterScene;
+mod resource_importer_shader_file;
+pub use resource_importer_shader_file::re_export::ResourceImporterShaderFile;
+pub use resource_importer_shader_file::re_export::IResourceImporterShaderFile;
+mod resource_importer_texture;
+pub use resource_importer_texture::re_export::ResourceImporterTexture;
+pub use resource_importer_texture::re_export::IResourceImporterTexture;
+mod resource_importer_texture_atlas;
+pub use resource_importer_texture_atlas::re_export::ResourceImporterTextureAtlas;
+pub use resource_importer_texture_atlas::re_export::IResourceImporterTextureAtlas;
+mod resource_importer_wav;
+pub use resource_importer_wav::re_export::ResourceImporterWav;
+pub use resource_importer_wav::re_export::IResourceImporterWav;
+pub mod resource_loader;
+pub use resource_loader::re_export::ResourceLoader;
+mod resource_preloader;
+pub use resource_preloader::re_export::ResourcePreloader;
+pub use resource_preloader::re_export::IResourcePreloader;
+pub mod resource_saver;
+pub use resource_saver::re_export::ResourceSaver;
+mod resource_uid;
+pub use resource_uid::re_export::ResourceUid;
+pub mod retarget_modifier_3d;
+pub use retarget_modifier_3d::re_export::RetargetModifier3D;
+pub use retarget_modifier_3d::re_export::IRetargetModifier3D;
+pub mod ribbon_trail_mesh;
+pub use ribbon_trail_mesh::re_export::RibbonTrailMesh;
+pub use ribbon_trail_mesh::re_export::IRibbonTrailMesh;
+mod rich_text_effect;
+pub use rich_text_effect::re_export::RichTextEffect;
+pub use rich_text_effect::re_export::IRichTextEffect;
+pub mod rich_text_label;
+pub use rich_text_label::re_export::RichTextLabel;
+pub use rich_text_label::re_export::IRichTextLabel;
+pub mod rigid_body_2d;
+pub use rigid_body_2d::re_export::RigidBody2D;
+pub use rigid_body_2d::re_export::IRigidBody2D;
+pub mod rigid_body_3d;
+pub use rigid_body_3d::re_export::RigidBody3D;
+pub use rigid_body_3d::re_export::IRigidBody3D;
+mod root_motion_view;
+pub use root_motion_view::re_export::RootMotionView;
+pub use root_motion_view::re_export::IRootMotionView;
+pub mod scene_multiplayer;
+pub use scene_multiplayer::re_export::SceneMultiplayer;
+pub use scene_multiplayer::re_export::ISceneMultiplayer;
+pub mod scene_replication_config;
+pub use scene_replication_config::re_export::SceneReplicationConfig;
+pub use scene_replication_config::re_export::ISceneReplicationConfig;
+pub mod scene_state;
+pub use scene_state::re_export::SceneState;
+pub mod scene_tree;
+pub use scene_tree::re_export::SceneTree;
+pub use scene_tree::re_export::ISceneTree;
+pub mod scene_tree_timer;
+pub use scene_tree_timer::re_export::SceneTreeTimer;
+pub mod script;
+pub use script::re_export::Script;
+pub mod script_backtrace;
+pub use script_backtrace::re_export::ScriptBacktrace;
+pub use script_backtrace::re_export::IScriptBacktrace;
+pub mod script_create_dialog;
+pub use script_create_dialog::re_export::ScriptCreateDialog;
+pub use script_create_dialog::re_export::IScriptCreateDialog;
+pub mod script_editor;
+pub use script_editor::re_export::ScriptEditor;
+pub mod script_editor_base;
+pub use script_editor_base::re_export::ScriptEditorBase;
+mod script_extension;
+pub use script_extension::re_export::ScriptExtension;
+pub use script_extension::re_export::IScriptExtension;
+pub mod script_language;
+pub use script_language::re_export::ScriptLanguage;
+pub mod script_language_extension;
+pub use script_language_extension::re_export::ScriptLanguageExtension;
+pub use script_language_extension::re_export::IScriptLanguageExtension;
+pub mod scroll_bar;
+pub use scroll_bar::re_export::ScrollBar;
+pub mod scroll_container;
+pub use scroll_container::re_export::ScrollContainer;
+pub use scroll_container::re_export::IScrollContainer;
+mod segment_shape_2d;
+pub use segment_shape_2d::re_export::SegmentShape2D;
+pub use segment_shape_2d::re_export::ISegmentShape2D;
+mod separation_ray_shape_2d;
+pub use separation_ray_shape_2d::re_export::SeparationRayShape2D;
+pub use separation_ray_shape_2d::re_export::ISeparationRayShape2D;
+mod separation_ray_shape_3d;
+pub use separation_ray_shape_3d::re_export::SeparationRayShape3D;
+pub use separation_ray_shape_3d::re_export::ISeparationRayShape3D;
+mod separator;
+pub use separator::re_export::Separator;
+pub mod shader;
+pub use shader::re_export::Shader;
+mod shader_globals_override;
+pub use shader_globals_override::re_export::ShaderGlobalsOverride;
+pub use shader_globals_override::re_export::IShaderGlobalsOverride;
+mod shader_include;
+pub use shader_include::re_export::ShaderInclude;
+pub use shader_include::re_export::IShaderInclude;
+mod shader_include_db;
+pub use shader_include_db::re_export::ShaderIncludeDb;
+pub use shader_include_db::re_export::IShaderIncludeDb;
+mod shader_material;
+pub use shader_material::re_export::ShaderMaterial;
+pub use shader_material::re_export::IShaderMaterial;
+mod shape_2d;
+pub use shape_2d::re_export::Shape2D;
+mod shape_3d;
+pub use shape_3d::re_export::Shape3D;
+mod shape_cast_2d;
+pub use shape_cast_2d::re_export::ShapeCast2D;
+pub use shape_cast_2d::re_export::IShapeCast2D;
+mod shape_cast_3d;
+pub use shape_cast_3d::re_export::ShapeCast3D;
+pub use shape_cast_3d::re_export::IShapeCast3D;
+mod shortcut;
+pub use shortcut::re_export::Shortcut;
+pub use shortcut::re_export::IShortcut;
+pub mod skeleton_2d;
+pub use skeleton_2d::re_export::Skeleton2D;
+pub use skeleton_2d::re_export::ISkeleton2D;
+pub mod skeleton_3d;
+pub use skeleton_3d::re_export::Skeleton3D;
+pub use skeleton_3d::re_export::ISkeleton3D;
+pub mod skeleton_ik_3d;
+pub use skeleton_ik_3d::re_export::SkeletonIk3d;
+pub use skeleton_ik_3d::re_export::ISkeletonIk3d;
+pub mod skeleton_modifier_3d;
+pub use skeleton_modifier_3d::re_export::SkeletonModifier3D;
+pub use skeleton_modifier_3d::re_export::ISkeletonModifier3D;
+pub mod skeleton_profile;
+pub use skeleton_profile::re_export::SkeletonProfile;
+pub use skeleton_profile::re_export::ISkeletonProfile;
+mod skeleton_profile_humanoid;
+pub use skeleton_profile_humanoid::re_export::SkeletonProfileHumanoid;
+pub use skeleton_profile_humanoid::re_export::ISkeletonProfileHumanoid;
+mod skin;
+pub use skin::re_export::Skin;
+pub use skin::re_export::ISkin;
+mod skin_reference;
+pub use skin_reference::re_export::SkinReference;
+pub mod sky;
+pub use sky::re_export::Sky;
+pub use sky::re_export::ISky;
+pub mod slider;
+pub use slider::re_export::Slider;
+pub mod slider_joint_3d;
+pub use slider_joint_3d::re_export::SliderJoint3D;
+pub use slider_joint_3d::re_export::ISliderJoint3D;
+pub mod soft_body_3d;
+pub use soft_body_3d::re_export::SoftBody3D;
+pub use soft_body_3d::re_export::ISoftBody3D;
+mod sphere_mesh;
+pub use sphere_mesh::re_export::SphereMesh;
+pub use sphere_mesh::re_export::ISphereMesh;
+mod sphere_occluder_3d;
+pub use sphere_occluder_3d::re_export::SphereOccluder3D;
+pub use sphere_occluder_3d::re_export::ISphereOccluder3D;
+mod sphere_shape_3d;
+pub use sphere_shape_3d::re_export::SphereShape3D;
+pub use sphere_shape_3d::re_export::ISphereShape3D;
+mod spin_box;
+pub use spin_box::re_export::SpinBox;
+pub use spin_box::re_export::ISpinBox;
+pub mod split_container;
+pub use split_container::re_export::SplitContainer;
+pub use split_container::re_export::ISplitContainer;
+mod spot_light_3d;
+pub use spot_light_3d::re_export::SpotLight3D;
+pub use spot_light_3d::re_export::ISpotLight3D;
+mod spring_arm_3d;
+pub use spring_arm_3d::re_export::SpringArm3D;
+pub use spring_arm_3d::re_export::ISpringArm3D;
+mod spring_bone_collision_3d;
+pub use spring_bone_collision_3d::re_export::SpringBoneCollision3D;
+pub use spring_bone_collision_3d::re_export::ISpringBoneCollision3D;
+mod spring_bone_collision_capsule_3d;
+pub use spring_bone_collision_capsule_3d::re_export::SpringBoneCollisionCapsule3D;
+pub use spring_bone_collision_capsule_3d::re_export::ISpringBoneCollisionCapsule3D;
+mod spring_bone_collision_plane_3d;
+pub use spring_bone_collision_plane_3d::re_export::SpringBoneCollisionPlane3D;
+pub use spring_bone_collision_plane_3d::re_export::ISpringBoneCollisionPlane3D;
+mod spring_bone_collision_sphere_3d;
+pub use spring_bone_collision_sphere_3d::re_export::SpringBoneCollisionSphere3D;
+pub use spring_bone_collision_sphere_3d::re_export::ISpringBoneCollisionSphere3D;
+pub mod spring_bone_simulator_3d;
+pub use spring_bone_simulator_3d::re_export::SpringBoneSimulator3D;
+pub use spring_bone_simulator_3d::re_export::ISpringBoneSimulator3D;
+pub mod sprite_2d;
+pub use sprite_2d::re_export::Sprite2D;
+pub use sprite_2d::re_export::ISprite2D;
+pub mod sprite_3d;
+pub use sprite_3d::re_export::Sprite3D;
+pub use sprite_3d::re_export::ISprite3D;
+pub mod sprite_base_3d;
+pub use sprite_base_3d::re_export::SpriteBase3D;
+pub mod sprite_frames;
+pub use sprite_frames::re_export::SpriteFrames;
+pub use sprite_frames::re_export::ISpriteFrames;
+mod standard_material_3d;
+pub use standard_material_3d::re_export::StandardMaterial3D;
+pub use standard_material_3d::re_export::IStandardMaterial3D;
+mod static_body_2d;
+pub use static_body_2d::re_export::StaticBody2D;
+pub use static_body_2d::re_export::IStaticBody2D;
+mod static_body_3d;
+pub use static_body_3d::re_export::StaticBody3D;
+pub use static_body_3d::re_export::IStaticBody3D;
+pub mod status_indicator;
+pub use status_indicator::re_export::StatusIndicator;
+pub use status_indicator::re_export::IStatusIndicator;
+pub mod stream_peer;
+pub use stream_peer::re_export::StreamPeer;
+mod stream_peer_buffer;
+pub use stream_peer_buffer::re_export::StreamPeerBuffer;
+pub use stream_peer_buffer::re_export::IStreamPeerBuffer;
+mod stream_peer_extension;
+pub use stream_peer_extension::re_export::StreamPeerExtension;
+pub use stream_peer_extension::re_export::IStreamPeerExtension;
+pub mod stream_peer_tcp;
+pub use stream_peer_tcp::re_export::StreamPeerTcp;
+pub use stream_peer_tcp::re_export::IStreamPeerTcp;
+pub mod stream_peer_tls;
+pub use stream_peer_tls::re_export::StreamPeerTls;
+pub use stream_peer_tls::re_export::IStreamPeerTls;
+mod style_box;
+pub use style_box::re_export::StyleBox;
+pub use style_box::re_export::IStyleBox;
+mod style_box_empty;
+pub use style_box_empty::re_export::StyleBoxEmpty;
+pub use style_box_empty::re_export::IStyleBoxEmpty;
+mod style_box_flat;
+pub use style_box_flat::re_export::StyleBoxFlat;
+pub use style_box_flat::re_export::IStyleBoxFlat;
+mod style_box_line;
+pub use style_box_line::re_export::StyleBoxLine;
+pub use style_box_line::re_export::IStyleBoxLine;
+pub mod style_box_texture;
+pub use style_box_texture::re_export::StyleBoxTexture;
+pub use style_box_texture::re_export::IStyleBoxTexture;
+pub mod sub_viewport;
+pub use sub_viewport::re_export::SubViewport;
+pub use sub_viewport::re_export::ISubViewport;
+mod sub_viewport_container;
+pub use sub_viewport_container::re_export::SubViewportContainer;
+pub use sub_viewport_container::re_export::ISubViewportContainer;
+mod subtween_tweener;
+pub use subtween_tweener::re_export::SubtweenTweener;
+pub use subtween_tweener::re_export::ISubtweenTweener;
+pub mod surface_tool;
+pub use surface_tool::re_export::SurfaceTool;
+pub use surface_tool::re_export::ISurfaceTool;
+mod syntax_highlighter;
+pub use syntax_highlighter::re_export::SyntaxHighlighter;
+pub use syntax_highlighter::re_export::ISyntaxHighlighter;
+mod system_font;
+pub use system_font::re_export::SystemFont;
+pub use system_font::re_export::ISystemFont;
+pub mod tcp_server;
+pub use tcp_server::re_export::TcpServer;
+pub use tcp_server::re_export::ITcpServer;
+pub mod tls_options;
+pub use tls_options::re_export::TlsOptions;
+pub mod tab_bar;
+pub use tab_bar::re_export::TabBar;
+pub use tab_bar::re_export::ITabBar;
+pub mod tab_container;
+pub use tab_container::re_export::TabContainer;
+pub use tab_container::re_export::ITabContainer;
+pub mod text_edit;
+pub use text_edit::re_export::TextEdit;
+pub use text_edit::re_export::ITextEdit;
+pub mod text_line;
+pub use text_line::re_export::TextLine;
+pub use text_line::re_export::ITextLine;
+mod text_mesh;
+pub use text_mesh::re_export::TextMesh;
+pub use text_mesh::re_export::ITextMesh;
+pub mod text_paragraph;
+pub use text_paragraph::re_export::TextParagraph;
+pub use text_paragraph::re_export::ITextParagraph;
+pub mod text_server;
+pub use text_server::re_export::TextServer;
+mod text_server_advanced;
+pub use text_server_advanced::re_export::TextServerAdvanced;
+pub use text_server_advanced::re_export::ITextServerAdvanced;
+mod text_server_dummy;
+pub use text_server_dummy::re_export::TextServerDummy;
+pub use text_server_dummy::re_export::ITextServerDummy;
+mod text_server_extension;
+pub use text_server_extension::re_export::TextServerExtension;
+pub use text_server_extension::re_export::ITextServerExtension;
+pub mod text_server_manager;
+pub use text_server_manager::re_export::TextServerManager;
+mod texture;
+pub use texture::re_export::Texture;
+pub use texture::re_export::ITexture;
+pub mod texture_2d;
+pub use texture_2d::re_export::Texture2D;
+pub use texture_2d::re_export::ITexture2D;
+mod texture_2d_array;
+pub use texture_2d_array::re_export::Texture2DArray;
+pub use texture_2d_array::re_export::ITexture2DArray;
+mod texture_2d_array_rd;
+pub use texture_2d_array_rd::re_export::Texture2DArrayRd;
+pub use texture_2d_array_rd::re_export::ITexture2DArrayRd;
+mod texture_2d_rd;
+pub use texture_2d_rd::re_export::Texture2Drd;
+pub use texture_2d_rd::re_export::ITexture2Drd;
+mod texture_3d;
+pub use texture_3d::re_export::Texture3D;
+pub use texture_3d::re_export::ITexture3D;
+mod texture_3d_rd;
+pub use texture_3d_rd::re_export::Texture3Drd;
+pub use texture_3d_rd::re_export::ITexture3Drd;
+pub mod texture_button;
+pub use texture_button::re_export::TextureButton;
+pub use texture_button::re_export::ITextureButton;
+mod texture_cubemap_array_rd;
+pub use texture_cubemap_array_rd::re_export::TextureCubemapArrayRd;
+pub use texture_cubemap_array_rd::re_export::ITextureCubemapArrayRd;
+mod texture_cubemap_rd;
+pub use texture_cubemap_rd::re_export::TextureCubemapRd;
+pub use texture_cubemap_rd::re_export::ITextureCubemapRd;
+pub mod texture_layered;
+pub use texture_layered::re_export::TextureLayered;
+pub use texture_layered::re_export::ITextureLayered;
+mod texture_layered_rd;
+pub use texture_layered_rd::re_export::TextureLayeredRd;
+pub mod texture_progress_bar;
+pub use texture_progress_bar::re_export::TextureProgressBar;
+pub use texture_progress_bar::re_export::ITextureProgressBar;
+pub mod texture_rect;
+pub use texture_rect::re_export::TextureRect;
+pub use texture_rect::re_export::ITextureRect;
+pub mod theme;
+pub use theme::re_export::Theme;
+pub use theme::re_export::ITheme;
+pub mod theme_db;
+pub use theme_db::re_export::ThemeDb;
+pub mod tile_data;
+pub use tile_data::re_export::TileData;
+pub use tile_data::re_export::ITileData;
+pub mod tile_map;
+pub use tile_map::re_export::TileMap;
+pub use tile_map::re_export::ITileMap;
+pub mod tile_map_layer;
+pub use tile_map_layer::re_export::TileMapLayer;
+pub use tile_map_layer::re_export::ITileMapLayer;
+pub mod tile_map_pattern;
+pub use tile_map_pattern::re_export::TileMapPattern;
+pub use tile_map_pattern::re_export::ITileMapPattern;
+pub mod tile_set;
+pub use tile_set::re_export::TileSet;
+pub use tile_set::re_export::ITileSet;
+pub mod tile_set_atlas_source;
+pub use tile_set_atlas_source::re_export::TileSetAtlasSource;
+pub use tile_set_atlas_source::re_export::ITileSetAtlasSource;
+pub mod tile_set_scenes_collection_source;
+pub use tile_set_scenes_collection_source::re_export::TileSetScenesCollectionSource;
+pub use tile_set_scenes_collection_source::re_export::ITileSetScenesCollectionSource;
+mod tile_set_source;
+pub use tile_set_source::re_export::TileSetSource;
+pub mod time;
+pub use time::re_export::Time;
+pub mod timer;
+pub use timer::re_export::Timer;
+pub use timer::re_export::ITimer;
+mod torus_mesh;
+pub use torus_mesh::re_export::TorusMesh;
+pub use torus_mesh::re_export::ITorusMesh;
+pub mod touch_screen_button;
+pub use touch_screen_button::re_export::TouchScreenButton;
+pub use touch_screen_button::re_export::ITouchScreenButton;
+pub mod translation;
+pub use translation::re_export::Translation;
+pub use translation::re_export::ITranslation;
+pub mod translation_domain;
+pub use translation_domain::re_export::TranslationDomain;
+pub use translation_domain::re_export::ITranslationDomain;
+pub mod translation_server;
+pub use translation_server::re_export::TranslationServer;
+pub mod tree;
+pub use tree::re_export::Tree;
+pub use tree::re_export::ITree;
+pub mod tree_item;
+pub use tree_item::re_export::TreeItem;
+mod triangle_mesh;
+pub use triangle_mesh::re_export::TriangleMesh;
+pub use triangle_mesh::re_export::ITriangleMesh;
+mod tube_trail_mesh;
+pub use tube_trail_mesh::re_export::TubeTrailMesh;
+pub use tube_trail_mesh::re_export::ITubeTrailMesh;
+pub mod tween;
+pub use tween::re_export::Tween;
+pub use tween::re_export::ITween;
+pub mod tweener;
+pub use tweener::re_export::Tweener;
+pub mod udp_server;
+pub use udp_server::re_export::UdpServer;
+pub use udp_server::re_export::IUdpServer;
+pub mod upnp;
+pub use upnp::re_export::Upnp;
+pub use upnp::re_export::IUpnp;
+pub mod upnp_device;
+pub use upnp_device::re_export::UpnpDevice;
+pub use upnp_device::re_export::IUpnpDevice;
+pub mod undo_redo;
+pub use undo_redo::re_export::UndoRedo;
+pub use undo_redo::re_export::IUndoRedo;
+mod uniform_set_cache_rd;
+pub use uniform_set_cache_rd::re_export::UniformSetCacheRd;
+pub use uniform_set_cache_rd::re_export::IUniformSetCacheRd;
+mod v_box_container;
+pub use v_box_container::re_export::VBoxContainer;
+pub use v_box_container::re_export::IVBoxContainer;
+mod v_flow_container;
+pub use v_flow_container::re_export::VFlowContainer;
+pub use v_flow_container::re_export::IVFlowContainer;
+mod v_scroll_bar;
+pub use v_scroll_bar::re_export::VScrollBar;
+pub use v_scroll_bar::re_export::IVScrollBar;
+mod v_separator;
+pub use v_separator::re_export::VSeparator;
+pub use v_separator::re_export::IVSeparator;
+mod v_slider;
+pub use v_slider::re_export::VSlider;
+pub use v_slider::re_export::IVSlider;
+mod v_split_container;
+pub use v_split_container::re_export::VSplitContainer;
+pub use v_split_container::re_export::IVSplitContainer;
+mod vehicle_body_3d;
+pub use vehicle_body_3d::re_export::VehicleBody3D;
+pub use vehicle_body_3d::re_export::IVehicleBody3D;
+mod vehicle_wheel_3d;
+pub use vehicle_wheel_3d::re_export::VehicleWheel3D;
+pub use vehicle_wheel_3d::re_export::IVehicleWheel3D;
+mod video_stream;
+pub use video_stream::re_export::VideoStream;
+pub use video_stream::re_export::IVideoStream;
+pub mod video_stream_playback;
+pub use video_stream_playback::re_export::VideoStreamPlayback;
+pub use video_stream_playback::re_export::IVideoStreamPlayback;
+pub mod video_stream_player;
+pub use video_stream_player::re_export::VideoStreamPlayer;
+pub use video_stream_player::re_export::IVideoStreamPlayer;
+mod video_stream_theora;
+pub use video_stream_theora::re_export::VideoStreamTheora;
+pub use video_stream_theora::re_export::IVideoStreamTheora;
+pub mod viewport;
+pub use viewport::re_export::Viewport;
+mod viewport_texture;
+pub use viewport_texture::re_export::ViewportTexture;
+pub use viewport_texture::re_export::IViewportTexture;
+pub mod visible_on_screen_enabler_2d;
+pub use visible_on_screen_enabler_2d::re_export::VisibleOnScreenEnabler2D;
+pub use visible_on_screen_enabler_2d::re_export::IVisibleOnScreenEnabler2D;
+pub mod visible_on_screen_enabler_3d;
+pub use visible_on_screen_enabler_3d::re_export::VisibleOnScreenEnabler3D;
+pub use visible_on_screen_enabler_3d::re_export::IVisibleOnScreenEnabler3D;
+pub mod visible_on_screen_notifier_2d;
+pub use visible_on_screen_notifier_2d::re_export::VisibleOnScreenNotifier2D;
+pub use visible_on_screen_notifier_2d::re_export::IVisibleOnScreenNotifier2D;
+pub mod visible_on_screen_notifier_3d;
+pub use visible_on_screen_notifier_3d::re_export::VisibleOnScreenNotifier3D;
+pub use visible_on_screen_notifier_3d::re_export::IVisibleOnScreenNotifier3D;
+mod visual_instance_3d;
+pub use visual_instance_3d::re_export::VisualInstance3D;
+pub use visual_instance_3d::re_export::IVisualInstance3D;
+pub mod visual_shader;
+pub use visual_shader::re_export::VisualShader;
+pub use visual_shader::re_export::IVisualShader;
+pub mod visual_shader_node;
+pub use visual_shader_node::re_export::VisualShaderNode;
+pub mod visual_shader_node_billboard;
+pub use visual_shader_node_billboard::re_export::VisualShaderNodeBillboard;
+pub use visual_shader_node_billboard::re_export::IVisualShaderNodeBillboard;
+mod visual_shader_node_boolean_constant;
+pub use visual_shader_node_boolean_constant::re_export::VisualShaderNodeBooleanConstant;
+pub use visual_shader_node_boolean_constant::re_export::IVisualShaderNodeBooleanConstant;
+mod visual_shader_node_boolean_parameter;
+pub use visual_shader_node_boolean_parameter::re_export::VisualShaderNodeBooleanParameter;
+pub use visual_shader_node_boolean_parameter::re_export::IVisualShaderNodeBooleanParameter;
+pub mod visual_shader_node_clamp;
+pub use visual_shader_node_clamp::re_export::VisualShaderNodeClamp;
+pub use visual_shader_node_clamp::re_export::IVisualShaderNodeClamp;
+mod visual_shader_node_color_constant;
+pub use visual_shader_node_color_constant::re_export::VisualShaderNodeColorConstant;
+pub use visual_shader_node_color_constant::re_export::IVisualShaderNodeColorConstant;
+pub mod visual_shader_node_color_func;
+pub use visual_shader_node_color_func::re_export::VisualShaderNodeColorFunc;
+pub use visual_shader_node_color_func::re_export::IVisualShaderNodeColorFunc;
+pub mod visual_shader_node_color_op;
+pub use visual_shader_node_color_op::re_export::VisualShaderNodeColorOp;
+pub use visual_shader_node_color_op::re_export::IVisualShaderNodeColorOp;
+mod visual_shader_node_color_parameter;
+pub use visual_shader_node_color_parameter::re_export::VisualShaderNodeColorParameter;
+pub use visual_shader_node_color_parameter::re_export::IVisualShaderNodeColorParameter;
+mod visual_shader_node_comment;
+pub use visual_shader_node_comment::re_export::VisualShaderNodeComment;
+pub use visual_shader_node_comment::re_export::IVisualShaderNodeComment;
+pub mod visual_shader_node_compare;
+pub use visual_shader_node_compare::re_export::VisualShaderNodeCompare;
+pub use visual_shader_node_compare::re_export::IVisualShaderNodeCompare;
+mod visual_shader_node_constant;
+pub use visual_shader_node_constant::re_export::VisualShaderNodeConstant;
+pub mod visual_shader_node_cubemap;
+pub use visual_shader_node_cubemap::re_export::VisualShaderNodeCubemap;
+pub use visual_shader_node_cubemap::re_export::IVisualShaderNodeCubemap;
+mod visual_shader_node_cubemap_parameter;
+pub use visual_shader_node_cubemap_parameter::re_export::VisualShaderNodeCubemapParameter;
+pub use visual_shader_node_cubemap_parameter::re_export::IVisualShaderNodeCubemapParameter;
+mod visual_shader_node_curve_texture;
+pub use visual_shader_node_curve_texture::re_export::VisualShaderNodeCurveTexture;
+pub use visual_shader_node_curve_texture::re_export::IVisualShaderNodeCurveTexture;
+mod visual_shader_node_curve_xyz_texture;
+pub use visual_shader_node_curve_xyz_texture::re_export::VisualShaderNodeCurveXyzTexture;
+pub use visual_shader_node_curve_xyz_texture::re_export::IVisualShaderNodeCurveXyzTexture;
+mod visual_shader_node_custom;
+pub use visual_shader_node_custom::re_export::VisualShaderNodeCustom;
+pub use visual_shader_node_custom::re_export::IVisualShaderNodeCustom;
+pub mod visual_shader_node_derivative_func;
+pub use visual_shader_node_derivative_func::re_export::VisualShaderNodeDerivativeFunc;
+pub use visual_shader_node_derivative_func::re_export::IVisualShaderNodeDerivativeFunc;
+mod visual_shader_node_determinant;
+pub use visual_shader_node_determinant::re_export::VisualShaderNodeDeterminant;
+pub use visual_shader_node_determinant::re_export::IVisualShaderNodeDeterminant;
+mod visual_shader_node_distance_fade;
+pub use visual_shader_node_distance_fade::re_export::VisualShaderNodeDistanceFade;
+pub use visual_shader_node_distance_fade::re_export::IVisualShaderNodeDistanceFade;
+mod visual_shader_node_dot_product;
+pub use visual_shader_node_dot_product::re_export::VisualShaderNodeDotProduct;
+pub use visual_shader_node_dot_product::re_export::IVisualShaderNodeDotProduct;
+mod visual_shader_node_expression;
+pub use visual_shader_node_expression::re_export::VisualShaderNodeExpression;
+pub use visual_shader_node_expression::re_export::IVisualShaderNodeExpression;
+mod visual_shader_node_face_forward;
+pub use visual_shader_node_face_forward::re_export::VisualShaderNodeFaceForward;
+pub use visual_shader_node_face_forward::re_export::IVisualShaderNodeFaceForward;
+mod visual_shader_node_float_constant;
+pub use visual_shader_node_float_constant::re_export::VisualShaderNodeFloatConstant;
+pub use visual_shader_node_float_constant::re_export::IVisualShaderNodeFloatConstant;
+pub mod visual_shader_node_float_func;
+pub use visual_shader_node_float_func::re_export::VisualShaderNodeFloatFunc;
+pub use visual_shader_node_float_func::re_export::IVisualShaderNodeFloatFunc;
+pub mod visual_shader_node_float_op;
+pub use visual_shader_node_float_op::re_export::VisualShaderNodeFloatOp;
+pub use visual_shader_node_float_op::re_export::IVisualShaderNodeFloatOp;
+pub mod visual_shader_node_float_parameter;
+pub use visual_shader_node_float_parameter::re_export::VisualShaderNodeFloatParameter;
+pub use visual_shader_node_float_parameter::re_export::IVisualShaderNodeFloatParameter;
+mod visual_shader_node_frame;
+pub use visual_shader_node_frame::re_export::VisualShaderNodeFrame;
+pub use visual_shader_node_frame::re_export::IVisualShaderNodeFrame;
+mod visual_shader_node_fresnel;
+pub use visual_shader_node_fresnel::re_export::VisualShaderNodeFresnel;
+pub use visual_shader_node_fresnel::re_export::IVisualShaderNodeFresnel;
+mod visual_shader_node_global_expression;
+pub use visual_shader_node_global_expression::re_export::VisualShaderNodeGlobalExpression;
+pub use visual_shader_node_global_expression::re_export::IVisualShaderNodeGlobalExpression;
+mod visual_shader_node_group_base;
+pub use visual_shader_node_group_base::re_export::VisualShaderNodeGroupBase;
+mod visual_shader_node_if;
+pub use visual_shader_node_if::re_export::VisualShaderNodeIf;
+pub use visual_shader_node_if::re_export::IVisualShaderNodeIf;
+pub mod visual_shader_node_input;
+pub use visual_shader_node_input::re_export::VisualShaderNodeInput;
+pub use visual_shader_node_input::re_export::IVisualShaderNodeInput;
+mod visual_shader_node_int_constant;
+pub use visual_shader_node_int_constant::re_export::VisualShaderNodeIntConstant;
+pub use visual_shader_node_int_constant::re_export::IVisualShaderNodeIntConstant;
+pub mod visual_shader_node_int_func;
+pub use visual_shader_node_int_func::re_export::VisualShaderNodeIntFunc;
+pub use visual_shader_node_int_func::re_export::IVisualShaderNodeIntFunc;
+pub mod visual_shader_node_int_op;
+pub use visual_shader_node_int_op::re_export::VisualShaderNodeIntOp;
+pub use visual_shader_node_int_op::re_export::IVisualShaderNodeIntOp;
+pub mod visual_shader_node_int_parameter;
+pub use visual_shader_node_int_parameter::re_export::VisualShaderNodeIntParameter;
+pub use visual_shader_node_int_parameter::re_export::IVisualShaderNodeIntParameter;
+pub mod visual_shader_node_is;
+pub use visual_shader_node_is::re_export::VisualShaderNodeIs;
+pub use visual_shader_node_is::re_export::IVisualShaderNodeIs;
+mod visual_shader_node_linear_scene_depth;
+pub use visual_shader_node_linear_scene_depth::re_export::VisualShaderNodeLinearSceneDepth;
+pub use visual_shader_node_linear_scene_depth::re_export::IVisualShaderNodeLinearSceneDepth;
+pub mod visual_shader_node_mix;
+pub use visual_shader_node_mix::re_export::VisualShaderNodeMix;
+pub use visual_shader_node_mix::re_export::IVisualShaderNodeMix;
+pub mod visual_shader_node_multiply_add;
+pub use visual_shader_node_multiply_add::re_export::VisualShaderNodeMultiplyAdd;
+pub use visual_shader_node_multiply_add::re_export::IVisualShaderNodeMultiplyAdd;
+mod visual_shader_node_outer_product;
+pub use visual_shader_node_outer_product::re_export::VisualShaderNodeOuterProduct;
+pub use visual_shader_node_outer_product::re_export::IVisualShaderNodeOuterProduct;
+mod visual_shader_node_output;
+pub use visual_shader_node_output::re_export::VisualShaderNodeOutput;
+pub mod visual_shader_node_parameter;
+pub use visual_shader_node_parameter::re_export::VisualShaderNodeParameter;
+mod visual_shader_node_parameter_ref;
+pub use visual_shader_node_parameter_ref::re_export::VisualShaderNodeParameterRef;
+pub use visual_shader_node_parameter_ref::re_export::IVisualShaderNodeParameterRef;
+pub mod visual_shader_node_particle_accelerator;
+pub use visual_shader_node_particle_accelerator::re_export::VisualShaderNodeParticleAccelerator;
+pub use visual_shader_node_particle_accelerator::re_export::IVisualShaderNodeParticleAccelerator;
+mod visual_shader_node_particle_box_emitter;
+pub use visual_shader_node_particle_box_emitter::re_export::VisualShaderNodeParticleBoxEmitter;
+pub use visual_shader_node_particle_box_emitter::re_export::IVisualShaderNodeParticleBoxEmitter;
+mod visual_shader_node_particle_cone_velocity;
+pub use visual_shader_node_particle_cone_velocity::re_export::VisualShaderNodeParticleConeVelocity;
+pub use visual_shader_node_particle_cone_velocity::re_export::IVisualShaderNodeParticleConeVelocity;
+pub mod visual_shader_node_particle_emit;
+pub use visual_shader_node_particle_emit::re_export::VisualShaderNodeParticleEmit;
+pub use visual_shader_node_particle_emit::re_export::IVisualShaderNodeParticleEmit;
+mod visual_shader_node_particle_emitter;
+pub use visual_shader_node_particle_emitter::re_export::VisualShaderNodeParticleEmitter;
+mod visual_shader_node_particle_mesh_emitter;
+pub use visual_shader_node_particle_mesh_emitter::re_export::VisualShaderNodeParticleMeshEmitter;
+pub use visual_shader_node_particle_mesh_emitter::re_export::IVisualShaderNodeParticleMeshEmitter;
+mod visual_shader_node_particle_multiply_by_axis_angle;
+pub use visual_shader_node_particle_multiply_by_axis_angle::re_export::VisualShaderNodeParticleMultiplyByAxisAngle;
+pub use visual_shader_node_particle_multiply_by_axis_angle::re_export::IVisualShaderNodeParticleMultiplyByAxisAngle;
+mod visual_shader_node_particle_output;
+pub use visual_shader_node_particle_output::re_export::VisualShaderNodeParticleOutput;
+pub use visual_shader_node_particle_output::re_export::IVisualShaderNodeParticleOutput;
+pub mod visual_shader_node_particle_randomness;
+pub use visual_shader_node_particle_randomness::re_export::VisualShaderNodeParticleRandomness;
+pub use visual_shader_node_particle_randomness::re_export::IVisualShaderNodeParticleRandomness;
+mod visual_shader_node_particle_ring_emitter;
+pub use visual_shader_node_particle_ring_emitter::re_export::VisualShaderNodeParticleRingEmitter;
+pub use visual_shader_node_particle_ring_emitter::re_export::IVisualShaderNodeParticleRingEmitter;
+mod visual_shader_node_particle_sphere_emitter;
+pub use visual_shader_node_particle_sphere_emitter::re_export::VisualShaderNodeParticleSphereEmitter;
+pub use visual_shader_node_particle_sphere_emitter::re_export::IVisualShaderNodeParticleSphereEmitter;
+mod visual_shader_node_proximity_fade;
+pub use visual_shader_node_proximity_fade::re_export::VisualShaderNodeProximityFade;
+pub use visual_shader_node_proximity_fade::re_export::IVisualShaderNodeProximityFade;
+mod visual_shader_node_random_range;
+pub use visual_shader_node_random_range::re_export::VisualShaderNodeRandomRange;
+pub use visual_shader_node_random_range::re_export::IVisualShaderNodeRandomRange;
+pub mod visual_shader_node_remap;
+pub use visual_shader_node_remap::re_export::VisualShaderNodeRemap;
+pub use visual_shader_node_remap::re_export::IVisualShaderNodeRemap;
+mod visual_shader_node_reroute;
+pub use visual_shader_node_reroute::re_export::VisualShaderNodeReroute;
+pub use visual_shader_node_reroute::re_export::IVisualShaderNodeReroute;
+mod visual_shader_node_resizable_base;
+pub use visual_shader_node_resizable_base::re_export::VisualShaderNodeResizableBase;
+mod visual_shader_node_rotation_by_axis;
+pub use visual_shader_node_rotation_by_axis::re_export::VisualShaderNodeRotationByAxis;
+pub use visual_shader_node_rotation_by_axis::re_export::IVisualShaderNodeRotationByAxis;
+mod visual_shader_node_sdf_raymarch;
+pub use visual_shader_node_sdf_raymarch::re_export::VisualShaderNodeSdfRaymarch;
+pub use visual_shader_node_sdf_raymarch::re_export::IVisualShaderNodeSdfRaymarch;
+mod visual_shader_node_sdf_to_screen_uv;
+pub use visual_shader_node_sdf_to_screen_uv::re_export::VisualShaderNodeSdfToScreenUv;
+pub use visual_shader_node_sdf_to_screen_uv::re_export::IVisualShaderNodeSdfToScreenUv;
+pub mod visual_shader_node_sample_3d;
+pub use visual_shader_node_sample_3d::re_export::VisualShaderNodeSample3D;
+mod visual_shader_node_screen_normal_world_space;
+pub use visual_shader_node_screen_normal_world_space::re_export::VisualShaderNodeScreenNormalWorldSpace;
+pub use visual_shader_node_screen_normal_world_space::re_export::IVisualShaderNodeScreenNormalWorldSpace;
+mod visual_shader_node_screen_uv_to_sdf;
+pub use visual_shader_node_screen_uv_to_sdf::re_export::VisualShaderNodeScreenUvToSdf;
+pub use visual_shader_node_screen_uv_to_sdf::re_export::IVisualShaderNodeScreenUvToSdf;
+pub mod visual_shader_node_smooth_step;
+pub use visual_shader_node_smooth_step::re_export::VisualShaderNodeSmoothStep;
+pub use visual_shader_node_smooth_step::re_export::IVisualShaderNodeSmoothStep;
+pub mod visual_shader_node_step;
+pub use visual_shader_node_step::re_export::VisualShaderNodeStep;
+pub use visual_shader_node_step::re_export::IVisualShaderNodeStep;
+pub mod visual_shader_node_switch;
+pub use visual_shader_node_switch::re_export::VisualShaderNodeSwitch;
+pub use visual_shader_node_switch::re_export::IVisualShaderNodeSwitch;
+pub mod visual_shader_node_texture;
+pub use visual_shader_node_texture::re_export::VisualShaderNodeTexture;
+pub use visual_shader_node_texture::re_export::IVisualShaderNodeTexture;
+mod visual_shader_node_texture_2d_array;
+pub use visual_shader_node_texture_2d_array::re_export::VisualShaderNodeTexture2DArray;
+pub use visual_shader_node_texture_2d_array::re_export::IVisualShaderNodeTexture2DArray;
+mod visual_shader_node_texture_2d_array_parameter;
+pub use visual_shader_node_texture_2d_array_parameter::re_export::VisualShaderNodeTexture2DArrayParameter;
+pub use visual_shader_node_texture_2d_array_parameter::re_export::IVisualShaderNodeTexture2DArrayParameter;
+mod visual_shader_node_texture_2d_parameter;
+pub use visual_shader_node_texture_2d_parameter::re_export::VisualShaderNodeTexture2DParameter;
+pub use visual_shader_node_texture_2d_parameter::re_export::IVisualShaderNodeTexture2DParameter;
+mod visual_shader_node_texture_3d;
+pub use visual_shader_node_texture_3d::re_export::VisualShaderNodeTexture3D;
+pub use visual_shader_node_texture_3d::re_export::IVisualShaderNodeTexture3D;
+mod visual_shader_node_texture_3d_parameter;
+pub use visual_shader_node_texture_3d_parameter::re_export::VisualShaderNodeTexture3DParameter;
+pub use visual_shader_node_texture_3d_parameter::re_export::IVisualShaderNodeTexture3DParameter;
+pub mod visual_shader_node_texture_parameter;
+pub use visual_shader_node_texture_parameter::re_export::VisualShaderNodeTextureParameter;
+mod visual_shader_node_texture_parameter_triplanar;
+pub use visual_shader_node_texture_parameter_triplanar::re_export::VisualShaderNodeTextureParameterTriplanar;
+pub use visual_shader_node_texture_parameter_triplanar::re_export::IVisualShaderNodeTextureParameterTriplanar;
+mod visual_shader_node_texture_sdf;
+pub use visual_shader_node_texture_sdf::re_export::VisualShaderNodeTextureSdf;
+pub use visual_shader_node_texture_sdf::re_export::IVisualShaderNodeTextureSdf;
+mod visual_shader_node_texture_sdf_normal;
+pub use visual_shader_node_texture_sdf_normal::re_export::VisualShaderNodeTextureSdfNormal;
+pub use visual_shader_node_texture_sdf_normal::re_export::IVisualShaderNodeTextureSdfNormal;
+mod visual_shader_node_transform_compose;
+pub use visual_shader_node_transform_compose::re_export::VisualShaderNodeTransformCompose;
+pub use visual_shader_node_transform_compose::re_export::IVisualShaderNodeTransformCompose;
+mod visual_shader_node_transform_constant;
+pub use visual_shader_node_transform_constant::re_export::VisualShaderNodeTransformConstant;
+pub use visual_shader_node_transform_constant::re_export::IVisualShaderNodeTransformConstant;
+mod visual_shader_node_transform_decompose;
+pub use visual_shader_node_transform_decompose::re_export::VisualShaderNodeTransformDecompose;
+pub use visual_shader_node_transform_decompose::re_export::IVisualShaderNodeTransformDecompose;
+pub mod visual_shader_node_transform_func;
+pub use visual_shader_node_transform_func::re_export::VisualShaderNodeTransformFunc;
+pub use visual_shader_node_transform_func::re_export::IVisualShaderNodeTransformFunc;
+pub mod visual_shader_node_transform_op;
+pub use visual_shader_node_transform_op::re_export::VisualShaderNodeTransformOp;
+pub use visual_shader_node_transform_op::re_export::IVisualShaderNodeTransformOp;
+mod visual_shader_node_transform_parameter;
+pub use visual_shader_node_transform_parameter::re_export::VisualShaderNodeTransformParameter;
+pub use visual_shader_node_transform_parameter::re_export::IVisualShaderNodeTransformParameter;
+pub mod visual_shader_node_transform_vec_mult;
+pub use visual_shader_node_transform_vec_mult::re_export::VisualShaderNodeTransformVecMult;
+pub use visual_shader_node_transform_vec_mult::re_export::IVisualShaderNodeTransformVecMult;
+mod visual_shader_node_u_int_constant;
+pub use visual_shader_node_u_int_constant::re_export::VisualShaderNodeUIntConstant;
+pub use visual_shader_node_u_int_constant::re_export::IVisualShaderNodeUIntConstant;
+pub mod visual_shader_node_u_int_func;
+pub use visual_shader_node_u_int_func::re_export::VisualShaderNodeUIntFunc;
+pub use visual_shader_node_u_int_func::re_export::IVisualShaderNodeUIntFunc;
+pub mod visual_shader_node_u_int_op;
+pub use visual_shader_node_u_int_op::re_export::VisualShaderNodeUIntOp;
+pub use visual_shader_node_u_int_op::re_export::IVisualShaderNodeUIntOp;
+mod visual_shader_node_u_int_parameter;
+pub use visual_shader_node_u_int_parameter::re_export::VisualShaderNodeUIntParameter;
+pub use visual_shader_node_u_int_parameter::re_export::IVisualShaderNodeUIntParameter;
+pub mod visual_shader_node_uv_func;
+pub use visual_shader_node_uv_func::re_export::VisualShaderNodeUvFunc;
+pub use visual_shader_node_uv_func::re_export::IVisualShaderNodeUvFunc;
+mod visual_shader_node_uv_polar_coord;
+pub use visual_shader_node_uv_polar_coord::re_export::VisualShaderNodeUvPolarCoord;
+pub use visual_shader_node_uv_polar_coord::re_export::IVisualShaderNodeUvPolarCoord;
+mod visual_shader_node_varying;
+pub use visual_shader_node_varying::re_export::VisualShaderNodeVarying;
+mod visual_shader_node_varying_getter;
+pub use visual_shader_node_varying_getter::re_export::VisualShaderNodeVaryingGetter;
+pub use visual_shader_node_varying_getter::re_export::IVisualShaderNodeVaryingGetter;
+mod visual_shader_node_varying_setter;
+pub use visual_shader_node_varying_setter::re_export::VisualShaderNodeVaryingSetter;
+pub use visual_shader_node_varying_setter::re_export::IVisualShaderNodeVaryingSetter;
+mod visual_shader_node_vec2_constant;
+pub use visual_shader_node_vec2_constant::re_export::VisualShaderNodeVec2Constant;
+pub use visual_shader_node_vec2_constant::re_export::IVisualShaderNodeVec2Constant;
+mod visual_shader_node_vec2_parameter;
+pub use visual_shader_node_vec2_parameter::re_export::VisualShaderNodeVec2Parameter;
+pub use visual_shader_node_vec2_parameter::re_export::IVisualShaderNodeVec2Parameter;
+mod visual_shader_node_vec3_constant;
+pub use visual_shader_node_vec3_constant::re_export::VisualShaderNodeVec3Constant;
+pub use visual_shader_node_vec3_constant::re_export::IVisualShaderNodeVec3Constant;
+mod visual_shader_node_vec3_parameter;
+pub use visual_shader_node_vec3_parameter::re_export::VisualShaderNodeVec3Parameter;
+pub use visual_shader_node_vec3_parameter::re_export::IVisualShaderNodeVec3Parameter;
+mod visual_shader_node_vec4_constant;
+pub use visual_shader_node_vec4_constant::re_export::VisualShaderNodeVec4Constant;
+pub use visual_shader_node_vec4_constant::re_export::IVisualShaderNodeVec4Constant;
+mod visual_shader_node_vec4_parameter;
+pub use visual_shader_node_vec4_parameter::re_export::VisualShaderNodeVec4Parameter;
+pub use visual_shader_node_vec4_parameter::re_export::IVisualShaderNodeVec4Parameter;
+pub mod visual_shader_node_vector_base;
+pub use visual_shader_node_vector_base::re_export::VisualShaderNodeVectorBase;
+mod visual_shader_node_vector_compose;
+pub use visual_shader_node_vector_compose::re_export::VisualShaderNodeVectorCompose;
+pub use visual_shader_node_vector_compose::re_export::IVisualShaderNodeVectorCompose;
+mod visual_shader_node_vector_decompose;
+pub use visual_shader_node_vector_decompose::re_export::VisualShaderNodeVectorDecompose;
+pub use visual_shader_node_vector_decompose::re_export::IVisualShaderNodeVectorDecompose;
+mod visual_shader_node_vector_distance;
+pub use visual_shader_node_vector_distance::re_export::VisualShaderNodeVectorDistance;
+pub use visual_shader_node_vector_distance::re_export::IVisualShaderNodeVectorDistance;
+pub mod visual_shader_node_vector_func;
+pub use visual_shader_node_vector_func::re_export::VisualShaderNodeVectorFunc;
+pub use visual_shader_node_vector_func::re_export::IVisualShaderNodeVectorFunc;
+mod visual_shader_node_vector_len;
+pub use visual_shader_node_vector_len::re_export::VisualShaderNodeVectorLen;
+pub use visual_shader_node_vector_len::re_export::IVisualShaderNodeVectorLen;
+pub mod visual_shader_node_vector_op;
+pub use visual_shader_node_vector_op::re_export::VisualShaderNodeVectorOp;
+pub use visual_shader_node_vector_op::re_export::IVisualShaderNodeVectorOp;
+mod visual_shader_node_vector_refract;
+pub use visual_shader_node_vector_refract::re_export::VisualShaderNodeVectorRefract;
+pub use visual_shader_node_vector_refract::re_export::IVisualShaderNodeVectorRefract;
+mod visual_shader_node_world_position_from_depth;
+pub use visual_shader_node_world_position_from_depth::re_export::VisualShaderNodeWorldPositionFromDepth;
+pub use visual_shader_node_world_position_from_depth::re_export::IVisualShaderNodeWorldPositionFromDepth;
+pub mod voxel_gi;
+pub use voxel_gi::re_export::VoxelGi;
+pub use voxel_gi::re_export::IVoxelGi;
+mod voxel_gi_data;
+pub use voxel_gi_data::re_export::VoxelGiData;
+pub use voxel_gi_data::re_export::IVoxelGiData;
+mod weak_ref;
+pub use weak_ref::re_export::WeakRef;
+pub use weak_ref::re_export::IWeakRef;
+pub mod web_rtc_data_channel;
+pub use web_rtc_data_channel::re_export::WebRtcDataChannel;
+mod web_rtc_data_channel_extension;
+pub use web_rtc_data_channel_extension::re_export::WebRtcDataChannelExtension;
+pub use web_rtc_data_channel_extension::re_export::IWebRtcDataChannelExtension;
+pub mod web_rtc_multiplayer_peer;
+pub use web_rtc_multiplayer_peer::re_export::WebRtcMultiplayerPeer;
+pub use web_rtc_multiplayer_peer::re_export::IWebRtcMultiplayerPeer;
+pub mod web_rtc_peer_connection;
+pub use web_rtc_peer_connection::re_export::WebRtcPeerConnection;
+pub use web_rtc_peer_connection::re_export::IWebRtcPeerConnection;
+mod web_rtc_peer_connection_extension;
+pub use web_rtc_peer_connection_extension::re_export::WebRtcPeerConnectionExtension;
+pub use web_rtc_peer_connection_extension::re_export::IWebRtcPeerConnectionExtension;
+pub mod web_socket_multiplayer_peer;
+pub use web_socket_multiplayer_peer::re_export::WebSocketMultiplayerPeer;
+pub use web_socket_multiplayer_peer::re_export::IWebSocketMultiplayerPeer;
+pub mod web_socket_peer;
+pub use web_socket_peer::re_export::WebSocketPeer;
+pub use web_socket_peer::re_export::IWebSocketPeer;
+pub mod web_xr_interface;
+pub use web_xr_interface::re_export::WebXrInterface;
+pub mod window;
+pub use window::re_export::Window;
+pub use window::re_export::IWindow;
+pub mod worker_thread_pool;
+pub use worker_thread_pool::re_export::WorkerThreadPool;
+mod world_2d;
+pub use world_2d::re_export::World2D;
+pub use world_2d::re_export::IWorld2D;
+mod world_3d;
+pub use world_3d::re_export::World3D;
+pub use world_3d::re_export::IWorld3D;
+mod world_boundary_shape_2d;
+pub use world_boundary_shape_2d::re_export::WorldBoundaryShape2D;
+pub use world_boundary_shape_2d::re_export::IWorldBoundaryShape2D;
+mod world_boundary_shape_3d;
+pub use world_boundary_shape_3d::re_export::WorldBoundaryShape3D;
+pub use world_boundary_shape_3d::re_export::IWorldBoundaryShape3D;
+mod world_environment;
+pub use world_environment::re_export::WorldEnvironment;
+pub use world_environment::re_export::IWorldEnvironment;
+mod x509_certificate;
+pub use x509_certificate::re_export::X509Certificate;
+pub use x509_certificate::re_export::IX509Certificate;
+pub mod xml_parser;
+pub use xml_parser::re_export::XmlParser;
+pub use xml_parser::re_export::IXmlParser;
+mod xr_anchor_3d;
+pub use xr_anchor_3d::re_export::XrAnchor3D;
+pub use xr_anchor_3d::re_export::IXrAnchor3D;
+mod xr_camera_3d;
+pub use xr_camera_3d::re_export::XrCamera3D;
+pub use xr_camera_3d::re_export::IXrCamera3D;
+pub mod xr_controller_3d;
+pub use xr_controller_3d::re_export::XrController3D;
+pub use xr_controller_3d::re_export::IXrController3D;
+mod xr_controller_tracker;
+pub use xr_controller_tracker::re_export::XrControllerTracker;
+pub use xr_controller_tracker::re_export::IXrControllerTracker;
+pub mod xr_hand_modifier_3d;
+pub use xr_hand_modifier_3d::re_export::XrHandModifier3D;
+pub use xr_hand_modifier_3d::re_export::IXrHandModifier3D;
+pub mod xr_hand_tracker;
+pub use xr_hand_tracker::re_export::XrHandTracker;
+pub use xr_hand_tracker::re_export::IXrHandTracker;
+pub mod xr_interface;
+pub use xr_interface::re_export::XrInterface;
+mod xr_interface_extension;
+pub use xr_interface_extension::re_export::XrInterfaceExtension;
+pub use xr_interface_extension::re_export::IXrInterfaceExtension;
+pub mod xr_node_3d;
+pub use xr_node_3d::re_export::XrNode3D;
+pub use xr_node_3d::re_export::IXrNode3D;
+mod xr_origin_3d;
+pub use xr_origin_3d::re_export::XrOrigin3D;
+pub use xr_origin_3d::re_export::IXrOrigin3D;
+pub mod xr_pose;
+pub use xr_pose::re_export::XrPose;
+pub use xr_pose::re_export::IXrPose;
+pub mod xr_positional_tracker;
+pub use xr_positional_tracker::re_export::XrPositionalTracker;
+pub use xr_positional_tracker::re_export::IXrPositionalTracker;
+pub mod xr_server;
+pub use xr_server::re_export::XrServer;
+mod xr_tracker;
+pub use xr_tracker::re_export::XrTracker;
+mod xrvrs;
+pub use xrvrs::re_export::Xrvrs;
+pub use xrvrs::re_export::IXrvrs;
+pub mod zip_packer;
+pub use zip_packer::re_export::ZipPacker;
+pub use zip_packer::re_export::IZipPacker;
+pub mod zip_reader;
+pub use zip_reader::re_export::ZipReader;
+pub use zip_reader::re_export::IZipReader;
+#[doc = r" Notification enums for all classes."]
+pub mod notify {
+    pub use super::canvas_item::re_export::CanvasItemNotification;
+    pub use super::container::re_export::ContainerNotification;
+    pub use super::control::re_export::ControlNotification;
+    pub use super::editor_settings::re_export::EditorSettingsNotification;
+    pub use super::main_loop::re_export::MainLoopNotification;
+    pub use super::node::re_export::NodeNotification;
+    pub use super::node_3d::re_export::Node3DNotification;
+    pub use super::object::re_export::ObjectNotification;
+    pub use super::skeleton_3d::re_export::Skeleton3DNotification;
+    pub use super::window::re_export::WindowNotification;
+    
+}
+#[doc(hidden)]
+pub mod class_macros {
+    pub use crate::*;
+    pub use inherit_from_AesContext__ensure_class_exists;
+    pub use inherit_from_AStar2D__ensure_class_exists;
+    pub use inherit_from_AStar3D__ensure_class_exists;
+    pub use inherit_from_AStarGrid2D__ensure_class_exists;
+    pub use inherit_from_AcceptDialog__ensure_class_exists;
+    pub use inherit_from_AimModifier3D__ensure_class_exists;
+    pub use inherit_from_AnimatableBody2D__ensure_class_exists;
+    pub use inherit_from_AnimatableBody3D__ensure_class_exists;
+    pub use inherit_from_AnimatedSprite2D__ensure_class_exists;
+    pub use inherit_from_AnimatedSprite3D__ensure_class_exists;
+    pub use inherit_from_AnimatedTexture__ensure_class_exists;
+    pub use inherit_from_Animation__ensure_class_exists;
+    pub use inherit_from_AnimationLibrary__ensure_class_exists;
+    pub use inherit_from_AnimationNode__ensure_class_exists;
+    pub use inherit_from_AnimationNodeAdd2__ensure_class_exists;
+    pub use inherit_from_AnimationNodeAdd3__ensure_class_exists;
+    pub use inherit_from_AnimationNodeAnimation__ensure_class_exists;
+    pub use inherit_from_AnimationNodeBlend2__ensure_class_exists;
+    pub use inherit_from_AnimationNodeBlend3__ensure_class_exists;
+    pub use inherit_from_AnimationNodeBlendSpace1D__ensure_class_exists;
+    pub use inherit_from_AnimationNodeBlendSpace2D__ensure_class_exists;
+    pub use inherit_from_AnimationNodeBlendTree__ensure_class_exists;
+    pub use inherit_from_AnimationNodeExtension__ensure_class_exists;
+    pub use inherit_from_AnimationNodeOneShot__ensure_class_exists;
+    pub use inherit_from_AnimationNodeOutput__ensure_class_exists;
+    pub use inherit_from_AnimationNodeStateMachine__ensure_class_exists;
+    pub use inherit_from_AnimationNodeStateMachinePlayback__ensure_class_exists;
+    pub use inherit_from_AnimationNodeStateMachineTransition__ensure_class_exists;
+    pub use inherit_from_AnimationNodeSub2__ensure_class_exists;
+    pub use inherit_from_AnimationNodeSync__ensure_class_exists;
+    pub use inherit_from_AnimationNodeTimeScale__ensure_class_exists;
+    pub use inherit_from_AnimationNodeTimeSeek__ensure_class_exists;
+    pub use inherit_from_AnimationNodeTransition__ensure_class_exists;
+    pub use inherit_from_AnimationPlayer__ensure_class_exists;
+    pub use inherit_from_AnimationRootNode__ensure_class_exists;
+    pub use inherit_from_AnimationTree__ensure_class_exists;
+    pub use inherit_from_Area2D__ensure_class_exists;
+    pub use inherit_from_Area3D__ensure_class_exists;
+    pub use inherit_from_ArrayMesh__ensure_class_exists;
+    pub use inherit_from_ArrayOccluder3D__ensure_class_exists;
+    pub use inherit_from_AspectRatioContainer__ensure_class_exists;
+    pub use inherit_from_AtlasTexture__ensure_class_exists;
+    pub use inherit_from_AudioBusLayout__ensure_class_exists;
+    pub use inherit_from_AudioEffect__ensure_class_exists;
+    pub use inherit_from_AudioEffectAmplify__ensure_class_exists;
+    pub use inherit_from_AudioEffectBandLimitFilter__ensure_class_exists;
+    pub use inherit_from_AudioEffectBandPassFilter__ensure_class_exists;
+    pub use inherit_from_AudioEffectCapture__ensure_class_exists;
+    pub use inherit_from_AudioEffectChorus__ensure_class_exists;
+    pub use inherit_from_AudioEffectCompressor__ensure_class_exists;
+    pub use inherit_from_AudioEffectDelay__ensure_class_exists;
+    pub use inherit_from_AudioEffectDistortion__ensure_class_exists;
+    pub use inherit_from_AudioEffectEq__ensure_class_exists;
+    pub use inherit_from_AudioEffectEq10__ensure_class_exists;
+    pub use inherit_from_AudioEffectEq21__ensure_class_exists;
+    pub use inherit_from_AudioEffectEq6__ensure_class_exists;
+    pub use inherit_from_AudioEffectFilter__ensure_class_exists;
+    pub use inherit_from_AudioEffectHardLimiter__ensure_class_exists;
+    pub use inherit_from_AudioEffectHighPassFilter__ensure_class_exists;
+    pub use inherit_from_AudioEffectHighShelfFilter__ensure_class_exists;
+    pub use inherit_from_AudioEffectInstance__ensure_class_exists;
+    pub use inherit_from_AudioEffectLimiter__ensure_class_exists;
+    pub use inherit_from_AudioEffectLowPassFilter__ensure_class_exists;
+    pub use inherit_from_AudioEffectLowShelfFilter__ensure_class_exists;
+    pub use inherit_from_AudioEffectNotchFilter__ensure_class_exists;
+    pub use inherit_from_AudioEffectPanner__ensure_class_exists;
+    pub use inherit_from_AudioEffectPhaser__ensure_class_exists;
+    pub use inherit_from_AudioEffectPitchShift__ensure_class_exists;
+    pub use inherit_from_AudioEffectRecord__ensure_class_exists;
+    pub use inherit_from_AudioEffectReverb__ensure_class_exists;
+    pub use inherit_from_AudioEffectSpectrumAnalyzer__ensure_class_exists;
+    pub use inherit_from_AudioEffectStereoEnhance__ensure_class_exists;
+    pub use inherit_from_AudioListener2D__ensure_class_exists;
+    pub use inherit_from_AudioListener3D__ensure_class_exists;
+    pub use inherit_from_AudioStream__ensure_class_exists;
+    pub use inherit_from_AudioStreamGenerator__ensure_class_exists;
+    pub use inherit_from_AudioStreamInteractive__ensure_class_exists;
+    pub use inherit_from_AudioStreamMp3__ensure_class_exists;
+    pub use inherit_from_AudioStreamMicrophone__ensure_class_exists;
+    pub use inherit_from_AudioStreamOggVorbis__ensure_class_exists;
+    pub use inherit_from_AudioStreamPlayback__ensure_class_exists;
+    pub use inherit_from_AudioStreamPlaybackOggVorbis__ensure_class_exists;
+    pub use inherit_from_AudioStreamPlaybackResampled__ensure_class_exists;
+    pub use inherit_from_AudioStreamPlayer__ensure_class_exists;
+    pub use inherit_from_AudioStreamPlayer2D__ensure_class_exists;
+    pub use inherit_from_AudioStreamPlayer3D__ensure_class_exists;
+    pub use inherit_from_AudioStreamPlaylist__ensure_class_exists;
+    pub use inherit_from_AudioStreamPolyphonic__ensure_class_exists;
+    pub use inherit_from_AudioStreamRandomizer__ensure_class_exists;
+    pub use inherit_from_AudioStreamSynchronized__ensure_class_exists;
+    pub use inherit_from_AudioStreamWav__ensure_class_exists;
+    pub use inherit_from_BackBufferCopy__ensure_class_exists;
+    pub use inherit_from_BaseButton__ensure_class_exists;
+    pub use inherit_from_BitMap__ensure_class_exists;
+    pub use inherit_from_Bone2D__ensure_class_exists;
+    pub use inherit_from_BoneAttachment3D__ensure_class_exists;
+    pub use inherit_from_BoneConstraint3D__ensure_class_exists;
+    pub use inherit_from_BoneMap__ensure_class_exists;
+    pub use inherit_from_BoxContainer__ensure_class_exists;
+    pub use inherit_from_BoxMesh__ensure_class_exists;
+    pub use inherit_from_BoxOccluder3D__ensure_class_exists;
+    pub use inherit_from_BoxShape3D__ensure_class_exists;
+    pub use inherit_from_Button__ensure_class_exists;
+    pub use inherit_from_ButtonGroup__ensure_class_exists;
+    pub use inherit_from_CpuParticles2D__ensure_class_exists;
+    pub use inherit_from_CpuParticles3D__ensure_class_exists;
+    pub use inherit_from_CsgBox3D__ensure_class_exists;
+    pub use inherit_from_CsgCombiner3D__ensure_class_exists;
+    pub use inherit_from_CsgCylinder3D__ensure_class_exists;
+    pub use inherit_from_CsgMesh3D__ensure_class_exists;
+    pub use inherit_from_CsgPolygon3D__ensure_class_exists;
+    pub use inherit_from_CsgSphere3D__ensure_class_exists;
+    pub use inherit_from_CsgTorus3D__ensure_class_exists;
+    pub use inherit_from_CallbackTweener__ensure_class_exists;
+    pub use inherit_from_Camera2D__ensure_class_exists;
+    pub use inherit_from_Camera3D__ensure_class_exists;
+    pub use inherit_from_CameraAttributes__ensure_class_exists;
+    pub use inherit_from_CameraAttributesPhysical__ensure_class_exists;
+    pub use inherit_from_CameraAttributesPractical__ensure_class_exists;
+    pub use inherit_from_CameraFeed__ensure_class_exists;
+    pub use inherit_from_CameraTexture__ensure_class_exists;
+    pub use inherit_from_CanvasGroup__ensure_class_exists;
+    pub use inherit_from_CanvasItemMaterial__ensure_class_exists;
+    pub use inherit_from_CanvasLayer__ensure_class_exists;
+    pub use inherit_from_CanvasModulate__ensure_class_exists;
+    pub use inherit_from_CanvasTexture__ensure_class_exists;
+    pub use inherit_from_CapsuleMesh__ensure_class_exists;
+    pub use inherit_from_CapsuleShape2D__ensure_class_exists;
+    pub use inherit_from_CapsuleShape3D__ensure_class_exists;
+    pub use inherit_from_CenterContainer__ensure_class_exists;
+    pub use inherit_from_CharFxTransform__ensure_class_exists;
+    pub use inherit_from_CharacterBody2D__ensure_class_exists;
+    pub use inherit_from_CharacterBody3D__ensure_class_exists;
+    pub use inherit_from_CheckBox__ensure_class_exists;
+    pub use inherit_from_CheckButton__ensure_class_exists;
+    pub use inherit_from_CircleShape2D__ensure_class_exists;
+    pub use inherit_from_CodeEdit__ensure_class_exists;
+    pub use inherit_from_CodeHighlighter__ensure_class_exists;
+    pub use inherit_from_CollisionPolygon2D__ensure_class_exists;
+    pub use inherit_from_CollisionPolygon3D__ensure_class_exists;
+    pub use inherit_from_CollisionShape2D__ensure_class_exists;
+    pub use inherit_from_CollisionShape3D__ensure_class_exists;
+    pub use inherit_from_ColorPalette__ensure_class_exists;
+    pub use inherit_from_ColorPicker__ensure_class_exists;
+    pub use inherit_from_ColorPickerButton__ensure_class_exists;
+    pub use inherit_from_ColorRect__ensure_class_exists;
+    pub use inherit_from_CompressedCubemap__ensure_class_exists;
+    pub use inherit_from_CompressedCubemapArray__ensure_class_exists;
+    pub use inherit_from_CompressedTexture2D__ensure_class_exists;
+    pub use inherit_from_CompressedTexture2DArray__ensure_class_exists;
+    pub use inherit_from_CompressedTexture3D__ensure_class_exists;
+    pub use inherit_from_ConcavePolygonShape2D__ensure_class_exists;
+    pub use inherit_from_ConcavePolygonShape3D__ensure_class_exists;
+    pub use inherit_from_ConeTwistJoint3D__ensure_class_exists;
+    pub use inherit_from_ConfigFile__ensure_class_exists;
+    pub use inherit_from_ConfirmationDialog__ensure_class_exists;
+    pub use inherit_from_Container__ensure_class_exists;
+    pub use inherit_from_Control__ensure_class_exists;
+    pub use inherit_from_ConvertTransformModifier3D__ensure_class_exists;
+    pub use inherit_from_ConvexPolygonShape2D__ensure_class_exists;
+    pub use inherit_from_ConvexPolygonShape3D__ensure_class_exists;
+    pub use inherit_from_CopyTransformModifier3D__ensure_class_exists;
+    pub use inherit_from_Crypto__ensure_class_exists;
+    pub use inherit_from_CryptoKey__ensure_class_exists;
+    pub use inherit_from_Cubemap__ensure_class_exists;
+    pub use inherit_from_CubemapArray__ensure_class_exists;
+    pub use inherit_from_Curve__ensure_class_exists;
+    pub use inherit_from_Curve2D__ensure_class_exists;
+    pub use inherit_from_Curve3D__ensure_class_exists;
+    pub use inherit_from_CurveTexture__ensure_class_exists;
+    pub use inherit_from_CurveXyzTexture__ensure_class_exists;
+    pub use inherit_from_CylinderMesh__ensure_class_exists;
+    pub use inherit_from_CylinderShape3D__ensure_class_exists;
+    pub use inherit_from_DpiTexture__ensure_class_exists;
+    pub use inherit_from_DtlsServer__ensure_class_exists;
+    pub use inherit_from_DampedSpringJoint2D__ensure_class_exists;
+    pub use inherit_from_Decal__ensure_class_exists;
+    pub use inherit_from_DirectionalLight2D__ensure_class_exists;
+    pub use inherit_from_DirectionalLight3D__ensure_class_exists;
+    pub use inherit_from_ENetConnection__ensure_class_exists;
+    pub use inherit_from_ENetMultiplayerPeer__ensure_class_exists;
+    pub use inherit_from_EditorCommandPalette__ensure_class_exists;
+    pub use inherit_from_EditorContextMenuPlugin__ensure_class_exists;
+    pub use inherit_from_EditorDebuggerPlugin__ensure_class_exists;
+    pub use inherit_from_EditorExportPlatformAndroid__ensure_class_exists;
+    pub use inherit_from_EditorExportPlatformExtension__ensure_class_exists;
+    pub use inherit_from_EditorExportPlatformIos__ensure_class_exists;
+    pub use inherit_from_EditorExportPlatformLinuxBsd__ensure_class_exists;
+    pub use inherit_from_EditorExportPlatformMacOs__ensure_class_exists;
+    pub use inherit_from_EditorExportPlatformVisionOs__ensure_class_exists;
+    pub use inherit_from_EditorExportPlatformWeb__ensure_class_exists;
+    pub use inherit_from_EditorExportPlatformWindows__ensure_class_exists;
+    pub use inherit_from_EditorExportPlugin__ensure_class_exists;
+    pub use inherit_from_EditorFeatureProfile__ensure_class_exists;
+    pub use inherit_from_EditorFileDialog__ensure_class_exists;
+    pub use inherit_from_EditorFileSystemDirectory__ensure_class_exists;
+    pub use inherit_from_EditorFileSystemImportFormatSupportQuery__ensure_class_exists;
+    pub use inherit_from_EditorImportPlugin__ensure_class_exists;
+    pub use inherit_from_EditorInspector__ensure_class_exists;
+    pub use inherit_from_EditorInspectorPlugin__ensure_class_exists;
+    pub use inherit_from_EditorNode3DGizmo__ensure_class_exists;
+    pub use inherit_from_EditorNode3DGizmoPlugin__ensure_class_exists;
+    pub use inherit_from_EditorPaths__ensure_class_exists;
+    pub use inherit_from_EditorPlugin__ensure_class_exists;
+    pub use inherit_from_EditorProperty__ensure_class_exists;
+    pub use inherit_from_EditorResourceConversionPlugin__ensure_class_exists;
+    pub use inherit_from_EditorResourcePicker__ensure_class_exists;
+    pub use inherit_from_EditorResourcePreviewGenerator__ensure_class_exists;
+    pub use inherit_from_EditorResourceTooltipPlugin__ensure_class_exists;
+    pub use inherit_from_EditorSceneFormatImporter__ensure_class_exists;
+    pub use inherit_from_EditorSceneFormatImporterBlend__ensure_class_exists;
+    pub use inherit_from_EditorSceneFormatImporterFbx2gltf__ensure_class_exists;
+    pub use inherit_from_EditorSceneFormatImporterGltf__ensure_class_exists;
+    pub use inherit_from_EditorSceneFormatImporterUfbx__ensure_class_exists;
+    pub use inherit_from_EditorScenePostImport__ensure_class_exists;
+    pub use inherit_from_EditorScenePostImportPlugin__ensure_class_exists;
+    pub use inherit_from_EditorScript__ensure_class_exists;
+    pub use inherit_from_EditorScriptPicker__ensure_class_exists;
+    pub use inherit_from_EditorSelection__ensure_class_exists;
+    pub use inherit_from_EditorSettings__ensure_class_exists;
+    pub use inherit_from_EditorSpinSlider__ensure_class_exists;
+    pub use inherit_from_EditorSyntaxHighlighter__ensure_class_exists;
+    pub use inherit_from_EditorTranslationParserPlugin__ensure_class_exists;
+    pub use inherit_from_EditorVcsInterface__ensure_class_exists;
+    pub use inherit_from_EncodedObjectAsId__ensure_class_exists;
+    pub use inherit_from_EngineProfiler__ensure_class_exists;
+    pub use inherit_from_Environment__ensure_class_exists;
+    pub use inherit_from_Expression__ensure_class_exists;
+    pub use inherit_from_ExternalTexture__ensure_class_exists;
+    pub use inherit_from_FbxDocument__ensure_class_exists;
+    pub use inherit_from_FbxState__ensure_class_exists;
+    pub use inherit_from_FastNoiseLite__ensure_class_exists;
+    pub use inherit_from_FileDialog__ensure_class_exists;
+    pub use inherit_from_FlowContainer__ensure_class_exists;
+    pub use inherit_from_FogMaterial__ensure_class_exists;
+    pub use inherit_from_FogVolume__ensure_class_exists;
+    pub use inherit_from_FoldableContainer__ensure_class_exists;
+    pub use inherit_from_FoldableGroup__ensure_class_exists;
+    pub use inherit_from_FontFile__ensure_class_exists;
+    pub use inherit_from_FontVariation__ensure_class_exists;
+    pub use inherit_from_FramebufferCacheRd__ensure_class_exists;
+    pub use inherit_from_GDExtension__ensure_class_exists;
+    pub use inherit_from_GDScript__ensure_class_exists;
+    pub use inherit_from_GDScriptSyntaxHighlighter__ensure_class_exists;
+    pub use inherit_from_GltfAccessor__ensure_class_exists;
+    pub use inherit_from_GltfAnimation__ensure_class_exists;
+    pub use inherit_from_GltfBufferView__ensure_class_exists;
+    pub use inherit_from_GltfCamera__ensure_class_exists;
+    pub use inherit_from_GltfDocument__ensure_class_exists;
+    pub use inherit_from_GltfDocumentExtension__ensure_class_exists;
+    pub use inherit_from_GltfDocumentExtensionConvertImporterMesh__ensure_class_exists;
+    pub use inherit_from_GltfLight__ensure_class_exists;
+    pub use inherit_from_GltfMesh__ensure_class_exists;
+    pub use inherit_from_GltfNode__ensure_class_exists;
+    pub use inherit_from_GltfObjectModelProperty__ensure_class_exists;
+    pub use inherit_from_GltfPhysicsBody__ensure_class_exists;
+    pub use inherit_from_GltfPhysicsShape__ensure_class_exists;
+    pub use inherit_from_GltfSkeleton__ensure_class_exists;
+    pub use inherit_from_GltfSkin__ensure_class_exists;
+    pub use inherit_from_GltfSpecGloss__ensure_class_exists;
+    pub use inherit_from_GltfState__ensure_class_exists;
+    pub use inherit_from_GltfTexture__ensure_class_exists;
+    pub use inherit_from_GltfTextureSampler__ensure_class_exists;
+    pub use inherit_from_GpuParticles2D__ensure_class_exists;
+    pub use inherit_from_GpuParticles3D__ensure_class_exists;
+    pub use inherit_from_GpuParticlesAttractorBox3D__ensure_class_exists;
+    pub use inherit_from_GpuParticlesAttractorSphere3D__ensure_class_exists;
+    pub use inherit_from_GpuParticlesAttractorVectorField3D__ensure_class_exists;
+    pub use inherit_from_GpuParticlesCollisionBox3D__ensure_class_exists;
+    pub use inherit_from_GpuParticlesCollisionHeightField3D__ensure_class_exists;
+    pub use inherit_from_GpuParticlesCollisionSdf3d__ensure_class_exists;
+    pub use inherit_from_GpuParticlesCollisionSphere3D__ensure_class_exists;
+    pub use inherit_from_Generic6DofJoint3D__ensure_class_exists;
+    pub use inherit_from_GeometryInstance3D__ensure_class_exists;
+    pub use inherit_from_Gradient__ensure_class_exists;
+    pub use inherit_from_GradientTexture1D__ensure_class_exists;
+    pub use inherit_from_GradientTexture2D__ensure_class_exists;
+    pub use inherit_from_GridContainer__ensure_class_exists;
+    pub use inherit_from_GridMap__ensure_class_exists;
+    pub use inherit_from_GridMapEditorPlugin__ensure_class_exists;
+    pub use inherit_from_GrooveJoint2D__ensure_class_exists;
+    pub use inherit_from_HBoxContainer__ensure_class_exists;
+    pub use inherit_from_HFlowContainer__ensure_class_exists;
+    pub use inherit_from_HmacContext__ensure_class_exists;
+    pub use inherit_from_HScrollBar__ensure_class_exists;
+    pub use inherit_from_HSeparator__ensure_class_exists;
+    pub use inherit_from_HSlider__ensure_class_exists;
+    pub use inherit_from_HSplitContainer__ensure_class_exists;
+    pub use inherit_from_HttpClient__ensure_class_exists;
+    pub use inherit_from_HttpRequest__ensure_class_exists;
+    pub use inherit_from_HashingContext__ensure_class_exists;
+    pub use inherit_from_HeightMapShape3D__ensure_class_exists;
+    pub use inherit_from_HingeJoint3D__ensure_class_exists;
+    pub use inherit_from_Image__ensure_class_exists;
+    pub use inherit_from_ImageFormatLoaderExtension__ensure_class_exists;
+    pub use inherit_from_ImageTexture__ensure_class_exists;
+    pub use inherit_from_ImageTexture3D__ensure_class_exists;
+    pub use inherit_from_ImmediateMesh__ensure_class_exists;
+    pub use inherit_from_ImporterMesh__ensure_class_exists;
+    pub use inherit_from_ImporterMeshInstance3D__ensure_class_exists;
+    pub use inherit_from_InputEventAction__ensure_class_exists;
+    pub use inherit_from_InputEventJoypadButton__ensure_class_exists;
+    pub use inherit_from_InputEventJoypadMotion__ensure_class_exists;
+    pub use inherit_from_InputEventKey__ensure_class_exists;
+    pub use inherit_from_InputEventMidi__ensure_class_exists;
+    pub use inherit_from_InputEventMagnifyGesture__ensure_class_exists;
+    pub use inherit_from_InputEventMouseButton__ensure_class_exists;
+    pub use inherit_from_InputEventMouseMotion__ensure_class_exists;
+    pub use inherit_from_InputEventPanGesture__ensure_class_exists;
+    pub use inherit_from_InputEventScreenDrag__ensure_class_exists;
+    pub use inherit_from_InputEventScreenTouch__ensure_class_exists;
+    pub use inherit_from_InputEventShortcut__ensure_class_exists;
+    pub use inherit_from_IntervalTweener__ensure_class_exists;
+    pub use inherit_from_ItemList__ensure_class_exists;
+    pub use inherit_from_Json__ensure_class_exists;
+    pub use inherit_from_JsonRpc__ensure_class_exists;
+    pub use inherit_from_KinematicCollision2D__ensure_class_exists;
+    pub use inherit_from_KinematicCollision3D__ensure_class_exists;
+    pub use inherit_from_Label__ensure_class_exists;
+    pub use inherit_from_Label3D__ensure_class_exists;
+    pub use inherit_from_LabelSettings__ensure_class_exists;
+    pub use inherit_from_LightOccluder2D__ensure_class_exists;
+    pub use inherit_from_LightmapGi__ensure_class_exists;
+    pub use inherit_from_LightmapGiData__ensure_class_exists;
+    pub use inherit_from_LightmapProbe__ensure_class_exists;
+    pub use inherit_from_LightmapperRd__ensure_class_exists;
+    pub use inherit_from_Line2D__ensure_class_exists;
+    pub use inherit_from_LineEdit__ensure_class_exists;
+    pub use inherit_from_LinkButton__ensure_class_exists;
+    pub use inherit_from_Logger__ensure_class_exists;
+    pub use inherit_from_LookAtModifier3D__ensure_class_exists;
+    pub use inherit_from_MainLoop__ensure_class_exists;
+    pub use inherit_from_MarginContainer__ensure_class_exists;
+    pub use inherit_from_Marker2D__ensure_class_exists;
+    pub use inherit_from_Marker3D__ensure_class_exists;
+    pub use inherit_from_Material__ensure_class_exists;
+    pub use inherit_from_MenuBar__ensure_class_exists;
+    pub use inherit_from_MenuButton__ensure_class_exists;
+    pub use inherit_from_Mesh__ensure_class_exists;
+    pub use inherit_from_MeshConvexDecompositionSettings__ensure_class_exists;
+    pub use inherit_from_MeshDataTool__ensure_class_exists;
+    pub use inherit_from_MeshInstance2D__ensure_class_exists;
+    pub use inherit_from_MeshInstance3D__ensure_class_exists;
+    pub use inherit_from_MeshLibrary__ensure_class_exists;
+    pub use inherit_from_MeshTexture__ensure_class_exists;
+    pub use inherit_from_MethodTweener__ensure_class_exists;
+    pub use inherit_from_MissingNode__ensure_class_exists;
+    pub use inherit_from_MissingResource__ensure_class_exists;
+    pub use inherit_from_MobileVrInterface__ensure_class_exists;
+    pub use inherit_from_ModifierBoneTarget3D__ensure_class_exists;
+    pub use inherit_from_MovieWriter__ensure_class_exists;
+    pub use inherit_from_MultiMesh__ensure_class_exists;
+    pub use inherit_from_MultiMeshInstance2D__ensure_class_exists;
+    pub use inherit_from_MultiMeshInstance3D__ensure_class_exists;
+    pub use inherit_from_MultiplayerApiExtension__ensure_class_exists;
+    pub use inherit_from_MultiplayerPeerExtension__ensure_class_exists;
+    pub use inherit_from_MultiplayerSpawner__ensure_class_exists;
+    pub use inherit_from_MultiplayerSynchronizer__ensure_class_exists;
+    pub use inherit_from_NinePatchRect__ensure_class_exists;
+    pub use inherit_from_Node__ensure_class_exists;
+    pub use inherit_from_Node2D__ensure_class_exists;
+    pub use inherit_from_Node3D__ensure_class_exists;
+    pub use inherit_from_NoiseTexture2D__ensure_class_exists;
+    pub use inherit_from_NoiseTexture3D__ensure_class_exists;
+    pub use inherit_from_OrmMaterial3D__ensure_class_exists;
+    pub use inherit_from_Object__ensure_class_exists;
+    pub use inherit_from_OccluderInstance3D__ensure_class_exists;
+    pub use inherit_from_OccluderPolygon2D__ensure_class_exists;
+    pub use inherit_from_OfflineMultiplayerPeer__ensure_class_exists;
+    pub use inherit_from_OggPacketSequence__ensure_class_exists;
+    pub use inherit_from_OggPacketSequencePlayback__ensure_class_exists;
+    pub use inherit_from_OmniLight3D__ensure_class_exists;
+    pub use inherit_from_OpenXrApiExtension__ensure_class_exists;
+    pub use inherit_from_OpenXrAction__ensure_class_exists;
+    pub use inherit_from_OpenXrActionBindingModifier__ensure_class_exists;
+    pub use inherit_from_OpenXrActionMap__ensure_class_exists;
+    pub use inherit_from_OpenXrActionSet__ensure_class_exists;
+    pub use inherit_from_OpenXrAnalogThresholdModifier__ensure_class_exists;
+    pub use inherit_from_OpenXrBindingModifierEditor__ensure_class_exists;
+    pub use inherit_from_OpenXrCompositionLayerCylinder__ensure_class_exists;
+    pub use inherit_from_OpenXrCompositionLayerEquirect__ensure_class_exists;
+    pub use inherit_from_OpenXrCompositionLayerQuad__ensure_class_exists;
+    pub use inherit_from_OpenXrDpadBindingModifier__ensure_class_exists;
+    pub use inherit_from_OpenXrExtensionWrapper__ensure_class_exists;
+    pub use inherit_from_OpenXrExtensionWrapperExtension__ensure_class_exists;
+    pub use inherit_from_OpenXrFutureExtension__ensure_class_exists;
+    pub use inherit_from_OpenXrHand__ensure_class_exists;
+    pub use inherit_from_OpenXrHapticVibration__ensure_class_exists;
+    pub use inherit_from_OpenXrIpBinding__ensure_class_exists;
+    pub use inherit_from_OpenXripBindingModifier__ensure_class_exists;
+    pub use inherit_from_OpenXrInteractionProfile__ensure_class_exists;
+    pub use inherit_from_OpenXrInteractionProfileEditor__ensure_class_exists;
+    pub use inherit_from_OpenXrInteractionProfileMetadata__ensure_class_exists;
+    pub use inherit_from_OpenXrInterface__ensure_class_exists;
+    pub use inherit_from_OpenXrRenderModel__ensure_class_exists;
+    pub use inherit_from_OpenXrRenderModelExtension__ensure_class_exists;
+    pub use inherit_from_OpenXrRenderModelManager__ensure_class_exists;
+    pub use inherit_from_OpenXrVisibilityMask__ensure_class_exists;
+    pub use inherit_from_OptimizedTranslation__ensure_class_exists;
+    pub use inherit_from_OptionButton__ensure_class_exists;
+    pub use inherit_from_PckPacker__ensure_class_exists;
+    pub use inherit_from_PackedDataContainer__ensure_class_exists;
+    pub use inherit_from_PackedScene__ensure_class_exists;
+    pub use inherit_from_PacketPeerDtls__ensure_class_exists;
+    pub use inherit_from_PacketPeerExtension__ensure_class_exists;
+    pub use inherit_from_PacketPeerStream__ensure_class_exists;
+    pub use inherit_from_PacketPeerUdp__ensure_class_exists;
+    pub use inherit_from_Panel__ensure_class_exists;
+    pub use inherit_from_PanelContainer__ensure_class_exists;
+    pub use inherit_from_PanoramaSkyMaterial__ensure_class_exists;
+    pub use inherit_from_ParallaxBackground__ensure_class_exists;
+    pub use inherit_from_ParallaxLayer__ensure_class_exists;
+    pub use inherit_from_ParticleProcessMaterial__ensure_class_exists;
+    pub use inherit_from_Path2D__ensure_class_exists;
+    pub use inherit_from_Path3D__ensure_class_exists;
+    pub use inherit_from_PathFollow2D__ensure_class_exists;
+    pub use inherit_from_PathFollow3D__ensure_class_exists;
+    pub use inherit_from_PhysicalBone2D__ensure_class_exists;
+    pub use inherit_from_PhysicalBone3D__ensure_class_exists;
+    pub use inherit_from_PhysicalBoneSimulator3D__ensure_class_exists;
+    pub use inherit_from_PhysicalSkyMaterial__ensure_class_exists;
+    pub use inherit_from_PhysicsDirectBodyState2DExtension__ensure_class_exists;
+    pub use inherit_from_PhysicsDirectBodyState3DExtension__ensure_class_exists;
+    pub use inherit_from_PhysicsDirectSpaceState2DExtension__ensure_class_exists;
+    pub use inherit_from_PhysicsDirectSpaceState3DExtension__ensure_class_exists;
+    pub use inherit_from_PhysicsMaterial__ensure_class_exists;
+    pub use inherit_from_PhysicsPointQueryParameters2D__ensure_class_exists;
+    pub use inherit_from_PhysicsPointQueryParameters3D__ensure_class_exists;
+    pub use inherit_from_PhysicsRayQueryParameters2D__ensure_class_exists;
+    pub use inherit_from_PhysicsRayQueryParameters3D__ensure_class_exists;
+    pub use inherit_from_PhysicsServer2DExtension__ensure_class_exists;
+    pub use inherit_from_PhysicsServer3DExtension__ensure_class_exists;
+    pub use inherit_from_PhysicsServer3DRenderingServerHandler__ensure_class_exists;
+    pub use inherit_from_PhysicsShapeQueryParameters2D__ensure_class_exists;
+    pub use inherit_from_PhysicsShapeQueryParameters3D__ensure_class_exists;
+    pub use inherit_from_PhysicsTestMotionParameters2D__ensure_class_exists;
+    pub use inherit_from_PhysicsTestMotionParameters3D__ensure_class_exists;
+    pub use inherit_from_PhysicsTestMotionResult2D__ensure_class_exists;
+    pub use inherit_from_PhysicsTestMotionResult3D__ensure_class_exists;
+    pub use inherit_from_PinJoint2D__ensure_class_exists;
+    pub use inherit_from_PinJoint3D__ensure_class_exists;
+    pub use inherit_from_PlaceholderCubemap__ensure_class_exists;
+    pub use inherit_from_PlaceholderCubemapArray__ensure_class_exists;
+    pub use inherit_from_PlaceholderMaterial__ensure_class_exists;
+    pub use inherit_from_PlaceholderMesh__ensure_class_exists;
+    pub use inherit_from_PlaceholderTexture2D__ensure_class_exists;
+    pub use inherit_from_PlaceholderTexture2DArray__ensure_class_exists;
+    pub use inherit_from_PlaceholderTexture3D__ensure_class_exists;
+    pub use inherit_from_PlaneMesh__ensure_class_exists;
+    pub use inherit_from_PointLight2D__ensure_class_exists;
+    pub use inherit_from_PointMesh__ensure_class_exists;
+    pub use inherit_from_Polygon2D__ensure_class_exists;
+    pub use inherit_from_PolygonOccluder3D__ensure_class_exists;
+    pub use inherit_from_PolygonPathFinder__ensure_class_exists;
+    pub use inherit_from_Popup__ensure_class_exists;
+    pub use inherit_from_PopupMenu__ensure_class_exists;
+    pub use inherit_from_PopupPanel__ensure_class_exists;
+    pub use inherit_from_PortableCompressedTexture2D__ensure_class_exists;
+    pub use inherit_from_PrimitiveMesh__ensure_class_exists;
+    pub use inherit_from_PrismMesh__ensure_class_exists;
+    pub use inherit_from_ProceduralSkyMaterial__ensure_class_exists;
+    pub use inherit_from_ProgressBar__ensure_class_exists;
+    pub use inherit_from_PropertyTweener__ensure_class_exists;
+    pub use inherit_from_QuadMesh__ensure_class_exists;
+    pub use inherit_from_QuadOccluder3D__ensure_class_exists;
+    pub use inherit_from_RdAttachmentFormat__ensure_class_exists;
+    pub use inherit_from_RdFramebufferPass__ensure_class_exists;
+    pub use inherit_from_RdPipelineColorBlendState__ensure_class_exists;
+    pub use inherit_from_RdPipelineColorBlendStateAttachment__ensure_class_exists;
+    pub use inherit_from_RdPipelineDepthStencilState__ensure_class_exists;
+    pub use inherit_from_RdPipelineMultisampleState__ensure_class_exists;
+    pub use inherit_from_RdPipelineRasterizationState__ensure_class_exists;
+    pub use inherit_from_RdPipelineSpecializationConstant__ensure_class_exists;
+    pub use inherit_from_RdSamplerState__ensure_class_exists;
+    pub use inherit_from_RdShaderFile__ensure_class_exists;
+    pub use inherit_from_RdShaderSpirv__ensure_class_exists;
+    pub use inherit_from_RdShaderSource__ensure_class_exists;
+    pub use inherit_from_RdTextureFormat__ensure_class_exists;
+    pub use inherit_from_RdTextureView__ensure_class_exists;
+    pub use inherit_from_RdUniform__ensure_class_exists;
+    pub use inherit_from_RdVertexAttribute__ensure_class_exists;
+    pub use inherit_from_RandomNumberGenerator__ensure_class_exists;
+    pub use inherit_from_Range__ensure_class_exists;
+    pub use inherit_from_RayCast2D__ensure_class_exists;
+    pub use inherit_from_RayCast3D__ensure_class_exists;
+    pub use inherit_from_RectangleShape2D__ensure_class_exists;
+    pub use inherit_from_RefCounted__ensure_class_exists;
+    pub use inherit_from_ReferenceRect__ensure_class_exists;
+    pub use inherit_from_ReflectionProbe__ensure_class_exists;
+    pub use inherit_from_RegEx__ensure_class_exists;
+    pub use inherit_from_RegExMatch__ensure_class_exists;
+    pub use inherit_from_RemoteTransform2D__ensure_class_exists;
+    pub use inherit_from_RemoteTransform3D__ensure_class_exists;
+    pub use inherit_from_RenderDataExtension__ensure_class_exists;
+    pub use inherit_from_RenderDataRd__ensure_class_exists;
+    pub use inherit_from_RenderSceneBuffersConfiguration__ensure_class_exists;
+    pub use inherit_from_RenderSceneBuffersExtension__ensure_class_exists;
+    pub use inherit_from_RenderSceneBuffersRd__ensure_class_exists;
+    pub use inherit_from_RenderSceneDataExtension__ensure_class_exists;
+    pub use inherit_from_RenderSceneDataRd__ensure_class_exists;
+    pub use inherit_from_Resource__ensure_class_exists;
+    pub use inherit_from_ResourceFormatLoader__ensure_class_exists;
+    pub use inherit_from_ResourceFormatSaver__ensure_class_exists;
+    pub use inherit_from_ResourceImporterBmFont__ensure_class_exists;
+    pub use inherit_from_ResourceImporterBitMap__ensure_class_exists;
+    pub use inherit_from_ResourceImporterCsvTranslation__ensure_class_exists;
+    pub use inherit_from_ResourceImporterDynamicFont__ensure_class_exists;
+    pub use inherit_from_ResourceImporterImage__ensure_class_exists;
+    pub use inherit_from_ResourceImporterImageFont__ensure_class_exists;
+    pub use inherit_from_ResourceImporterLayeredTexture__ensure_class_exists;
+    pub use inherit_from_ResourceImporterMp3__ensure_class_exists;
+    pub use inherit_from_ResourceImporterObj__ensure_class_exists;
+    pub use inherit_from_ResourceImporterOggVorbis__ensure_class_exists;
+    pub use inherit_from_ResourceImporterSvg__ensure_class_exists;
+    pub use inherit_from_ResourceImporterScene__ensure_class_exists;
+    pub use inherit_from_ResourceImporterShaderFile__ensure_class_exists;
+    pub use inherit_from_ResourceImporterTexture__ensure_class_exists;
+    pub use inherit_from_ResourceImporterTextureAtlas__ensure_class_exists;
+    pub use inherit_from_ResourceImporterWav__ensure_class_exists;
+    pub use inherit_from_ResourcePreloader__ensure_class_exists;
+    pub use inherit_from_RetargetModifier3D__ensure_class_exists;
+    pub use inherit_from_RibbonTrailMesh__ensure_class_exists;
+    pub use inherit_from_RichTextEffect__ensure_class_exists;
+    pub use inherit_from_RichTextLabel__ensure_class_exists;
+    pub use inherit_from_RigidBody2D__ensure_class_exists;
+    pub use inherit_from_RigidBody3D__ensure_class_exists;
+    pub use inherit_from_RootMotionView__ensure_class_exists;
+    pub use inherit_from_SceneMultiplayer__ensure_class_exists;
+    pub use inherit_from_SceneReplicationConfig__ensure_class_exists;
+    pub use inherit_from_SceneTree__ensure_class_exists;
+    pub use inherit_from_ScriptBacktrace__ensure_class_exists;
+    pub use inherit_from_ScriptCreateDialog__ensure_class_exists;
+    pub use inherit_from_ScriptExtension__ensure_class_exists;
+    pub use inherit_from_ScriptLanguageExtension__ensure_class_exists;
+    pub use inherit_from_ScrollContainer__ensure_class_exists;
+    pub use inherit_from_SegmentShape2D__ensure_class_exists;
+    pub use inherit_from_SeparationRayShape2D__ensure_class_exists;
+    pub use inherit_from_SeparationRayShape3D__ensure_class_exists;
+    pub use inherit_from_ShaderGlobalsOverride__ensure_class_exists;
+    pub use inherit_from_ShaderInclude__ensure_class_exists;
+    pub use inherit_from_ShaderIncludeDb__ensure_class_exists;
+    pub use inherit_from_ShaderMaterial__ensure_class_exists;
+    pub use inherit_from_ShapeCast2D__ensure_class_exists;
+    pub use inherit_from_ShapeCast3D__ensure_class_exists;
+    pub use inherit_from_Shortcut__ensure_class_exists;
+    pub use inherit_from_Skeleton2D__ensure_class_exists;
+    pub use inherit_from_Skeleton3D__ensure_class_exists;
+    pub use inherit_from_SkeletonIk3d__ensure_class_exists;
+    pub use inherit_from_SkeletonModifier3D__ensure_class_exists;
+    pub use inherit_from_SkeletonProfile__ensure_class_exists;
+    pub use inherit_from_SkeletonProfileHumanoid__ensure_class_exists;
+    pub use inherit_from_Skin__ensure_class_exists;
+    pub use inherit_from_Sky__ensure_class_exists;
+    pub use inherit_from_SliderJoint3D__ensure_class_exists;
+    pub use inherit_from_SoftBody3D__ensure_class_exists;
+    pub use inherit_from_SphereMesh__ensure_class_exists;
+    pub use inherit_from_SphereOccluder3D__ensure_class_exists;
+    pub use inherit_from_SphereShape3D__ensure_class_exists;
+    pub use inherit_from_SpinBox__ensure_class_exists;
+    pub use inherit_from_SplitContainer__ensure_class_exists;
+    pub use inherit_from_SpotLight3D__ensure_class_exists;
+    pub use inherit_from_SpringArm3D__ensure_class_exists;
+    pub use inherit_from_SpringBoneCollision3D__ensure_class_exists;
+    pub use inherit_from_SpringBoneCollisionCapsule3D__ensure_class_exists;
+    pub use inherit_from_SpringBoneCollisionPlane3D__ensure_class_exists;
+    pub use inherit_from_SpringBoneCollisionSphere3D__ensure_class_exists;
+    pub use inherit_from_SpringBoneSimulator3D__ensure_class_exists;
+    pub use inherit_from_Sprite2D__ensure_class_exists;
+    pub use inherit_from_Sprite3D__ensure_class_exists;
+    pub use inherit_from_SpriteFrames__ensure_class_exists;
+    pub use inherit_from_StandardMaterial3D__ensure_class_exists;
+    pub use inherit_from_StaticBody2D__ensure_class_exists;
+    pub use inherit_from_StaticBody3D__ensure_class_exists;
+    pub use inherit_from_StatusIndicator__ensure_class_exists;
+    pub use inherit_from_StreamPeerBuffer__ensure_class_exists;
+    pub use inherit_from_StreamPeerExtension__ensure_class_exists;
+    pub use inherit_from_StreamPeerTcp__ensure_class_exists;
+    pub use inherit_from_StreamPeerTls__ensure_class_exists;
+    pub use inherit_from_StyleBox__ensure_class_exists;
+    pub use inherit_from_StyleBoxEmpty__ensure_class_exists;
+    pub use inherit_from_StyleBoxFlat__ensure_class_exists;
+    pub use inherit_from_StyleBoxLine__ensure_class_exists;
+    pub use inherit_from_StyleBoxTexture__ensure_class_exists;
+    pub use inherit_from_SubViewport__ensure_class_exists;
+    pub use inherit_from_SubViewportContainer__ensure_class_exists;
+    pub use inherit_from_SubtweenTweener__ensure_class_exists;
+    pub use inherit_from_SurfaceTool__ensure_class_exists;
+    pub use inherit_from_SyntaxHighlighter__ensure_class_exists;
+    pub use inherit_from_SystemFont__ensure_class_exists;
+    pub use inherit_from_TcpServer__ensure_class_exists;
+    pub use inherit_from_TabBar__ensure_class_exists;
+    pub use inherit_from_TabContainer__ensure_class_exists;
+    pub use inherit_from_TextEdit__ensure_class_exists;
+    pub use inherit_from_TextLine__ensure_class_exists;
+    pub use inherit_from_TextMesh__ensure_class_exists;
+    pub use inherit_from_TextParagraph__ensure_class_exists;
+    pub use inherit_from_TextServerAdvanced__ensure_class_exists;
+    pub use inherit_from_TextServerDummy__ensure_class_exists;
+    pub use inherit_from_TextServerExtension__ensure_class_exists;
+    pub use inherit_from_Texture__ensure_class_exists;
+    pub use inherit_from_Texture2D__ensure_class_exists;
+    pub use inherit_from_Texture2DArray__ensure_class_exists;
+    pub use inherit_from_Texture2DArrayRd__ensure_class_exists;
+    pub use inherit_from_Texture2Drd__ensure_class_exists;
+    pub use inherit_from_Texture3D__ensure_class_exists;
+    pub use inherit_from_Texture3Drd__ensure_class_exists;
+    pub use inherit_from_TextureButton__ensure_class_exists;
+    pub use inherit_from_TextureCubemapArrayRd__ensure_class_exists;
+    pub use inherit_from_TextureCubemapRd__ensure_class_exists;
+    pub use inherit_from_TextureLayered__ensure_class_exists;
+    pub use inherit_from_TextureProgressBar__ensure_class_exists;
+    pub use inherit_from_TextureRect__ensure_class_exists;
+    pub use inherit_from_Theme__ensure_class_exists;
+    pub use inherit_from_TileData__ensure_class_exists;
+    pub use inherit_from_TileMap__ensure_class_exists;
+    pub use inherit_from_TileMapLayer__ensure_class_exists;
+    pub use inherit_from_TileMapPattern__ensure_class_exists;
+    pub use inherit_from_TileSet__ensure_class_exists;
+    pub use inherit_from_TileSetAtlasSource__ensure_class_exists;
+    pub use inherit_from_TileSetScenesCollectionSource__ensure_class_exists;
+    pub use inherit_from_Timer__ensure_class_exists;
+    pub use inherit_from_TorusMesh__ensure_class_exists;
+    pub use inherit_from_TouchScreenButton__ensure_class_exists;
+    pub use inherit_from_Translation__ensure_class_exists;
+    pub use inherit_from_TranslationDomain__ensure_class_exists;
+    pub use inherit_from_Tree__ensure_class_exists;
+    pub use inherit_from_TriangleMesh__ensure_class_exists;
+    pub use inherit_from_TubeTrailMesh__ensure_class_exists;
+    pub use inherit_from_Tween__ensure_class_exists;
+    pub use inherit_from_UdpServer__ensure_class_exists;
+    pub use inherit_from_Upnp__ensure_class_exists;
+    pub use inherit_from_UpnpDevice__ensure_class_exists;
+    pub use inherit_from_UndoRedo__ensure_class_exists;
+    pub use inherit_from_UniformSetCacheRd__ensure_class_exists;
+    pub use inherit_from_VBoxContainer__ensure_class_exists;
+    pub use inherit_from_VFlowContainer__ensure_class_exists;
+    pub use inherit_from_VScrollBar__ensure_class_exists;
+    pub use inherit_from_VSeparator__ensure_class_exists;
+    pub use inherit_from_VSlider__ensure_class_exists;
+    pub use inherit_from_VSplitContainer__ensure_class_exists;
+    pub use inherit_from_VehicleBody3D__ensure_class_exists;
+    pub use inherit_from_VehicleWheel3D__ensure_class_exists;
+    pub use inherit_from_VideoStream__ensure_class_exists;
+    pub use inherit_from_VideoStreamPlayback__ensure_class_exists;
+    pub use inherit_from_VideoStreamPlayer__ensure_class_exists;
+    pub use inherit_from_VideoStreamTheora__ensure_class_exists;
+    pub use inherit_from_ViewportTexture__ensure_class_exists;
+    pub use inherit_from_VisibleOnScreenEnabler2D__ensure_class_exists;
+    pub use inherit_from_VisibleOnScreenEnabler3D__ensure_class_exists;
+    pub use inherit_from_VisibleOnScreenNotifier2D__ensure_class_exists;
+    pub use inherit_from_VisibleOnScreenNotifier3D__ensure_class_exists;
+    pub use inherit_from_VisualInstance3D__ensure_class_exists;
+    pub use inherit_from_VisualShader__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeBillboard__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeBooleanConstant__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeBooleanParameter__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeClamp__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeColorConstant__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeColorFunc__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeColorOp__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeColorParameter__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeComment__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeCompare__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeCubemap__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeCubemapParameter__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeCurveTexture__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeCurveXyzTexture__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeCustom__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeDerivativeFunc__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeDeterminant__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeDistanceFade__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeDotProduct__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeExpression__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeFaceForward__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeFloatConstant__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeFloatFunc__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeFloatOp__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeFloatParameter__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeFrame__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeFresnel__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeGlobalExpression__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeIf__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeInput__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeIntConstant__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeIntFunc__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeIntOp__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeIntParameter__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeIs__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeLinearSceneDepth__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeMix__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeMultiplyAdd__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeOuterProduct__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeParameterRef__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeParticleAccelerator__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeParticleBoxEmitter__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeParticleConeVelocity__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeParticleEmit__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeParticleMeshEmitter__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeParticleMultiplyByAxisAngle__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeParticleOutput__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeParticleRandomness__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeParticleRingEmitter__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeParticleSphereEmitter__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeProximityFade__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeRandomRange__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeRemap__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeReroute__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeRotationByAxis__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeSdfRaymarch__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeSdfToScreenUv__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeScreenNormalWorldSpace__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeScreenUvToSdf__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeSmoothStep__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeStep__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeSwitch__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeTexture__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeTexture2DArray__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeTexture2DArrayParameter__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeTexture2DParameter__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeTexture3D__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeTexture3DParameter__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeTextureParameterTriplanar__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeTextureSdf__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeTextureSdfNormal__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeTransformCompose__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeTransformConstant__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeTransformDecompose__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeTransformFunc__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeTransformOp__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeTransformParameter__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeTransformVecMult__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeUIntConstant__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeUIntFunc__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeUIntOp__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeUIntParameter__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeUvFunc__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeUvPolarCoord__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeVaryingGetter__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeVaryingSetter__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeVec2Constant__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeVec2Parameter__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeVec3Constant__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeVec3Parameter__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeVec4Constant__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeVec4Parameter__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeVectorCompose__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeVectorDecompose__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeVectorDistance__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeVectorFunc__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeVectorLen__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeVectorOp__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeVectorRefract__ensure_class_exists;
+    pub use inherit_from_VisualShaderNodeWorldPositionFromDepth__ensure_class_exists;
+    pub use inherit_from_VoxelGi__ensure_class_exists;
+    pub use inherit_from_VoxelGiData__ensure_class_exists;
+    pub use inherit_from_WeakRef__ensure_class_exists;
+    pub use inherit_from_WebRtcDataChannelExtension__ensure_class_exists;
+    pub use inherit_from_WebRtcMultiplayerPeer__ensure_class_exists;
+    pub use inherit_from_WebRtcPeerConnection__ensure_class_exists;
+    pub use inherit_from_WebRtcPeerConnectionExtension__ensure_class_exists;
+    pub use inherit_from_WebSocketMultiplayerPeer__ensure_class_exists;
+    pub use inherit_from_WebSocketPeer__ensure_class_exists;
+    pub use inherit_from_Window__ensure_class_exists;
+    pub use inherit_from_World2D__ensure_class_exists;
+    pub use inherit_from_World3D__ensure_class_exists;
+    pub use inherit_from_WorldBoundaryShape2D__ensure_class_exists;
+    pub use inherit_from_WorldBoundaryShape3D__ensure_class_exists;
+    pub use inherit_from_WorldEnvironment__ensure_class_exists;
+    pub use inherit_from_X509Certificate__ensure_class_exists;
+    pub use inherit_from_XmlParser__ensure_class_exists;
+    pub use inherit_from_XrAnchor3D__ensure_class_exists;
+    pub use inherit_from_XrCamera3D__ensure_class_exists;
+    pub use inherit_from_XrController3D__ensure_class_exists;
+    pub use inherit_from_XrControllerTracker__ensure_class_exists;
+    pub use inherit_from_XrHandModifier3D__ensure_class_exists;
+    pub use inherit_from_XrHandTracker__ensure_class_exists;
+    pub use inherit_from_XrInterfaceExtension__ensure_class_exists;
+    pub use inherit_from_XrNode3D__ensure_class_exists;
+    pub use inherit_from_XrOrigin3D__ensure_class_exists;
+    pub use inherit_from_XrPose__ensure_class_exists;
+    pub use inherit_from_XrPositionalTracker__ensure_class_exists;
+    pub use inherit_from_Xrvrs__ensure_class_exists;
+    pub use inherit_from_ZipPacker__ensure_class_exists;
+    pub use inherit_from_ZipReader__ensure_class_exists;
+    
+}
\ No newline at end of file
@@ -0,0 +1,450 @@
+#![doc = "Sidecar module for class [`OpenXrRenderModelManager`][crate::classes::OpenXrRenderModelManager].\n\nDefines related flag and enum types. In GDScript, those are nested under the class scope.\n\nSee also [Godot docs for `OpenXRRenderModelManager` enums](https://docs.godotengine.org/en/stable/classes/class_openxrrendermodelmanager.html#enumerations).\n\n"]
+use godot_ffi as sys;
+use crate::builtin::*;
+use crate::meta::{
+    AsArg, ClassId, CowArg, InParamTuple, OutParamTuple, ParamTuple, RefArg, Signature
+};
+use crate::classes::native::*;
+use crate::classes::Object;
+use crate::obj::Gd;
+use crate::sys::GodotFfi as _;
+use crate::classes::notify::*;
+use std::ffi::c_void;
+pub(super) mod re_export {
+    use super::*;
+    #[doc = "Godot class `OpenXRRenderModelManager.`\n\nInherits [`Node3D`][crate::classes::Node3D].\n\nRelated symbols:\n\n* [`open_xr_render_model_manager`][crate::classes::open_xr_render_model_manager]: sidecar module with related enum/flag types\n* [`IOpenXrRenderModelManager`][crate::classes::IOpenXrRenderModelManager]: virtual methods\n* [`SignalsOfOpenXrRenderModelManager`][crate::classes::open_xr_render_model_manager::SignalsOfOpenXrRenderModelManager]: signal collection\n\n\nSee also [Godot docs for `OpenXRRenderModelManager`](https://docs.godotengine.org/en/stable/classes/class_openxrrendermodelmanager.html).\n\n"]
+    #[doc = "# Construction\n\nThis class is manually managed. You can create a new instance using [`OpenXrRenderModelManager::new_alloc()`][crate::obj::NewAlloc::new_alloc].\n\nDo not forget to call [`free()`][crate::obj::Gd::free] or hand over ownership to Godot."]
+    #[derive(Debug)]
+    #[repr(C)]
+    pub struct OpenXrRenderModelManager {
+        object_ptr: sys::GDExtensionObjectPtr, rtti: Option < crate::private::ObjectRtti >,
+    }
+    #[doc = "# Interface trait for class [`OpenXrRenderModelManager`][crate::classes::OpenXrRenderModelManager].\n\nFunctions in this trait represent constructors (`init`) or virtual method callbacks invoked by the engine.\n\n\n\n# Related symbols\n\nBase interfaces: [`INode3D`][crate::classes::INode3D] > [`INode`][crate::classes::INode] > [`IObject`][crate::classes::IObject].\n\nSee also [Godot docs for `OpenXRRenderModelManager` methods](https://docs.godotengine.org/en/stable/classes/class_openxrrendermodelmanager.html#methods)."]
+    #[doc = ""]
+    #[allow(unused_variables)]
+    #[allow(clippy::unimplemented)]
+    pub trait IOpenXrRenderModelManager: crate::obj::GodotClass < Base = OpenXrRenderModelManager > + crate::private::You_forgot_the_attribute__godot_api {
+        #[doc(hidden)]
+        fn register_class(builder: &mut crate::builder::ClassBuilder < Self >) {
+            unimplemented !()
+        }
+        #[doc = r" Godot constructor, accepting an injected `base` object."]
+        #[doc = r""]
+        #[doc = r" `base` refers to the base instance of the class, which can either be stored in a `Base<T>` field or discarded."]
+        #[doc = r" This method returns a fully-constructed instance, which will then be moved into a [`Gd<T>`][crate::obj::Gd] pointer."]
+        #[doc = r""]
+        #[doc = r" If the class has a `#[class(init)]` attribute, this method will be auto-generated and must not be overridden."]
+        fn init(base: crate::obj::Base < Self::Base >) -> Self {
+            unimplemented !()
+        }
+        #[doc = r" String representation of the Godot instance."]
+        #[doc = r""]
+        #[doc = r" Override this method to define how the instance is represented as a string."]
+        #[doc = r" Used by `impl Display for Gd<T>`, as well as `str()` and `print()` in GDScript."]
+        fn to_string(&self) -> crate::builtin::GString {
+            unimplemented !()
+        }
+        #[doc = r" Called when the object receives a Godot notification."]
+        #[doc = r""]
+        #[doc = r" The type of notification can be identified through `what`. The enum is designed to hold all possible `NOTIFICATION_*`"]
+        #[doc = r" constants that the current class can handle. However, this is not validated in Godot, so an enum variant `Unknown` exists"]
+        #[doc = r" to represent integers out of known constants (mistakes or future additions)."]
+        #[doc = r""]
+        #[doc = r" This method is named `_notification` in Godot, but `on_notification` in Rust. To _send_ notifications, use the"]
+        #[doc = r" [`Object::notify`][crate::classes::Object::notify] method."]
+        #[doc = r""]
+        #[doc = r" See also in Godot docs:"]
+        #[doc = r" * [`Object::_notification`](https://docs.godotengine.org/en/stable/classes/class_object.html#class-object-method-notification)."]
+        #[doc = r" * [Notifications tutorial](https://docs.godotengine.org/en/stable/tutorials/best_practices/godot_notifications.html)."]
+        fn on_notification(&mut self, what: Node3DNotification) {
+            unimplemented !()
+        }
+        #[doc = r" Called whenever [`get()`](crate::classes::Object::get) is called or Godot gets the value of a property."]
+        #[doc = r""]
+        #[doc = r" Should return the given `property`'s value as `Some(value)`, or `None` if the property should be handled normally."]
+        #[doc = r""]
+        #[doc = r" See also in Godot docs:"]
+        #[doc = r" * [`Object::_get`](https://docs.godotengine.org/en/stable/classes/class_object.html#class-object-private-method-get)."]
+        fn get_property(&self, property: StringName) -> Option < Variant > {
+            unimplemented !()
+        }
+        #[doc = r" Called whenever Godot [`set()`](crate::classes::Object::set) is called or Godot sets the value of a property."]
+        #[doc = r""]
+        #[doc = r" Should set `property` to the given `value` and return `true`, or return `false` to indicate the `property`"]
+        #[doc = r" should be handled normally."]
+        #[doc = r""]
+        #[doc = r" See also in Godot docs:"]
+        #[doc = r" * [`Object::_set`](https://docs.godotengine.org/en/stable/classes/class_object.html#class-object-private-method-set)."]
+        fn set_property(&mut self, property: StringName, value: Variant) -> bool {
+            unimplemented !()
+        }
+        #[doc = r" Called whenever Godot [`get_property_list()`](crate::classes::Object::get_property_list) is called, the returned vector here is"]
+        #[doc = r" appended to the existing list of properties."]
+        #[doc = r""]
+        #[doc = r" This should mainly be used for advanced purposes, such as dynamically updating the property list in the editor."]
+        #[doc = r""]
+        #[doc = r" See also in Godot docs:"]
+        #[doc = r" * [`Object::_get_property_list`](https://docs.godotengine.org/en/latest/classes/class_object.html#class-object-private-method-get-property-list)"]
+        #[cfg(since_api = "4.3")]
+        #[cfg_attr(published_docs, doc(cfg(since_api = "4.3")))]
+        fn get_property_list(&mut self) -> Vec < crate::meta::PropertyInfo > {
+            unimplemented !()
+        }
+        #[doc = r" Called whenever Godot retrieves value of property. Allows to customize existing properties."]
+        #[doc = r" Every property info goes through this method, except properties **added** with `get_property_list()`."]
+        #[doc = r""]
+        #[doc = r" Exposed `property` here is a shared mutable reference obtained (and returned to) from Godot."]
+        #[doc = r""]
+        #[doc = r" See also in the Godot docs:"]
+        #[doc = r" * [`Object::_validate_property`](https://docs.godotengine.org/en/stable/classes/class_object.html#class-object-private-method-validate-property)"]
+        fn validate_property(&self, property: &mut crate::meta::PropertyInfo) {
+            unimplemented !()
+        }
+        #[doc = r" Called by Godot to tell if a property has a custom revert or not."]
+        #[doc = r""]
+        #[doc = r" Return `None` for no custom revert, and return `Some(value)` to specify the custom revert."]
+        #[doc = r""]
+        #[doc = r" This is a combination of Godot's [`Object::_property_get_revert`] and [`Object::_property_can_revert`]. This means that this"]
+        #[doc = r" function will usually be called twice by Godot to find the revert."]
+        #[doc = r""]
+        #[doc = r" Note that this should be a _pure_ function. That is, it should always return the same value for a property as long as `self`"]
+        #[doc = r" remains unchanged. Otherwise, this may lead to unexpected (safe) behavior."]
+        #[doc = r""]
+        #[doc = r" [`Object::_property_get_revert`]: https://docs.godotengine.org/en/latest/classes/class_object.html#class-object-private-method-property-get-revert"]
+        #[doc = r" [`Object::_property_can_revert`]: https://docs.godotengine.org/en/latest/classes/class_object.html#class-object-private-method-property-can-revert"]
+        #[doc(alias = "property_can_revert")]
+        fn property_get_revert(&self, property: StringName) -> Option < Variant > {
+            unimplemented !()
+        }
+        fn process(&mut self, delta: f64,) {
+            unimplemented !()
+        }
+        fn physics_process(&mut self, delta: f64,) {
+            unimplemented !()
+        }
+        fn enter_tree(&mut self,) {
+            unimplemented !()
+        }
+        fn exit_tree(&mut self,) {
+            unimplemented !()
+        }
+        fn ready(&mut self,) {
+            unimplemented !()
+        }
+        fn get_configuration_warnings(&self,) -> PackedStringArray {
+            unimplemented !()
+        }
+        fn get_accessibility_configuration_warnings(&self,) -> PackedStringArray {
+            unimplemented !()
+        }
+        fn input(&mut self, event: Gd < crate::classes::InputEvent >,) {
+            unimplemented !()
+        }
+        fn shortcut_input(&mut self, event: Gd < crate::classes::InputEvent >,) {
+            unimplemented !()
+        }
+        fn unhandled_input(&mut self, event: Gd < crate::classes::InputEvent >,) {
+            unimplemented !()
+        }
+        fn unhandled_key_input(&mut self, event: Gd < crate::classes::InputEvent >,) {
+            unimplemented !()
+        }
+        fn get_focused_accessibility_element(&self,) -> Rid {
+            unimplemented !()
+        }
+    }
+    impl OpenXrRenderModelManager {
+        pub fn get_tracker(&self,) -> crate::classes::open_xr_render_model_manager::RenderModelTracker {
+            type CallRet = crate::classes::open_xr_render_model_manager::RenderModelTracker;
+            type CallParams = ();
+            let args = ();
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(6158usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "OpenXrRenderModelManager", "get_tracker", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn set_tracker(&mut self, tracker: crate::classes::open_xr_render_model_manager::RenderModelTracker,) {
+            type CallRet = ();
+            type CallParams = (crate::classes::open_xr_render_model_manager::RenderModelTracker,);
+            let args = (tracker,);
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(6159usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "OpenXrRenderModelManager", "set_tracker", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn get_make_local_to_pose(&self,) -> GString {
+            type CallRet = GString;
+            type CallParams = ();
+            let args = ();
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(6160usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "OpenXrRenderModelManager", "get_make_local_to_pose", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn set_make_local_to_pose(&mut self, make_local_to_pose: impl AsArg < GString >,) {
+            type CallRet = ();
+            type CallParams < 'a0, > = (CowArg < 'a0, GString >,);
+            let args = (make_local_to_pose.into_arg(),);
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(6161usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "OpenXrRenderModelManager", "set_make_local_to_pose", Some(self.__validated_obj()), args,)
+            }
+        }
+        #[doc = r" Creates a validated object for FFI boundary crossing."]
+        #[doc = r""]
+        #[doc = r" Low-level internal method. Validation (liveness/type checks) depend on safeguard level."]
+        fn __validated_obj(&self) -> crate::obj::ValidatedObject {
+            let raw_gd = unsafe {
+                std::mem::transmute::< &Self, &crate::obj::RawGd < Self >> (self)
+            };
+            raw_gd.validated_object()
+        }
+        #[doc(hidden)]
+        pub fn __object_ptr(&self) -> sys::GDExtensionObjectPtr {
+            self.object_ptr
+        }
+    }
+    impl crate::obj::GodotClass for OpenXrRenderModelManager {
+        type Base = crate::classes::Node3D;
+        fn class_id() -> ClassId {
+            static CLASS_ID: std::sync::OnceLock < ClassId > = std::sync::OnceLock::new();
+            let name: &'static ClassId = CLASS_ID.get_or_init(|| ClassId::__alloc_next_unicode("OpenXRRenderModelManager"));
+            * name
+        }
+        const INIT_LEVEL: crate::init::InitLevel = crate::init::InitLevel::Scene;
+        
+    }
+    unsafe impl crate::obj::Bounds for OpenXrRenderModelManager {
+        type Memory = crate::obj::bounds::MemManual;
+        type DynMemory = crate::obj::bounds::MemManual;
+        type Declarer = crate::obj::bounds::DeclEngine;
+        type Exportable = crate::obj::bounds::Yes;
+        
+    }
+    unsafe impl crate::obj::Inherits < crate::classes::Node3D > for OpenXrRenderModelManager {
+        
+    }
+    unsafe impl crate::obj::Inherits < crate::classes::Node > for OpenXrRenderModelManager {
+        
+    }
+    unsafe impl crate::obj::Inherits < crate::classes::Object > for OpenXrRenderModelManager {
+        
+    }
+    impl crate::obj::cap::GodotDefault for OpenXrRenderModelManager {
+        fn __godot_default() -> crate::obj::Gd < Self > {
+            crate::classes::construct_engine_object::< Self > ()
+        }
+    }
+    impl std::ops::Deref for OpenXrRenderModelManager {
+        type Target = crate::classes::Node3D;
+        fn deref(&self) -> &Self::Target {
+            unsafe {
+                std::mem::transmute::< &Self, &Self::Target > (self)
+            }
+        }
+    }
+    impl std::ops::DerefMut for OpenXrRenderModelManager {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            unsafe {
+                std::mem::transmute::< &mut Self, &mut Self::Target > (self)
+            }
+        }
+    }
+    #[doc = r" # Safety"]
+    #[doc = r""]
+    #[doc = "The provided class must be a subclass of all the superclasses of [`OpenXrRenderModelManager`]"]
+    #[macro_export]
+    #[allow(non_snake_case)]
+    macro_rules !inherit_from_OpenXrRenderModelManager__ensure_class_exists {
+        ($Class: ident) => {
+            unsafe impl::godot::obj::Inherits < ::godot::classes::OpenXrRenderModelManager > for $Class {
+                
+            }
+            unsafe impl::godot::obj::Inherits < ::godot::classes::Node3D > for $Class {
+                
+            }
+            unsafe impl::godot::obj::Inherits < ::godot::classes::Node > for $Class {
+                
+            }
+            unsafe impl::godot::obj::Inherits < ::godot::classes::Object > for $Class {
+                
+            }
+        }
+    }
+}
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+pub struct RenderModelTracker {
+    ord: i32
+}
+impl RenderModelTracker {
+    #[doc(alias = "RENDER_MODEL_TRACKER_ANY")]
+    #[doc = "Godot enumerator name: `RENDER_MODEL_TRACKER_ANY`"]
+    pub const ANY: RenderModelTracker = RenderModelTracker {
+        ord: 0i32
+    };
+    #[doc(alias = "RENDER_MODEL_TRACKER_NONE_SET")]
+    #[doc = "Godot enumerator name: `RENDER_MODEL_TRACKER_NONE_SET`"]
+    pub const NONE_SET: RenderModelTracker = RenderModelTracker {
+        ord: 1i32
+    };
+    #[doc(alias = "RENDER_MODEL_TRACKER_LEFT_HAND")]
+    #[doc = "Godot enumerator name: `RENDER_MODEL_TRACKER_LEFT_HAND`"]
+    pub const LEFT_HAND: RenderModelTracker = RenderModelTracker {
+        ord: 2i32
+    };
+    #[doc(alias = "RENDER_MODEL_TRACKER_RIGHT_HAND")]
+    #[doc = "Godot enumerator name: `RENDER_MODEL_TRACKER_RIGHT_HAND`"]
+    pub const RIGHT_HAND: RenderModelTracker = RenderModelTracker {
+        ord: 3i32
+    };
+    
+}
+impl std::fmt::Debug for RenderModelTracker {
+    fn fmt(&self, f: &mut std::fmt::Formatter < '_ >) -> std::fmt::Result {
+        use crate::obj::EngineEnum;
+        let enumerator = self.as_str();
+        if enumerator.is_empty() {
+            f.debug_struct("RenderModelTracker") . field("ord", &self.ord) . finish() ?;
+            return Ok(());
+            
+        }
+        f.write_str(enumerator)
+    }
+}
+impl crate::obj::EngineEnum for RenderModelTracker {
+    fn try_from_ord(ord: i32) -> Option < Self > {
+        match ord {
+            ord @ 0i32 | ord @ 1i32 | ord @ 2i32 | ord @ 3i32 => Some(Self {
+                ord
+            }), _ => None,
+        }
+    }
+    fn ord(self) -> i32 {
+        self.ord
+    }
+    #[inline]
+    fn as_str(&self) -> &'static str {
+        #[allow(unreachable_patterns)]
+        match * self {
+            Self::ANY => "ANY", Self::NONE_SET => "NONE_SET", Self::LEFT_HAND => "LEFT_HAND", Self::RIGHT_HAND => "RIGHT_HAND", _ => "",
+        }
+    }
+    fn values() -> &'static[Self] {
+        &[RenderModelTracker::ANY, RenderModelTracker::NONE_SET, RenderModelTracker::LEFT_HAND, RenderModelTracker::RIGHT_HAND]
+    }
+    fn all_constants() -> &'static[crate::meta::inspect::EnumConstant < RenderModelTracker >] {
+        const {
+            &[crate::meta::inspect::EnumConstant::new("ANY", "RENDER_MODEL_TRACKER_ANY", RenderModelTracker::ANY), crate::meta::inspect::EnumConstant::new("NONE_SET", "RENDER_MODEL_TRACKER_NONE_SET", RenderModelTracker::NONE_SET), crate::meta::inspect::EnumConstant::new("LEFT_HAND", "RENDER_MODEL_TRACKER_LEFT_HAND", RenderModelTracker::LEFT_HAND), crate::meta::inspect::EnumConstant::new("RIGHT_HAND", "RENDER_MODEL_TRACKER_RIGHT_HAND", RenderModelTracker::RIGHT_HAND)]
+        }
+    }
+}
+impl crate::meta::GodotConvert for RenderModelTracker {
+    type Via = i32;
+    
+}
+impl crate::meta::ToGodot for RenderModelTracker {
+    type Pass = crate::meta::ByValue;
+    fn to_godot(&self) -> Self::Via {
+        < Self as crate::obj::EngineEnum > ::ord(* self)
+    }
+}
+impl crate::meta::FromGodot for RenderModelTracker {
+    fn try_from_godot(via: Self::Via) -> std::result::Result < Self, crate::meta::error::ConvertError > {
+        < Self as crate::obj::EngineEnum > ::try_from_ord(via) . ok_or_else(|| crate::meta::error::FromGodotError::InvalidEnum.into_error(via))
+    }
+}
+pub use signals::*;
+mod signals {
+    use crate::obj::{
+        Gd, GodotClass
+    };
+    use super::re_export::OpenXrRenderModelManager;
+    use crate::registry::signal::TypedSignal;
+    use super::*;
+    #[doc = "A collection of signals for the [`OpenXrRenderModelManager`][crate::classes::OpenXrRenderModelManager] class."]
+    pub struct SignalsOfOpenXrRenderModelManager < 'c, C: WithSignals > {
+        #[doc(hidden)]
+        pub(crate) __internal_obj: Option < C::__SignalObj < 'c >>,
+    }
+    impl < 'c, C: WithSignals > SignalsOfOpenXrRenderModelManager < 'c, C > {
+        #[doc = "Signature: `(render_model: Gd<OpenXrRenderModel>)`"]
+        pub fn render_model_added(&mut self) -> SigRenderModelAdded < 'c, C > {
+            SigRenderModelAdded {
+                typed: TypedSignal::extract(&mut self.__internal_obj, "render_model_added")
+            }
+        }
+        #[doc = "Signature: `(render_model: Gd<OpenXrRenderModel>)`"]
+        pub fn render_model_removed(&mut self) -> SigRenderModelRemoved < 'c, C > {
+            SigRenderModelRemoved {
+                typed: TypedSignal::extract(&mut self.__internal_obj, "render_model_removed")
+            }
+        }
+    }
+    type TypedSigRenderModelAdded < 'c, C > = TypedSignal < 'c, C, (Gd < crate::classes::OpenXrRenderModel >,) >;
+    pub struct SigRenderModelAdded < 'c, C: WithSignals > {
+        typed: TypedSigRenderModelAdded < 'c, C >,
+    }
+    impl < 'c, C: WithSignals > SigRenderModelAdded < 'c, C > {
+        pub fn emit(&mut self, render_model: Gd < crate::classes::OpenXrRenderModel >,) {
+            self.typed.emit_tuple((render_model,));
+            
+        }
+    }
+    impl < 'c, C: WithSignals > std::ops::Deref for SigRenderModelAdded < 'c, C > {
+        type Target = TypedSigRenderModelAdded < 'c, C >;
+        fn deref(&self) -> &Self::Target {
+            &self.typed
+        }
+    }
+    impl < C: WithSignals > std::ops::DerefMut for SigRenderModelAdded < '_, C > {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            &mut self.typed
+        }
+    }
+    type TypedSigRenderModelRemoved < 'c, C > = TypedSignal < 'c, C, (Gd < crate::classes::OpenXrRenderModel >,) >;
+    pub struct SigRenderModelRemoved < 'c, C: WithSignals > {
+        typed: TypedSigRenderModelRemoved < 'c, C >,
+    }
+    impl < 'c, C: WithSignals > SigRenderModelRemoved < 'c, C > {
+        pub fn emit(&mut self, render_model: Gd < crate::classes::OpenXrRenderModel >,) {
+            self.typed.emit_tuple((render_model,));
+            
+        }
+    }
+    impl < 'c, C: WithSignals > std::ops::Deref for SigRenderModelRemoved < 'c, C > {
+        type Target = TypedSigRenderModelRemoved < 'c, C >;
+        fn deref(&self) -> &Self::Target {
+            &self.typed
+        }
+    }
+    impl < C: WithSignals > std::ops::DerefMut for SigRenderModelRemoved < '_, C > {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            &mut self.typed
+        }
+    }
+    use crate::obj::WithSignals;
+    impl WithSignals for OpenXrRenderModelManager {
+        type SignalCollection < 'c, C: WithSignals > = SignalsOfOpenXrRenderModelManager < 'c, C >;
+        type __SignalObj < 'c > = Gd < Self >;
+        #[doc(hidden)]
+        fn __signals_from_external(gd_ref: &Gd < Self >) -> Self::SignalCollection < '_, Self > {
+            Self::SignalCollection {
+                __internal_obj: Some(gd_ref.clone()),
+            }
+        }
+    }
+    impl < 'c, C: WithSignals > std::ops::Deref for SignalsOfOpenXrRenderModelManager < 'c, C > {
+        type Target = < < OpenXrRenderModelManager as crate::obj::GodotClass > ::Base as WithSignals > ::SignalCollection < 'c, C >;
+        fn deref(&self) -> &Self::Target {
+            type Derived = OpenXrRenderModelManager;
+            crate::private::signal_collection_to_base::< C, Derived > (self)
+        }
+    }
+    impl < 'c, C: WithSignals > std::ops::DerefMut for SignalsOfOpenXrRenderModelManager < 'c, C > {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            type Derived = OpenXrRenderModelManager;
+            crate::private::signal_collection_to_base_mut::< C, Derived > (self)
+        }
+    }
+}
\ No newline at end of file
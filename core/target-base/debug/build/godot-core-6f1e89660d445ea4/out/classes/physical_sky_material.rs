@@ -0,0 +1,447 @@
+#![doc = "Sidecar module for class [`PhysicalSkyMaterial`][crate::classes::PhysicalSkyMaterial].\n\nDefines related flag and enum types. In GDScript, those are nested under the class scope.\n\nSee also [Godot docs for `PhysicalSkyMaterial` enums](https://docs.godotengine.org/en/stable/classes/class_physicalskymaterial.html#enumerations).\n\n"]
+use godot_ffi as sys;
+use crate::builtin::*;
+use crate::meta::{
+    AsArg, ClassId, CowArg, InParamTuple, OutParamTuple, ParamTuple, RefArg, Signature
+};
+use crate::classes::native::*;
+use crate::classes::Object;
+use crate::obj::Gd;
+use crate::sys::GodotFfi as _;
+use crate::classes::notify::*;
+use std::ffi::c_void;
+pub(super) mod re_export {
+    use super::*;
+    #[doc = "Godot class `PhysicalSkyMaterial.`\n\nInherits [`Material`][crate::classes::Material].\n\nRelated symbols:\n\n* [`IPhysicalSkyMaterial`][crate::classes::IPhysicalSkyMaterial]: virtual methods\n\n\nSee also [Godot docs for `PhysicalSkyMaterial`](https://docs.godotengine.org/en/stable/classes/class_physicalskymaterial.html).\n\n"]
+    #[doc = "# Construction\n\nThis class is reference-counted. You can create a new instance using [`PhysicalSkyMaterial::new_gd()`][crate::obj::NewGd::new_gd]."]
+    #[derive(Debug)]
+    #[repr(C)]
+    pub struct PhysicalSkyMaterial {
+        object_ptr: sys::GDExtensionObjectPtr, rtti: Option < crate::private::ObjectRtti >,
+    }
+    #[doc = "# Interface trait for class [`PhysicalSkyMaterial`][crate::classes::PhysicalSkyMaterial].\n\nFunctions in this trait represent constructors (`init`) or virtual method callbacks invoked by the engine.\n\n\n\n# Related symbols\n\nBase interfaces: [`IMaterial`][crate::classes::IMaterial] > [`IResource`][crate::classes::IResource] > [`IRefCounted`][crate::classes::IRefCounted] > [`IObject`][crate::classes::IObject].\n\nSee also [Godot docs for `PhysicalSkyMaterial` methods](https://docs.godotengine.org/en/stable/classes/class_physicalskymaterial.html#methods)."]
+    #[doc = ""]
+    #[allow(unused_variables)]
+    #[allow(clippy::unimplemented)]
+    pub trait IPhysicalSkyMaterial: crate::obj::GodotClass < Base = PhysicalSkyMaterial > + crate::private::You_forgot_the_attribute__godot_api {
+        #[doc(hidden)]
+        fn register_class(builder: &mut crate::builder::ClassBuilder < Self >) {
+            unimplemented !()
+        }
+        #[doc = r" Godot constructor, accepting an injected `base` object."]
+        #[doc = r""]
+        #[doc = r" `base` refers to the base instance of the class, which can either be stored in a `Base<T>` field or discarded."]
+        #[doc = r" This method returns a fully-constructed instance, which will then be moved into a [`Gd<T>`][crate::obj::Gd] pointer."]
+        #[doc = r""]
+        #[doc = r" If the class has a `#[class(init)]` attribute, this method will be auto-generated and must not be overridden."]
+        fn init(base: crate::obj::Base < Self::Base >) -> Self {
+            unimplemented !()
+        }
+        #[doc = r" String representation of the Godot instance."]
+        #[doc = r""]
+        #[doc = r" Override this method to define how the instance is represented as a string."]
+        #[doc = r" Used by `impl Display for Gd<T>`, as well as `str()` and `print()` in GDScript."]
+        fn to_string(&self) -> crate::builtin::GString {
+            unimplemented !()
+        }
+        #[doc = r" Called when the object receives a Godot notification."]
+        #[doc = r""]
+        #[doc = r" The type of notification can be identified through `what`. The enum is designed to hold all possible `NOTIFICATION_*`"]
+        #[doc = r" constants that the current class can handle. However, this is not validated in Godot, so an enum variant `Unknown` exists"]
+        #[doc = r" to represent integers out of known constants (mistakes or future additions)."]
+        #[doc = r""]
+        #[doc = r" This method is named `_notification` in Godot, but `on_notification` in Rust. To _send_ notifications, use the"]
+        #[doc = r" [`Object::notify`][crate::classes::Object::notify] method."]
+        #[doc = r""]
+        #[doc = r" See also in Godot docs:"]
+        #[doc = r" * [`Object::_notification`](https://docs.godotengine.org/en/stable/classes/class_object.html#class-object-method-notification)."]
+        #[doc = r" * [Notifications tutorial](https://docs.godotengine.org/en/stable/tutorials/best_practices/godot_notifications.html)."]
+        fn on_notification(&mut self, what: ObjectNotification) {
+            unimplemented !()
+        }
+        #[doc = r" Called whenever [`get()`](crate::classes::Object::get) is called or Godot gets the value of a property."]
+        #[doc = r""]
+        #[doc = r" Should return the given `property`'s value as `Some(value)`, or `None` if the property should be handled normally."]
+        #[doc = r""]
+        #[doc = r" See also in Godot docs:"]
+        #[doc = r" * [`Object::_get`](https://docs.godotengine.org/en/stable/classes/class_object.html#class-object-private-method-get)."]
+        fn get_property(&self, property: StringName) -> Option < Variant > {
+            unimplemented !()
+        }
+        #[doc = r" Called whenever Godot [`set()`](crate::classes::Object::set) is called or Godot sets the value of a property."]
+        #[doc = r""]
+        #[doc = r" Should set `property` to the given `value` and return `true`, or return `false` to indicate the `property`"]
+        #[doc = r" should be handled normally."]
+        #[doc = r""]
+        #[doc = r" See also in Godot docs:"]
+        #[doc = r" * [`Object::_set`](https://docs.godotengine.org/en/stable/classes/class_object.html#class-object-private-method-set)."]
+        fn set_property(&mut self, property: StringName, value: Variant) -> bool {
+            unimplemented !()
+        }
+        #[doc = r" Called whenever Godot [`get_property_list()`](crate::classes::Object::get_property_list) is called, the returned vector here is"]
+        #[doc = r" appended to the existing list of properties."]
+        #[doc = r""]
+        #[doc = r" This should mainly be used for advanced purposes, such as dynamically updating the property list in the editor."]
+        #[doc = r""]
+        #[doc = r" See also in Godot docs:"]
+        #[doc = r" * [`Object::_get_property_list`](https://docs.godotengine.org/en/latest/classes/class_object.html#class-object-private-method-get-property-list)"]
+        #[cfg(since_api = "4.3")]
+        #[cfg_attr(published_docs, doc(cfg(since_api = "4.3")))]
+        fn get_property_list(&mut self) -> Vec < crate::meta::PropertyInfo > {
+            unimplemented !()
+        }
+        #[doc = r" Called whenever Godot retrieves value of property. Allows to customize existing properties."]
+        #[doc = r" Every property info goes through this method, except properties **added** with `get_property_list()`."]
+        #[doc = r""]
+        #[doc = r" Exposed `property` here is a shared mutable reference obtained (and returned to) from Godot."]
+        #[doc = r""]
+        #[doc = r" See also in the Godot docs:"]
+        #[doc = r" * [`Object::_validate_property`](https://docs.godotengine.org/en/stable/classes/class_object.html#class-object-private-method-validate-property)"]
+        fn validate_property(&self, property: &mut crate::meta::PropertyInfo) {
+            unimplemented !()
+        }
+        #[doc = r" Called by Godot to tell if a property has a custom revert or not."]
+        #[doc = r""]
+        #[doc = r" Return `None` for no custom revert, and return `Some(value)` to specify the custom revert."]
+        #[doc = r""]
+        #[doc = r" This is a combination of Godot's [`Object::_property_get_revert`] and [`Object::_property_can_revert`]. This means that this"]
+        #[doc = r" function will usually be called twice by Godot to find the revert."]
+        #[doc = r""]
+        #[doc = r" Note that this should be a _pure_ function. That is, it should always return the same value for a property as long as `self`"]
+        #[doc = r" remains unchanged. Otherwise, this may lead to unexpected (safe) behavior."]
+        #[doc = r""]
+        #[doc = r" [`Object::_property_get_revert`]: https://docs.godotengine.org/en/latest/classes/class_object.html#class-object-private-method-property-get-revert"]
+        #[doc = r" [`Object::_property_can_revert`]: https://docs.godotengine.org/en/latest/classes/class_object.html#class-object-private-method-property-can-revert"]
+        #[doc(alias = "property_can_revert")]
+        fn property_get_revert(&self, property: StringName) -> Option < Variant > {
+            unimplemented !()
+        }
+        fn get_shader_rid(&self,) -> Rid;
+        fn get_shader_mode(&self,) -> crate::classes::shader::Mode;
+        fn can_do_next_pass(&self,) -> bool {
+            unimplemented !()
+        }
+        fn can_use_render_priority(&self,) -> bool {
+            unimplemented !()
+        }
+        fn setup_local_to_scene(&mut self,) {
+            unimplemented !()
+        }
+        fn get_rid(&self,) -> Rid {
+            unimplemented !()
+        }
+        fn reset_state(&mut self,) {
+            unimplemented !()
+        }
+        fn set_path_cache(&self, path: GString,) {
+            unimplemented !()
+        }
+    }
+    impl PhysicalSkyMaterial {
+        pub fn set_rayleigh_coefficient(&mut self, rayleigh: f32,) {
+            type CallRet = ();
+            type CallParams = (f32,);
+            let args = (rayleigh,);
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(6461usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "PhysicalSkyMaterial", "set_rayleigh_coefficient", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn get_rayleigh_coefficient(&self,) -> f32 {
+            type CallRet = f32;
+            type CallParams = ();
+            let args = ();
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(6462usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "PhysicalSkyMaterial", "get_rayleigh_coefficient", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn set_rayleigh_color(&mut self, color: Color,) {
+            type CallRet = ();
+            type CallParams = (Color,);
+            let args = (color,);
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(6463usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "PhysicalSkyMaterial", "set_rayleigh_color", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn get_rayleigh_color(&self,) -> Color {
+            type CallRet = Color;
+            type CallParams = ();
+            let args = ();
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(6464usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "PhysicalSkyMaterial", "get_rayleigh_color", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn set_mie_coefficient(&mut self, mie: f32,) {
+            type CallRet = ();
+            type CallParams = (f32,);
+            let args = (mie,);
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(6465usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "PhysicalSkyMaterial", "set_mie_coefficient", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn get_mie_coefficient(&self,) -> f32 {
+            type CallRet = f32;
+            type CallParams = ();
+            let args = ();
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(6466usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "PhysicalSkyMaterial", "get_mie_coefficient", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn set_mie_eccentricity(&mut self, eccentricity: f32,) {
+            type CallRet = ();
+            type CallParams = (f32,);
+            let args = (eccentricity,);
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(6467usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "PhysicalSkyMaterial", "set_mie_eccentricity", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn get_mie_eccentricity(&self,) -> f32 {
+            type CallRet = f32;
+            type CallParams = ();
+            let args = ();
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(6468usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "PhysicalSkyMaterial", "get_mie_eccentricity", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn set_mie_color(&mut self, color: Color,) {
+            type CallRet = ();
+            type CallParams = (Color,);
+            let args = (color,);
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(6469usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "PhysicalSkyMaterial", "set_mie_color", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn get_mie_color(&self,) -> Color {
+            type CallRet = Color;
+            type CallParams = ();
+            let args = ();
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(6470usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "PhysicalSkyMaterial", "get_mie_color", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn set_turbidity(&mut self, turbidity: f32,) {
+            type CallRet = ();
+            type CallParams = (f32,);
+            let args = (turbidity,);
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(6471usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "PhysicalSkyMaterial", "set_turbidity", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn get_turbidity(&self,) -> f32 {
+            type CallRet = f32;
+            type CallParams = ();
+            let args = ();
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(6472usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "PhysicalSkyMaterial", "get_turbidity", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn set_sun_disk_scale(&mut self, scale: f32,) {
+            type CallRet = ();
+            type CallParams = (f32,);
+            let args = (scale,);
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(6473usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "PhysicalSkyMaterial", "set_sun_disk_scale", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn get_sun_disk_scale(&self,) -> f32 {
+            type CallRet = f32;
+            type CallParams = ();
+            let args = ();
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(6474usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "PhysicalSkyMaterial", "get_sun_disk_scale", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn set_ground_color(&mut self, color: Color,) {
+            type CallRet = ();
+            type CallParams = (Color,);
+            let args = (color,);
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(6475usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "PhysicalSkyMaterial", "set_ground_color", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn get_ground_color(&self,) -> Color {
+            type CallRet = Color;
+            type CallParams = ();
+            let args = ();
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(6476usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "PhysicalSkyMaterial", "get_ground_color", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn set_energy_multiplier(&mut self, multiplier: f32,) {
+            type CallRet = ();
+            type CallParams = (f32,);
+            let args = (multiplier,);
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(6477usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "PhysicalSkyMaterial", "set_energy_multiplier", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn get_energy_multiplier(&self,) -> f32 {
+            type CallRet = f32;
+            type CallParams = ();
+            let args = ();
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(6478usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "PhysicalSkyMaterial", "get_energy_multiplier", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn set_use_debanding(&mut self, use_debanding: bool,) {
+            type CallRet = ();
+            type CallParams = (bool,);
+            let args = (use_debanding,);
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(6479usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "PhysicalSkyMaterial", "set_use_debanding", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn get_use_debanding(&self,) -> bool {
+            type CallRet = bool;
+            type CallParams = ();
+            let args = ();
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(6480usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "PhysicalSkyMaterial", "get_use_debanding", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn set_night_sky(&mut self, night_sky: impl AsArg < Option < Gd < crate::classes::Texture2D >> >,) {
+            type CallRet = ();
+            type CallParams < 'a0, > = (CowArg < 'a0, Option < Gd < crate::classes::Texture2D > > >,);
+            let args = (night_sky.into_arg(),);
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(6481usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "PhysicalSkyMaterial", "set_night_sky", Some(self.__validated_obj()), args,)
+            }
+        }
+        pub fn get_night_sky(&self,) -> Option < Gd < crate::classes::Texture2D > > {
+            type CallRet = Option < Gd < crate::classes::Texture2D > >;
+            type CallParams = ();
+            let args = ();
+            unsafe {
+                let method_bind = sys::class_scene_api() . fptr_by_index(6482usize);
+                Signature::< CallParams, CallRet > ::out_class_ptrcall(method_bind, "PhysicalSkyMaterial", "get_night_sky", Some(self.__validated_obj()), args,)
+            }
+        }
+        #[doc = r" Creates a validated object for FFI boundary crossing."]
+        #[doc = r""]
+        #[doc = r" Low-level internal method. Validation (liveness/type checks) depend on safeguard level."]
+        fn __validated_obj(&self) -> crate::obj::ValidatedObject {
+            let raw_gd = unsafe {
+                std::mem::transmute::< &Self, &crate::obj::RawGd < Self >> (self)
+            };
+            raw_gd.validated_object()
+        }
+        #[doc(hidden)]
+        pub fn __object_ptr(&self) -> sys::GDExtensionObjectPtr {
+            self.object_ptr
+        }
+    }
+    impl crate::obj::GodotClass for PhysicalSkyMaterial {
+        type Base = crate::classes::Material;
+        fn class_id() -> ClassId {
+            static CLASS_ID: std::sync::OnceLock < ClassId > = std::sync::OnceLock::new();
+            let name: &'static ClassId = CLASS_ID.get_or_init(|| ClassId::__alloc_next_unicode("PhysicalSkyMaterial"));
+            * name
+        }
+        const INIT_LEVEL: crate::init::InitLevel = crate::init::InitLevel::Scene;
+        
+    }
+    unsafe impl crate::obj::Bounds for PhysicalSkyMaterial {
+        type Memory = crate::obj::bounds::MemRefCounted;
+        type DynMemory = crate::obj::bounds::MemRefCounted;
+        type Declarer = crate::obj::bounds::DeclEngine;
+        type Exportable = crate::obj::bounds::Yes;
+        
+    }
+    unsafe impl crate::obj::Inherits < crate::classes::Material > for PhysicalSkyMaterial {
+        
+    }
+    unsafe impl crate::obj::Inherits < crate::classes::Resource > for PhysicalSkyMaterial {
+        
+    }
+    unsafe impl crate::obj::Inherits < crate::classes::RefCounted > for PhysicalSkyMaterial {
+        
+    }
+    unsafe impl crate::obj::Inherits < crate::classes::Object > for PhysicalSkyMaterial {
+        
+    }
+    impl crate::obj::cap::GodotDefault for PhysicalSkyMaterial {
+        fn __godot_default() -> crate::obj::Gd < Self > {
+            crate::classes::construct_engine_object::< Self > ()
+        }
+    }
+    impl std::ops::Deref for PhysicalSkyMaterial {
+        type Target = crate::classes::Material;
+        fn deref(&self) -> &Self::Target {
+            unsafe {
+                std::mem::transmute::< &Self, &Self::Target > (self)
+            }
+        }
+    }
+    impl std::ops::DerefMut for PhysicalSkyMaterial {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            unsafe {
+                std::mem::transmute::< &mut Self, &mut Self::Target > (self)
+            }
+        }
+    }
+    #[doc = r" # Safety"]
+    #[doc = r""]
+    #[doc = "The provided class must be a subclass of all the superclasses of [`PhysicalSkyMaterial`]"]
+    #[macro_export]
+    #[allow(non_snake_case)]
+    macro_rules !inherit_from_PhysicalSkyMaterial__ensure_class_exists {
+        ($Class: ident) => {
+            unsafe impl::godot::obj::Inherits < ::godot::classes::PhysicalSkyMaterial > for $Class {
+                
+            }
+            unsafe impl::godot::obj::Inherits < ::godot::classes::Material > for $Class {
+                
+            }
+            unsafe impl::godot::obj::Inherits < ::godot::classes::Resource > for $Class {
+                
+            }
+            unsafe impl::godot::obj::Inherits < ::godot::classes::RefCounted > for $Class {
+                
+            }
+            unsafe impl::godot::obj::Inherits < ::godot::classes::Object > for $Class {
+                
+            }
+        }
+    }
+}
+pub use signals::*;
+mod signals {
+    use crate::obj::{
+        Gd, GodotClass
+    };
+    use super::re_export::PhysicalSkyMaterial;
+    use crate::registry::signal::TypedSignal;
+    use super::*;
+    use crate::obj::WithSignals;
+    use crate::classes::resource::SignalsOfResource;
+    impl WithSignals for PhysicalSkyMaterial {
+        type SignalCollection < 'c, C: WithSignals > = SignalsOfResource < 'c, C >;
+        type __SignalObj < 'c > = Gd < Self >;
+        #[doc(hidden)]
+        fn __signals_from_external(gd_ref: &Gd < Self >) -> Self::SignalCollection < '_, Self > {
+            Self::SignalCollection {
+                __internal_obj: Some(gd_ref.clone()),
+            }
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,6 @@
+pub mod central;
+pub mod classes;
+pub mod builtin_classes;
+pub mod utilities;
+pub mod native;
+pub mod virtuals;
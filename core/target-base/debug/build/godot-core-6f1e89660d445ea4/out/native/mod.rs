@@ -0,0 +1,28 @@
+mod audio_frame;
+pub use audio_frame::AudioFrame;
+mod caret_info;
+pub use caret_info::CaretInfo;
+mod glyph;
+pub use glyph::Glyph;
+mod object_id;
+pub use object_id::ObjectId;
+mod physics_server_2d_extension_motion_result;
+pub use physics_server_2d_extension_motion_result::PhysicsServer2DExtensionMotionResult;
+mod physics_server_2d_extension_ray_result;
+pub use physics_server_2d_extension_ray_result::PhysicsServer2DExtensionRayResult;
+mod physics_server_2d_extension_shape_rest_info;
+pub use physics_server_2d_extension_shape_rest_info::PhysicsServer2DExtensionShapeRestInfo;
+mod physics_server_2d_extension_shape_result;
+pub use physics_server_2d_extension_shape_result::PhysicsServer2DExtensionShapeResult;
+mod physics_server_3d_extension_motion_collision;
+pub use physics_server_3d_extension_motion_collision::PhysicsServer3DExtensionMotionCollision;
+mod physics_server_3d_extension_motion_result;
+pub use physics_server_3d_extension_motion_result::PhysicsServer3DExtensionMotionResult;
+mod physics_server_3d_extension_ray_result;
+pub use physics_server_3d_extension_ray_result::PhysicsServer3DExtensionRayResult;
+mod physics_server_3d_extension_shape_rest_info;
+pub use physics_server_3d_extension_shape_rest_info::PhysicsServer3DExtensionShapeRestInfo;
+mod physics_server_3d_extension_shape_result;
+pub use physics_server_3d_extension_shape_result::PhysicsServer3DExtensionShapeResult;
+mod script_language_extension_profiling_info;
+pub use script_language_extension_profiling_info::ScriptLanguageExtensionProfilingInfo;
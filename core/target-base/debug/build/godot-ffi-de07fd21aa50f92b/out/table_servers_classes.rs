@@ -0,0 +1,1452 @@
+type FetchFn = < crate::GDExtensionInterfaceClassdbGetMethodBind as crate::Inner > ::FnPtr;
+pub struct ClassServersMethodTable {
+    function_pointers: Vec < crate::ClassMethodBind >,
+}
+impl ClassServersMethodTable {
+    pub const CLASS_COUNT: usize = 21usize;
+    pub const METHOD_COUNT: usize = 1323usize;
+    #[doc = r" # Safety"]
+    #[doc = r" - Must be called exactly once during library initialization."]
+    #[doc = r" - All parameters (dependencies) must have been initialized and valid."]
+    pub unsafe fn load(interface: &crate::GDExtensionInterface, string_names: &mut crate::StringCache,) -> Self {
+        let fetch_fptr = interface.classdb_get_method_bind.expect("classdb_get_method_bind absent");
+        let mut function_pointers = Vec::with_capacity(1323usize);
+        load_AudioServer_methods(&mut function_pointers, string_names, fetch_fptr);
+        load_CameraServer_methods(&mut function_pointers, string_names, fetch_fptr);
+        load_DisplayServer_methods(&mut function_pointers, string_names, fetch_fptr);
+        load_PhysicsDirectBodyState2D_methods(&mut function_pointers, string_names, fetch_fptr);
+        load_PhysicsDirectBodyState3D_methods(&mut function_pointers, string_names, fetch_fptr);
+        load_PhysicsDirectSpaceState2D_methods(&mut function_pointers, string_names, fetch_fptr);
+        load_PhysicsDirectSpaceState2DExtension_methods(&mut function_pointers, string_names, fetch_fptr);
+        load_PhysicsDirectSpaceState3D_methods(&mut function_pointers, string_names, fetch_fptr);
+        load_PhysicsDirectSpaceState3DExtension_methods(&mut function_pointers, string_names, fetch_fptr);
+        load_PhysicsServer2D_methods(&mut function_pointers, string_names, fetch_fptr);
+        load_PhysicsServer2DExtension_methods(&mut function_pointers, string_names, fetch_fptr);
+        load_PhysicsServer2DManager_methods(&mut function_pointers, string_names, fetch_fptr);
+        load_PhysicsServer3D_methods(&mut function_pointers, string_names, fetch_fptr);
+        load_PhysicsServer3DExtension_methods(&mut function_pointers, string_names, fetch_fptr);
+        load_PhysicsServer3DManager_methods(&mut function_pointers, string_names, fetch_fptr);
+        load_PhysicsServer3DRenderingServerHandler_methods(&mut function_pointers, string_names, fetch_fptr);
+        load_RenderData_methods(&mut function_pointers, string_names, fetch_fptr);
+        load_RenderSceneData_methods(&mut function_pointers, string_names, fetch_fptr);
+        load_RenderingServer_methods(&mut function_pointers, string_names, fetch_fptr);
+        load_TranslationServer_methods(&mut function_pointers, string_names, fetch_fptr);
+        load_XRServer_methods(&mut function_pointers, string_names, fetch_fptr);
+        Self {
+            function_pointers
+        }
+    }
+    #[inline(always)]
+    pub fn fptr_by_index(&self, index: usize) -> crate::ClassMethodBind {
+        unsafe {
+            * self.function_pointers.get_unchecked(index)
+        }
+    }
+}
+fn load_AudioServer_methods(function_pointers: &mut Vec < crate::ClassMethodBind >, string_names: &mut crate::StringCache, fetch_fptr: FetchFn,) {
+    let sname_AudioServer = string_names.fetch("AudioServer");
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_AudioServer), "AudioServer", "set_bus_count", 1286410249i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_AudioServer), "AudioServer", "get_bus_count", 3905245786i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_AudioServer), "AudioServer", "remove_bus", 1286410249i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_AudioServer), "AudioServer", "add_bus", 1025054187i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_AudioServer), "AudioServer", "move_bus", 3937882851i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_AudioServer), "AudioServer", "set_bus_name", 501894301i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_AudioServer), "AudioServer", "get_bus_name", 844755477i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_AudioServer), "AudioServer", "get_bus_index", 2458036349i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_AudioServer), "AudioServer", "get_bus_channels", 923996154i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_AudioServer), "AudioServer", "set_bus_volume_db", 1602489585i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_AudioServer), "AudioServer", "get_bus_volume_db", 2339986948i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_AudioServer), "AudioServer", "set_bus_volume_linear", 1602489585i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_AudioServer), "AudioServer", "get_bus_volume_linear", 2339986948i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_AudioServer), "AudioServer", "set_bus_send", 3780747571i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_AudioServer), "AudioServer", "get_bus_send", 659327637i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_AudioServer), "AudioServer", "set_bus_solo", 300928843i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_AudioServer), "AudioServer", "is_bus_solo", 1116898809i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_AudioServer), "AudioServer", "set_bus_mute", 300928843i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_AudioServer), "AudioServer", "is_bus_mute", 1116898809i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_AudioServer), "AudioServer", "set_bus_bypass_effects", 300928843i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_AudioServer), "AudioServer", "is_bus_bypassing_effects", 1116898809i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_AudioServer), "AudioServer", "add_bus_effect", 4068819785i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_AudioServer), "AudioServer", "remove_bus_effect", 3937882851i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_AudioServer), "AudioServer", "get_bus_effect_count", 3744713108i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_AudioServer), "AudioServer", "get_bus_effect", 726064442i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_AudioServer), "AudioServer", "get_bus_effect_instance", 1829771234i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_AudioServer), "AudioServer", "swap_bus_effects", 1649997291i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_AudioServer), "AudioServer", "set_bus_effect_enabled", 1383440665i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_AudioServer), "AudioServer", "is_bus_effect_enabled", 2522259332i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_AudioServer), "AudioServer", "get_bus_peak_volume_left_db", 3085491603i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_AudioServer), "AudioServer", "get_bus_peak_volume_right_db", 3085491603i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_AudioServer), "AudioServer", "set_playback_speed_scale", 373806689i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_AudioServer), "AudioServer", "get_playback_speed_scale", 1740695150i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_AudioServer), "AudioServer", "lock", 3218959716i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_AudioServer), "AudioServer", "unlock", 3218959716i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_AudioServer), "AudioServer", "get_speaker_mode", 2549190337i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_AudioServer), "AudioServer", "get_mix_rate", 1740695150i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_AudioServer), "AudioServer", "get_input_mix_rate", 1740695150i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_AudioServer), "AudioServer", "get_driver_name", 201670096i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_AudioServer), "AudioServer", "get_output_device_list", 2981934095i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_AudioServer), "AudioServer", "get_output_device", 2841200299i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_AudioServer), "AudioServer", "set_output_device", 83702148i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_AudioServer), "AudioServer", "get_time_to_next_mix", 1740695150i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_AudioServer), "AudioServer", "get_time_since_last_mix", 1740695150i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_AudioServer), "AudioServer", "get_output_latency", 1740695150i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_AudioServer), "AudioServer", "get_input_device_list", 2981934095i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_AudioServer), "AudioServer", "get_input_device", 2841200299i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_AudioServer), "AudioServer", "set_input_device", 83702148i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_AudioServer), "AudioServer", "set_bus_layout", 3319058824i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_AudioServer), "AudioServer", "generate_bus_layout", 3769973890i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_AudioServer), "AudioServer", "set_enable_tagging_used_audio_streams", 2586408642i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_AudioServer), "AudioServer", "is_stream_registered_as_sample", 500225754i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_AudioServer), "AudioServer", "register_stream_as_sample", 2210767741i64),);
+    
+}
+fn load_CameraServer_methods(function_pointers: &mut Vec < crate::ClassMethodBind >, string_names: &mut crate::StringCache, fetch_fptr: FetchFn,) {
+    let sname_CameraServer = string_names.fetch("CameraServer");
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_CameraServer), "CameraServer", "set_monitoring_feeds", 2586408642i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_CameraServer), "CameraServer", "is_monitoring_feeds", 36873697i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_CameraServer), "CameraServer", "get_feed", 361927068i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_CameraServer), "CameraServer", "get_feed_count", 2455072627i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_CameraServer), "CameraServer", "feeds", 2915620761i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_CameraServer), "CameraServer", "add_feed", 3204782488i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_CameraServer), "CameraServer", "remove_feed", 3204782488i64),);
+    
+}
+fn load_DisplayServer_methods(function_pointers: &mut Vec < crate::ClassMethodBind >, string_names: &mut crate::StringCache, fetch_fptr: FetchFn,) {
+    let sname_DisplayServer = string_names.fetch("DisplayServer");
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "has_feature", 334065950i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "get_name", 201670096i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "help_set_search_callbacks", 1687350599i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "global_menu_set_popup_callbacks", 3893727526i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "global_menu_add_submenu_item", 2828985934i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "global_menu_add_item", 3616842746i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "global_menu_add_check_item", 3616842746i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "global_menu_add_icon_item", 3867083847i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "global_menu_add_icon_check_item", 3867083847i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "global_menu_add_radio_check_item", 3616842746i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "global_menu_add_icon_radio_check_item", 3867083847i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "global_menu_add_multistate_item", 3297554655i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "global_menu_add_separator", 3214812433i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "global_menu_get_item_index_from_text", 2878152881i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "global_menu_get_item_index_from_tag", 2941063483i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "global_menu_is_item_checked", 3511468594i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "global_menu_is_item_checkable", 3511468594i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "global_menu_is_item_radio_checkable", 3511468594i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "global_menu_get_item_callback", 748666903i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "global_menu_get_item_key_callback", 748666903i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "global_menu_get_item_tag", 330672633i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "global_menu_get_item_text", 591067909i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "global_menu_get_item_submenu", 591067909i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "global_menu_get_item_accelerator", 936065394i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "global_menu_is_item_disabled", 3511468594i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "global_menu_is_item_hidden", 3511468594i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "global_menu_get_item_tooltip", 591067909i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "global_menu_get_item_state", 3422818498i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "global_menu_get_item_max_states", 3422818498i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "global_menu_get_item_icon", 3591713183i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "global_menu_get_item_indentation_level", 3422818498i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "global_menu_set_item_checked", 4108344793i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "global_menu_set_item_checkable", 4108344793i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "global_menu_set_item_radio_checkable", 4108344793i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "global_menu_set_item_callback", 3809915389i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "global_menu_set_item_hover_callbacks", 3809915389i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "global_menu_set_item_key_callback", 3809915389i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "global_menu_set_item_tag", 453659863i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "global_menu_set_item_text", 965966136i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "global_menu_set_item_submenu", 965966136i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "global_menu_set_item_accelerator", 566943293i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "global_menu_set_item_disabled", 4108344793i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "global_menu_set_item_hidden", 4108344793i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "global_menu_set_item_tooltip", 965966136i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "global_menu_set_item_state", 3474840532i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "global_menu_set_item_max_states", 3474840532i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "global_menu_set_item_icon", 3201338066i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "global_menu_set_item_indentation_level", 3474840532i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "global_menu_get_item_count", 1321353865i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "global_menu_remove_item", 2956805083i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "global_menu_clear", 83702148i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "global_menu_get_system_menu_roots", 3102165223i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "tts_is_speaking", 36873697i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "tts_is_paused", 36873697i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "tts_get_voices", 3995934104i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "tts_get_voices_for_language", 4291131558i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "tts_speak", 903992738i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "tts_pause", 3218959716i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "tts_resume", 3218959716i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "tts_stop", 3218959716i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "tts_set_utterance_callback", 109679083i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "is_dark_mode_supported", 36873697i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "is_dark_mode", 36873697i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "get_accent_color", 3444240500i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "get_base_color", 3444240500i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "set_system_theme_change_callback", 1611583062i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "mouse_set_mode", 348288463i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "mouse_get_mode", 1353961651i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "warp_mouse", 1130785943i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "mouse_get_position", 3690982128i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "mouse_get_button_state", 2512161324i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "clipboard_set", 83702148i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "clipboard_get", 201670096i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "clipboard_get_image", 4190603485i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "clipboard_has", 36873697i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "clipboard_has_image", 36873697i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "clipboard_set_primary", 83702148i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "clipboard_get_primary", 201670096i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "get_display_cutouts", 3995934104i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "get_display_safe_area", 410525958i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "get_screen_count", 3905245786i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "get_primary_screen", 3905245786i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "get_keyboard_focus_screen", 3905245786i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "get_screen_from_rect", 741354659i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "screen_get_position", 1725937825i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "screen_get_size", 1725937825i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "screen_get_usable_rect", 2439012528i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "screen_get_dpi", 181039630i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "screen_get_scale", 909105437i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "is_touchscreen_available", 36873697i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "screen_get_max_scale", 1740695150i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "screen_get_refresh_rate", 909105437i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "screen_get_pixel", 1532707496i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "screen_get_image", 3813388802i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "screen_get_image_rect", 2601441065i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "screen_set_orientation", 2211511631i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "screen_get_orientation", 133818562i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "screen_set_keep_on", 2586408642i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "screen_is_kept_on", 36873697i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "get_window_list", 1930428628i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "get_window_at_screen_position", 2485466453i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "window_get_native_handle", 1096425680i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "window_get_active_popup", 3905245786i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "window_set_popup_safe_rect", 3317281434i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "window_get_popup_safe_rect", 2161169500i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "window_set_title", 441246282i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "window_get_title_size", 2925301799i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "window_set_mouse_passthrough", 1993637420i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "window_get_current_screen", 1591665591i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "window_set_current_screen", 2230941749i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "window_get_position", 763922886i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "window_get_position_with_decorations", 763922886i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "window_set_position", 2019273902i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "window_get_size", 763922886i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "window_set_size", 2019273902i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "window_set_rect_changed_callback", 1091192925i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "window_set_window_event_callback", 1091192925i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "window_set_input_event_callback", 1091192925i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "window_set_input_text_callback", 1091192925i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "window_set_drop_files_callback", 1091192925i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "window_get_attached_instance_id", 1591665591i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "window_get_max_size", 763922886i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "window_set_max_size", 2019273902i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "window_get_min_size", 763922886i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "window_set_min_size", 2019273902i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "window_get_size_with_decorations", 763922886i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "window_get_mode", 2185728461i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "window_set_mode", 1319965401i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "window_set_flag", 254894155i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "window_get_flag", 802816991i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "window_set_window_buttons_offset", 2019273902i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "window_get_safe_title_margins", 2295066620i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "window_request_attention", 1995695955i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "window_move_to_foreground", 1995695955i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "window_is_focused", 1051549951i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "window_can_draw", 1051549951i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "window_set_transient", 3937882851i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "window_set_exclusive", 300928843i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "window_set_ime_active", 1661950165i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "window_set_ime_position", 2019273902i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "window_set_vsync_mode", 2179333492i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "window_get_vsync_mode", 578873795i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "window_is_maximize_allowed", 1051549951i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "window_maximize_on_title_dbl_click", 36873697i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "window_minimize_on_title_dbl_click", 36873697i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "window_start_drag", 1995695955i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "window_start_resize", 4009722312i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_should_increase_contrast", 3905245786i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_should_reduce_animation", 3905245786i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_should_reduce_transparency", 3905245786i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_screen_reader_active", 3905245786i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_create_element", 2968347744i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_create_sub_element", 1949948826i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_create_sub_text_edit_elements", 3328635351i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_has_element", 4155700596i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_free_element", 2722037293i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_element_set_meta", 3175752987i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_element_get_meta", 4171304767i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_set_window_rect", 2386961724i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_set_window_focused", 300928843i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_set_focus", 2722037293i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_get_window_root", 495598643i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_set_role", 3352768215i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_set_name", 2726140452i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_set_extra_info", 2726140452i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_set_description", 2726140452i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_set_value", 2726140452i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_set_tooltip", 2726140452i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_set_bounds", 1378122625i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_set_transform", 1246044741i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_add_child", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_add_related_controls", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_add_related_details", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_add_related_described_by", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_add_related_flow_to", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_add_related_labeled_by", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_add_related_radio_group", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_set_active_descendant", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_set_next_on_line", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_set_previous_on_line", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_set_member_of", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_set_in_page_link_target", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_set_error_message", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_set_live", 2683302212i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_add_action", 2898696987i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_add_custom_action", 4153150897i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_set_table_row_count", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_set_table_column_count", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_set_table_row_index", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_set_table_column_index", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_set_table_cell_position", 4288446313i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_set_table_cell_span", 4288446313i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_set_list_item_count", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_set_list_item_index", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_set_list_item_level", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_set_list_item_selected", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_set_list_item_expanded", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_set_popup_type", 2040885448i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_set_checked", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_set_num_value", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_set_num_range", 2513314492i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_set_num_step", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_set_num_jump", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_set_scroll_x", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_set_scroll_x_range", 2513314492i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_set_scroll_y", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_set_scroll_y_range", 2513314492i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_set_text_decorations", 1672422386i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_set_text_align", 3725995085i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_set_text_selection", 3119144029i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_set_flag", 3758675396i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_set_classname", 2726140452i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_set_placeholder", 2726140452i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_set_language", 2726140452i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_set_text_orientation", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_set_list_orientation", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_set_shortcut", 2726140452i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_set_url", 2726140452i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_set_role_description", 2726140452i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_set_state_description", 2726140452i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_set_color_value", 2948539648i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_set_background_color", 2948539648i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "accessibility_update_set_foreground_color", 2948539648i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "ime_get_selection", 3690982128i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "ime_get_text", 201670096i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "virtual_keyboard_show", 3042891259i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "virtual_keyboard_hide", 3218959716i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "virtual_keyboard_get_height", 3905245786i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "has_hardware_keyboard", 36873697i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "set_hardware_keyboard_connection_change_callback", 1611583062i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "cursor_set_shape", 2026291549i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "cursor_get_shape", 1087724927i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "cursor_set_custom_image", 1816663697i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "get_swap_cancel_ok", 2240911060i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "enable_for_stealing_focus", 1286410249i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "dialog_show", 4115553226i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "dialog_input_text", 3088703427i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "file_dialog_show", 1386825884i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "file_dialog_with_options_show", 1448789813i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "beep", 4051624405i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "keyboard_get_layout_count", 3905245786i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "keyboard_get_current_layout", 3905245786i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "keyboard_set_current_layout", 1286410249i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "keyboard_get_layout_language", 844755477i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "keyboard_get_layout_name", 844755477i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "keyboard_get_keycode_from_physical", 3447613187i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "keyboard_get_label_from_physical", 3447613187i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "show_emoji_and_symbol_picker", 4051624405i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "color_picker", 151643214i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "process_events", 3218959716i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "force_process_and_drop_events", 3218959716i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "set_native_icon", 83702148i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "set_icon", 532598488i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "create_status_indicator", 1904285171i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "status_indicator_set_icon", 666127730i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "status_indicator_set_tooltip", 501894301i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "status_indicator_set_menu", 4040184819i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "status_indicator_set_callback", 957362965i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "status_indicator_get_rect", 3327874267i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "delete_status_indicator", 1286410249i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "tablet_get_driver_count", 3905245786i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "tablet_get_driver_name", 844755477i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "tablet_get_current_driver", 201670096i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "tablet_set_current_driver", 83702148i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "is_window_transparency_available", 36873697i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "register_additional_output", 3975164845i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "unregister_additional_output", 3975164845i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_DisplayServer), "DisplayServer", "has_additional_outputs", 36873697i64),);
+    
+}
+fn load_PhysicsDirectBodyState2D_methods(function_pointers: &mut Vec < crate::ClassMethodBind >, string_names: &mut crate::StringCache, fetch_fptr: FetchFn,) {
+    let sname_PhysicsDirectBodyState2D = string_names.fetch("PhysicsDirectBodyState2D");
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState2D), "PhysicsDirectBodyState2D", "get_total_gravity", 3341600327i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState2D), "PhysicsDirectBodyState2D", "get_total_linear_damp", 1740695150i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState2D), "PhysicsDirectBodyState2D", "get_total_angular_damp", 1740695150i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState2D), "PhysicsDirectBodyState2D", "get_center_of_mass", 3341600327i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState2D), "PhysicsDirectBodyState2D", "get_center_of_mass_local", 3341600327i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState2D), "PhysicsDirectBodyState2D", "get_inverse_mass", 1740695150i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState2D), "PhysicsDirectBodyState2D", "get_inverse_inertia", 1740695150i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState2D), "PhysicsDirectBodyState2D", "set_linear_velocity", 743155724i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState2D), "PhysicsDirectBodyState2D", "get_linear_velocity", 3341600327i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState2D), "PhysicsDirectBodyState2D", "set_angular_velocity", 373806689i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState2D), "PhysicsDirectBodyState2D", "get_angular_velocity", 1740695150i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState2D), "PhysicsDirectBodyState2D", "set_transform", 2761652528i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState2D), "PhysicsDirectBodyState2D", "get_transform", 3814499831i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState2D), "PhysicsDirectBodyState2D", "get_velocity_at_local_position", 2656412154i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState2D), "PhysicsDirectBodyState2D", "apply_central_impulse", 743155724i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState2D), "PhysicsDirectBodyState2D", "apply_torque_impulse", 373806689i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState2D), "PhysicsDirectBodyState2D", "apply_impulse", 4288681949i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState2D), "PhysicsDirectBodyState2D", "apply_central_force", 3862383994i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState2D), "PhysicsDirectBodyState2D", "apply_force", 4288681949i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState2D), "PhysicsDirectBodyState2D", "apply_torque", 373806689i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState2D), "PhysicsDirectBodyState2D", "add_constant_central_force", 3862383994i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState2D), "PhysicsDirectBodyState2D", "add_constant_force", 4288681949i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState2D), "PhysicsDirectBodyState2D", "add_constant_torque", 373806689i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState2D), "PhysicsDirectBodyState2D", "set_constant_force", 743155724i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState2D), "PhysicsDirectBodyState2D", "get_constant_force", 3341600327i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState2D), "PhysicsDirectBodyState2D", "set_constant_torque", 373806689i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState2D), "PhysicsDirectBodyState2D", "get_constant_torque", 1740695150i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState2D), "PhysicsDirectBodyState2D", "set_sleep_state", 2586408642i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState2D), "PhysicsDirectBodyState2D", "is_sleeping", 36873697i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState2D), "PhysicsDirectBodyState2D", "set_collision_layer", 1286410249i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState2D), "PhysicsDirectBodyState2D", "get_collision_layer", 3905245786i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState2D), "PhysicsDirectBodyState2D", "set_collision_mask", 1286410249i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState2D), "PhysicsDirectBodyState2D", "get_collision_mask", 3905245786i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState2D), "PhysicsDirectBodyState2D", "get_contact_count", 3905245786i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState2D), "PhysicsDirectBodyState2D", "get_contact_local_position", 2299179447i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState2D), "PhysicsDirectBodyState2D", "get_contact_local_normal", 2299179447i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState2D), "PhysicsDirectBodyState2D", "get_contact_local_shape", 923996154i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState2D), "PhysicsDirectBodyState2D", "get_contact_local_velocity_at_position", 2299179447i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState2D), "PhysicsDirectBodyState2D", "get_contact_collider", 495598643i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState2D), "PhysicsDirectBodyState2D", "get_contact_collider_position", 2299179447i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState2D), "PhysicsDirectBodyState2D", "get_contact_collider_id", 923996154i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState2D), "PhysicsDirectBodyState2D", "get_contact_collider_object", 3332903315i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState2D), "PhysicsDirectBodyState2D", "get_contact_collider_shape", 923996154i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState2D), "PhysicsDirectBodyState2D", "get_contact_collider_velocity_at_position", 2299179447i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState2D), "PhysicsDirectBodyState2D", "get_contact_impulse", 2299179447i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState2D), "PhysicsDirectBodyState2D", "get_step", 1740695150i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState2D), "PhysicsDirectBodyState2D", "integrate_forces", 3218959716i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState2D), "PhysicsDirectBodyState2D", "get_space_state", 2506717822i64),);
+    
+}
+fn load_PhysicsDirectBodyState3D_methods(function_pointers: &mut Vec < crate::ClassMethodBind >, string_names: &mut crate::StringCache, fetch_fptr: FetchFn,) {
+    let sname_PhysicsDirectBodyState3D = string_names.fetch("PhysicsDirectBodyState3D");
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState3D), "PhysicsDirectBodyState3D", "get_total_gravity", 3360562783i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState3D), "PhysicsDirectBodyState3D", "get_total_linear_damp", 1740695150i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState3D), "PhysicsDirectBodyState3D", "get_total_angular_damp", 1740695150i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState3D), "PhysicsDirectBodyState3D", "get_center_of_mass", 3360562783i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState3D), "PhysicsDirectBodyState3D", "get_center_of_mass_local", 3360562783i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState3D), "PhysicsDirectBodyState3D", "get_principal_inertia_axes", 2716978435i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState3D), "PhysicsDirectBodyState3D", "get_inverse_mass", 1740695150i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState3D), "PhysicsDirectBodyState3D", "get_inverse_inertia", 3360562783i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState3D), "PhysicsDirectBodyState3D", "get_inverse_inertia_tensor", 2716978435i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState3D), "PhysicsDirectBodyState3D", "set_linear_velocity", 3460891852i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState3D), "PhysicsDirectBodyState3D", "get_linear_velocity", 3360562783i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState3D), "PhysicsDirectBodyState3D", "set_angular_velocity", 3460891852i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState3D), "PhysicsDirectBodyState3D", "get_angular_velocity", 3360562783i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState3D), "PhysicsDirectBodyState3D", "set_transform", 2952846383i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState3D), "PhysicsDirectBodyState3D", "get_transform", 3229777777i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState3D), "PhysicsDirectBodyState3D", "get_velocity_at_local_position", 192990374i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState3D), "PhysicsDirectBodyState3D", "apply_central_impulse", 2007698547i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState3D), "PhysicsDirectBodyState3D", "apply_impulse", 2754756483i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState3D), "PhysicsDirectBodyState3D", "apply_torque_impulse", 3460891852i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState3D), "PhysicsDirectBodyState3D", "apply_central_force", 2007698547i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState3D), "PhysicsDirectBodyState3D", "apply_force", 2754756483i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState3D), "PhysicsDirectBodyState3D", "apply_torque", 3460891852i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState3D), "PhysicsDirectBodyState3D", "add_constant_central_force", 2007698547i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState3D), "PhysicsDirectBodyState3D", "add_constant_force", 2754756483i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState3D), "PhysicsDirectBodyState3D", "add_constant_torque", 3460891852i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState3D), "PhysicsDirectBodyState3D", "set_constant_force", 3460891852i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState3D), "PhysicsDirectBodyState3D", "get_constant_force", 3360562783i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState3D), "PhysicsDirectBodyState3D", "set_constant_torque", 3460891852i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState3D), "PhysicsDirectBodyState3D", "get_constant_torque", 3360562783i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState3D), "PhysicsDirectBodyState3D", "set_sleep_state", 2586408642i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState3D), "PhysicsDirectBodyState3D", "is_sleeping", 36873697i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState3D), "PhysicsDirectBodyState3D", "set_collision_layer", 1286410249i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState3D), "PhysicsDirectBodyState3D", "get_collision_layer", 3905245786i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState3D), "PhysicsDirectBodyState3D", "set_collision_mask", 1286410249i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState3D), "PhysicsDirectBodyState3D", "get_collision_mask", 3905245786i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState3D), "PhysicsDirectBodyState3D", "get_contact_count", 3905245786i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState3D), "PhysicsDirectBodyState3D", "get_contact_local_position", 711720468i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState3D), "PhysicsDirectBodyState3D", "get_contact_local_normal", 711720468i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState3D), "PhysicsDirectBodyState3D", "get_contact_impulse", 711720468i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState3D), "PhysicsDirectBodyState3D", "get_contact_local_shape", 923996154i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState3D), "PhysicsDirectBodyState3D", "get_contact_local_velocity_at_position", 711720468i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState3D), "PhysicsDirectBodyState3D", "get_contact_collider", 495598643i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState3D), "PhysicsDirectBodyState3D", "get_contact_collider_position", 711720468i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState3D), "PhysicsDirectBodyState3D", "get_contact_collider_id", 923996154i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState3D), "PhysicsDirectBodyState3D", "get_contact_collider_object", 3332903315i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState3D), "PhysicsDirectBodyState3D", "get_contact_collider_shape", 923996154i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState3D), "PhysicsDirectBodyState3D", "get_contact_collider_velocity_at_position", 711720468i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState3D), "PhysicsDirectBodyState3D", "get_step", 1740695150i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState3D), "PhysicsDirectBodyState3D", "integrate_forces", 3218959716i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectBodyState3D), "PhysicsDirectBodyState3D", "get_space_state", 2069328350i64),);
+    
+}
+fn load_PhysicsDirectSpaceState2D_methods(function_pointers: &mut Vec < crate::ClassMethodBind >, string_names: &mut crate::StringCache, fetch_fptr: FetchFn,) {
+    let sname_PhysicsDirectSpaceState2D = string_names.fetch("PhysicsDirectSpaceState2D");
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectSpaceState2D), "PhysicsDirectSpaceState2D", "intersect_point", 2118456068i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectSpaceState2D), "PhysicsDirectSpaceState2D", "intersect_ray", 1590275562i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectSpaceState2D), "PhysicsDirectSpaceState2D", "intersect_shape", 2488867228i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectSpaceState2D), "PhysicsDirectSpaceState2D", "cast_motion", 711275086i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectSpaceState2D), "PhysicsDirectSpaceState2D", "collide_shape", 2488867228i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectSpaceState2D), "PhysicsDirectSpaceState2D", "get_rest_info", 2803666496i64),);
+    
+}
+fn load_PhysicsDirectSpaceState2DExtension_methods(function_pointers: &mut Vec < crate::ClassMethodBind >, string_names: &mut crate::StringCache, fetch_fptr: FetchFn,) {
+    let sname_PhysicsDirectSpaceState2DExtension = string_names.fetch("PhysicsDirectSpaceState2DExtension");
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectSpaceState2DExtension), "PhysicsDirectSpaceState2DExtension", "is_body_excluded_from_query", 4155700596i64),);
+    
+}
+fn load_PhysicsDirectSpaceState3D_methods(function_pointers: &mut Vec < crate::ClassMethodBind >, string_names: &mut crate::StringCache, fetch_fptr: FetchFn,) {
+    let sname_PhysicsDirectSpaceState3D = string_names.fetch("PhysicsDirectSpaceState3D");
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectSpaceState3D), "PhysicsDirectSpaceState3D", "intersect_point", 975173756i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectSpaceState3D), "PhysicsDirectSpaceState3D", "intersect_ray", 3957970750i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectSpaceState3D), "PhysicsDirectSpaceState3D", "intersect_shape", 3762137681i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectSpaceState3D), "PhysicsDirectSpaceState3D", "cast_motion", 1778757334i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectSpaceState3D), "PhysicsDirectSpaceState3D", "collide_shape", 3762137681i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectSpaceState3D), "PhysicsDirectSpaceState3D", "get_rest_info", 1376751592i64),);
+    
+}
+fn load_PhysicsDirectSpaceState3DExtension_methods(function_pointers: &mut Vec < crate::ClassMethodBind >, string_names: &mut crate::StringCache, fetch_fptr: FetchFn,) {
+    let sname_PhysicsDirectSpaceState3DExtension = string_names.fetch("PhysicsDirectSpaceState3DExtension");
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsDirectSpaceState3DExtension), "PhysicsDirectSpaceState3DExtension", "is_body_excluded_from_query", 4155700596i64),);
+    
+}
+fn load_PhysicsServer2D_methods(function_pointers: &mut Vec < crate::ClassMethodBind >, string_names: &mut crate::StringCache, fetch_fptr: FetchFn,) {
+    let sname_PhysicsServer2D = string_names.fetch("PhysicsServer2D");
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "world_boundary_shape_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "separation_ray_shape_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "segment_shape_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "circle_shape_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "rectangle_shape_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "capsule_shape_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "convex_polygon_shape_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "concave_polygon_shape_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "shape_set_data", 3175752987i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "shape_get_type", 1240598777i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "shape_get_data", 4171304767i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "space_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "space_set_active", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "space_is_active", 4155700596i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "space_set_param", 949194586i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "space_get_param", 874111783i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "space_get_direct_state", 3160173886i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "area_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "area_set_space", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "area_get_space", 3814569979i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "area_add_shape", 339056240i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "area_set_shape", 2310537182i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "area_set_shape_transform", 736082694i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "area_set_shape_disabled", 2658558584i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "area_get_shape_count", 2198884583i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "area_get_shape", 1066463050i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "area_get_shape_transform", 1324854622i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "area_remove_shape", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "area_clear_shapes", 2722037293i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "area_set_collision_layer", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "area_get_collision_layer", 2198884583i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "area_set_collision_mask", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "area_get_collision_mask", 2198884583i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "area_set_param", 1257146028i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "area_set_transform", 1246044741i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "area_get_param", 3047435120i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "area_get_transform", 213527486i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "area_attach_object_instance_id", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "area_get_object_instance_id", 2198884583i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "area_attach_canvas_instance_id", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "area_get_canvas_instance_id", 2198884583i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "area_set_monitor_callback", 3379118538i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "area_set_area_monitor_callback", 3379118538i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "area_set_monitorable", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "body_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "body_set_space", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "body_get_space", 3814569979i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "body_set_mode", 1658067650i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "body_get_mode", 3261702585i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "body_add_shape", 339056240i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "body_set_shape", 2310537182i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "body_set_shape_transform", 736082694i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "body_get_shape_count", 2198884583i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "body_get_shape", 1066463050i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "body_get_shape_transform", 1324854622i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "body_remove_shape", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "body_clear_shapes", 2722037293i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "body_set_shape_disabled", 2658558584i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "body_set_shape_as_one_way_collision", 2556489974i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "body_attach_object_instance_id", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "body_get_object_instance_id", 2198884583i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "body_attach_canvas_instance_id", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "body_get_canvas_instance_id", 2198884583i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "body_set_continuous_collision_detection_mode", 1882257015i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "body_get_continuous_collision_detection_mode", 2661282217i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "body_set_collision_layer", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "body_get_collision_layer", 2198884583i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "body_set_collision_mask", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "body_get_collision_mask", 2198884583i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "body_set_collision_priority", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "body_get_collision_priority", 866169185i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "body_set_param", 2715630609i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "body_get_param", 3208033526i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "body_reset_mass_properties", 2722037293i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "body_set_state", 1706355209i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "body_get_state", 4036367961i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "body_apply_central_impulse", 3201125042i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "body_apply_torque_impulse", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "body_apply_impulse", 205485391i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "body_apply_central_force", 3201125042i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "body_apply_force", 205485391i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "body_apply_torque", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "body_add_constant_central_force", 3201125042i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "body_add_constant_force", 205485391i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "body_add_constant_torque", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "body_set_constant_force", 3201125042i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "body_get_constant_force", 2440833711i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "body_set_constant_torque", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "body_get_constant_torque", 866169185i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "body_set_axis_velocity", 3201125042i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "body_add_collision_exception", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "body_remove_collision_exception", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "body_set_max_contacts_reported", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "body_get_max_contacts_reported", 2198884583i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "body_set_omit_force_integration", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "body_is_omitting_force_integration", 4155700596i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "body_set_state_sync_callback", 3379118538i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "body_set_force_integration_callback", 3059434249i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "body_test_motion", 1699844009i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "body_get_direct_state", 1191931871i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "joint_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "joint_clear", 2722037293i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "joint_set_param", 3972556514i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "joint_get_param", 4016448949i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "joint_disable_collisions_between_bodies", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "joint_is_disabled_collisions_between_bodies", 4155700596i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "joint_make_pin", 1612646186i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "joint_make_groove", 481430435i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "joint_make_damped_spring", 1994657646i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "pin_joint_set_flag", 3520002352i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "pin_joint_get_flag", 2647867364i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "pin_joint_set_param", 550574241i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "pin_joint_get_param", 348281383i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "damped_spring_joint_set_param", 220564071i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "damped_spring_joint_get_param", 2075871277i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "joint_get_type", 4262502231i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "free_rid", 2722037293i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "set_active", 2586408642i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2D), "PhysicsServer2D", "get_process_info", 576496006i64),);
+    
+}
+fn load_PhysicsServer2DExtension_methods(function_pointers: &mut Vec < crate::ClassMethodBind >, string_names: &mut crate::StringCache, fetch_fptr: FetchFn,) {
+    let sname_PhysicsServer2DExtension = string_names.fetch("PhysicsServer2DExtension");
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2DExtension), "PhysicsServer2DExtension", "body_test_motion_is_excluding_body", 4155700596i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2DExtension), "PhysicsServer2DExtension", "body_test_motion_is_excluding_object", 1116898809i64),);
+    
+}
+fn load_PhysicsServer2DManager_methods(function_pointers: &mut Vec < crate::ClassMethodBind >, string_names: &mut crate::StringCache, fetch_fptr: FetchFn,) {
+    let sname_PhysicsServer2DManager = string_names.fetch("PhysicsServer2DManager");
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2DManager), "PhysicsServer2DManager", "register_server", 2137474292i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer2DManager), "PhysicsServer2DManager", "set_default_server", 2956805083i64),);
+    
+}
+fn load_PhysicsServer3D_methods(function_pointers: &mut Vec < crate::ClassMethodBind >, string_names: &mut crate::StringCache, fetch_fptr: FetchFn,) {
+    let sname_PhysicsServer3D = string_names.fetch("PhysicsServer3D");
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "world_boundary_shape_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "separation_ray_shape_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "sphere_shape_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "box_shape_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "capsule_shape_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "cylinder_shape_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "convex_polygon_shape_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "concave_polygon_shape_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "heightmap_shape_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "custom_shape_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "shape_set_data", 3175752987i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "shape_set_margin", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "shape_get_type", 3418923367i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "shape_get_data", 4171304767i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "shape_get_margin", 866169185i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "space_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "space_set_active", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "space_is_active", 4155700596i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "space_set_param", 2406017470i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "space_get_param", 1523206731i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "space_get_direct_state", 2048616813i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "area_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "area_set_space", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "area_get_space", 3814569979i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "area_add_shape", 3711419014i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "area_set_shape", 2310537182i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "area_set_shape_transform", 675327471i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "area_set_shape_disabled", 2658558584i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "area_get_shape_count", 2198884583i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "area_get_shape", 1066463050i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "area_get_shape_transform", 1050775521i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "area_remove_shape", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "area_clear_shapes", 2722037293i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "area_set_collision_layer", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "area_get_collision_layer", 2198884583i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "area_set_collision_mask", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "area_get_collision_mask", 2198884583i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "area_set_param", 2980114638i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "area_set_transform", 3935195649i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "area_get_param", 890056067i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "area_get_transform", 1128465797i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "area_attach_object_instance_id", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "area_get_object_instance_id", 2198884583i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "area_set_monitor_callback", 3379118538i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "area_set_area_monitor_callback", 3379118538i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "area_set_monitorable", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "area_set_ray_pickable", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "body_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "body_set_space", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "body_get_space", 3814569979i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "body_set_mode", 606803466i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "body_get_mode", 2488819728i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "body_set_collision_layer", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "body_get_collision_layer", 2198884583i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "body_set_collision_mask", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "body_get_collision_mask", 2198884583i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "body_set_collision_priority", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "body_get_collision_priority", 866169185i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "body_add_shape", 3711419014i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "body_set_shape", 2310537182i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "body_set_shape_transform", 675327471i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "body_set_shape_disabled", 2658558584i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "body_get_shape_count", 2198884583i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "body_get_shape", 1066463050i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "body_get_shape_transform", 1050775521i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "body_remove_shape", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "body_clear_shapes", 2722037293i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "body_attach_object_instance_id", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "body_get_object_instance_id", 2198884583i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "body_set_enable_continuous_collision_detection", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "body_is_continuous_collision_detection_enabled", 4155700596i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "body_set_param", 910941953i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "body_get_param", 3385027841i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "body_reset_mass_properties", 2722037293i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "body_set_state", 599977762i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "body_get_state", 1850449534i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "body_apply_central_impulse", 3227306858i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "body_apply_impulse", 390416203i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "body_apply_torque_impulse", 3227306858i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "body_apply_central_force", 3227306858i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "body_apply_force", 390416203i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "body_apply_torque", 3227306858i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "body_add_constant_central_force", 3227306858i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "body_add_constant_force", 390416203i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "body_add_constant_torque", 3227306858i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "body_set_constant_force", 3227306858i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "body_get_constant_force", 531438156i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "body_set_constant_torque", 3227306858i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "body_get_constant_torque", 531438156i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "body_set_axis_velocity", 3227306858i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "body_set_axis_lock", 2020836892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "body_is_axis_locked", 587853580i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "body_add_collision_exception", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "body_remove_collision_exception", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "body_set_max_contacts_reported", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "body_get_max_contacts_reported", 2198884583i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "body_set_omit_force_integration", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "body_is_omitting_force_integration", 4155700596i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "body_set_state_sync_callback", 3379118538i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "body_set_force_integration_callback", 3059434249i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "body_set_ray_pickable", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "body_test_motion", 1944921792i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "body_get_direct_state", 3029727957i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "soft_body_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "soft_body_update_rendering_server", 2218179753i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "soft_body_set_space", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "soft_body_get_space", 3814569979i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "soft_body_set_mesh", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "soft_body_get_bounds", 974181306i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "soft_body_set_collision_layer", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "soft_body_get_collision_layer", 2198884583i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "soft_body_set_collision_mask", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "soft_body_get_collision_mask", 2198884583i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "soft_body_add_collision_exception", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "soft_body_remove_collision_exception", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "soft_body_set_state", 599977762i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "soft_body_get_state", 1850449534i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "soft_body_set_transform", 3935195649i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "soft_body_set_ray_pickable", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "soft_body_set_simulation_precision", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "soft_body_get_simulation_precision", 2198884583i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "soft_body_set_total_mass", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "soft_body_get_total_mass", 866169185i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "soft_body_set_linear_stiffness", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "soft_body_get_linear_stiffness", 866169185i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "soft_body_set_shrinking_factor", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "soft_body_get_shrinking_factor", 866169185i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "soft_body_set_pressure_coefficient", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "soft_body_get_pressure_coefficient", 866169185i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "soft_body_set_damping_coefficient", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "soft_body_get_damping_coefficient", 866169185i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "soft_body_set_drag_coefficient", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "soft_body_get_drag_coefficient", 866169185i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "soft_body_move_point", 831953689i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "soft_body_get_point_global_position", 3440143363i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "soft_body_remove_all_pinned_points", 2722037293i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "soft_body_pin_point", 2658558584i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "soft_body_is_point_pinned", 3120086654i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "soft_body_apply_point_impulse", 831953689i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "soft_body_apply_point_force", 831953689i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "soft_body_apply_central_impulse", 3227306858i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "soft_body_apply_central_force", 3227306858i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "joint_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "joint_clear", 2722037293i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "joint_make_pin", 4280171926i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "pin_joint_set_param", 810685294i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "pin_joint_get_param", 2817972347i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "pin_joint_set_local_a", 3227306858i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "pin_joint_get_local_a", 531438156i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "pin_joint_set_local_b", 3227306858i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "pin_joint_get_local_b", 531438156i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "joint_make_hinge", 1684107643i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "hinge_joint_set_param", 3165502333i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "hinge_joint_get_param", 2129207581i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "hinge_joint_set_flag", 1601626188i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "hinge_joint_get_flag", 4165147865i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "joint_make_slider", 1684107643i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "slider_joint_set_param", 2264833593i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "slider_joint_get_param", 3498644957i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "joint_make_cone_twist", 1684107643i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "cone_twist_joint_set_param", 808587618i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "cone_twist_joint_get_param", 1134789658i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "joint_get_type", 4290791900i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "joint_set_solver_priority", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "joint_get_solver_priority", 2198884583i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "joint_disable_collisions_between_bodies", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "joint_is_disabled_collisions_between_bodies", 4155700596i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "joint_make_generic_6dof", 1684107643i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "generic_6dof_joint_set_param", 2600081391i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "generic_6dof_joint_get_param", 467122058i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "generic_6dof_joint_set_flag", 3570926903i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "generic_6dof_joint_get_flag", 4158090196i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "free_rid", 2722037293i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "set_active", 2586408642i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3D), "PhysicsServer3D", "get_process_info", 1332958745i64),);
+    
+}
+fn load_PhysicsServer3DExtension_methods(function_pointers: &mut Vec < crate::ClassMethodBind >, string_names: &mut crate::StringCache, fetch_fptr: FetchFn,) {
+    let sname_PhysicsServer3DExtension = string_names.fetch("PhysicsServer3DExtension");
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3DExtension), "PhysicsServer3DExtension", "body_test_motion_is_excluding_body", 4155700596i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3DExtension), "PhysicsServer3DExtension", "body_test_motion_is_excluding_object", 1116898809i64),);
+    
+}
+fn load_PhysicsServer3DManager_methods(function_pointers: &mut Vec < crate::ClassMethodBind >, string_names: &mut crate::StringCache, fetch_fptr: FetchFn,) {
+    let sname_PhysicsServer3DManager = string_names.fetch("PhysicsServer3DManager");
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3DManager), "PhysicsServer3DManager", "register_server", 2137474292i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3DManager), "PhysicsServer3DManager", "set_default_server", 2956805083i64),);
+    
+}
+fn load_PhysicsServer3DRenderingServerHandler_methods(function_pointers: &mut Vec < crate::ClassMethodBind >, string_names: &mut crate::StringCache, fetch_fptr: FetchFn,) {
+    let sname_PhysicsServer3DRenderingServerHandler = string_names.fetch("PhysicsServer3DRenderingServerHandler");
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3DRenderingServerHandler), "PhysicsServer3DRenderingServerHandler", "set_vertex", 1530502735i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3DRenderingServerHandler), "PhysicsServer3DRenderingServerHandler", "set_normal", 1530502735i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_PhysicsServer3DRenderingServerHandler), "PhysicsServer3DRenderingServerHandler", "set_aabb", 259215842i64),);
+    
+}
+fn load_RenderData_methods(function_pointers: &mut Vec < crate::ClassMethodBind >, string_names: &mut crate::StringCache, fetch_fptr: FetchFn,) {
+    let sname_RenderData = string_names.fetch("RenderData");
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderData), "RenderData", "get_render_scene_buffers", 2793216201i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderData), "RenderData", "get_render_scene_data", 1288715698i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderData), "RenderData", "get_environment", 2944877500i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderData), "RenderData", "get_camera_attributes", 2944877500i64),);
+    
+}
+fn load_RenderSceneData_methods(function_pointers: &mut Vec < crate::ClassMethodBind >, string_names: &mut crate::StringCache, fetch_fptr: FetchFn,) {
+    let sname_RenderSceneData = string_names.fetch("RenderSceneData");
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderSceneData), "RenderSceneData", "get_cam_transform", 3229777777i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderSceneData), "RenderSceneData", "get_cam_projection", 2910717950i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderSceneData), "RenderSceneData", "get_view_count", 3905245786i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderSceneData), "RenderSceneData", "get_view_eye_offset", 711720468i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderSceneData), "RenderSceneData", "get_view_projection", 3179846605i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderSceneData), "RenderSceneData", "get_uniform_buffer", 2944877500i64),);
+    
+}
+fn load_RenderingServer_methods(function_pointers: &mut Vec < crate::ClassMethodBind >, string_names: &mut crate::StringCache, fetch_fptr: FetchFn,) {
+    let sname_RenderingServer = string_names.fetch("RenderingServer");
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "texture_2d_create", 2010018390i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "texture_2d_layered_create", 913689023i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "texture_3d_create", 4036838706i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "texture_proxy_create", 41030802i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "texture_create_from_native_handle", 1682977582i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "texture_2d_update", 999539803i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "texture_3d_update", 684822712i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "texture_proxy_update", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "texture_2d_placeholder_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "texture_2d_layered_placeholder_create", 1394585590i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "texture_3d_placeholder_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "texture_2d_get", 4206205781i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "texture_2d_layer_get", 2705440895i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "texture_3d_get", 2684255073i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "texture_replace", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "texture_set_size_override", 4288446313i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "texture_set_path", 2726140452i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "texture_get_path", 642473191i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "texture_get_format", 1932918979i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "texture_set_force_redraw_if_visible", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "texture_rd_create", 1434128712i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "texture_get_rd_texture", 2790148051i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "texture_get_native_handle", 1834114100i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "shader_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "shader_set_code", 2726140452i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "shader_set_path_hint", 2726140452i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "shader_get_code", 642473191i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "get_shader_parameter_list", 2684255073i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "shader_get_parameter_default", 2621281810i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "shader_set_default_texture_parameter", 4094001817i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "shader_get_default_texture_parameter", 1464608890i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "material_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "material_set_shader", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "material_set_param", 3477296213i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "material_get_param", 2621281810i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "material_set_render_priority", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "material_set_next_pass", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "mesh_create_from_surfaces", 4291747531i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "mesh_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "mesh_surface_get_format_offset", 2981368685i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "mesh_surface_get_format_vertex_stride", 3188363337i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "mesh_surface_get_format_normal_tangent_stride", 3188363337i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "mesh_surface_get_format_attribute_stride", 3188363337i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "mesh_surface_get_format_skin_stride", 3188363337i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "mesh_surface_get_format_index_stride", 3188363337i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "mesh_add_surface", 1217542888i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "mesh_add_surface_from_arrays", 2342446560i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "mesh_get_blend_shape_count", 2198884583i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "mesh_set_blend_shape_mode", 1294662092i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "mesh_get_blend_shape_mode", 4282291819i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "mesh_surface_set_material", 2310537182i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "mesh_surface_get_material", 1066463050i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "mesh_get_surface", 186674697i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "mesh_surface_get_arrays", 1778388067i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "mesh_surface_get_blend_shape_arrays", 1778388067i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "mesh_get_surface_count", 2198884583i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "mesh_set_custom_aabb", 3696536120i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "mesh_get_custom_aabb", 974181306i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "mesh_surface_remove", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "mesh_clear", 2722037293i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "mesh_surface_update_vertex_region", 2900195149i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "mesh_surface_update_attribute_region", 2900195149i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "mesh_surface_update_skin_region", 2900195149i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "mesh_surface_update_index_region", 2900195149i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "mesh_set_shadow_mesh", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "multimesh_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "multimesh_allocate_data", 557240154i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "multimesh_get_instance_count", 2198884583i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "multimesh_set_mesh", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "multimesh_instance_set_transform", 675327471i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "multimesh_instance_set_transform_2d", 736082694i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "multimesh_instance_set_color", 176975443i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "multimesh_instance_set_custom_data", 176975443i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "multimesh_get_mesh", 3814569979i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "multimesh_get_aabb", 974181306i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "multimesh_set_custom_aabb", 3696536120i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "multimesh_get_custom_aabb", 974181306i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "multimesh_instance_get_transform", 1050775521i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "multimesh_instance_get_transform_2d", 1324854622i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "multimesh_instance_get_color", 2946315076i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "multimesh_instance_get_custom_data", 2946315076i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "multimesh_set_visible_instances", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "multimesh_get_visible_instances", 2198884583i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "multimesh_set_buffer", 2960552364i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "multimesh_get_command_buffer_rd_rid", 3814569979i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "multimesh_get_buffer_rd_rid", 3814569979i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "multimesh_get_buffer", 3964669176i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "multimesh_set_buffer_interpolated", 659844711i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "multimesh_set_physics_interpolated", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "multimesh_set_physics_interpolation_quality", 3934808223i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "multimesh_instance_reset_physics_interpolation", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "skeleton_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "skeleton_allocate_data", 1904426712i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "skeleton_get_bone_count", 2198884583i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "skeleton_bone_set_transform", 675327471i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "skeleton_bone_get_transform", 1050775521i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "skeleton_bone_set_transform_2d", 736082694i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "skeleton_bone_get_transform_2d", 1324854622i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "skeleton_set_base_transform_2d", 1246044741i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "directional_light_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "omni_light_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "spot_light_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "light_set_color", 2948539648i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "light_set_param", 501936875i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "light_set_shadow", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "light_set_projector", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "light_set_negative", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "light_set_cull_mask", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "light_set_distance_fade", 1622292572i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "light_set_reverse_cull_face_mode", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "light_set_shadow_caster_mask", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "light_set_bake_mode", 1048525260i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "light_set_max_sdfgi_cascade", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "light_omni_set_shadow_mode", 2552677200i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "light_directional_set_shadow_mode", 380462970i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "light_directional_set_blend_splits", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "light_directional_set_sky_mode", 2559740754i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "light_projectors_set_filter", 43944325i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "lightmaps_set_bicubic_filter", 2586408642i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "positional_soft_shadow_filter_set_quality", 3613045266i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "directional_soft_shadow_filter_set_quality", 3613045266i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "directional_shadow_atlas_set_size", 300928843i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "reflection_probe_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "reflection_probe_set_update_mode", 3853670147i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "reflection_probe_set_intensity", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "reflection_probe_set_blend_distance", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "reflection_probe_set_ambient_mode", 184163074i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "reflection_probe_set_ambient_color", 2948539648i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "reflection_probe_set_ambient_energy", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "reflection_probe_set_max_distance", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "reflection_probe_set_size", 3227306858i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "reflection_probe_set_origin_offset", 3227306858i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "reflection_probe_set_as_interior", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "reflection_probe_set_enable_box_projection", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "reflection_probe_set_enable_shadows", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "reflection_probe_set_cull_mask", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "reflection_probe_set_reflection_mask", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "reflection_probe_set_resolution", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "reflection_probe_set_mesh_lod_threshold", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "decal_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "decal_set_size", 3227306858i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "decal_set_texture", 3953344054i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "decal_set_emission_energy", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "decal_set_albedo_mix", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "decal_set_modulate", 2948539648i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "decal_set_cull_mask", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "decal_set_distance_fade", 2972769666i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "decal_set_fade", 2513314492i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "decal_set_normal_fade", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "decals_set_filter", 3519875702i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "gi_set_use_half_resolution", 2586408642i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "voxel_gi_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "voxel_gi_allocate_data", 4108223027i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "voxel_gi_get_octree_size", 2607699645i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "voxel_gi_get_octree_cells", 3348040486i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "voxel_gi_get_data_cells", 3348040486i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "voxel_gi_get_distance_field", 3348040486i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "voxel_gi_get_level_counts", 788230395i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "voxel_gi_get_to_cell_xform", 1128465797i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "voxel_gi_set_dynamic_range", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "voxel_gi_set_propagation", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "voxel_gi_set_energy", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "voxel_gi_set_baked_exposure_normalization", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "voxel_gi_set_bias", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "voxel_gi_set_normal_bias", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "voxel_gi_set_interior", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "voxel_gi_set_use_two_bounces", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "voxel_gi_set_quality", 1538689978i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "lightmap_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "lightmap_set_textures", 2646464759i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "lightmap_set_probe_bounds", 3696536120i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "lightmap_set_probe_interior", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "lightmap_set_probe_capture_data", 3217845880i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "lightmap_get_probe_capture_points", 808965560i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "lightmap_get_probe_capture_sh", 1569415609i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "lightmap_get_probe_capture_tetrahedra", 788230395i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "lightmap_get_probe_capture_bsp_tree", 788230395i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "lightmap_set_baked_exposure_normalization", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "lightmap_set_probe_capture_update_speed", 373806689i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "particles_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "particles_set_mode", 3492270028i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "particles_set_emitting", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "particles_get_emitting", 3521089500i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "particles_set_amount", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "particles_set_amount_ratio", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "particles_set_lifetime", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "particles_set_one_shot", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "particles_set_pre_process_time", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "particles_request_process_time", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "particles_set_explosiveness_ratio", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "particles_set_randomness_ratio", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "particles_set_interp_to_end", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "particles_set_emitter_velocity", 3227306858i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "particles_set_custom_aabb", 3696536120i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "particles_set_speed_scale", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "particles_set_use_local_coordinates", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "particles_set_process_material", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "particles_set_fixed_fps", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "particles_set_interpolate", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "particles_set_fractional_delta", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "particles_set_collision_base_size", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "particles_set_transform_align", 3264971368i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "particles_set_trails", 2010054925i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "particles_set_trail_bind_poses", 684822712i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "particles_is_inactive", 3521089500i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "particles_request_process", 2722037293i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "particles_restart", 2722037293i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "particles_set_subemitter", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "particles_emit", 4043136117i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "particles_set_draw_order", 935028487i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "particles_set_draw_passes", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "particles_set_draw_pass_mesh", 2310537182i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "particles_get_current_aabb", 3952830260i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "particles_set_emission_transform", 3935195649i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "particles_collision_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "particles_collision_set_collision_type", 1497044930i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "particles_collision_set_cull_mask", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "particles_collision_set_sphere_radius", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "particles_collision_set_box_extents", 3227306858i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "particles_collision_set_attractor_strength", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "particles_collision_set_attractor_directionality", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "particles_collision_set_attractor_attenuation", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "particles_collision_set_field_texture", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "particles_collision_height_field_update", 2722037293i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "particles_collision_set_height_field_resolution", 962977297i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "particles_collision_set_height_field_mask", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "fog_volume_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "fog_volume_set_shape", 3818703106i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "fog_volume_set_size", 3227306858i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "fog_volume_set_material", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "visibility_notifier_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "visibility_notifier_set_aabb", 3696536120i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "visibility_notifier_set_callbacks", 2689735388i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "occluder_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "occluder_set_mesh", 3854404263i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "camera_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "camera_set_perspective", 157498339i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "camera_set_orthogonal", 157498339i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "camera_set_frustum", 1889878953i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "camera_set_transform", 3935195649i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "camera_set_cull_mask", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "camera_set_environment", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "camera_set_camera_attributes", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "camera_set_compositor", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "camera_set_use_vertical_aspect", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "viewport_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "viewport_set_use_xr", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "viewport_set_size", 4288446313i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "viewport_set_active", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "viewport_set_parent_viewport", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "viewport_attach_to_screen", 1062245816i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "viewport_set_render_direct_to_screen", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "viewport_set_canvas_cull_mask", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "viewport_set_scaling_3d_mode", 2386524376i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "viewport_set_scaling_3d_scale", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "viewport_set_fsr_sharpness", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "viewport_set_texture_mipmap_bias", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "viewport_set_anisotropic_filtering_level", 3953214029i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "viewport_set_update_mode", 3161116010i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "viewport_get_update_mode", 3803901472i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "viewport_set_clear_mode", 3628367896i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "viewport_get_render_target", 3814569979i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "viewport_get_texture", 3814569979i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "viewport_set_disable_3d", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "viewport_set_disable_2d", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "viewport_set_environment_mode", 2196892182i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "viewport_attach_camera", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "viewport_set_scenario", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "viewport_attach_canvas", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "viewport_remove_canvas", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "viewport_set_snap_2d_transforms_to_pixel", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "viewport_set_snap_2d_vertices_to_pixel", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "viewport_set_default_canvas_item_texture_filter", 1155129294i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "viewport_set_default_canvas_item_texture_repeat", 1652956681i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "viewport_set_canvas_transform", 3608606053i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "viewport_set_canvas_stacking", 3713930247i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "viewport_set_transparent_background", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "viewport_set_global_canvas_transform", 1246044741i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "viewport_set_sdf_oversize_and_scale", 1329198632i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "viewport_set_positional_shadow_atlas_size", 1904426712i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "viewport_set_positional_shadow_atlas_quadrant_subdivision", 4288446313i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "viewport_set_msaa_3d", 3764433340i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "viewport_set_msaa_2d", 3764433340i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "viewport_set_use_hdr_2d", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "viewport_set_screen_space_aa", 1447279591i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "viewport_set_use_taa", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "viewport_set_use_debanding", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "viewport_set_use_occlusion_culling", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "viewport_set_occlusion_rays_per_thread", 1286410249i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "viewport_set_occlusion_culling_build_quality", 2069725696i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "viewport_get_render_info", 2041262392i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "viewport_set_debug_draw", 2089420930i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "viewport_set_measure_render_time", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "viewport_get_measured_render_time_cpu", 866169185i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "viewport_get_measured_render_time_gpu", 866169185i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "viewport_set_vrs_mode", 398809874i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "viewport_set_vrs_update_mode", 2696154815i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "viewport_set_vrs_texture", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "sky_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "sky_set_radiance_size", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "sky_set_mode", 3279019937i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "sky_set_material", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "sky_bake_panorama", 3875285818i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "compositor_effect_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "compositor_effect_set_enabled", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "compositor_effect_set_callback", 487412485i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "compositor_effect_set_flag", 3659527075i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "compositor_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "compositor_set_compositor_effects", 684822712i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "environment_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "environment_set_background", 3937328877i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "environment_set_camera_id", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "environment_set_sky", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "environment_set_sky_custom_fov", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "environment_set_sky_orientation", 1735850857i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "environment_set_bg_color", 2948539648i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "environment_set_bg_energy", 2513314492i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "environment_set_canvas_max_layer", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "environment_set_ambient_light", 1214961493i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "environment_set_glow", 2421724940i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "environment_set_tonemap", 2914312638i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "environment_set_adjustment", 876799838i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "environment_set_ssr", 3607294374i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "environment_set_ssao", 3994732740i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "environment_set_fog", 105051629i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "environment_set_fog_depth", 157498339i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "environment_set_sdfgi", 3519144388i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "environment_set_volumetric_fog", 1553633833i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "environment_glow_set_use_bicubic_upscale", 2586408642i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "environment_set_ssr_roughness_quality", 1190026788i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "environment_set_ssao_quality", 189753569i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "environment_set_ssil_quality", 1713836683i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "environment_set_sdfgi_ray_count", 340137951i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "environment_set_sdfgi_frames_to_converge", 2182444374i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "environment_set_sdfgi_frames_to_update_light", 1251144068i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "environment_set_volumetric_fog_volume_size", 3937882851i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "environment_set_volumetric_fog_filter_active", 2586408642i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "environment_bake_panorama", 2452908646i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "screen_space_roughness_limiter_set_active", 916716790i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "sub_surface_scattering_set_quality", 64571803i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "sub_surface_scattering_set_scale", 1017552074i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "camera_attributes_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "camera_attributes_set_dof_blur_quality", 2220136795i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "camera_attributes_set_dof_blur_bokeh_shape", 1205058394i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "camera_attributes_set_dof_blur", 316272616i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "camera_attributes_set_exposure", 2513314492i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "camera_attributes_set_auto_exposure", 4266986332i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "scenario_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "scenario_set_environment", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "scenario_set_fallback_environment", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "scenario_set_camera_attributes", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "scenario_set_compositor", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "instance_create2", 746547085i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "instance_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "instance_set_base", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "instance_set_scenario", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "instance_set_layer_mask", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "instance_set_pivot_data", 1280615259i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "instance_set_transform", 3935195649i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "instance_attach_object_instance_id", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "instance_set_blend_shape_weight", 1892459533i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "instance_set_surface_override_material", 2310537182i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "instance_set_visible", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "instance_geometry_set_transparency", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "instance_teleport", 2722037293i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "instance_set_custom_aabb", 3696536120i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "instance_attach_skeleton", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "instance_set_extra_visibility_margin", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "instance_set_visibility_parent", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "instance_set_ignore_culling", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "instance_geometry_set_flag", 1014989537i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "instance_geometry_set_cast_shadows_setting", 3768836020i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "instance_geometry_set_material_override", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "instance_geometry_set_material_overlay", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "instance_geometry_set_visibility_range", 4263925858i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "instance_geometry_set_lightmap", 536974962i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "instance_geometry_set_lod_bias", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "instance_geometry_set_shader_parameter", 3477296213i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "instance_geometry_get_shader_parameter", 2621281810i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "instance_geometry_get_shader_parameter_default_value", 2621281810i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "instance_geometry_get_shader_parameter_list", 2684255073i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "instances_cull_aabb", 2570105777i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "instances_cull_ray", 2208759584i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "instances_cull_convex", 2488539944i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "bake_render_uv2", 1904608558i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_set_item_mirroring", 2343975398i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_set_item_repeat", 1739512717i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_set_modulate", 2948539648i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_set_disable_scale", 2586408642i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_texture_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_texture_set_channel", 3822119138i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_texture_set_shading_parameters", 2124967469i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_texture_set_texture_filter", 1155129294i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_texture_set_texture_repeat", 1652956681i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_item_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_item_set_parent", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_item_set_default_texture_filter", 1155129294i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_item_set_default_texture_repeat", 1652956681i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_item_set_visible", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_item_set_light_mask", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_item_set_visibility_layer", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_item_set_transform", 1246044741i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_item_set_clip", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_item_set_distance_field_mode", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_item_set_custom_rect", 1333997032i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_item_set_modulate", 2948539648i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_item_set_self_modulate", 2948539648i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_item_set_draw_behind_parent", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_item_set_interpolated", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_item_reset_physics_interpolation", 2722037293i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_item_transform_physics_interpolation", 1246044741i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_item_add_line", 1819681853i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_item_add_polyline", 3098767073i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_item_add_multiline", 3098767073i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_item_add_rect", 3523446176i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_item_add_circle", 333077949i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_item_add_texture_rect", 324864032i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_item_add_msdf_texture_rect_region", 97408773i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_item_add_lcd_texture_rect_region", 359793297i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_item_add_texture_rect_region", 485157892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_item_add_nine_patch", 389957886i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_item_add_primitive", 3731601077i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_item_add_polygon", 3580000528i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_item_add_triangle_array", 660261329i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_item_add_mesh", 316450961i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_item_add_multimesh", 2131855138i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_item_add_particles", 2575754278i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_item_add_set_transform", 1246044741i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_item_add_clip_ignore", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_item_add_animation_slice", 2646834499i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_item_set_sort_children_by_y", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_item_set_z_index", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_item_set_z_as_relative_to_parent", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_item_set_copy_to_backbuffer", 2429202503i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_item_attach_skeleton", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_item_clear", 2722037293i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_item_set_draw_index", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_item_set_material", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_item_set_use_parent_material", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_item_set_instance_shader_parameter", 3477296213i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_item_get_instance_shader_parameter", 2621281810i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_item_get_instance_shader_parameter_default_value", 2621281810i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_item_get_instance_shader_parameter_list", 2684255073i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_item_set_visibility_notifier", 3568945579i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_item_set_canvas_group_mode", 3973586316i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "debug_canvas_item_get_rect", 624227424i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_light_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_light_attach_to_canvas", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_light_set_enabled", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_light_set_texture_scale", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_light_set_transform", 1246044741i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_light_set_texture", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_light_set_texture_offset", 3201125042i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_light_set_color", 2948539648i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_light_set_height", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_light_set_energy", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_light_set_z_range", 4288446313i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_light_set_layer_range", 4288446313i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_light_set_item_cull_mask", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_light_set_item_shadow_cull_mask", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_light_set_mode", 2957564891i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_light_set_shadow_enabled", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_light_set_shadow_filter", 393119659i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_light_set_shadow_color", 2948539648i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_light_set_shadow_smooth", 1794382983i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_light_set_blend_mode", 804895945i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_light_set_interpolated", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_light_reset_physics_interpolation", 2722037293i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_light_transform_physics_interpolation", 1246044741i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_light_occluder_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_light_occluder_attach_to_canvas", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_light_occluder_set_enabled", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_light_occluder_set_polygon", 395945892i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_light_occluder_set_as_sdf_collision", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_light_occluder_set_transform", 1246044741i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_light_occluder_set_light_mask", 3411492887i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_light_occluder_set_interpolated", 1265174801i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_light_occluder_reset_physics_interpolation", 2722037293i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_light_occluder_transform_physics_interpolation", 1246044741i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_occluder_polygon_create", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_occluder_polygon_set_shape", 2103882027i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_occluder_polygon_set_cull_mode", 1839404663i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "canvas_set_shadow_texture_size", 1286410249i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "global_shader_parameter_add", 463390080i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "global_shader_parameter_remove", 3304788590i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "global_shader_parameter_get_list", 3995934104i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "global_shader_parameter_set", 3776071444i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "global_shader_parameter_set_override", 3776071444i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "global_shader_parameter_get", 2760726917i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "global_shader_parameter_get_type", 1601414142i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "free_rid", 2722037293i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "request_frame_drawn_callback", 1611583062i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "has_changed", 36873697i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "get_rendering_info", 3763192241i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "get_video_adapter_name", 201670096i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "get_video_adapter_vendor", 201670096i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "get_video_adapter_type", 3099547011i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "get_video_adapter_api_version", 201670096i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "get_current_rendering_driver_name", 201670096i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "get_current_rendering_method", 201670096i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "make_sphere_mesh", 2251015897i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "get_test_cube", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "get_test_texture", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "get_white_texture", 529393457i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "set_boot_image", 3759744527i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "get_default_clear_color", 3200896285i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "set_default_clear_color", 2920490490i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "has_os_feature", 3927539163i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "set_debug_generate_wireframes", 2586408642i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "is_render_loop_enabled", 36873697i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "set_render_loop_enabled", 2586408642i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "get_frame_setup_time_cpu", 1740695150i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "force_sync", 3218959716i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "force_draw", 1076185472i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "get_rendering_device", 1405107940i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "create_local_rendering_device", 1405107940i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "is_on_render_thread", 2240911060i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "call_on_render_thread", 1611583062i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_RenderingServer), "RenderingServer", "has_feature", 598462696i64),);
+    
+}
+fn load_TranslationServer_methods(function_pointers: &mut Vec < crate::ClassMethodBind >, string_names: &mut crate::StringCache, fetch_fptr: FetchFn,) {
+    let sname_TranslationServer = string_names.fetch("TranslationServer");
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_TranslationServer), "TranslationServer", "set_locale", 83702148i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_TranslationServer), "TranslationServer", "get_locale", 201670096i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_TranslationServer), "TranslationServer", "get_tool_locale", 2841200299i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_TranslationServer), "TranslationServer", "compare_locales", 2878152881i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_TranslationServer), "TranslationServer", "standardize_locale", 4216441673i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_TranslationServer), "TranslationServer", "get_all_languages", 1139954409i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_TranslationServer), "TranslationServer", "get_language_name", 3135753539i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_TranslationServer), "TranslationServer", "get_all_scripts", 1139954409i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_TranslationServer), "TranslationServer", "get_script_name", 3135753539i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_TranslationServer), "TranslationServer", "get_all_countries", 1139954409i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_TranslationServer), "TranslationServer", "get_country_name", 3135753539i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_TranslationServer), "TranslationServer", "get_locale_name", 3135753539i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_TranslationServer), "TranslationServer", "translate", 1829228469i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_TranslationServer), "TranslationServer", "translate_plural", 229954002i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_TranslationServer), "TranslationServer", "add_translation", 1466479800i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_TranslationServer), "TranslationServer", "remove_translation", 1466479800i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_TranslationServer), "TranslationServer", "get_translation_object", 2065240175i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_TranslationServer), "TranslationServer", "has_domain", 2619796661i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_TranslationServer), "TranslationServer", "get_or_add_domain", 397200075i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_TranslationServer), "TranslationServer", "remove_domain", 3304788590i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_TranslationServer), "TranslationServer", "clear", 3218959716i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_TranslationServer), "TranslationServer", "get_loaded_locales", 1139954409i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_TranslationServer), "TranslationServer", "is_pseudolocalization_enabled", 36873697i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_TranslationServer), "TranslationServer", "set_pseudolocalization_enabled", 2586408642i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_TranslationServer), "TranslationServer", "reload_pseudolocalization", 3218959716i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_TranslationServer), "TranslationServer", "pseudolocalize", 1965194235i64),);
+    
+}
+fn load_XRServer_methods(function_pointers: &mut Vec < crate::ClassMethodBind >, string_names: &mut crate::StringCache, fetch_fptr: FetchFn,) {
+    let sname_XRServer = string_names.fetch("XRServer");
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_XRServer), "XRServer", "get_world_scale", 1740695150i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_XRServer), "XRServer", "set_world_scale", 373806689i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_XRServer), "XRServer", "get_world_origin", 3229777777i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_XRServer), "XRServer", "set_world_origin", 2952846383i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_XRServer), "XRServer", "get_reference_frame", 3229777777i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_XRServer), "XRServer", "clear_reference_frame", 3218959716i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_XRServer), "XRServer", "center_on_hmd", 1450904707i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_XRServer), "XRServer", "get_hmd_transform", 4183770049i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_XRServer), "XRServer", "set_camera_locked_to_origin", 2586408642i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_XRServer), "XRServer", "is_camera_locked_to_origin", 36873697i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_XRServer), "XRServer", "add_interface", 1898711491i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_XRServer), "XRServer", "get_interface_count", 3905245786i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_XRServer), "XRServer", "remove_interface", 1898711491i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_XRServer), "XRServer", "get_interface", 4237347919i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_XRServer), "XRServer", "get_interfaces", 3995934104i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_XRServer), "XRServer", "find_interface", 1395192955i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_XRServer), "XRServer", "add_tracker", 684804553i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_XRServer), "XRServer", "remove_tracker", 684804553i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_XRServer), "XRServer", "get_trackers", 3554694381i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_XRServer), "XRServer", "get_tracker", 147382240i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_XRServer), "XRServer", "get_primary_interface", 2143545064i64),);
+    function_pointers.push(crate::load_class_method(fetch_fptr, string_names, Some(sname_XRServer), "XRServer", "set_primary_interface", 1898711491i64),);
+    
+}
\ No newline at end of file